@@ -47,6 +47,11 @@ import operator
 import os
 import sys
 
+try:
+    from _gettext import parse_mo as _parse_mo
+except ImportError:
+    _parse_mo = None
+
 
 __all__ = ['NullTranslations', 'GNUTranslations', 'Catalog',
            'bindtextdomain', 'find', 'translation', 'install',
@@ -346,6 +351,56 @@ class GNUTranslations(NullTranslations):
         """Returns a tuple of major version, minor version"""
         return (version >> 16, version & 0xffff)
 
+    def _add_entry(self, msg, tmsg):
+        """Add a single raw (msgid, msgstr) entry to the catalog, handling
+        metadata, plural forms and charset conversion."""
+        catalog = self._catalog
+        # See if we're looking at GNU .mo conventions for metadata
+        if len(msg) == 0:
+            # Catalog description
+            lastk = None
+            for b_item in tmsg.split(b'\n'):
+                item = b_item.decode().strip()
+                if not item:
+                    continue
+                # Skip over comment lines:
+                if item.startswith('#-#-#-#-#') and item.endswith('#-#-#-#-#'):
+                    continue
+                k = v = None
+                if ':' in item:
+                    k, v = item.split(':', 1)
+                    k = k.strip().lower()
+                    v = v.strip()
+                    self._info[k] = v
+                    lastk = k
+                elif lastk:
+                    self._info[lastk] += '\n' + item
+                if k == 'content-type':
+                    self._charset = v.split('charset=')[1]
+                elif k == 'plural-forms':
+                    v = v.split(';')
+                    plural = v[1].split('plural=')[1]
+                    self.plural = c2py(plural)
+        # Note: we unconditionally convert both msgids and msgstrs to
+        # Unicode using the character encoding specified in the charset
+        # parameter of the Content-Type header.  The gettext documentation
+        # strongly encourages msgids to be us-ascii, but some applications
+        # require alternative encodings (e.g. Zope's ZCML and ZPT).  For
+        # traditional gettext applications, the msgid conversion will
+        # cause no problems since us-ascii should always be a subset of
+        # the charset encoding.  We may want to fall back to 8-bit msgids
+        # if the Unicode conversion fails.
+        charset = self._charset or 'ascii'
+        if b'\x00' in msg:
+            # Plural forms
+            msgid1, msgid2 = msg.split(b'\x00')
+            tmsg = tmsg.split(b'\x00')
+            msgid1 = str(msgid1, charset)
+            for i, x in enumerate(tmsg):
+                catalog[(msgid1, i)] = str(x, charset)
+        else:
+            catalog[str(msg, charset)] = str(tmsg, charset)
+
     def _parse(self, fp):
         """Override this method to support alternative .mo formats."""
         # Delay struct import for speeding up gettext import when .mo files
@@ -354,10 +409,19 @@ class GNUTranslations(NullTranslations):
         filename = getattr(fp, 'name', '')
         # Parse the .mo file header, which consists of 5 little endian 32
         # bit words.
-        self._catalog = catalog = {}
+        self._catalog = {}
         self.plural = lambda n: int(n != 1) # germanic plural by default
         buf = fp.read()
         buflen = len(buf)
+        if _parse_mo is not None:
+            # Native catalog reader; raises ValueError for malformed files.
+            try:
+                entries = _parse_mo(buf)
+            except ValueError as exc:
+                raise OSError(0, str(exc), filename) from None
+            for msg, tmsg in entries:
+                self._add_entry(msg, tmsg)
+            return
         # Are we big endian or little endian?
         magic = unpack('<I', buf[:4])[0]
         if magic == self.LE_MAGIC:
@@ -386,51 +450,7 @@ class GNUTranslations(NullTranslations):
                 tmsg = buf[toff:tend]
             else:
                 raise OSError(0, 'File is corrupt', filename)
-            # See if we're looking at GNU .mo conventions for metadata
-            if mlen == 0:
-                # Catalog description
-                lastk = None
-                for b_item in tmsg.split(b'\n'):
-                    item = b_item.decode().strip()
-                    if not item:
-                        continue
-                    # Skip over comment lines:
-                    if item.startswith('#-#-#-#-#') and item.endswith('#-#-#-#-#'):
-                        continue
-                    k = v = None
-                    if ':' in item:
-                        k, v = item.split(':', 1)
-                        k = k.strip().lower()
-                        v = v.strip()
-                        self._info[k] = v
-                        lastk = k
-                    elif lastk:
-                        self._info[lastk] += '\n' + item
-                    if k == 'content-type':
-                        self._charset = v.split('charset=')[1]
-                    elif k == 'plural-forms':
-                        v = v.split(';')
-                        plural = v[1].split('plural=')[1]
-                        self.plural = c2py(plural)
-            # Note: we unconditionally convert both msgids and msgstrs to
-            # Unicode using the character encoding specified in the charset
-            # parameter of the Content-Type header.  The gettext documentation
-            # strongly encourages msgids to be us-ascii, but some applications
-            # require alternative encodings (e.g. Zope's ZCML and ZPT).  For
-            # traditional gettext applications, the msgid conversion will
-            # cause no problems since us-ascii should always be a subset of
-            # the charset encoding.  We may want to fall back to 8-bit msgids
-            # if the Unicode conversion fails.
-            charset = self._charset or 'ascii'
-            if b'\x00' in msg:
-                # Plural forms
-                msgid1, msgid2 = msg.split(b'\x00')
-                tmsg = tmsg.split(b'\x00')
-                msgid1 = str(msgid1, charset)
-                for i, x in enumerate(tmsg):
-                    catalog[(msgid1, i)] = str(x, charset)
-            else:
-                catalog[str(msg, charset)] = str(tmsg, charset)
+            self._add_entry(msg, tmsg)
             # advance to next entry in the seek tables
             masteridx += 8
             transidx += 8
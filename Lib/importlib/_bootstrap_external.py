@@ -232,6 +232,13 @@ if _MS_WINDOWS:
 
 EXTENSION_SUFFIXES = _imp.extension_suffixes()
 
+try:
+    # RustPython extension: stat + listdir a directory in one native call,
+    # used by FileFinder to refresh its cache.
+    _dir_snapshot = _imp._dir_snapshot
+except AttributeError:
+    _dir_snapshot = None
+
 BYTECODE_SUFFIXES = ['.pyc']
 # Deprecated.
 DEBUG_BYTECODE_SUFFIXES = OPTIMIZED_BYTECODE_SUFFIXES = BYTECODE_SUFFIXES
@@ -1361,13 +1368,22 @@ class FileFinder:
         """
         is_namespace = False
         tail_module = fullname.rpartition('.')[2]
-        try:
-            mtime = _path_stat(self.path or _os.getcwd()).st_mtime
-        except OSError:
-            mtime = -1
-        if mtime != self._path_mtime:
-            self._fill_cache()
-            self._path_mtime = mtime
+        if _dir_snapshot is not None:
+            # contents is None when the directory mtime still matches the
+            # cached one, so an up-to-date cache costs a single stat call.
+            mtime, contents = _dir_snapshot(self.path or _os.getcwd(),
+                                            self._path_mtime)
+            if contents is not None:
+                self._fill_cache(contents)
+                self._path_mtime = mtime
+        else:
+            try:
+                mtime = _path_stat(self.path or _os.getcwd()).st_mtime
+            except OSError:
+                mtime = -1
+            if mtime != self._path_mtime:
+                self._fill_cache()
+                self._path_mtime = mtime
         # tail_module keeps the original casing, for __file__ and friends
         if _relax_case():
             cache = self._relaxed_path_cache
@@ -1405,15 +1421,16 @@ class FileFinder:
             return spec
         return None
 
-    def _fill_cache(self):
+    def _fill_cache(self, contents=None):
         """Fill the cache of potential modules and packages for this directory."""
-        path = self.path
-        try:
-            contents = _os.listdir(path or _os.getcwd())
-        except (FileNotFoundError, PermissionError, NotADirectoryError):
-            # Directory has either been removed, turned into a file, or made
-            # unreadable.
-            contents = []
+        if contents is None:
+            path = self.path
+            try:
+                contents = _os.listdir(path or _os.getcwd())
+            except (FileNotFoundError, PermissionError, NotADirectoryError):
+                # Directory has either been removed, turned into a file, or made
+                # unreadable.
+                contents = []
         # We store two cached versions, to handle runtime changes of the
         # PYTHONCASEOK environment variable.
         if not sys.platform.startswith('win'):
@@ -158,6 +158,12 @@ def dump(obj, fp, *, skipkeys=False, ensure_ascii=True, check_circular=True,
     If *sort_keys* is true (default: ``False``), then the output of
     dictionaries will be sorted by key.
 
+    If *fast* is true and all other arguments are left at their defaults,
+    a native serializer is used for trees of plain ``dict``, ``list``,
+    ``tuple``, ``str``, ``int``, ``float``, ``bool`` and ``None`` values.
+    The output is identical to the regular encoder; unsupported inputs
+    silently fall back to it.
+
     To use a custom ``JSONEncoder`` subclass (e.g. one that overrides the
     ``.default()`` method to serialize additional types), specify it with
     the ``cls`` kwarg; otherwise ``JSONEncoder`` is used.
@@ -184,7 +190,7 @@ def dump(obj, fp, *, skipkeys=False, ensure_ascii=True, check_circular=True,
 
 def dumps(obj, *, skipkeys=False, ensure_ascii=True, check_circular=True,
         allow_nan=True, cls=None, indent=None, separators=None,
-        default=None, sort_keys=False, **kw):
+        default=None, sort_keys=False, fast=False, **kw):
     """Serialize ``obj`` to a JSON formatted ``str``.
 
     If ``skipkeys`` is true then ``dict`` keys that are not basic types
@@ -232,6 +238,13 @@ def dumps(obj, *, skipkeys=False, ensure_ascii=True, check_circular=True,
         check_circular and allow_nan and
         cls is None and indent is None and separators is None and
         default is None and not sort_keys and not kw):
+        if fast and _dumps_fast is not None:
+            try:
+                return _dumps_fast(obj)
+            except TypeError:
+                # input contains objects the native serializer doesn't
+                # handle; fall back to the regular encoder
+                pass
         return _default_encoder.encode(obj)
     if cls is None:
         cls = JSONEncoder
@@ -242,6 +255,11 @@ def dumps(obj, *, skipkeys=False, ensure_ascii=True, check_circular=True,
         **kw).encode(obj)
 
 
+try:
+    from _json import dumps_fast as _dumps_fast
+except ImportError:
+    _dumps_fast = None
+
 _default_decoder = JSONDecoder(object_hook=None, object_pairs_hook=None)
 
 
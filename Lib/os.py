@@ -68,6 +68,11 @@ if 'posix' in _names:
         from posix import _create_environ
     except ImportError:
         pass
+    # XXX RUSTPYTHON: native scandir-based directory walker
+    try:
+        from posix import _walk
+    except ImportError:
+        _walk = None
 
     import posix
     __all__.extend(_get_exports_list(posix))
@@ -83,6 +88,11 @@ elif 'nt' in _names:
     except ImportError:
         pass
     import ntpath as path
+    # XXX RUSTPYTHON: native scandir-based directory walker
+    try:
+        from nt import _walk
+    except ImportError:
+        _walk = None
 
     import nt
     __all__.extend(_get_exports_list(nt))
@@ -356,6 +366,12 @@ def walk(top, topdown=True, onerror=None, followlinks=False):
     """
     sys.audit("os.walk", top, topdown, onerror, followlinks)
 
+    # XXX RUSTPYTHON: delegate to the native walker; it implements the same
+    # triples, pruning, onerror and followlinks semantics over scandir.
+    if _walk is not None and followlinks is not _walk_symlinks_as_files:
+        yield from _walk(fspath(top), topdown, onerror, bool(followlinks))
+        return
+
     stack = [fspath(top)]
     islink, join = path.islink, path.join
     while stack:
@@ -305,7 +305,8 @@ class EnvBuilder:
             if not os.path.islink(path):
                 os.chmod(path, 0o755)
             for suffix in ('python', 'python3',
-                           f'python3.{sys.version_info[1]}'):
+                           f'python3.{sys.version_info[1]}',
+                           'rustpython'):
                 path = os.path.join(binpath, suffix)
                 if not os.path.exists(path):
                     # Issue 18807: make copies if
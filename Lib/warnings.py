@@ -91,8 +91,10 @@ except ImportError:
 
 # Module initialization
 _set_module(sys.modules[__name__])
-_processoptions(sys.warnoptions)
 if not _warnings_defaults:
+    # RustPython's native _warnings already applied sys.warnoptions during
+    # interpreter startup; only the pure-Python fallback processes them here.
+    _processoptions(sys.warnoptions)
     _setup_defaults()
 
 del _warnings_defaults
@@ -21,6 +21,12 @@ import marshal  # for loads
 import sys  # for modules
 import time  # for mktime
 
+try:
+    # RustPython extension: native central-directory parser
+    from _zipimport import _read_directory as _read_directory_native
+except ImportError:
+    _read_directory_native = None
+
 __all__ = ['ZipImportError', 'zipimporter']
 
 
@@ -344,6 +350,24 @@ def _get_module_info(self, fullname):
 # Directories can be recognized by the trailing path_sep in the name,
 # data_size and file_offset are 0.
 def _read_directory(archive):
+    if _read_directory_native is not None:
+        try:
+            entries = _read_directory_native(archive)
+        except (OSError, ValueError) as exc:
+            raise ZipImportError(str(exc), path=archive)
+        files = {}
+        for name, compress, data_size, file_size, file_offset, time, date, crc in entries:
+            name = name.replace('/', path_sep)
+            path = _bootstrap_external._path_join(archive, name)
+            files[name] = (path, compress, data_size, file_size, file_offset,
+                           time, date, crc)
+        _bootstrap._verbose_message('zipimport: found {} names in {!r}',
+                                    len(files), archive)
+        _add_implicit_directories(files, archive)
+        return files
+    return _read_directory_py(archive)
+
+def _read_directory_py(archive):
     try:
         fp = _io.open_code(archive)
     except OSError:
@@ -550,7 +574,10 @@ def _read_directory(archive):
             fp.seek(start_offset)
     _bootstrap._verbose_message('zipimport: found {} names in {!r}', count, archive)
 
-    # Add implicit directories.
+    _add_implicit_directories(files, archive)
+    return files
+
+def _add_implicit_directories(files, archive):
     count = 0
     for name in list(files):
         while True:
@@ -565,7 +592,6 @@ def _read_directory(archive):
     if count:
         _bootstrap._verbose_message('zipimport: added {} implicit directories in {!r}',
                                     count, archive)
-    return files
 
 # During bootstrap, we may need to load the encodings
 # package from a ZIP file. But the cp437 encoding is implemented
@@ -13,6 +13,7 @@ use crate::{
     IndexMap, IndexSet, ToPythonName,
     error::{CodegenError, CodegenErrorType, InternalError, PatternUnreachableReason},
     ir::{self, BlockIdx},
+    stack_guard::StackGuard,
     symboltable::{self, CompilerScope, SymbolFlags, SymbolScope, SymbolTable},
     unparse::UnparseExpr,
 };
@@ -154,6 +155,8 @@ struct Compiler {
     /// True when compiling in "single" (interactive) mode.
     /// Expression statements at module scope emit CALL_INTRINSIC_1(Print).
     interactive: bool,
+    /// Native stack watermark for the recursive AST walk.
+    stack_guard: StackGuard,
 }
 
 #[derive(Clone, Copy)]
@@ -465,6 +468,7 @@ impl Compiler {
             opts,
             in_annotation: false,
             interactive: false,
+            stack_guard: StackGuard::new(),
         }
     }
 
@@ -673,6 +677,15 @@ impl Compiler {
         self.error_ranged(error, self.current_source_range)
     }
 
+    /// Guard the recursive AST walk against native stack exhaustion.
+    fn check_stack_depth(&mut self) -> CompileResult<()> {
+        if self.stack_guard.exhausted() {
+            Err(self.error(CodegenErrorType::RecursionLimit))
+        } else {
+            Ok(())
+        }
+    }
+
     fn error_ranged(&mut self, error: CodegenErrorType, range: TextRange) -> CodegenError {
         let location = self
             .source_file
@@ -2051,6 +2064,7 @@ impl Compiler {
     fn compile_statement(&mut self, statement: &ast::Stmt) -> CompileResult<()> {
         trace!("Compiling {statement:?}");
         self.set_source_range(statement.range());
+        self.check_stack_depth()?;
 
         match &statement {
             // we do this here because `from __future__` still executes that `from` statement at runtime,
@@ -6791,6 +6805,7 @@ impl Compiler {
         trace!("Compiling {expression:?}");
         let range = expression.range();
         self.set_source_range(range);
+        self.check_stack_depth()?;
 
         match &expression {
             ast::Expr::Call(ast::ExprCall {
@@ -8980,6 +8995,22 @@ if (True and False) or (False and True):
         ));
     }
 
+    #[test]
+    fn test_fstring_pep701() {
+        // PEP 701: reused quotes, multiline expressions and comments inside
+        // the replacement field must compile
+        compile_exec(
+            "\
+cond = True
+x = f\"{'a' if cond else \"b\"}\"
+y = f'{
+    1 +  # a comment inside the braces
+    2
+}'
+",
+        );
+    }
+
     #[test]
     fn test_nested_double_async_with() {
         assert_dis_snapshot!(compile_exec(
@@ -94,9 +94,14 @@ pub enum CodegenErrorType {
     ConflictingNameBindPattern,
     /// break/continue/return inside except* block
     BreakContinueReturnInExceptStar,
+    /// Native stack exhausted while recursing over a deeply nested AST.
+    RecursionLimit,
     NotImplementedYet, // RustPython marker for unimplemented features
 }
 
+/// Message CPython uses when the compiler itself runs out of stack.
+pub(crate) const RECURSION_LIMIT_MSG: &str = "maximum recursion depth exceeded during compilation";
+
 impl core::error::Error for CodegenErrorType {}
 
 impl fmt::Display for CodegenErrorType {
@@ -168,6 +173,9 @@ impl fmt::Display for CodegenErrorType {
                     "'break', 'continue' and 'return' cannot appear in an except* block"
                 )
             }
+            RecursionLimit => {
+                write!(f, "{RECURSION_LIMIT_MSG}")
+            }
             NotImplementedYet => {
                 write!(f, "RustPython does not implement this feature yet")
             }
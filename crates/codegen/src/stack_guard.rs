@@ -0,0 +1,48 @@
+//! Native-stack watermark for the recursive AST walkers.
+//!
+//! The symbol table builder and the code generator both recurse over the
+//! AST, so deeply nested source would otherwise overflow the native stack
+//! long before any Python-level recursion limit could apply. The guard
+//! records the stack pointer when compilation starts and reports
+//! exhaustion once a walker has descended more than [`MAX_STACK_BYTES`]
+//! below it, so the caller can raise a proper error instead of aborting
+//! the process.
+
+/// Stack budget the AST walkers may consume below their entry stack
+/// pointer. Kept well inside the smallest default thread stacks (1 MiB
+/// for the main thread on Windows) while still allowing a few hundred
+/// levels of nesting even in unoptimized builds.
+const MAX_STACK_BYTES: usize = 512 * 1024;
+
+/// Watermark captured at the start of a compilation pass.
+#[derive(Clone, Copy)]
+pub(crate) struct StackGuard {
+    /// Lowest stack pointer the walkers may reach; stacks grow downwards
+    /// on all supported targets.
+    floor: usize,
+}
+
+impl StackGuard {
+    pub(crate) fn new() -> Self {
+        Self {
+            floor: stack_pointer().saturating_sub(MAX_STACK_BYTES),
+        }
+    }
+
+    /// Whether the current stack pointer has dropped below the budget.
+    pub(crate) fn exhausted(&self) -> bool {
+        stack_pointer() < self.floor
+    }
+}
+
+#[cfg(not(miri))]
+fn stack_pointer() -> usize {
+    psm::stack_pointer() as usize
+}
+
+/// Miri doesn't support the inline assembly psm relies on, so the guard
+/// is effectively disabled there.
+#[cfg(miri)]
+fn stack_pointer() -> usize {
+    usize::MAX
+}
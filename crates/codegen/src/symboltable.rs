@@ -9,7 +9,8 @@ Inspirational file: https://github.com/python/cpython/blob/main/Python/symtable.
 
 use crate::{
     IndexMap, IndexSet,
-    error::{CodegenError, CodegenErrorType},
+    error::{CodegenError, CodegenErrorType, RECURSION_LIMIT_MSG},
+    stack_guard::StackGuard,
 };
 use alloc::{borrow::Cow, fmt};
 use bitflags::bitflags;
@@ -239,10 +240,23 @@ pub struct SymbolTableError {
 }
 
 impl SymbolTableError {
+    /// The error raised when the scan trips the native stack guard.
+    pub(crate) fn recursion_limit() -> Self {
+        Self {
+            error: RECURSION_LIMIT_MSG.to_owned(),
+            location: None,
+        }
+    }
+
     pub fn into_codegen_error(self, source_path: String) -> CodegenError {
+        let error = if self.error == RECURSION_LIMIT_MSG {
+            CodegenErrorType::RecursionLimit
+        } else {
+            CodegenErrorType::SyntaxError(self.error)
+        };
         CodegenError {
             location: self.location,
-            error: CodegenErrorType::SyntaxError(self.error),
+            error,
             source_path,
         }
     }
@@ -853,6 +867,8 @@ struct SymbolTableBuilder {
     scope_info: Option<&'static str>,
     // PEP 649: Track if we're inside a conditional block (if/for/while/etc.)
     in_conditional_block: bool,
+    // Native stack watermark for the recursive scan
+    stack_guard: StackGuard,
 }
 
 /// Enum to indicate in what mode an expression
@@ -883,11 +899,21 @@ impl SymbolTableBuilder {
             in_comp_inner_loop_target: false,
             scope_info: None,
             in_conditional_block: false,
+            stack_guard: StackGuard::new(),
         };
         this.enter_scope("top", CompilerScope::Module, 0);
         this
     }
 
+    /// Guard the recursive AST walk against native stack exhaustion.
+    fn check_stack(&self) -> SymbolTableResult {
+        if self.stack_guard.exhausted() {
+            Err(SymbolTableError::recursion_limit())
+        } else {
+            Ok(())
+        }
+    }
+
     fn finish(mut self) -> Result<SymbolTable, SymbolTableError> {
         assert_eq!(self.tables.len(), 1);
         let mut symbol_table = self.tables.pop().unwrap();
@@ -1176,6 +1202,7 @@ impl SymbolTableBuilder {
 
     fn scan_statement(&mut self, statement: &ast::Stmt) -> SymbolTableResult {
         use ast::*;
+        self.check_stack()?;
         if let Stmt::ImportFrom(StmtImportFrom { module, names, .. }) = &statement
             && module.as_ref().map(|id| id.as_str()) == Some("__future__")
         {
@@ -1609,6 +1636,7 @@ impl SymbolTableBuilder {
         context: ExpressionContext,
     ) -> SymbolTableResult {
         use ast::*;
+        self.check_stack()?;
 
         // Check for expressions not allowed in certain contexts
         // (type parameters, annotations, type aliases, TypeVar bounds/defaults)
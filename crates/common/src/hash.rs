@@ -1,7 +1,7 @@
 use core::hash::{BuildHasher, Hash, Hasher};
 use malachite_bigint::BigInt;
 use num_traits::ToPrimitive;
-use siphasher::sip::SipHasher24;
+use siphasher::sip::SipHasher13;
 
 pub type PyHash = i64;
 pub type PyUHash = u64;
@@ -18,9 +18,9 @@ pub const MODULUS: PyUHash = (1 << BITS) - 1;
 pub const INF: PyHash = 314_159;
 pub const NAN: PyHash = 0;
 pub const IMAG: PyHash = MULTIPLIER;
-pub const ALGO: &str = "siphash24";
+pub const ALGO: &str = "siphash13";
 pub const HASH_BITS: usize = core::mem::size_of::<PyHash>() * 8;
-// SipHasher24 takes 2 u64s as a seed
+// SipHasher13 takes 2 u64s as a seed
 pub const SEED_BITS: usize = core::mem::size_of::<u64>() * 2 * 8;
 
 // pub const CUTOFF: usize = 7;
@@ -32,10 +32,10 @@ pub struct HashSecret {
 }
 
 impl BuildHasher for HashSecret {
-    type Hasher = SipHasher24;
+    type Hasher = SipHasher13;
 
     fn build_hasher(&self) -> Self::Hasher {
-        SipHasher24::new_with_keys(self.k0, self.k1)
+        SipHasher13::new_with_keys(self.k0, self.k1)
     }
 }
 
@@ -181,7 +181,7 @@ pub const fn hash_object_id(p: usize) -> PyHash {
 }
 
 pub fn keyed_hash(key: u64, buf: &[u8]) -> u64 {
-    let mut hasher = SipHasher24::new_with_keys(key, 0);
+    let mut hasher = SipHasher13::new_with_keys(key, 0);
     buf.hash(&mut hasher);
     hasher.finish()
 }
@@ -9,6 +9,13 @@ fn refcount_overflow() -> ! {
     core::panic!("refcount overflow");
 }
 
+#[inline(never)]
+#[cold]
+#[cfg(feature = "debug-checks")]
+fn refcount_corrupt(what: &str, count: usize) -> ! {
+    panic!("refcount corruption: {what} (count was {count:#x})");
+}
+
 /// from alloc::sync
 /// A soft limit on the amount of references that may be made to an `Arc`.
 ///
@@ -44,6 +51,13 @@ impl RefCount {
     pub fn inc(&self) {
         let old_size = self.strong.fetch_add(1, Relaxed);
 
+        // a count of zero means the object is already being (or has been)
+        // dropped; incrementing it revives a dead object
+        #[cfg(feature = "debug-checks")]
+        if old_size & Self::MASK == 0 {
+            refcount_corrupt("increment of a dead object", old_size);
+        }
+
         if old_size & Self::MASK == Self::MASK {
             refcount_overflow();
         }
@@ -70,7 +84,14 @@ impl RefCount {
     /// Decrement the reference count. Returns true when the refcount drops to 0.
     #[inline]
     pub fn dec(&self) -> bool {
-        if self.strong.fetch_sub(1, Release) != 1 {
+        let old_size = self.strong.fetch_sub(1, Release);
+
+        #[cfg(feature = "debug-checks")]
+        if old_size & Self::MASK == 0 {
+            refcount_corrupt("decrement of a dead object", old_size);
+        }
+
+        if old_size != 1 {
             return false;
         }
 
@@ -319,10 +319,20 @@ impl StrData {
     }
 
     pub fn nth_char(&self, index: usize) -> CodePoint {
-        match self.as_str_kind() {
-            PyKindStr::Ascii(s) => s[index].into(),
-            PyKindStr::Utf8(s) => s.chars().nth(index).unwrap().into(),
-            PyKindStr::Wtf8(w) => w.code_points().nth(index).unwrap(),
+        // Ascii indexes in O(1); for the multibyte kinds, walk from whichever
+        // end is closer once the char length is known (it always is after a
+        // len() or a negative-index lookup, both of which compute it).
+        let back_offset = match self.len.0.load(Relaxed) {
+            usize::MAX => None,
+            len if index >= len / 2 => Some(len - index - 1),
+            _ => None,
+        };
+        match (self.as_str_kind(), back_offset) {
+            (PyKindStr::Ascii(s), _) => s[index].into(),
+            (PyKindStr::Utf8(s), None) => s.chars().nth(index).unwrap().into(),
+            (PyKindStr::Utf8(s), Some(back)) => s.chars().rev().nth(back).unwrap().into(),
+            (PyKindStr::Wtf8(w), None) => w.code_points().nth(index).unwrap(),
+            (PyKindStr::Wtf8(w), Some(back)) => w.code_points().rev().nth(back).unwrap(),
         }
     }
 }
@@ -375,12 +385,25 @@ pub fn char_range_end(s: &str, n_chars: usize) -> Option<usize> {
 }
 
 pub fn try_get_codepoints(w: &Wtf8, range: impl RangeBounds<usize>) -> Option<&Wtf8> {
-    let mut chars = w.code_points();
     let start = match range.start_bound() {
         Bound::Included(&i) => i,
         Bound::Excluded(&i) => i + 1,
         Bound::Unbounded => 0,
     };
+    // ASCII fast path: code point and byte indices coincide, and the ascii
+    // scan is much cheaper than decoding every code point up front.
+    if w.as_bytes().is_ascii() {
+        let end = match range.end_bound() {
+            Bound::Included(&i) => i + 1,
+            Bound::Excluded(&i) => i,
+            Bound::Unbounded => w.len(),
+        };
+        if start > end || end > w.len() {
+            return None;
+        }
+        return Some(&w[start..end]);
+    }
+    let mut chars = w.code_points();
     for _ in 0..start {
         chars.next()?;
     }
@@ -665,4 +688,12 @@ mod tests {
         let s = "0😀😃😄😁😆😅😂🤣9";
         assert_eq!(get_chars(s, 3..7), "😄😁😆😅");
     }
+
+    #[test]
+    fn test_get_codepoints_ascii() {
+        let w = Wtf8::new("0123456789");
+        assert_eq!(get_codepoints(w, 3..7), Wtf8::new("3456"));
+        assert_eq!(get_codepoints(w, 0..10), w);
+        assert!(try_get_codepoints(w, 3..11).is_none());
+    }
 }
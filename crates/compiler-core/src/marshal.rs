@@ -5,7 +5,10 @@ use malachite_bigint::{BigInt, Sign};
 use num_complex::Complex64;
 use rustpython_wtf8::Wtf8;
 
-pub const FORMAT_VERSION: u32 = 5;
+/// Version of the marshal format. Scalars, strings and containers use
+/// CPython's encodings (format version 4); code objects use RustPython's own
+/// layout, hence the distinct version number.
+pub const FORMAT_VERSION: u32 = 6;
 
 #[derive(Clone, Copy, Debug)]
 pub enum MarshalError {
@@ -19,6 +22,8 @@ pub enum MarshalError {
     InvalidLocation,
     /// Bad type marker
     BadType,
+    /// Reference to an object that was never recorded
+    InvalidReference,
 }
 
 impl core::fmt::Display for MarshalError {
@@ -29,6 +34,7 @@ impl core::fmt::Display for MarshalError {
             Self::InvalidUtf8 => f.write_str("invalid utf8"),
             Self::InvalidLocation => f.write_str("invalid source location"),
             Self::BadType => f.write_str("bad type marker"),
+            Self::InvalidReference => f.write_str("invalid reference"),
         }
     }
 }
@@ -46,19 +52,19 @@ type Result<T, E = MarshalError> = core::result::Result<T, E>;
 #[derive(Clone, Copy)]
 #[repr(u8)]
 enum Type {
-    // Null = b'0',
+    // Null = b'0', // only valid as a dict terminator
     None = b'N',
     False = b'F',
     True = b'T',
     StopIter = b'S',
     Ellipsis = b'.',
-    Int = b'i',
+    Int = b'i',  // i32
+    Long = b'l', // 15-bit digit string
     Float = b'g',
     Complex = b'y',
-    // Long = b'l',  // i32
     Bytes = b's', // = TYPE_STRING
-    // Interned = b't',
-    // Ref = b'r',
+    Interned = b't',
+    Ref = b'r',
     Tuple = b'(',
     List = b'[',
     Dict = b'{',
@@ -69,12 +75,15 @@ enum Type {
     FrozenSet = b'>',
     Slice = b':', // Added in version 5
     Ascii = b'a',
-    // AsciiInterned = b'A',
-    // SmallTuple = b')',
-    // ShortAscii = b'z',
-    // ShortAsciiInterned = b'Z',
+    AsciiInterned = b'A',
+    SmallTuple = b')',
+    ShortAscii = b'z',
+    ShortAsciiInterned = b'Z',
 }
-// const FLAG_REF: u8 = b'\x80';
+
+/// Set on the type marker when CPython also records the value in the ref
+/// table, making it available to later `Type::Ref` back-references.
+const FLAG_REF: u8 = b'\x80';
 
 impl TryFrom<u8> for Type {
     type Error = MarshalError;
@@ -90,12 +99,12 @@ impl TryFrom<u8> for Type {
             b'S' => StopIter,
             b'.' => Ellipsis,
             b'i' => Int,
+            b'l' => Long,
             b'g' => Float,
             b'y' => Complex,
-            // b'l' => Long,
             b's' => Bytes,
-            // b't' => Interned,
-            // b'r' => Ref,
+            b't' => Interned,
+            b'r' => Ref,
             b'(' => Tuple,
             b'[' => List,
             b'{' => Dict,
@@ -106,10 +115,10 @@ impl TryFrom<u8> for Type {
             b'>' => FrozenSet,
             b':' => Slice,
             b'a' => Ascii,
-            // b'A' => AsciiInterned,
-            // b')' => SmallTuple,
-            // b'z' => ShortAscii,
-            // b'Z' => ShortAsciiInterned,
+            b'A' => AsciiInterned,
+            b')' => SmallTuple,
+            b'z' => ShortAscii,
+            b'Z' => ShortAsciiInterned,
             _ => return Err(MarshalError::BadType),
         })
     }
@@ -291,7 +300,7 @@ pub fn deserialize_code<R: Read, Bag: ConstantBag>(
 }
 
 pub trait MarshalBag: Copy {
-    type Value;
+    type Value: Clone;
     type ConstantBag: ConstantBag;
 
     fn make_bool(&self, value: bool) -> Self::Value;
@@ -409,7 +418,32 @@ impl<Bag: ConstantBag> MarshalBag for Bag {
 }
 
 pub fn deserialize_value<R: Read, Bag: MarshalBag>(rdr: &mut R, bag: Bag) -> Result<Bag::Value> {
-    let typ = Type::try_from(rdr.read_u8()?)?;
+    deserialize_value_impl(rdr, bag, &mut Vec::new())
+}
+
+fn deserialize_value_impl<R: Read, Bag: MarshalBag>(
+    rdr: &mut R,
+    bag: Bag,
+    refs: &mut Vec<Option<Bag::Value>>,
+) -> Result<Bag::Value> {
+    let type_byte = rdr.read_u8()?;
+    deserialize_typed_value(rdr, bag, refs, type_byte)
+}
+
+fn deserialize_typed_value<R: Read, Bag: MarshalBag>(
+    rdr: &mut R,
+    bag: Bag,
+    refs: &mut Vec<Option<Bag::Value>>,
+    type_byte: u8,
+) -> Result<Bag::Value> {
+    let flag_ref = type_byte & FLAG_REF != 0;
+    let typ = Type::try_from(type_byte & !FLAG_REF)?;
+    // Reserve the ref slot before reading the payload, as CPython does, so
+    // back-references inside containers resolve to the right index.
+    let ref_idx = flag_ref.then(|| {
+        refs.push(None);
+        refs.len() - 1
+    });
     let value = match typ {
         Type::True => bag.make_bool(true),
         Type::False => bag.make_bool(false),
@@ -417,12 +451,10 @@ pub fn deserialize_value<R: Read, Bag: MarshalBag>(rdr: &mut R, bag: Bag) -> Res
         Type::StopIter => bag.make_stop_iter()?,
         Type::Ellipsis => bag.make_ellipsis(),
         Type::Int => {
-            let len = rdr.read_u32()? as i32;
-            let sign = if len < 0 { Sign::Minus } else { Sign::Plus };
-            let bytes = rdr.read_slice(len.unsigned_abs())?;
-            let int = BigInt::from_bytes_le(sign, bytes);
-            bag.make_int(int)
+            let value = rdr.read_u32()? as i32;
+            bag.make_int(value.into())
         }
+        Type::Long => bag.make_int(read_long(rdr)?),
         Type::Float => {
             let value = f64::from_bits(rdr.read_u64()?);
             bag.make_float(value)
@@ -433,39 +465,55 @@ pub fn deserialize_value<R: Read, Bag: MarshalBag>(rdr: &mut R, bag: Bag) -> Res
             let value = Complex64 { re, im };
             bag.make_complex(value)
         }
-        Type::Ascii | Type::Unicode => {
+        Type::Ascii | Type::AsciiInterned | Type::Interned | Type::Unicode => {
             let len = rdr.read_u32()?;
             let value = rdr.read_wtf8(len)?;
             bag.make_str(value)
         }
+        Type::ShortAscii | Type::ShortAsciiInterned => {
+            let len = rdr.read_u8()?;
+            let value = rdr.read_wtf8(len.into())?;
+            bag.make_str(value)
+        }
         Type::Tuple => {
             let len = rdr.read_u32()?;
-            let it = (0..len).map(|_| deserialize_value(rdr, bag));
+            let it = (0..len).map(|_| deserialize_value_impl(rdr, bag, refs));
+            itertools::process_results(it, |it| bag.make_tuple(it))?
+        }
+        Type::SmallTuple => {
+            let len = rdr.read_u8()?;
+            let it = (0..len).map(|_| deserialize_value_impl(rdr, bag, refs));
             itertools::process_results(it, |it| bag.make_tuple(it))?
         }
         Type::List => {
             let len = rdr.read_u32()?;
-            let it = (0..len).map(|_| deserialize_value(rdr, bag));
+            let it = (0..len).map(|_| deserialize_value_impl(rdr, bag, refs));
             itertools::process_results(it, |it| bag.make_list(it))??
         }
         Type::Set => {
             let len = rdr.read_u32()?;
-            let it = (0..len).map(|_| deserialize_value(rdr, bag));
+            let it = (0..len).map(|_| deserialize_value_impl(rdr, bag, refs));
             itertools::process_results(it, |it| bag.make_set(it))??
         }
         Type::FrozenSet => {
             let len = rdr.read_u32()?;
-            let it = (0..len).map(|_| deserialize_value(rdr, bag));
+            let it = (0..len).map(|_| deserialize_value_impl(rdr, bag, refs));
             itertools::process_results(it, |it| bag.make_frozenset(it))??
         }
         Type::Dict => {
-            let len = rdr.read_u32()?;
-            let it = (0..len).map(|_| {
-                let k = deserialize_value(rdr, bag)?;
-                let v = deserialize_value(rdr, bag)?;
-                Ok::<_, MarshalError>((k, v))
-            });
-            itertools::process_results(it, |it| bag.make_dict(it))??
+            // Dict items run until a TYPE_NULL marker instead of carrying a
+            // length prefix.
+            let mut entries = Vec::new();
+            loop {
+                let key_type = rdr.read_u8()?;
+                if key_type == b'0' {
+                    break;
+                }
+                let k = deserialize_typed_value(rdr, bag, refs, key_type)?;
+                let v = deserialize_value_impl(rdr, bag, refs)?;
+                entries.push((k, v));
+            }
+            bag.make_dict(entries.into_iter())?
         }
         Type::Bytes => {
             // Following CPython, after marshaling, byte arrays are converted into bytes.
@@ -480,10 +528,47 @@ pub fn deserialize_value<R: Read, Bag: MarshalBag>(rdr: &mut R, bag: Bag) -> Res
             // For now, return an error if we encounter a slice in marshal data
             return Err(MarshalError::BadType);
         }
+        Type::Ref => {
+            let n = rdr.read_u32()? as usize;
+            return refs
+                .get(n)
+                .and_then(|v| v.clone())
+                .ok_or(MarshalError::InvalidReference);
+        }
     };
+    if let Some(idx) = ref_idx {
+        refs[idx] = Some(value.clone());
+    }
     Ok(value)
 }
 
+/// Read CPython's TYPE_LONG payload: a signed digit count followed by that
+/// many 15-bit digits, least significant first.
+fn read_long<R: Read>(rdr: &mut R) -> Result<BigInt> {
+    let n = rdr.read_u32()? as i32;
+    let sign = if n < 0 { Sign::Minus } else { Sign::Plus };
+    let mut bytes = Vec::with_capacity(n.unsigned_abs() as usize * 2);
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+    for _ in 0..n.unsigned_abs() {
+        let digit = rdr.read_u16()?;
+        if digit > 0x7fff {
+            return Err(MarshalError::BadType);
+        }
+        acc |= u32::from(digit) << bits;
+        bits += 15;
+        while bits >= 8 {
+            bytes.push(acc as u8);
+            acc >>= 8;
+            bits -= 8;
+        }
+    }
+    if bits > 0 {
+        bytes.push(acc as u8);
+    }
+    Ok(BigInt::from_bytes_le(sign, &bytes))
+}
+
 pub trait Dumpable: Sized {
     type Error;
     type Constant: Constant;
@@ -580,12 +665,15 @@ pub fn serialize_value<W: Write, D: Dumpable>(
 ) -> Result<(), D::Error> {
     match constant {
         DumpableValue::Integer(int) => {
-            buf.write_u8(Type::Int as u8);
-            let (sign, bytes) = int.to_bytes_le();
-            let len: i32 = bytes.len().try_into().expect("too long to serialize");
-            let len = if sign == Sign::Minus { -len } else { len };
-            buf.write_u32(len as u32);
-            buf.write_slice(&bytes);
+            // Machine-sized ints are written as TYPE_INT, everything else as
+            // TYPE_LONG's 15-bit digit string, as CPython does.
+            if let Ok(value) = i32::try_from(int) {
+                buf.write_u8(Type::Int as u8);
+                buf.write_u32(value as u32);
+            } else {
+                buf.write_u8(Type::Long as u8);
+                write_long(buf, int);
+            }
         }
         DumpableValue::Float(f) => {
             buf.write_u8(Type::Float as u8);
@@ -650,16 +738,42 @@ pub fn serialize_value<W: Write, D: Dumpable>(
         }
         DumpableValue::Dict(d) => {
             buf.write_u8(Type::Dict as u8);
-            write_len(buf, d.len());
             for (k, v) in d {
                 k.with_dump(|val| serialize_value(buf, val))??;
                 v.with_dump(|val| serialize_value(buf, val))??;
             }
+            // dict items are terminated by TYPE_NULL rather than counted
+            buf.write_u8(b'0');
         }
     }
     Ok(())
 }
 
+/// Write CPython's TYPE_LONG payload; see [`read_long`] for the layout.
+fn write_long<W: Write>(buf: &mut W, int: &BigInt) {
+    let (sign, bytes) = int.to_bytes_le();
+    let mut digits: Vec<u16> = Vec::with_capacity(bytes.len() * 8 / 15 + 1);
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+    for &byte in &bytes {
+        acc |= u32::from(byte) << bits;
+        bits += 8;
+        while bits >= 15 {
+            digits.push((acc & 0x7fff) as u16);
+            acc >>= 15;
+            bits -= 15;
+        }
+    }
+    if acc != 0 {
+        digits.push(acc as u16);
+    }
+    let n: i32 = digits.len().try_into().expect("too long to serialize");
+    buf.write_u32(if sign == Sign::Minus { -n } else { n } as u32);
+    for digit in digits {
+        buf.write_u16(digit);
+    }
+}
+
 pub fn serialize_code<W: Write, C: Constant>(buf: &mut W, code: &CodeObject<C>) {
     write_len(buf, code.instructions.len());
     // SAFETY: it's ok to transmute CodeUnit to [u8; 2]
@@ -715,3 +829,40 @@ pub fn serialize_code<W: Write, C: Constant>(buf: &mut W, code: &CodeObject<C>)
     write_vec(buf, &code.linetable);
     write_vec(buf, &code.exceptiontable);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_int_cpython_encoding() {
+        // marshal.dumps(42) without the ref flag
+        let value = BigInt::from(42);
+        let mut buf = Vec::new();
+        serialize_value(&mut buf, DumpableValue::<ConstantData>::Integer(&value)).unwrap();
+        assert_eq!(buf, b"i\x2a\x00\x00\x00");
+    }
+
+    #[test]
+    fn test_long_cpython_encoding() {
+        // marshal.dumps(2**40) without the type marker and ref flag
+        let mut buf = Vec::new();
+        write_long(&mut buf, &BigInt::from(1u64 << 40));
+        assert_eq!(buf, b"\x03\x00\x00\x00\x00\x00\x00\x00\x00\x04");
+    }
+
+    #[test]
+    fn test_long_roundtrip() {
+        for value in [
+            BigInt::from(0),
+            BigInt::from(-5),
+            BigInt::from(i64::MAX),
+            BigInt::from(1u128 << 100),
+            -BigInt::from(1u128 << 100),
+        ] {
+            let mut buf = Vec::new();
+            write_long(&mut buf, &value);
+            assert_eq!(read_long(&mut &buf[..]).unwrap(), value);
+        }
+    }
+}
@@ -268,6 +268,67 @@ fn test_to_hex() {
     }
 }
 
+#[test]
+fn test_to_string_matches_cpython_repr() {
+    // expected strings are CPython's repr() output for the same values
+    let cases: &[(f64, &str)] = &[
+        (0.30000000000000004, "0.30000000000000004"),
+        (1e16, "1e+16"),
+        (1e15, "1000000000000000.0"),
+        (1e-4, "0.0001"),
+        (1e-5, "1e-05"),
+        (5e-324, "5e-324"),
+        (f64::MAX, "1.7976931348623157e+308"),
+        (123456789012345678.0, "1.2345678901234568e+17"),
+        (0.5, "0.5"),
+        (2.0, "2.0"),
+        (-0.007, "-0.007"),
+        (3.14159, "3.14159"),
+    ];
+    for &(value, expected) in cases {
+        assert_eq!(to_string(value), expected, "{value:?}");
+    }
+}
+
+#[test]
+fn test_to_hex_matches_cpython() {
+    assert_eq!(to_hex(0.1), "0x1.999999999999ap-4");
+    assert_eq!(to_hex(5e-324), "0x0.0000000000001p-1022");
+    assert_eq!(to_hex(-2.5), "-0x1.4000000000000p+1");
+    assert_eq!(to_hex(0.0), "0x0.0p+0");
+}
+
+#[test]
+fn test_from_hex_variants() {
+    // float.fromhex accepts a missing 0x prefix, either prefix case and
+    // omitted fraction/exponent parts
+    assert_eq!(from_hex("0x1.8p3"), Some(12.0));
+    assert_eq!(from_hex("1.8p3"), Some(12.0));
+    assert_eq!(from_hex("-0X1.0p-3"), Some(-0.125));
+    assert_eq!(from_hex("inf"), Some(f64::INFINITY));
+    assert!(from_hex("nan").unwrap().is_nan());
+}
+
+#[test]
+fn test_format_general_matches_cpython() {
+    // expected strings are CPython's format(value, 'g') output
+    let cases: &[(usize, f64, bool, &str)] = &[
+        (6, 0.0001, false, "0.0001"),
+        (6, 1e-5, false, "1e-05"),
+        (3, 1234.5678, false, "1.23e+03"),
+        (1, 0.5, false, "0.5"),
+        (6, 1.0, true, "1.00000"),
+        (10, 0.1, false, "0.1"),
+    ];
+    for &(precision, value, alternate_form, expected) in cases {
+        assert_eq!(
+            format_general(precision, value, Case::Lower, alternate_form, false),
+            expected,
+            "{value:?} precision {precision}"
+        );
+    }
+}
+
 #[test]
 fn test_remove_trailing_zeros() {
     assert!(remove_trailing_zeros(String::from("100")) == *"1");
@@ -0,0 +1,389 @@
+// spell-checker:ignore lsprof reccallcount inlinetime totaltime timeunit subentry subentries
+
+pub(crate) use _lsprof::module_def;
+
+#[pymodule]
+mod _lsprof {
+    use crate::vm::{
+        AsObject, Py, PyObjectRef, PyPayload, PyRef, PyResult, VirtualMachine,
+        builtins::{PyStr, PyStrRef, PyType},
+        common::lock::PyMutex,
+        frame::FrameRef,
+        types::{Callable, Constructor, PyStructSequence},
+    };
+    use std::collections::HashMap;
+    use std::time::Instant;
+
+    #[pystruct_sequence_data]
+    struct ProfilerEntryData {
+        code: PyObjectRef,
+        callcount: u64,
+        reccallcount: u64,
+        totaltime: f64,
+        inlinetime: f64,
+        calls: PyObjectRef,
+    }
+
+    #[pyattr]
+    #[pystruct_sequence(
+        name = "profiler_entry",
+        module = "_lsprof",
+        data = "ProfilerEntryData"
+    )]
+    struct ProfilerEntry;
+
+    #[pyclass(with(PyStructSequence))]
+    impl ProfilerEntry {}
+
+    #[pystruct_sequence_data]
+    struct ProfilerSubentryData {
+        code: PyObjectRef,
+        callcount: u64,
+        reccallcount: u64,
+        totaltime: f64,
+        inlinetime: f64,
+    }
+
+    #[pyattr]
+    #[pystruct_sequence(
+        name = "profiler_subentry",
+        module = "_lsprof",
+        data = "ProfilerSubentryData"
+    )]
+    struct ProfilerSubentry;
+
+    #[pyclass(with(PyStructSequence))]
+    impl ProfilerSubentry {}
+
+    /// Per-callee bookkeeping inside one caller's entry
+    #[derive(Debug, Default)]
+    struct SubEntry {
+        callcount: u64,
+        recursive_callcount: u64,
+        tt: f64,
+        it: f64,
+        recursion_level: u32,
+    }
+
+    /// Accumulated statistics for one profiled function
+    #[derive(Debug)]
+    struct Entry {
+        /// The code object, or a descriptive string for built-in callables
+        userobj: PyObjectRef,
+        callcount: u64,
+        recursive_callcount: u64,
+        tt: f64,
+        it: f64,
+        recursion_level: u32,
+        calls: HashMap<usize, SubEntry>,
+    }
+
+    /// One frame of the shadow call stack maintained while profiling
+    #[derive(Debug)]
+    struct Context {
+        key: usize,
+        t0: f64,
+        /// Time spent in callees, subtracted to get the inline time
+        subt: f64,
+    }
+
+    #[derive(Debug, Default)]
+    struct ProfilerInner {
+        entries: HashMap<usize, Entry>,
+        stack: Vec<Context>,
+        timer: Option<PyObjectRef>,
+        timeunit: f64,
+        subcalls: bool,
+        builtins: bool,
+        enabled: bool,
+    }
+
+    #[derive(FromArgs)]
+    struct ProfilerArgs {
+        #[pyarg(any, default)]
+        timer: Option<PyObjectRef>,
+        #[pyarg(any, default)]
+        timeunit: Option<f64>,
+        #[pyarg(any, default = true)]
+        subcalls: bool,
+        #[pyarg(any, default = true)]
+        builtins: bool,
+    }
+
+    #[derive(FromArgs)]
+    struct EnableArgs {
+        #[pyarg(any, default = true)]
+        subcalls: bool,
+        #[pyarg(any, default = true)]
+        builtins: bool,
+    }
+
+    #[pyattr]
+    #[pyclass(name = "Profiler")]
+    #[derive(Debug, PyPayload)]
+    struct Profiler {
+        inner: PyMutex<ProfilerInner>,
+    }
+
+    /// Seconds since the first timer use; only deltas are ever looked at
+    fn monotonic() -> f64 {
+        use std::sync::OnceLock;
+        static START: OnceLock<Instant> = OnceLock::new();
+        START.get_or_init(Instant::now).elapsed().as_secs_f64()
+    }
+
+    fn call_timer(
+        timer: &Option<PyObjectRef>,
+        timeunit: f64,
+        vm: &VirtualMachine,
+    ) -> PyResult<f64> {
+        let Some(timer) = timer else {
+            return Ok(monotonic());
+        };
+        let value = timer.call((), vm)?.try_float(vm)?.to_f64();
+        Ok(if timeunit > 0.0 {
+            value * timeunit
+        } else {
+            value
+        })
+    }
+
+    /// Built-in callables are replaced by a descriptive string so the stats
+    /// don't keep `__self__` alive, like CPython's normalizeUserObj
+    fn normalize_c_callable(obj: &PyObjectRef, vm: &VirtualMachine) -> PyObjectRef {
+        if !obj.fast_isinstance(vm.ctx.types.builtin_function_or_method_type) {
+            return obj.clone();
+        }
+        let name = ["__qualname__", "__name__"]
+            .iter()
+            .find_map(|attr| {
+                vm.get_attribute_opt(obj.clone(), vm.ctx.intern_str(*attr))
+                    .ok()
+                    .flatten()
+            })
+            .and_then(|n| n.downcast_ref::<PyStr>().map(|s| s.as_str().to_owned()))
+            .unwrap_or_else(|| obj.class().name().to_owned());
+        let module = vm
+            .get_attribute_opt(obj.clone(), vm.ctx.intern_str("__module__"))
+            .ok()
+            .flatten()
+            .and_then(|m| m.downcast_ref::<PyStr>().map(|s| s.as_str().to_owned()));
+        let label = match module {
+            Some(module) => format!("<built-in method {module}.{name}>"),
+            None => format!("<built-in method {name}>"),
+        };
+        vm.ctx.new_str(label).into()
+    }
+
+    impl Profiler {
+        fn enter_call(&self, key: usize, userobj: PyObjectRef, now: f64) {
+            let mut inner = self.inner.lock();
+            let subcalls = inner.subcalls;
+            let entry = inner.entries.entry(key).or_insert_with(|| Entry {
+                userobj,
+                callcount: 0,
+                recursive_callcount: 0,
+                tt: 0.0,
+                it: 0.0,
+                recursion_level: 0,
+                calls: HashMap::new(),
+            });
+            entry.recursion_level += 1;
+            if subcalls && let Some(caller) = inner.stack.last() {
+                let caller_key = caller.key;
+                if let Some(caller_entry) = inner.entries.get_mut(&caller_key) {
+                    caller_entry.calls.entry(key).or_default().recursion_level += 1;
+                }
+            }
+            inner.stack.push(Context {
+                key,
+                t0: now,
+                subt: 0.0,
+            });
+        }
+
+        fn leave_call(&self, now: f64) {
+            let mut inner = self.inner.lock();
+            // events can be unbalanced if the profiler was enabled mid-call
+            let Some(context) = inner.stack.pop() else {
+                return;
+            };
+            let tt = now - context.t0;
+            let it = tt - context.subt;
+            let caller_key = if let Some(previous) = inner.stack.last_mut() {
+                previous.subt += tt;
+                Some(previous.key)
+            } else {
+                None
+            };
+            let subcalls = inner.subcalls;
+            if let Some(entry) = inner.entries.get_mut(&context.key) {
+                entry.recursion_level = entry.recursion_level.saturating_sub(1);
+                if entry.recursion_level == 0 {
+                    entry.tt += tt;
+                } else {
+                    entry.recursive_callcount += 1;
+                }
+                entry.it += it;
+                entry.callcount += 1;
+            }
+            if subcalls
+                && let Some(caller_key) = caller_key
+                && let Some(caller_entry) = inner.entries.get_mut(&caller_key)
+                && let Some(subentry) = caller_entry.calls.get_mut(&context.key)
+            {
+                subentry.recursion_level = subentry.recursion_level.saturating_sub(1);
+                if subentry.recursion_level == 0 {
+                    subentry.tt += tt;
+                } else {
+                    subentry.recursive_callcount += 1;
+                }
+                subentry.it += it;
+                subentry.callcount += 1;
+            }
+        }
+    }
+
+    impl Constructor for Profiler {
+        type Args = ProfilerArgs;
+
+        fn py_new(_cls: &Py<PyType>, args: Self::Args, _vm: &VirtualMachine) -> PyResult<Self> {
+            Ok(Self {
+                inner: PyMutex::new(ProfilerInner {
+                    timer: args.timer,
+                    timeunit: args.timeunit.unwrap_or(0.0),
+                    subcalls: args.subcalls,
+                    builtins: args.builtins,
+                    ..Default::default()
+                }),
+            })
+        }
+    }
+
+    impl Callable for Profiler {
+        type Args = (FrameRef, PyStrRef, PyObjectRef);
+
+        fn call(zelf: &Py<Self>, args: Self::Args, vm: &VirtualMachine) -> PyResult {
+            let (frame, event, arg) = args;
+            let (timer, timeunit, builtins, enabled) = {
+                let inner = zelf.inner.lock();
+                (
+                    inner.timer.clone(),
+                    inner.timeunit,
+                    inner.builtins,
+                    inner.enabled,
+                )
+            };
+            if !enabled {
+                return Ok(vm.ctx.none());
+            }
+            let event = event.as_str();
+            if matches!(event, "c_call" | "c_return" | "c_exception") && !builtins {
+                return Ok(vm.ctx.none());
+            }
+            let now = call_timer(&timer, timeunit, vm)?;
+            match event {
+                "call" => {
+                    let code = frame.code.clone();
+                    let key = code.as_object().get_id();
+                    zelf.enter_call(key, code.into(), now);
+                }
+                "return" => zelf.leave_call(now),
+                "c_call" => {
+                    let key = arg.get_id();
+                    let userobj = normalize_c_callable(&arg, vm);
+                    zelf.enter_call(key, userobj, now);
+                }
+                "c_return" | "c_exception" => zelf.leave_call(now),
+                _ => {}
+            }
+            Ok(vm.ctx.none())
+        }
+    }
+
+    #[pyclass(with(Callable, Constructor), flags(BASETYPE))]
+    impl Profiler {
+        #[pymethod]
+        fn enable(zelf: PyRef<Self>, args: EnableArgs, vm: &VirtualMachine) {
+            {
+                let mut inner = zelf.inner.lock();
+                inner.subcalls = args.subcalls;
+                inner.builtins = args.builtins;
+                inner.enabled = true;
+            }
+            // register ourselves as the thread's profile function, like
+            // PyEval_SetProfile in CPython's _lsprof
+            vm.profile_func.replace(zelf.into());
+            vm.use_tracing.set(true);
+        }
+
+        #[pymethod]
+        fn disable(&self, vm: &VirtualMachine) -> PyResult<()> {
+            let (timer, timeunit) = {
+                let inner = self.inner.lock();
+                (inner.timer.clone(), inner.timeunit)
+            };
+            // close whatever is still on the shadow stack so totals include
+            // calls that have not returned yet
+            let now = call_timer(&timer, timeunit, vm)?;
+            loop {
+                {
+                    let inner = self.inner.lock();
+                    if inner.stack.is_empty() {
+                        break;
+                    }
+                }
+                self.leave_call(now);
+            }
+            self.inner.lock().enabled = false;
+            vm.profile_func.replace(vm.ctx.none());
+            vm.use_tracing.set(!vm.is_none(&vm.trace_func.borrow()));
+            Ok(())
+        }
+
+        #[pymethod]
+        fn clear(&self) {
+            let mut inner = self.inner.lock();
+            inner.entries.clear();
+            inner.stack.clear();
+        }
+
+        #[pymethod]
+        fn getstats(&self, vm: &VirtualMachine) -> PyResult<Vec<PyObjectRef>> {
+            let inner = self.inner.lock();
+            let mut stats = Vec::with_capacity(inner.entries.len());
+            for entry in inner.entries.values() {
+                let calls: PyObjectRef = if entry.calls.is_empty() {
+                    vm.ctx.none()
+                } else {
+                    let subentries = entry
+                        .calls
+                        .iter()
+                        .filter_map(|(key, sub)| {
+                            let callee = inner.entries.get(key)?;
+                            let data = ProfilerSubentryData {
+                                code: callee.userobj.clone(),
+                                callcount: sub.callcount,
+                                reccallcount: sub.recursive_callcount,
+                                totaltime: sub.tt,
+                                inlinetime: sub.it,
+                            };
+                            Some(ProfilerSubentry::from_data(data, vm).into())
+                        })
+                        .collect();
+                    vm.ctx.new_list(subentries).into()
+                };
+                let data = ProfilerEntryData {
+                    code: entry.userobj.clone(),
+                    callcount: entry.callcount,
+                    reccallcount: entry.recursive_callcount,
+                    totaltime: entry.tt,
+                    inlinetime: entry.it,
+                    calls,
+                };
+                stats.push(ProfilerEntry::from_data(data, vm).into());
+            }
+            Ok(stats)
+        }
+    }
+}
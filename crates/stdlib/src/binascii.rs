@@ -16,8 +16,6 @@ mod decl {
         convert::ToPyException,
         function::{ArgAsciiBuffer, ArgBytesLike, OptionalArg},
     };
-    use base64::Engine;
-    use itertools::Itertools;
 
     #[pyattr(name = "Error", once)]
     pub(super) fn error_type(vm: &VirtualMachine) -> PyTypeRef {
@@ -33,7 +31,7 @@ mod decl {
         vm.ctx.new_exception_type("binascii", "Incomplete", None)
     }
 
-    fn hex_nibble(n: u8) -> u8 {
+    const fn hex_nibble(n: u8) -> u8 {
         match n {
             0..=9 => b'0' + n,
             10..=15 => b'a' + (n - 10),
@@ -41,6 +39,27 @@ mod decl {
         }
     }
 
+    /// Two-character hex expansion of every byte value. Encoding through
+    /// this table keeps the no-separator `hexlify` path a straight-line
+    /// copy loop that the compiler can vectorize.
+    const HEX_PAIRS: [[u8; 2]; 256] = {
+        let mut table = [[0u8; 2]; 256];
+        let mut i = 0;
+        while i < 256 {
+            table[i] = [hex_nibble((i >> 4) as u8), hex_nibble((i & 0xf) as u8)];
+            i += 1;
+        }
+        table
+    };
+
+    fn hex_encode_fast(bytes: &[u8]) -> Vec<u8> {
+        let mut hex = vec![0u8; bytes.len() * 2];
+        for (out, b) in hex.chunks_exact_mut(2).zip(bytes) {
+            out.copy_from_slice(&HEX_PAIRS[*b as usize]);
+        }
+        hex
+    }
+
     #[pyfunction(name = "b2a_hex")]
     #[pyfunction]
     fn hexlify(
@@ -70,12 +89,7 @@ mod decl {
 
             // If no separator or bytes_per_sep is 0, use simple hexlify
             if sep_char.is_none() || bytes_per_sep == 0 || bytes.is_empty() {
-                let mut hex = Vec::<u8>::with_capacity(bytes.len() * 2);
-                for b in bytes {
-                    hex.push(hex_nibble(b >> 4));
-                    hex.push(hex_nibble(b & 0xf));
-                }
-                return Ok(hex);
+                return Ok(hex_encode_fast(bytes));
             }
 
             let sep_char = sep_char.unwrap();
@@ -83,12 +97,7 @@ mod decl {
 
             // If separator interval is >= data length, no separators needed
             if abs_bytes_per_sep >= bytes.len() {
-                let mut hex = Vec::<u8>::with_capacity(bytes.len() * 2);
-                for b in bytes {
-                    hex.push(hex_nibble(b >> 4));
-                    hex.push(hex_nibble(b & 0xf));
-                }
-                return Ok(hex);
+                return Ok(hex_encode_fast(bytes));
             }
 
             // Calculate result length
@@ -169,6 +178,20 @@ mod decl {
         }
     }
 
+    /// `unhex_nibble` flattened into a table with `-1` marking invalid
+    /// input, so the decode loop is branch-free except for the error check.
+    const UNHEX_TABLE: [i8; 256] = {
+        let mut table = [-1i8; 256];
+        let mut i = 0;
+        while i < 256 {
+            if let Some(n) = unhex_nibble(i as u8) {
+                table[i] = n as i8;
+            }
+            i += 1;
+        }
+        table
+    };
+
     #[pyfunction(name = "a2b_hex")]
     #[pyfunction]
     fn unhexlify(data: ArgAsciiBuffer, vm: &VirtualMachine) -> PyResult<Vec<u8>> {
@@ -180,16 +203,16 @@ mod decl {
                 ));
             }
 
-            let mut unhex = Vec::<u8>::with_capacity(hex_bytes.len() / 2);
-            for (n1, n2) in hex_bytes.iter().tuples() {
-                if let (Some(n1), Some(n2)) = (unhex_nibble(*n1), unhex_nibble(*n2)) {
-                    unhex.push((n1 << 4) | n2);
-                } else {
+            let mut unhex = vec![0u8; hex_bytes.len() / 2];
+            for (out, pair) in unhex.iter_mut().zip(hex_bytes.chunks_exact(2)) {
+                let (n1, n2) = (UNHEX_TABLE[pair[0] as usize], UNHEX_TABLE[pair[1] as usize]);
+                if (n1 | n2) < 0 {
                     return Err(super::new_binascii_error(
                         "Non-hexadecimal digit found".to_owned(),
                         vm,
                     ));
                 }
+                *out = ((n1 as u8) << 4) | n2 as u8;
             }
 
             Ok(unhex)
@@ -298,13 +321,34 @@ mod decl {
                 return Err(base64::DecodeError::InvalidByte(0, 61));
             }
 
-            let mut decoded: Vec<u8> = vec![];
+            let mut decoded: Vec<u8> = Vec::with_capacity(b.len() / 4 * 3);
+
+            // Fast path: consume well-formed leading quads (the usual shape of
+            // email and http payloads) three output bytes at a time. The
+            // general state machine below picks up at the first pad,
+            // whitespace or invalid byte.
+            let mut start = 0;
+            while let Some(quad) = b.get(start..start + 4) {
+                let (c0, c1, c2, c3) = (
+                    BASE64_TABLE[quad[0] as usize],
+                    BASE64_TABLE[quad[1] as usize],
+                    BASE64_TABLE[quad[2] as usize],
+                    BASE64_TABLE[quad[3] as usize],
+                );
+                // the table maps PAD to 0, so pads must be rejected explicitly
+                if (c0 | c1 | c2 | c3) < 0 || quad.contains(&PAD) {
+                    break;
+                }
+                let group = (c0 as u32) << 18 | (c1 as u32) << 12 | (c2 as u32) << 6 | c3 as u32;
+                decoded.extend_from_slice(&[(group >> 16) as u8, (group >> 8) as u8, group as u8]);
+                start += 4;
+            }
 
             let mut quad_pos = 0; // position in the nibble
             let mut pads = 0;
             let mut left_char: u8 = 0;
             let mut padding_started = false;
-            for (i, &el) in b.iter().enumerate() {
+            for (i, &el) in b.iter().enumerate().skip(start) {
                 if el == PAD {
                     padding_started = true;
 
@@ -376,16 +420,50 @@ mod decl {
         .map_err(|err| super::Base64DecodeError(err).to_pyexception(vm))
     }
 
+    /// Standard base64 alphabet, indexed by 6-bit group value; the encode
+    /// counterpart of `BASE64_TABLE` in `a2b_base64`.
+    const BASE64_ALPHABET: &[u8; 64] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
     #[pyfunction]
     fn b2a_base64(data: ArgBytesLike, NewlineArg { newline }: NewlineArg) -> Vec<u8> {
-        // https://stackoverflow.com/questions/63916821
-        let mut encoded = data
-            .with_ref(|b| base64::engine::general_purpose::STANDARD.encode(b))
-            .into_bytes();
-        if newline {
-            encoded.push(b'\n');
-        }
-        encoded
+        data.with_ref(|b| {
+            // 3-to-4 expansion plus the optional trailing newline in a single
+            // allocation; like the hexlify kernel, the chunked loop keeps the
+            // hot path free of bounds checks so it can vectorize.
+            let mut encoded = Vec::with_capacity(b.len().div_ceil(3) * 4 + 1);
+            let mut chunks = b.chunks_exact(3);
+            for chunk in &mut chunks {
+                let group =
+                    u32::from(chunk[0]) << 16 | u32::from(chunk[1]) << 8 | u32::from(chunk[2]);
+                encoded.extend_from_slice(&[
+                    BASE64_ALPHABET[(group >> 18) as usize & 0x3f],
+                    BASE64_ALPHABET[(group >> 12) as usize & 0x3f],
+                    BASE64_ALPHABET[(group >> 6) as usize & 0x3f],
+                    BASE64_ALPHABET[group as usize & 0x3f],
+                ]);
+            }
+            match *chunks.remainder() {
+                [] => {}
+                [b0] => encoded.extend_from_slice(&[
+                    BASE64_ALPHABET[(b0 >> 2) as usize],
+                    BASE64_ALPHABET[((b0 & 0x03) << 4) as usize],
+                    PAD,
+                    PAD,
+                ]),
+                [b0, b1] => encoded.extend_from_slice(&[
+                    BASE64_ALPHABET[(b0 >> 2) as usize],
+                    BASE64_ALPHABET[(((b0 & 0x03) << 4) | (b1 >> 4)) as usize],
+                    BASE64_ALPHABET[((b1 & 0x0f) << 2) as usize],
+                    PAD,
+                ]),
+                _ => unreachable!("chunks_exact(3) remainder is at most 2 bytes"),
+            }
+            if newline {
+                encoded.push(b'\n');
+            }
+            encoded
+        })
     }
 
     #[inline]
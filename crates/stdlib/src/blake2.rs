@@ -4,16 +4,484 @@ pub(crate) use _blake2::module_def;
 
 #[pymodule]
 mod _blake2 {
-    use crate::hashlib::_hashlib::{BlakeHashArgs, local_blake2b, local_blake2s};
-    use crate::vm::{PyPayload, PyResult, VirtualMachine};
+    use crate::common::lock::PyRwLock;
+    use crate::vm::{
+        Py, PyPayload, PyResult, VirtualMachine,
+        builtins::{PyBytes, PyType},
+        function::{ArgBytesLike, OptionalArg},
+        types::{Constructor, Representable},
+    };
 
-    #[pyfunction]
-    fn blake2b(args: BlakeHashArgs, vm: &VirtualMachine) -> PyResult {
-        Ok(local_blake2b(args).into_pyobject(vm))
+    // message word schedule from RFC 7693; blake2b reuses rows 0 and 1 for
+    // its final two rounds
+    const SIGMA: [[usize; 16]; 10] = [
+        [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+        [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+        [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+        [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+        [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+        [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+        [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+        [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+        [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+        [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+    ];
+
+    const BLAKE2B_IV: [u64; 8] = [
+        0x6a09e667f3bcc908,
+        0xbb67ae8584caa73b,
+        0x3c6ef372fe94f82b,
+        0xa54ff53a5f1d36f1,
+        0x510e527fade682d1,
+        0x9b05688c2b3e6c1f,
+        0x1f83d9abfb41bd6b,
+        0x5be0cd19137e2179,
+    ];
+
+    const BLAKE2S_IV: [u32; 8] = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+        0x5be0cd19,
+    ];
+
+    macro_rules! blake2_core {
+        ($core:ident, $word:ty, $block_size:expr, $rounds:expr, $iv:expr, $rots:expr) => {
+            #[derive(Clone)]
+            struct $core {
+                h: [$word; 8],
+                t: u128,
+                buf: [u8; $block_size],
+                buf_len: usize,
+                last_node: bool,
+            }
+
+            impl $core {
+                const BLOCK_SIZE: usize = $block_size;
+                const WORD_BYTES: usize = core::mem::size_of::<$word>();
+
+                fn new(param_block: &[u8], key: &[u8], last_node: bool) -> Self {
+                    let mut h = $iv;
+                    for (h, chunk) in h.iter_mut().zip(param_block.chunks_exact(Self::WORD_BYTES)) {
+                        *h ^= <$word>::from_le_bytes(chunk.try_into().unwrap());
+                    }
+                    let mut core = Self {
+                        h,
+                        t: 0,
+                        buf: [0; $block_size],
+                        buf_len: 0,
+                        last_node,
+                    };
+                    if !key.is_empty() {
+                        let mut block = [0u8; $block_size];
+                        block[..key.len()].copy_from_slice(key);
+                        core.update(&block);
+                    }
+                    core
+                }
+
+                fn g(
+                    v: &mut [$word; 16],
+                    a: usize,
+                    b: usize,
+                    c: usize,
+                    d: usize,
+                    x: $word,
+                    y: $word,
+                ) {
+                    const R: [u32; 4] = $rots;
+                    v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+                    v[d] = (v[d] ^ v[a]).rotate_right(R[0]);
+                    v[c] = v[c].wrapping_add(v[d]);
+                    v[b] = (v[b] ^ v[c]).rotate_right(R[1]);
+                    v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+                    v[d] = (v[d] ^ v[a]).rotate_right(R[2]);
+                    v[c] = v[c].wrapping_add(v[d]);
+                    v[b] = (v[b] ^ v[c]).rotate_right(R[3]);
+                }
+
+                fn compress(&mut self, block: &[u8], last: bool) {
+                    let mut m = [0 as $word; 16];
+                    for (m, chunk) in m.iter_mut().zip(block.chunks_exact(Self::WORD_BYTES)) {
+                        *m = <$word>::from_le_bytes(chunk.try_into().unwrap());
+                    }
+                    let mut v = [0 as $word; 16];
+                    v[..8].copy_from_slice(&self.h);
+                    v[8..].copy_from_slice(&$iv);
+                    v[12] ^= self.t as $word;
+                    v[13] ^= (self.t >> (8 * Self::WORD_BYTES)) as $word;
+                    if last {
+                        v[14] = !v[14];
+                        if self.last_node {
+                            v[15] = !v[15];
+                        }
+                    }
+                    for r in 0..$rounds {
+                        let s = &SIGMA[r % 10];
+                        Self::g(&mut v, 0, 4, 8, 12, m[s[0]], m[s[1]]);
+                        Self::g(&mut v, 1, 5, 9, 13, m[s[2]], m[s[3]]);
+                        Self::g(&mut v, 2, 6, 10, 14, m[s[4]], m[s[5]]);
+                        Self::g(&mut v, 3, 7, 11, 15, m[s[6]], m[s[7]]);
+                        Self::g(&mut v, 0, 5, 10, 15, m[s[8]], m[s[9]]);
+                        Self::g(&mut v, 1, 6, 11, 12, m[s[10]], m[s[11]]);
+                        Self::g(&mut v, 2, 7, 8, 13, m[s[12]], m[s[13]]);
+                        Self::g(&mut v, 3, 4, 9, 14, m[s[14]], m[s[15]]);
+                    }
+                    for (i, h) in self.h.iter_mut().enumerate() {
+                        *h ^= v[i] ^ v[i + 8];
+                    }
+                }
+
+                fn update(&mut self, mut data: &[u8]) {
+                    if data.is_empty() {
+                        return;
+                    }
+                    let fill = Self::BLOCK_SIZE - self.buf_len;
+                    if data.len() > fill {
+                        self.buf[self.buf_len..].copy_from_slice(&data[..fill]);
+                        data = &data[fill..];
+                        self.t += Self::BLOCK_SIZE as u128;
+                        let block = self.buf;
+                        self.compress(&block, false);
+                        self.buf_len = 0;
+                        while data.len() > Self::BLOCK_SIZE {
+                            self.t += Self::BLOCK_SIZE as u128;
+                            self.compress(&data[..Self::BLOCK_SIZE], false);
+                            data = &data[Self::BLOCK_SIZE..];
+                        }
+                    }
+                    self.buf[self.buf_len..self.buf_len + data.len()].copy_from_slice(data);
+                    self.buf_len += data.len();
+                }
+
+                fn finalize(&self, digest_size: usize) -> Vec<u8> {
+                    let mut this = self.clone();
+                    this.t += this.buf_len as u128;
+                    this.buf[this.buf_len..].fill(0);
+                    let block = this.buf;
+                    this.compress(&block, true);
+                    let mut out: Vec<u8> = this.h.iter().flat_map(|w| w.to_le_bytes()).collect();
+                    out.truncate(digest_size);
+                    out
+                }
+            }
+        };
+    }
+
+    blake2_core!(Blake2bCore, u64, 128, 12, BLAKE2B_IV, [32, 24, 16, 63]);
+    blake2_core!(Blake2sCore, u32, 64, 10, BLAKE2S_IV, [16, 12, 8, 7]);
+
+    #[derive(FromArgs)]
+    #[allow(unused)]
+    pub struct Blake2Args {
+        #[pyarg(positional, optional)]
+        data: OptionalArg<ArgBytesLike>,
+        #[pyarg(named, optional)]
+        digest_size: OptionalArg<usize>,
+        #[pyarg(named, optional)]
+        key: OptionalArg<ArgBytesLike>,
+        #[pyarg(named, optional)]
+        salt: OptionalArg<ArgBytesLike>,
+        #[pyarg(named, optional)]
+        person: OptionalArg<ArgBytesLike>,
+        #[pyarg(named, default = 1)]
+        fanout: u8,
+        #[pyarg(named, default = 1)]
+        depth: u8,
+        #[pyarg(named, default = 0)]
+        leaf_size: u32,
+        #[pyarg(named, default = 0)]
+        node_offset: u64,
+        #[pyarg(named, default = 0)]
+        node_depth: u8,
+        #[pyarg(named, default = 0)]
+        inner_size: u8,
+        #[pyarg(named, default = false)]
+        last_node: bool,
+        #[pyarg(named, default = true)]
+        usedforsecurity: bool,
+    }
+
+    struct Blake2Params {
+        digest_size: usize,
+        key: Vec<u8>,
+        salt: Vec<u8>,
+        person: Vec<u8>,
+    }
+
+    impl Blake2Args {
+        fn check(
+            &self,
+            name: &str,
+            max_digest_size: usize,
+            max_key_size: usize,
+            salt_size: usize,
+            person_size: usize,
+            vm: &VirtualMachine,
+        ) -> PyResult<Blake2Params> {
+            let digest_size = match self.digest_size {
+                OptionalArg::Present(size) => size,
+                OptionalArg::Missing => max_digest_size,
+            };
+            if digest_size == 0 || digest_size > max_digest_size {
+                return Err(vm.new_value_error(format!(
+                    "digest_size for {name} must be between 1 and {max_digest_size} bytes"
+                )));
+            }
+            let to_vec = |arg: &OptionalArg<ArgBytesLike>| match arg {
+                OptionalArg::Present(bytes) => bytes.borrow_buf().to_vec(),
+                OptionalArg::Missing => Vec::new(),
+            };
+            let key = to_vec(&self.key);
+            if key.len() > max_key_size {
+                return Err(
+                    vm.new_value_error(format!("maximum key length is {max_key_size} bytes"))
+                );
+            }
+            let salt = to_vec(&self.salt);
+            if salt.len() > salt_size {
+                return Err(vm.new_value_error(format!("maximum salt length is {salt_size} bytes")));
+            }
+            let person = to_vec(&self.person);
+            if person.len() > person_size {
+                return Err(
+                    vm.new_value_error(format!("maximum person length is {person_size} bytes"))
+                );
+            }
+            if self.depth == 0 {
+                return Err(vm.new_value_error("depth must be between 1 and 255"));
+            }
+            if usize::from(self.inner_size) > max_digest_size {
+                return Err(vm.new_value_error(format!(
+                    "inner_size must be between 0 and {max_digest_size}"
+                )));
+            }
+            Ok(Blake2Params {
+                digest_size,
+                key,
+                salt,
+                person,
+            })
+        }
+    }
+
+    #[pyattr]
+    #[pyclass(module = "_blake2", name = "blake2b")]
+    #[derive(PyPayload)]
+    pub struct PyBlake2b {
+        digest_size: usize,
+        core: PyRwLock<Blake2bCore>,
+    }
+
+    impl core::fmt::Debug for PyBlake2b {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(f, "blake2b")
+        }
+    }
+
+    impl Constructor for PyBlake2b {
+        type Args = Blake2Args;
+
+        fn py_new(_cls: &Py<PyType>, args: Self::Args, vm: &VirtualMachine) -> PyResult<Self> {
+            let params = args.check(
+                "blake2b",
+                Self::MAX_DIGEST_SIZE,
+                Self::MAX_KEY_SIZE,
+                Self::SALT_SIZE,
+                Self::PERSON_SIZE,
+                vm,
+            )?;
+
+            let mut p = [0u8; 64];
+            p[0] = params.digest_size as u8;
+            p[1] = params.key.len() as u8;
+            p[2] = args.fanout;
+            p[3] = args.depth;
+            p[4..8].copy_from_slice(&args.leaf_size.to_le_bytes());
+            p[8..16].copy_from_slice(&args.node_offset.to_le_bytes());
+            p[16] = args.node_depth;
+            p[17] = args.inner_size;
+            p[32..32 + params.salt.len()].copy_from_slice(&params.salt);
+            p[48..48 + params.person.len()].copy_from_slice(&params.person);
+
+            let mut core = Blake2bCore::new(&p, &params.key, args.last_node);
+            if let OptionalArg::Present(data) = &args.data {
+                data.with_ref(|bytes| core.update(bytes));
+            }
+            Ok(Self {
+                digest_size: params.digest_size,
+                core: PyRwLock::new(core),
+            })
+        }
+    }
+
+    #[pyclass(with(Constructor, Representable), flags(IMMUTABLETYPE))]
+    impl PyBlake2b {
+        #[pyattr]
+        const SALT_SIZE: usize = 16;
+        #[pyattr]
+        const PERSON_SIZE: usize = 16;
+        #[pyattr]
+        const MAX_KEY_SIZE: usize = 64;
+        #[pyattr]
+        const MAX_DIGEST_SIZE: usize = 64;
+
+        #[pygetset]
+        fn name(&self) -> String {
+            "blake2b".to_owned()
+        }
+
+        #[pygetset]
+        const fn digest_size(&self) -> usize {
+            self.digest_size
+        }
+
+        #[pygetset]
+        const fn block_size(&self) -> usize {
+            Blake2bCore::BLOCK_SIZE
+        }
+
+        #[pymethod]
+        fn update(&self, data: ArgBytesLike) {
+            data.with_ref(|bytes| self.core.write().update(bytes));
+        }
+
+        #[pymethod]
+        fn digest(&self) -> PyBytes {
+            self.core.read().finalize(self.digest_size).into()
+        }
+
+        #[pymethod]
+        fn hexdigest(&self) -> String {
+            hex::encode(self.core.read().finalize(self.digest_size))
+        }
+
+        #[pymethod]
+        fn copy(&self) -> Self {
+            Self {
+                digest_size: self.digest_size,
+                core: PyRwLock::new(self.core.read().clone()),
+            }
+        }
+    }
+
+    impl Representable for PyBlake2b {
+        fn repr_str(zelf: &Py<Self>, _vm: &VirtualMachine) -> PyResult<String> {
+            Ok(format!(
+                "<blake2b _blake2.blake2b object @ {:#x}>",
+                zelf as *const _ as usize
+            ))
+        }
+    }
+
+    #[pyattr]
+    #[pyclass(module = "_blake2", name = "blake2s")]
+    #[derive(PyPayload)]
+    pub struct PyBlake2s {
+        digest_size: usize,
+        core: PyRwLock<Blake2sCore>,
+    }
+
+    impl core::fmt::Debug for PyBlake2s {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(f, "blake2s")
+        }
+    }
+
+    impl Constructor for PyBlake2s {
+        type Args = Blake2Args;
+
+        fn py_new(_cls: &Py<PyType>, args: Self::Args, vm: &VirtualMachine) -> PyResult<Self> {
+            let params = args.check(
+                "blake2s",
+                Self::MAX_DIGEST_SIZE,
+                Self::MAX_KEY_SIZE,
+                Self::SALT_SIZE,
+                Self::PERSON_SIZE,
+                vm,
+            )?;
+            // the blake2s node offset field is only 48 bits wide
+            if args.node_offset >= 1 << 48 {
+                return Err(vm.new_overflow_error("node_offset is too large"));
+            }
+
+            let mut p = [0u8; 32];
+            p[0] = params.digest_size as u8;
+            p[1] = params.key.len() as u8;
+            p[2] = args.fanout;
+            p[3] = args.depth;
+            p[4..8].copy_from_slice(&args.leaf_size.to_le_bytes());
+            p[8..14].copy_from_slice(&args.node_offset.to_le_bytes()[..6]);
+            p[14] = args.node_depth;
+            p[15] = args.inner_size;
+            p[16..16 + params.salt.len()].copy_from_slice(&params.salt);
+            p[24..24 + params.person.len()].copy_from_slice(&params.person);
+
+            let mut core = Blake2sCore::new(&p, &params.key, args.last_node);
+            if let OptionalArg::Present(data) = &args.data {
+                data.with_ref(|bytes| core.update(bytes));
+            }
+            Ok(Self {
+                digest_size: params.digest_size,
+                core: PyRwLock::new(core),
+            })
+        }
+    }
+
+    #[pyclass(with(Constructor, Representable), flags(IMMUTABLETYPE))]
+    impl PyBlake2s {
+        #[pyattr]
+        const SALT_SIZE: usize = 8;
+        #[pyattr]
+        const PERSON_SIZE: usize = 8;
+        #[pyattr]
+        const MAX_KEY_SIZE: usize = 32;
+        #[pyattr]
+        const MAX_DIGEST_SIZE: usize = 32;
+
+        #[pygetset]
+        fn name(&self) -> String {
+            "blake2s".to_owned()
+        }
+
+        #[pygetset]
+        const fn digest_size(&self) -> usize {
+            self.digest_size
+        }
+
+        #[pygetset]
+        const fn block_size(&self) -> usize {
+            Blake2sCore::BLOCK_SIZE
+        }
+
+        #[pymethod]
+        fn update(&self, data: ArgBytesLike) {
+            data.with_ref(|bytes| self.core.write().update(bytes));
+        }
+
+        #[pymethod]
+        fn digest(&self) -> PyBytes {
+            self.core.read().finalize(self.digest_size).into()
+        }
+
+        #[pymethod]
+        fn hexdigest(&self) -> String {
+            hex::encode(self.core.read().finalize(self.digest_size))
+        }
+
+        #[pymethod]
+        fn copy(&self) -> Self {
+            Self {
+                digest_size: self.digest_size,
+                core: PyRwLock::new(self.core.read().clone()),
+            }
+        }
     }
 
-    #[pyfunction]
-    fn blake2s(args: BlakeHashArgs, vm: &VirtualMachine) -> PyResult {
-        Ok(local_blake2s(args).into_pyobject(vm))
+    impl Representable for PyBlake2s {
+        fn repr_str(zelf: &Py<Self>, _vm: &VirtualMachine) -> PyResult<String> {
+            Ok(format!(
+                "<blake2s _blake2.blake2s object @ {:#x}>",
+                zelf as *const _ as usize
+            ))
+        }
     }
 }
@@ -25,11 +25,365 @@ mod _contextvars {
         sync::atomic::Ordering,
     };
     use crossbeam_utils::atomic::AtomicCell;
-    use indexmap::IndexMap;
     use rustpython_common::lock::LazyLock;
+    use std::sync::Arc;
+
+    /// Persistent hash-array-mapped trie, mirroring CPython's `hamt.c`.
+    ///
+    /// Keys are hashed with the `ContextVar`'s precomputed hash and compared
+    /// by identity. All update operations copy only the path from the root to
+    /// the affected leaf and share every other node, so cloning a `Hamt` (and
+    /// therefore `Context.copy()`/`copy_context()`) is O(1).
+    #[derive(Debug, Clone, Default)]
+    struct Hamt {
+        root: Option<Arc<HamtNode>>,
+        count: usize,
+    }
+
+    type HamtKv = (PyRef<ContextVar>, PyObjectRef);
+
+    #[derive(Debug)]
+    enum HamtNode {
+        /// Sparse up-to-32-way branch; `entries` holds the set bits of
+        /// `bitmap` in order.
+        Bitmap {
+            bitmap: u32,
+            entries: Box<[HamtEntry]>,
+        },
+        /// All keys whose full 64-bit hashes are equal.
+        Collision { hash: u64, items: Box<[HamtKv]> },
+    }
+
+    #[derive(Debug, Clone)]
+    enum HamtEntry {
+        KeyValue(PyRef<ContextVar>, PyObjectRef),
+        Child(Arc<HamtNode>),
+    }
+
+    const HAMT_BITS: u32 = 5;
+    const HAMT_MASK: u64 = (1 << HAMT_BITS) - 1;
+
+    const fn hamt_index(hash: u64, shift: u32) -> u32 {
+        ((hash >> shift) & HAMT_MASK) as u32
+    }
+
+    fn hamt_key_hash(key: &Py<ContextVar>) -> u64 {
+        // SAFETY: the hash is written once in `ContextVar::slot_new` before
+        // the object becomes visible and is immutable afterwards.
+        unsafe { *key.hash.get() as u64 }
+    }
+
+    /// Build the smallest subtree holding two key-value pairs with distinct
+    /// keys. Recursion terminates because differing hashes split at some
+    /// level; equal hashes end in a collision node.
+    fn hamt_merge(shift: u32, h1: u64, kv1: HamtKv, h2: u64, kv2: HamtKv) -> Arc<HamtNode> {
+        if h1 == h2 {
+            return Arc::new(HamtNode::Collision {
+                hash: h1,
+                items: Box::new([kv1, kv2]),
+            });
+        }
+        let (i1, i2) = (hamt_index(h1, shift), hamt_index(h2, shift));
+        if i1 == i2 {
+            let child = hamt_merge(shift + HAMT_BITS, h1, kv1, h2, kv2);
+            Arc::new(HamtNode::Bitmap {
+                bitmap: 1 << i1,
+                entries: Box::new([HamtEntry::Child(child)]),
+            })
+        } else {
+            let (e1, e2) = (
+                HamtEntry::KeyValue(kv1.0, kv1.1),
+                HamtEntry::KeyValue(kv2.0, kv2.1),
+            );
+            let entries = if i1 < i2 { [e1, e2] } else { [e2, e1] };
+            Arc::new(HamtNode::Bitmap {
+                bitmap: (1 << i1) | (1 << i2),
+                entries: Box::new(entries),
+            })
+        }
+    }
+
+    /// Return a new subtree with `key` bound to `value`, plus whether a new
+    /// entry was added (false means an existing binding was replaced).
+    fn hamt_assoc(
+        node: &Arc<HamtNode>,
+        shift: u32,
+        hash: u64,
+        key: &PyRef<ContextVar>,
+        value: &PyObjectRef,
+    ) -> (Arc<HamtNode>, bool) {
+        match &**node {
+            HamtNode::Bitmap { bitmap, entries } => {
+                let bit = 1u32 << hamt_index(hash, shift);
+                let idx = (bitmap & (bit - 1)).count_ones() as usize;
+                let mut new_entries = entries.to_vec();
+                if bitmap & bit == 0 {
+                    new_entries.insert(idx, HamtEntry::KeyValue(key.clone(), value.clone()));
+                    let node = HamtNode::Bitmap {
+                        bitmap: bitmap | bit,
+                        entries: new_entries.into(),
+                    };
+                    return (Arc::new(node), true);
+                }
+                let (entry, added) = match &entries[idx] {
+                    HamtEntry::KeyValue(k, v) => {
+                        if k.is(key) {
+                            (HamtEntry::KeyValue(key.clone(), value.clone()), false)
+                        } else {
+                            let child = hamt_merge(
+                                shift + HAMT_BITS,
+                                hamt_key_hash(k),
+                                (k.clone(), v.clone()),
+                                hash,
+                                (key.clone(), value.clone()),
+                            );
+                            (HamtEntry::Child(child), true)
+                        }
+                    }
+                    HamtEntry::Child(child) => {
+                        let (child, added) = hamt_assoc(child, shift + HAMT_BITS, hash, key, value);
+                        (HamtEntry::Child(child), added)
+                    }
+                };
+                new_entries[idx] = entry;
+                let node = HamtNode::Bitmap {
+                    bitmap: *bitmap,
+                    entries: new_entries.into(),
+                };
+                (Arc::new(node), added)
+            }
+            HamtNode::Collision {
+                hash: node_hash,
+                items,
+            } => {
+                if *node_hash == hash {
+                    let mut new_items = items.to_vec();
+                    let added = if let Some(kv) = new_items.iter_mut().find(|(k, _)| k.is(key)) {
+                        kv.1 = value.clone();
+                        false
+                    } else {
+                        new_items.push((key.clone(), value.clone()));
+                        true
+                    };
+                    let node = HamtNode::Collision {
+                        hash,
+                        items: new_items.into(),
+                    };
+                    (Arc::new(node), added)
+                } else {
+                    // Push the collision node one level down and retry.
+                    let bit = 1u32 << hamt_index(*node_hash, shift);
+                    let wrapped = Arc::new(HamtNode::Bitmap {
+                        bitmap: bit,
+                        entries: Box::new([HamtEntry::Child(node.clone())]),
+                    });
+                    hamt_assoc(&wrapped, shift, hash, key, value)
+                }
+            }
+        }
+    }
+
+    /// Return the subtree without `key` along with the removed value, or
+    /// `None` if the key is absent. An empty subtree is reported as `None`.
+    #[allow(clippy::type_complexity)]
+    fn hamt_dissoc(
+        node: &Arc<HamtNode>,
+        shift: u32,
+        hash: u64,
+        key: &Py<ContextVar>,
+    ) -> Option<(Option<Arc<HamtNode>>, PyObjectRef)> {
+        match &**node {
+            HamtNode::Bitmap { bitmap, entries } => {
+                let bit = 1u32 << hamt_index(hash, shift);
+                if bitmap & bit == 0 {
+                    return None;
+                }
+                let idx = (bitmap & (bit - 1)).count_ones() as usize;
+                match &entries[idx] {
+                    HamtEntry::KeyValue(k, v) => {
+                        if !k.is(key) {
+                            return None;
+                        }
+                        let new_bitmap = bitmap & !bit;
+                        if new_bitmap == 0 {
+                            return Some((None, v.clone()));
+                        }
+                        let mut new_entries = entries.to_vec();
+                        new_entries.remove(idx);
+                        let node = HamtNode::Bitmap {
+                            bitmap: new_bitmap,
+                            entries: new_entries.into(),
+                        };
+                        Some((Some(Arc::new(node)), v.clone()))
+                    }
+                    HamtEntry::Child(child) => {
+                        let (new_child, removed) =
+                            hamt_dissoc(child, shift + HAMT_BITS, hash, key)?;
+                        let mut new_entries = entries.to_vec();
+                        let new_bitmap = match new_child {
+                            Some(child) => {
+                                new_entries[idx] = HamtEntry::Child(child);
+                                *bitmap
+                            }
+                            None => {
+                                new_entries.remove(idx);
+                                bitmap & !bit
+                            }
+                        };
+                        if new_bitmap == 0 {
+                            return Some((None, removed));
+                        }
+                        let node = HamtNode::Bitmap {
+                            bitmap: new_bitmap,
+                            entries: new_entries.into(),
+                        };
+                        Some((Some(Arc::new(node)), removed))
+                    }
+                }
+            }
+            HamtNode::Collision {
+                hash: node_hash,
+                items,
+            } => {
+                if *node_hash != hash {
+                    return None;
+                }
+                let pos = items.iter().position(|(k, _)| k.is(key))?;
+                let removed = items[pos].1.clone();
+                if items.len() == 1 {
+                    return Some((None, removed));
+                }
+                let mut new_items = items.to_vec();
+                new_items.remove(pos);
+                let node = HamtNode::Collision {
+                    hash,
+                    items: new_items.into(),
+                };
+                Some((Some(Arc::new(node)), removed))
+            }
+        }
+    }
+
+    enum HamtIterFrame<'a> {
+        Entries(core::slice::Iter<'a, HamtEntry>),
+        Items(core::slice::Iter<'a, HamtKv>),
+    }
+
+    struct HamtIter<'a> {
+        stack: Vec<HamtIterFrame<'a>>,
+    }
+
+    impl<'a> Iterator for HamtIter<'a> {
+        type Item = (&'a PyRef<ContextVar>, &'a PyObjectRef);
+
+        fn next(&mut self) -> Option<Self::Item> {
+            loop {
+                match self.stack.last_mut()? {
+                    HamtIterFrame::Entries(entries) => match entries.next() {
+                        Some(HamtEntry::KeyValue(k, v)) => return Some((k, v)),
+                        Some(HamtEntry::Child(child)) => self.stack.push(match &**child {
+                            HamtNode::Bitmap { entries, .. } => {
+                                HamtIterFrame::Entries(entries.iter())
+                            }
+                            HamtNode::Collision { items, .. } => HamtIterFrame::Items(items.iter()),
+                        }),
+                        None => drop(self.stack.pop()),
+                    },
+                    HamtIterFrame::Items(items) => match items.next() {
+                        Some((k, v)) => return Some((k, v)),
+                        None => drop(self.stack.pop()),
+                    },
+                }
+            }
+        }
+    }
+
+    impl Hamt {
+        fn get(&self, key: &Py<ContextVar>) -> Option<&PyObjectRef> {
+            let mut node = self.root.as_deref()?;
+            let hash = hamt_key_hash(key);
+            let mut shift = 0;
+            loop {
+                match node {
+                    HamtNode::Bitmap { bitmap, entries } => {
+                        let bit = 1u32 << hamt_index(hash, shift);
+                        if bitmap & bit == 0 {
+                            return None;
+                        }
+                        let idx = (bitmap & (bit - 1)).count_ones() as usize;
+                        match &entries[idx] {
+                            HamtEntry::KeyValue(k, v) => return k.is(key).then_some(v),
+                            HamtEntry::Child(child) => {
+                                node = child;
+                                shift += HAMT_BITS;
+                            }
+                        }
+                    }
+                    HamtNode::Collision {
+                        hash: node_hash,
+                        items,
+                    } => {
+                        if *node_hash != hash {
+                            return None;
+                        }
+                        return items.iter().find(|(k, _)| k.is(key)).map(|(_, v)| v);
+                    }
+                }
+            }
+        }
 
-    // TODO: Real hamt implementation
-    type Hamt = IndexMap<PyRef<ContextVar>, PyObjectRef, ahash::RandomState>;
+        fn insert(&mut self, key: PyRef<ContextVar>, value: PyObjectRef) {
+            let hash = hamt_key_hash(&key);
+            let (root, added) = match &self.root {
+                Some(root) => hamt_assoc(root, 0, hash, &key, &value),
+                None => {
+                    let bit = 1u32 << hamt_index(hash, 0);
+                    let node = HamtNode::Bitmap {
+                        bitmap: bit,
+                        entries: Box::new([HamtEntry::KeyValue(key, value)]),
+                    };
+                    (Arc::new(node), true)
+                }
+            };
+            self.root = Some(root);
+            if added {
+                self.count += 1;
+            }
+        }
+
+        fn remove(&mut self, key: &Py<ContextVar>) -> Option<PyObjectRef> {
+            let root = self.root.as_ref()?;
+            let (root, removed) = hamt_dissoc(root, 0, hamt_key_hash(key), key)?;
+            self.root = root;
+            self.count -= 1;
+            Some(removed)
+        }
+
+        const fn len(&self) -> usize {
+            self.count
+        }
+
+        fn iter(&self) -> HamtIter<'_> {
+            let stack = match self.root.as_deref() {
+                Some(HamtNode::Bitmap { entries, .. }) => {
+                    vec![HamtIterFrame::Entries(entries.iter())]
+                }
+                Some(HamtNode::Collision { items, .. }) => {
+                    vec![HamtIterFrame::Items(items.iter())]
+                }
+                None => vec![],
+            };
+            HamtIter { stack }
+        }
+
+        fn keys(&self) -> impl Iterator<Item = &PyRef<ContextVar>> {
+            self.iter().map(|(k, _)| k)
+        }
+
+        fn values(&self) -> impl Iterator<Item = &PyObjectRef> {
+            self.iter().map(|(_, v)| v)
+        }
+    }
 
     #[pyclass(no_attr, name = "Hamt", module = "contextvars")]
     #[derive(Debug, PyPayload)]
@@ -169,7 +523,7 @@ mod _contextvars {
 
         #[pymethod]
         fn copy(&self, vm: &VirtualMachine) -> Self {
-            // Deep copy the vars - clone the underlying Hamt data, not just the PyRef
+            // O(1): clones the persistent trie's root, sharing all nodes
             let vars_copy = HamtObject {
                 hamt: RefCell::new(self.inner.vars.hamt.borrow().clone()),
             };
@@ -330,7 +684,7 @@ mod _contextvars {
             let ctx = PyContext::current(vm);
 
             let mut vars = ctx.borrow_vars_mut();
-            if vars.swap_remove(zelf).is_none() {
+            if vars.remove(zelf).is_none() {
                 // TODO:
                 // PyErr_SetObject(PyExc_LookupError, (PyObject *)var);
                 let msg = zelf.as_object().repr(vm)?.as_str().to_owned();
@@ -0,0 +1,65 @@
+// spell-checker:ignore msgid msgstr masteridx transidx
+
+pub(crate) use _gettext::module_def;
+
+#[pymodule]
+mod _gettext {
+    use crate::vm::{PyResult, VirtualMachine, function::ArgBytesLike};
+
+    const LE_MAGIC: u32 = 0x950412de;
+    const BE_MAGIC: u32 = 0xde120495;
+
+    fn read_u32(buf: &[u8], offset: usize, le: bool) -> Option<u32> {
+        let bytes: [u8; 4] = buf.get(offset..offset + 4)?.try_into().ok()?;
+        Some(if le {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    /// Parse a GNU .mo catalog and return its entries as a list of
+    /// `(msgid, msgstr)` byte-string pairs in file order. Charset decoding,
+    /// metadata extraction and plural-form evaluation are left to
+    /// `gettext.py`, which wraps any `ValueError` raised here in `OSError`.
+    #[pyfunction]
+    fn parse_mo(data: ArgBytesLike, vm: &VirtualMachine) -> PyResult<Vec<(Vec<u8>, Vec<u8>)>> {
+        data.with_ref(|buf| {
+            let le = match read_u32(buf, 0, true) {
+                Some(LE_MAGIC) => true,
+                Some(BE_MAGIC) => false,
+                _ => return Err(vm.new_value_error("Bad magic number")),
+            };
+            let corrupt = || vm.new_value_error("File is corrupt");
+            let version = read_u32(buf, 4, le).ok_or_else(corrupt)?;
+            let major_version = version >> 16;
+            if major_version > 1 {
+                return Err(vm.new_value_error(format!("Bad version number {major_version}")));
+            }
+            let msgcount = read_u32(buf, 8, le).ok_or_else(corrupt)? as usize;
+            let mut masteridx = read_u32(buf, 12, le).ok_or_else(corrupt)? as usize;
+            let mut transidx = read_u32(buf, 16, le).ok_or_else(corrupt)? as usize;
+
+            let slice_at = |idx: usize| -> Option<&[u8]> {
+                let len = read_u32(buf, idx, le)? as usize;
+                let off = read_u32(buf, idx + 4, le)? as usize;
+                // mirror gettext.py's `end < buflen` corruption check
+                if off.checked_add(len)? < buf.len() {
+                    buf.get(off..off + len)
+                } else {
+                    None
+                }
+            };
+
+            let mut entries = Vec::with_capacity(msgcount.min(buf.len() / 8));
+            for _ in 0..msgcount {
+                let msg = slice_at(masteridx).ok_or_else(corrupt)?;
+                let tmsg = slice_at(transidx).ok_or_else(corrupt)?;
+                entries.push((msg.to_vec(), tmsg.to_vec()));
+                masteridx += 8;
+                transidx += 8;
+            }
+            Ok(entries)
+        })
+    }
+}
@@ -5,8 +5,10 @@ mod machinery;
 mod _json {
     use super::machinery;
     use crate::vm::{
-        AsObject, Py, PyObjectRef, PyPayload, PyResult, VirtualMachine,
-        builtins::{PyBaseExceptionRef, PyStrRef, PyType},
+        AsObject, Py, PyObject, PyObjectRef, PyPayload, PyResult, VirtualMachine,
+        builtins::{
+            PyBaseExceptionRef, PyDict, PyFloat, PyInt, PyList, PyStr, PyStrRef, PyTuple, PyType,
+        },
         convert::ToPyResult,
         function::{IntoFuncArgs, OptionalArg},
         protocol::PyIterReturn,
@@ -673,6 +675,139 @@ mod _json {
         unsafe { String::from_utf8_unchecked(buf) }
     }
 
+    /// Escape a string for the fast path. Strings containing lone surrogates
+    /// cannot be viewed as `&str`, so those are escaped per code point; the
+    /// output stays pure ASCII either way.
+    fn encode_pystr_ascii(s: &PyStr) -> String {
+        if let Some(s) = s.to_str() {
+            return encode_string(s, true);
+        }
+        use core::fmt::Write;
+        let mut buf = String::with_capacity(s.byte_len() + 2);
+        buf.push('"');
+        for point in s.as_wtf8().code_points() {
+            match point.to_char() {
+                Some(c) if c.is_ascii() => {
+                    if let Some(escaped) = machinery::json_escaped_char(c as u8) {
+                        buf.push_str(escaped);
+                    } else {
+                        buf.push(c);
+                    }
+                }
+                Some(c) => {
+                    for unit in c.encode_utf16(&mut [0; 2]) {
+                        write!(buf, "\\u{unit:04x}").unwrap();
+                    }
+                }
+                // lone surrogate, escaped just like CPython does
+                None => write!(buf, "\\u{:04x}", point.to_u32()).unwrap(),
+            }
+        }
+        buf.push('"');
+        buf
+    }
+
+    /// Serialize a tree of plain `dict`/`list`/`tuple`/`str`/`int`/`float`/
+    /// `bool`/`None` objects, matching the output of `json.dumps` with its
+    /// default arguments byte-for-byte. Any subclass or unsupported object
+    /// raises `TypeError` so callers can fall back to the Python encoder.
+    /// Container recursion goes through the VM's recursion guard, so deep
+    /// nesting raises `RecursionError` like the pure Python encoder.
+    fn write_fast(
+        obj: &PyObject,
+        buf: &mut String,
+        seen: &mut Vec<usize>,
+        vm: &VirtualMachine,
+    ) -> PyResult<()> {
+        let cls = obj.class();
+        if vm.is_none(obj) {
+            buf.push_str("null");
+        } else if cls.is(vm.ctx.types.bool_type) {
+            buf.push_str(if obj.is(&vm.ctx.true_value) {
+                "true"
+            } else {
+                "false"
+            });
+        } else if cls.is(vm.ctx.types.int_type) {
+            let int = unsafe { obj.downcast_unchecked_ref::<PyInt>() };
+            buf.push_str(&int.as_bigint().to_string());
+        } else if cls.is(vm.ctx.types.float_type) {
+            let value = unsafe { obj.downcast_unchecked_ref::<PyFloat>() }.to_f64();
+            if value.is_finite() {
+                buf.push_str(&crate::vm::literal::float::to_string(value));
+            } else if value.is_nan() {
+                buf.push_str("NaN");
+            } else if value > 0.0 {
+                buf.push_str("Infinity");
+            } else {
+                buf.push_str("-Infinity");
+            }
+        } else if cls.is(vm.ctx.types.str_type) {
+            let s = unsafe { obj.downcast_unchecked_ref::<PyStr>() };
+            buf.push_str(&encode_pystr_ascii(s));
+        } else if cls.is(vm.ctx.types.list_type) || cls.is(vm.ctx.types.tuple_type) {
+            let id = obj.get_id();
+            if seen.contains(&id) {
+                return Err(vm.new_value_error("Circular reference detected"));
+            }
+            seen.push(id);
+            buf.push('[');
+            let items: Vec<PyObjectRef> = if let Some(list) = obj.downcast_ref::<PyList>() {
+                list.borrow_vec().to_vec()
+            } else {
+                unsafe { obj.downcast_unchecked_ref::<PyTuple>() }.to_vec()
+            };
+            vm.with_recursion("while encoding a JSON document", || {
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        buf.push_str(", ");
+                    }
+                    write_fast(item, buf, seen, vm)?;
+                }
+                Ok(())
+            })?;
+            buf.push(']');
+            seen.pop();
+        } else if cls.is(vm.ctx.types.dict_type) {
+            let id = obj.get_id();
+            if seen.contains(&id) {
+                return Err(vm.new_value_error("Circular reference detected"));
+            }
+            seen.push(id);
+            buf.push('{');
+            let dict = unsafe { obj.downcast_unchecked_ref::<PyDict>() };
+            vm.with_recursion("while encoding a JSON document", || {
+                for (i, (key, value)) in dict.into_iter().enumerate() {
+                    if i > 0 {
+                        buf.push_str(", ");
+                    }
+                    let key = key.downcast_ref::<PyStr>().ok_or_else(|| {
+                        vm.new_type_error(format!("keys must be str, not {}", key.class().name()))
+                    })?;
+                    buf.push_str(&encode_pystr_ascii(key));
+                    buf.push_str(": ");
+                    write_fast(value, buf, seen, vm)?;
+                }
+                Ok(())
+            })?;
+            buf.push('}');
+            seen.pop();
+        } else {
+            return Err(vm.new_type_error(format!(
+                "Object of type {} is not JSON serializable",
+                cls.name()
+            )));
+        }
+        Ok(())
+    }
+
+    #[pyfunction]
+    fn dumps_fast(obj: PyObjectRef, vm: &VirtualMachine) -> PyResult<String> {
+        let mut buf = String::new();
+        write_fast(&obj, &mut buf, &mut Vec::new(), vm)?;
+        Ok(buf)
+    }
+
     #[pyfunction]
     fn encode_basestring(s: PyStrRef) -> String {
         encode_string(s.as_str(), false)
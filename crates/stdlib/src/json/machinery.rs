@@ -57,7 +57,7 @@ static NEEDS_ESCAPING_BITSET: [u64; 4] = [
 ];
 
 #[inline(always)]
-fn json_escaped_char(c: u8) -> Option<&'static str> {
+pub(super) fn json_escaped_char(c: u8) -> Option<&'static str> {
     let bitset_value = NEEDS_ESCAPING_BITSET[(c / 64) as usize] & (1 << (c % 64));
     if bitset_value == 0 {
         None
@@ -11,6 +11,7 @@ extern crate alloc;
 pub(crate) mod macros;
 
 mod _asyncio;
+mod _lsprof;
 mod _remote_debugging;
 pub mod array;
 mod binascii;
@@ -20,9 +21,14 @@ mod cmath;
 mod compression; // internal module
 mod contextvars;
 mod csv;
+mod gettext;
 #[cfg(not(any(target_os = "android", target_arch = "wasm32")))]
 mod lzma;
+#[cfg(feature = "host_env")]
+mod zipimport;
 mod zlib;
+#[cfg(not(target_arch = "wasm32"))]
+mod zstd;
 
 mod blake2;
 mod hashlib;
@@ -141,6 +147,7 @@ use crate::vm::{Context, builtins};
 pub fn stdlib_module_defs(ctx: &Context) -> Vec<&'static builtins::PyModuleDef> {
     vec![
         _asyncio::module_def(ctx),
+        _lsprof::module_def(ctx),
         _opcode::module_def(ctx),
         _remote_debugging::module_def(ctx),
         array::module_def(ctx),
@@ -155,6 +162,7 @@ pub fn stdlib_module_defs(ctx: &Context) -> Vec<&'static builtins::PyModuleDef>
         faulthandler::module_def(ctx),
         #[cfg(all(feature = "host_env", any(unix, target_os = "wasi")))]
         fcntl::module_def(ctx),
+        gettext::module_def(ctx),
         #[cfg(all(
             feature = "host_env",
             unix,
@@ -243,6 +251,10 @@ pub fn stdlib_module_defs(ctx: &Context) -> Vec<&'static builtins::PyModuleDef>
             ))
         ))]
         uuid::module_def(ctx),
+        #[cfg(feature = "host_env")]
+        zipimport::module_def(ctx),
         zlib::module_def(ctx),
+        #[cfg(not(target_arch = "wasm32"))]
+        zstd::module_def(ctx),
     ]
 }
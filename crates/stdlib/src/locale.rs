@@ -148,6 +148,34 @@ mod _locale {
         )
     }
 
+    /// Convert a str to a NUL-terminated wide-character string for the
+    /// C library's wide collation functions (PyUnicode_AsWideCharString).
+    #[cfg(unix)]
+    fn to_wide(string: &PyStrRef, vm: &VirtualMachine) -> PyResult<Vec<libc::wchar_t>> {
+        if string.as_str().contains('\0') {
+            return Err(vm.new_value_error("embedded null character"));
+        }
+        let mut wide: Vec<libc::wchar_t> = string
+            .as_str()
+            .chars()
+            .map(|c| c as libc::wchar_t)
+            .collect();
+        wide.push(0);
+        Ok(wide)
+    }
+
+    // Like CPython, collation works on wide-character strings so that
+    // non-ASCII text is compared according to the locale rather than by
+    // code point.
+    #[cfg(unix)]
+    #[pyfunction]
+    fn strcoll(string1: PyStrRef, string2: PyStrRef, vm: &VirtualMachine) -> PyResult {
+        let ws1 = to_wide(&string1, vm)?;
+        let ws2 = to_wide(&string2, vm)?;
+        Ok(vm.new_pyobj(unsafe { libc::wcscoll(ws1.as_ptr(), ws2.as_ptr()) }))
+    }
+
+    #[cfg(windows)]
     #[pyfunction]
     fn strcoll(string1: PyStrRef, string2: PyStrRef, vm: &VirtualMachine) -> PyResult {
         let cstr1 = CString::new(string1.as_str()).map_err(|e| e.to_pyexception(vm))?;
@@ -155,9 +183,30 @@ mod _locale {
         Ok(vm.new_pyobj(unsafe { libc::strcoll(cstr1.as_ptr(), cstr2.as_ptr()) }))
     }
 
+    #[cfg(unix)]
     #[pyfunction]
     fn strxfrm(string: PyStrRef, vm: &VirtualMachine) -> PyResult {
         // https://github.com/python/cpython/blob/eaae563b6878aa050b4ad406b67728b6b066220e/Modules/_localemodule.c#L390-L442
+        let ws = to_wide(&string, vm)?;
+        let n1 = ws.len();
+        let mut buff: Vec<libc::wchar_t> = vec![0; n1];
+        let n2 = unsafe { libc::wcsxfrm(buff.as_mut_ptr(), ws.as_ptr(), n1) };
+        if n2 >= n1 {
+            buff = vec![0; n2 + 1];
+            unsafe {
+                libc::wcsxfrm(buff.as_mut_ptr(), ws.as_ptr(), n2 + 1);
+            }
+        }
+        let transformed: String = buff[..n2]
+            .iter()
+            .map(|&c| char::from_u32(c as u32).unwrap_or(char::REPLACEMENT_CHARACTER))
+            .collect();
+        Ok(vm.new_pyobj(transformed))
+    }
+
+    #[cfg(windows)]
+    #[pyfunction]
+    fn strxfrm(string: PyStrRef, vm: &VirtualMachine) -> PyResult {
         let n1 = string.byte_len() + 1;
         let mut buff = vec![0u8; n1];
 
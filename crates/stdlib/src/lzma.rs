@@ -34,7 +34,7 @@ mod _lzma {
         LZMA_PRESET_LEVEL_MASK as PRESET_LEVEL_MASK,
     };
     use rustpython_common::lock::PyMutex;
-    use rustpython_vm::builtins::{PyBaseExceptionRef, PyBytesRef, PyType, PyTypeRef};
+    use rustpython_vm::builtins::{PyBaseExceptionRef, PyBytesRef, PyDictRef, PyType, PyTypeRef};
     use rustpython_vm::convert::ToPyException;
     use rustpython_vm::function::ArgBytesLike;
     use rustpython_vm::types::Constructor;
@@ -142,7 +142,7 @@ mod _lzma {
         #[pyarg(any, optional)]
         mem_limit: Option<u64>,
         #[pyarg(any, optional)]
-        filters: Option<u32>,
+        filters: Option<Vec<PyObjectRef>>,
     }
 
     impl Constructor for LZMADecompressor {
@@ -152,13 +152,21 @@ mod _lzma {
             if args.format == FORMAT_RAW && args.mem_limit.is_some() {
                 return Err(vm.new_value_error("Cannot specify memory limit with FORMAT_RAW"));
             }
+            if args.format != FORMAT_RAW && args.filters.is_some() {
+                return Err(vm.new_value_error("Cannot specify filters except with FORMAT_RAW"));
+            }
             let mem_limit = args.mem_limit.unwrap_or(u64::MAX);
-            let filters = args.filters.unwrap_or(0);
             let stream_result = match args.format {
-                FORMAT_AUTO => Stream::new_auto_decoder(mem_limit, filters),
-                FORMAT_XZ => Stream::new_stream_decoder(mem_limit, filters),
+                FORMAT_AUTO => Stream::new_auto_decoder(mem_limit, 0),
+                FORMAT_XZ => Stream::new_stream_decoder(mem_limit, 0),
                 FORMAT_ALONE => Stream::new_lzma_decoder(mem_limit),
-                // TODO: FORMAT_RAW
+                FORMAT_RAW => {
+                    let filter_specs = args
+                        .filters
+                        .ok_or_else(|| vm.new_value_error("Must specify filters for FORMAT_RAW"))?;
+                    let filters = parse_filter_chain_spec(filter_specs, vm)?;
+                    Stream::new_raw_decoder(&filters)
+                }
                 _ => return Err(new_lzma_error("Invalid format", vm)),
             };
             Ok(Self {
@@ -287,6 +295,37 @@ mod _lzma {
         }
     }
 
+    fn spec_u32(spec: &PyDictRef, key: &str, vm: &VirtualMachine) -> PyResult<Option<u32>> {
+        spec.get_item_opt(key, vm)?
+            .map(|obj| obj.try_to_value(vm))
+            .transpose()
+    }
+
+    fn lzma_options_from_spec(spec: &PyDictRef, vm: &VirtualMachine) -> PyResult<LzmaOptions> {
+        let preset = spec_u32(spec, "preset", vm)?.unwrap_or(PRESET_DEFAULT);
+        let mut options = LzmaOptions::new_preset(preset)
+            .map_err(|_| new_lzma_error("Invalid filter specifier for LZMA filter", vm))?;
+        if let Some(dict_size) = spec_u32(spec, "dict_size", vm)? {
+            options.dict_size(dict_size);
+        }
+        if let Some(lc) = spec_u32(spec, "lc", vm)? {
+            options.literal_context_bits(lc);
+        }
+        if let Some(lp) = spec_u32(spec, "lp", vm)? {
+            options.literal_position_bits(lp);
+        }
+        if let Some(pb) = spec_u32(spec, "pb", vm)? {
+            options.position_bits(pb);
+        }
+        if let Some(nice_len) = spec_u32(spec, "nice_len", vm)? {
+            options.nice_len(nice_len);
+        }
+        if let Some(depth) = spec_u32(spec, "depth", vm)? {
+            options.depth(depth);
+        }
+        Ok(options)
+    }
+
     fn parse_filter_chain_spec(
         filter_specs: Vec<PyObjectRef>,
         vm: &VirtualMachine,
@@ -299,8 +338,46 @@ mod _lzma {
                 vm,
             ));
         }
-        let filters = Filters::new();
-        for _item in filter_specs {}
+        let mut filters = Filters::new();
+        for spec in filter_specs {
+            let spec: PyDictRef = spec.downcast().map_err(|_| {
+                vm.new_type_error("Filter specifier must be a dict or dict-like object")
+            })?;
+            let id: u64 = spec
+                .get_item_opt("id", vm)?
+                .ok_or_else(|| vm.new_value_error("Filter specifier must have an \"id\" entry"))?
+                .try_to_value(vm)?;
+            match id {
+                FILTER_LZMA1 => {
+                    filters.lzma1(&lzma_options_from_spec(&spec, vm)?);
+                }
+                FILTER_LZMA2 => {
+                    filters.lzma2(&lzma_options_from_spec(&spec, vm)?);
+                }
+                FILTER_X86 => {
+                    filters.x86();
+                }
+                FILTER_POWERPC => {
+                    filters.powerpc();
+                }
+                FILTER_IA64 => {
+                    filters.ia64();
+                }
+                FILTER_ARM => {
+                    filters.arm();
+                }
+                FILTER_ARMTHUMB => {
+                    filters.arm_thumb();
+                }
+                FILTER_SPARC => {
+                    filters.sparc();
+                }
+                id if id == FILTER_DELTA as u64 => {
+                    return Err(new_lzma_error("TODO: RUSTPYTHON: LZMA: Delta filter", vm));
+                }
+                _ => return Err(vm.new_value_error(format!("Invalid filter ID: {id}"))),
+            }
+        }
         Ok(filters)
     }
 
@@ -341,6 +418,17 @@ mod _lzma {
                 Ok(stream)
             }
         }
+
+        fn init_raw(
+            filter_specs: Option<Vec<PyObjectRef>>,
+            vm: &VirtualMachine,
+        ) -> PyResult<Stream> {
+            let filter_specs = filter_specs
+                .ok_or_else(|| vm.new_value_error("Must specify filters for FORMAT_RAW"))?;
+            let filters = parse_filter_chain_spec(filter_specs, vm)?;
+            Stream::new_raw_encoder(&filters)
+                .map_err(|_| new_lzma_error("Failed to initialize encoder", vm))
+        }
     }
 
     #[derive(FromArgs)]
@@ -385,7 +473,7 @@ mod _lzma {
             let stream = match args.format {
                 FORMAT_XZ => Self::init_xz(args.check, preset, args.filters, vm)?,
                 FORMAT_ALONE => Self::init_alone(preset, args.filters, vm)?,
-                // TODO: RAW
+                FORMAT_RAW => Self::init_raw(args.filters, vm)?,
                 _ => return Err(new_lzma_error("Invalid format", vm)),
             };
             Ok(Self {
@@ -771,8 +771,6 @@ mod mmap {
             Ok(m)
         }
 
-        /// TODO: impl resize
-        #[allow(dead_code)]
         fn check_resizeable(&self, vm: &VirtualMachine) -> PyResult<()> {
             if self.exports.load() > 0 {
                 return Err(vm.new_buffer_error("mmap can't resize with extant buffers exported."));
@@ -1024,15 +1022,71 @@ mod mmap {
 
         #[cfg(unix)]
         #[pymethod]
-        fn resize(&self, _newsize: PyIntRef, vm: &VirtualMachine) -> PyResult<()> {
+        fn resize(&self, newsize: PyIntRef, vm: &VirtualMachine) -> PyResult<()> {
+            self.check_valid(vm)?;
             self.check_resizeable(vm)?;
-            // TODO: implement using mremap on Linux
-            Err(vm.new_system_error("mmap: resizing not available--no mremap()"))
+
+            let newsize: usize = newsize
+                .try_to_primitive(vm)
+                .map_err(|_| vm.new_value_error("new size out of range"))?;
+
+            if newsize == 0 {
+                return Err(vm.new_value_error("new size must be positive"));
+            }
+
+            let fd = self.fd.load();
+            let mut mmap_guard = self.mmap.lock();
+
+            if fd < 0 {
+                // Anonymous mapping: create a new one and copy the overlap over
+                let old_size = self.size.load();
+                let copy_size = core::cmp::min(old_size, newsize);
+
+                let mut new_mmap = MmapOptions::new()
+                    .len(newsize)
+                    .map_anon()
+                    .map_err(|e| e.to_pyexception(vm))?;
+                if let Some(old_mmap) = mmap_guard.as_ref() {
+                    new_mmap[..copy_size].copy_from_slice(&old_mmap.as_slice()[..copy_size]);
+                }
+
+                *mmap_guard = Some(MmapObj::Write(new_mmap));
+                self.size.store(newsize);
+            } else {
+                // File-backed mapping: drop the mapping, adjust the file
+                // size (resizing alters the underlying file, as on CPython)
+                // and remap with the new length
+                *mmap_guard = None;
+
+                let required_size = self.offset + newsize as i64;
+                if unsafe { libc::ftruncate(fd, required_size as libc::off_t) } == -1 {
+                    return Err(io::Error::last_os_error().to_pyexception(vm));
+                }
+
+                let fd = unsafe { crt_fd::Borrowed::try_borrow_raw(fd) }
+                    .map_err(|_| io::Error::from_raw_os_error(libc::EBADF).to_pyexception(vm))?;
+                let mut mmap_opt = MmapOptions::new();
+                let mmap_opt = mmap_opt.offset(self.offset as u64).len(newsize);
+                let new_mmap = match self.access {
+                    AccessMode::Default | AccessMode::Write => {
+                        unsafe { mmap_opt.map_mut(&fd) }.map(MmapObj::Write)
+                    }
+                    AccessMode::Read => unsafe { mmap_opt.map(&fd) }.map(MmapObj::Read),
+                    AccessMode::Copy => unsafe { mmap_opt.map_copy(&fd) }.map(MmapObj::Write),
+                }
+                .map_err(|e| e.to_pyexception(vm))?;
+
+                *mmap_guard = Some(new_mmap);
+                self.size.store(newsize);
+            }
+
+            Ok(())
         }
 
         #[cfg(windows)]
         #[pymethod]
         fn resize(&self, newsize: PyIntRef, vm: &VirtualMachine) -> PyResult<()> {
+            self.check_valid(vm)?;
             self.check_resizeable(vm)?;
 
             let newsize: usize = newsize
@@ -33,6 +33,10 @@ mod _posixsubprocess {
 
     #[pyfunction]
     fn fork_exec(args: ForkExecArgs<'_>, vm: &VirtualMachine) -> PyResult<libc::pid_t> {
+        vm.check_capability(
+            vm.state.config.settings.capabilities.allow_subprocess,
+            "spawning subprocesses",
+        )?;
         // Check for interpreter shutdown when preexec_fn is used
         if args.preexec_fn.is_some()
             && vm
@@ -98,6 +98,7 @@ mod _socket {
         pub const AI_PASSIVE: i32 = windows_sys::Win32::Networking::WinSock::AI_PASSIVE as _;
         pub const AI_NUMERICHOST: i32 =
             windows_sys::Win32::Networking::WinSock::AI_NUMERICHOST as _;
+        pub const AF_UNIX: i32 = windows_sys::Win32::Networking::WinSock::AF_UNIX as _;
         pub const FROM_PROTOCOL_INFO: i32 = -1;
     }
     // constants
@@ -122,9 +123,13 @@ mod _socket {
         IPPROTO_PIM, IPPROTO_PUP, IPPROTO_RAW, IPPROTO_ROUTING,
     };
 
+    #[cfg(any(unix, windows))]
+    #[pyattr]
+    use c::AF_UNIX;
+
     #[cfg(unix)]
     #[pyattr]
-    use c::{AF_UNIX, SO_REUSEPORT};
+    use c::SO_REUSEPORT;
 
     #[pyattr]
     use c::{AI_ADDRCONFIG, AI_NUMERICHOST, AI_NUMERICSERV, AI_PASSIVE};
@@ -1139,14 +1144,38 @@ mod _socket {
         ) -> Result<socket2::SockAddr, IoOrPyException> {
             let family = self.family.load();
             match family {
-                #[cfg(unix)]
+                #[cfg(any(unix, windows))]
                 c::AF_UNIX => {
                     use crate::vm::function::ArgStrOrBytesLike;
-                    use std::os::unix::ffi::OsStrExt;
                     let buf = ArgStrOrBytesLike::try_from_object(vm, addr)?;
                     let bytes = &*buf.borrow_bytes();
-                    let path = match &buf {
-                        ArgStrOrBytesLike::Buf(_) => ffi::OsStr::from_bytes(bytes).into(),
+                    // a leading NUL byte selects the Linux abstract namespace:
+                    // the rest of the bytes name a socket with no filesystem
+                    // presence, and socket2 can't represent the embedded NUL
+                    #[cfg(any(target_os = "linux", target_os = "android"))]
+                    if bytes.first() == Some(&0) {
+                        return unix_abstract_address(&bytes[1..], vm);
+                    }
+                    #[cfg(unix)]
+                    let path = {
+                        use std::os::unix::ffi::OsStrExt;
+                        match &buf {
+                            ArgStrOrBytesLike::Buf(_) => ffi::OsStr::from_bytes(bytes).into(),
+                            ArgStrOrBytesLike::Str(s) => vm.fsencode(s)?,
+                        }
+                    };
+                    // Windows AF_UNIX (build 17063+) only supports filesystem
+                    // paths, which must round-trip through UTF-8
+                    #[cfg(windows)]
+                    let path: std::borrow::Cow<'_, ffi::OsStr> = match &buf {
+                        ArgStrOrBytesLike::Buf(_) => std::str::from_utf8(bytes)
+                            .map_err(|_| {
+                                vm.new_value_error(
+                                    "AF_UNIX path must be valid UTF-8 on Windows".to_owned(),
+                                )
+                            })?
+                            .as_ref()
+                            .into(),
                         ArgStrOrBytesLike::Str(s) => vm.fsencode(s)?,
                     };
                     socket2::SockAddr::unix(path)
@@ -1263,6 +1292,125 @@ mod _socket {
                     })
                 }
                 #[cfg(target_os = "linux")]
+                c::AF_PACKET => {
+                    let tuple: PyTupleRef = addr.downcast().map_err(|obj| {
+                        vm.new_type_error(format!(
+                            "{}(): AF_PACKET address must be tuple, not {}",
+                            caller,
+                            obj.class().name()
+                        ))
+                    })?;
+                    if tuple.len() < 2 || tuple.len() > 5 {
+                        return Err(vm
+                            .new_type_error(
+                                "AF_PACKET address must be a tuple of two to five elements",
+                            )
+                            .into());
+                    }
+                    let slots = tuple.as_slice();
+                    let interface: PyStrRef = slots[0].clone().downcast().map_err(|obj| {
+                        vm.new_type_error(format!(
+                            "{}(): AF_PACKET interface must be str, not {}",
+                            caller,
+                            obj.class().name()
+                        ))
+                    })?;
+                    let protocol: u16 = slots[1].clone().try_into_value(vm)?;
+                    let pkttype: u8 = match slots.get(2) {
+                        Some(obj) => obj.clone().try_into_value(vm)?,
+                        None => 0,
+                    };
+                    let hatype: u16 = match slots.get(3) {
+                        Some(obj) => obj.clone().try_into_value(vm)?,
+                        None => 0,
+                    };
+                    let hwaddr = match slots.get(4) {
+                        Some(obj) => ArgBytesLike::try_from_object(vm, obj.clone())?
+                            .borrow_buf()
+                            .to_vec(),
+                        None => vec![],
+                    };
+                    if hwaddr.len() > 8 {
+                        return Err(vm
+                            .new_os_error("Hardware address must be 8 bytes or less".to_owned())
+                            .into());
+                    }
+
+                    let ifname = interface.as_str();
+                    let ifindex = if ifname.is_empty() {
+                        0
+                    } else {
+                        if ifname.len() >= libc::IF_NAMESIZE {
+                            return Err(vm
+                                .new_os_error("interface name too long".to_owned())
+                                .into());
+                        }
+                        let cstr = alloc::ffi::CString::new(ifname)
+                            .map_err(|_| vm.new_os_error("invalid interface name".to_owned()))?;
+                        let idx = unsafe { libc::if_nametoindex(cstr.as_ptr()) };
+                        if idx == 0 {
+                            return Err(io::Error::last_os_error().into());
+                        }
+                        idx as i32
+                    };
+
+                    // Create sockaddr_ll; the protocol travels in network order
+                    let mut storage: libc::sockaddr_storage = unsafe { core::mem::zeroed() };
+                    let ll_addr =
+                        &mut storage as *mut libc::sockaddr_storage as *mut libc::sockaddr_ll;
+                    unsafe {
+                        (*ll_addr).sll_family = libc::AF_PACKET as libc::sa_family_t;
+                        (*ll_addr).sll_protocol = protocol.to_be();
+                        (*ll_addr).sll_ifindex = ifindex;
+                        (*ll_addr).sll_hatype = hatype;
+                        (*ll_addr).sll_pkttype = pkttype;
+                        (*ll_addr).sll_halen = hwaddr.len() as u8;
+                        (*ll_addr).sll_addr[..hwaddr.len()].copy_from_slice(&hwaddr);
+                    }
+                    let storage: socket2::SockAddrStorage =
+                        unsafe { core::mem::transmute(storage) };
+                    Ok(unsafe {
+                        socket2::SockAddr::new(
+                            storage,
+                            core::mem::size_of::<libc::sockaddr_ll>() as libc::socklen_t,
+                        )
+                    })
+                }
+                #[cfg(target_os = "linux")]
+                c::AF_NETLINK => {
+                    let tuple: PyTupleRef = addr.downcast().map_err(|obj| {
+                        vm.new_type_error(format!(
+                            "{}(): AF_NETLINK address must be tuple, not {}",
+                            caller,
+                            obj.class().name()
+                        ))
+                    })?;
+                    if tuple.len() != 2 {
+                        return Err(vm
+                            .new_type_error("AF_NETLINK address must be a pair (pid, groups)")
+                            .into());
+                    }
+                    let pid: u32 = tuple[0].clone().try_into_value(vm)?;
+                    let groups: u32 = tuple[1].clone().try_into_value(vm)?;
+
+                    let mut storage: libc::sockaddr_storage = unsafe { core::mem::zeroed() };
+                    let nl_addr =
+                        &mut storage as *mut libc::sockaddr_storage as *mut libc::sockaddr_nl;
+                    unsafe {
+                        (*nl_addr).nl_family = libc::AF_NETLINK as libc::sa_family_t;
+                        (*nl_addr).nl_pid = pid;
+                        (*nl_addr).nl_groups = groups;
+                    }
+                    let storage: socket2::SockAddrStorage =
+                        unsafe { core::mem::transmute(storage) };
+                    Ok(unsafe {
+                        socket2::SockAddr::new(
+                            storage,
+                            core::mem::size_of::<libc::sockaddr_nl>() as libc::socklen_t,
+                        )
+                    })
+                }
+                #[cfg(target_os = "linux")]
                 c::AF_ALG => {
                     let tuple: PyTupleRef = addr.downcast().map_err(|obj| {
                         vm.new_type_error(format!(
@@ -1386,6 +1534,11 @@ mod _socket {
         );
 
         fn init(zelf: PyRef<Self>, args: Self::Args, vm: &VirtualMachine) -> PyResult<()> {
+            vm.check_capability(
+                vm.state.config.settings.capabilities.allow_socket,
+                "creating sockets",
+            )?;
+            vm.register_shutdown_closer(zelf.as_object())?;
             Self::_init(zelf, args, vm).map_err(|e| e.into_pyexception(vm))
         }
     }
@@ -2026,6 +2179,92 @@ mod _socket {
             ]))
         }
 
+        /// recvmsg_into(buffers[, ancbufsize[, flags]]) -> (nbytes, ancdata, msg_flags, address)
+        ///
+        /// Receive normal data and ancillary data from the socket, scattering the
+        /// non-ancillary data into a series of buffers.
+        #[cfg(all(unix, not(target_os = "redox")))]
+        #[pymethod]
+        fn recvmsg_into(
+            &self,
+            buffers: Vec<ArgMemoryBuffer>,
+            ancbufsize: OptionalArg<isize>,
+            flags: OptionalArg<i32>,
+            vm: &VirtualMachine,
+        ) -> PyResult<PyTupleRef> {
+            use core::mem::MaybeUninit;
+
+            let ancbufsize = ancbufsize.unwrap_or(0);
+            if ancbufsize < 0 {
+                return Err(
+                    vm.new_value_error("negative ancillary buffer size in recvmsg_into".to_owned())
+                );
+            }
+            let ancbufsize = ancbufsize as usize;
+            let flags = flags.unwrap_or(0);
+
+            // Borrow every destination buffer for the duration of the call so
+            // the iovecs below stay valid
+            let mut borrowed = buffers
+                .iter()
+                .map(|buf| buf.borrow_buf_mut())
+                .collect::<Vec<_>>();
+            let mut iov = borrowed
+                .iter_mut()
+                .map(|buf| libc::iovec {
+                    iov_base: buf.as_mut_ptr().cast(),
+                    iov_len: buf.len(),
+                })
+                .collect::<Vec<_>>();
+
+            let mut anc_buf: Vec<MaybeUninit<u8>> = vec![MaybeUninit::uninit(); ancbufsize];
+            let mut addr_storage: libc::sockaddr_storage = unsafe { core::mem::zeroed() };
+
+            // Set up msghdr
+            let mut msg: libc::msghdr = unsafe { core::mem::zeroed() };
+            msg.msg_name = (&mut addr_storage as *mut libc::sockaddr_storage).cast();
+            msg.msg_namelen = core::mem::size_of::<libc::sockaddr_storage>() as libc::socklen_t;
+            msg.msg_iov = iov.as_mut_ptr();
+            msg.msg_iovlen = iov.len() as _;
+            if ancbufsize > 0 {
+                msg.msg_control = anc_buf.as_mut_ptr().cast();
+                msg.msg_controllen = ancbufsize as _;
+            }
+
+            let n = self
+                .sock_op(vm, SelectKind::Read, || {
+                    let sock = self.sock()?;
+                    let fd = sock_fileno(&sock);
+                    let ret = unsafe { libc::recvmsg(fd as libc::c_int, &mut msg, flags) };
+                    if ret < 0 {
+                        Err(io::Error::last_os_error())
+                    } else {
+                        Ok(ret as usize)
+                    }
+                })
+                .map_err(|e| e.into_pyexception(vm))?;
+
+            // Build ancdata list
+            let ancdata = Self::parse_ancillary_data(&msg, vm)?;
+
+            // Build address tuple
+            let address = if msg.msg_namelen > 0 {
+                let storage: socket2::SockAddrStorage =
+                    unsafe { core::mem::transmute(addr_storage) };
+                let addr = unsafe { socket2::SockAddr::new(storage, msg.msg_namelen) };
+                get_addr_tuple(&addr, vm)
+            } else {
+                vm.ctx.none()
+            };
+
+            Ok(vm.ctx.new_tuple(vec![
+                vm.ctx.new_int(n).into(),
+                ancdata,
+                vm.ctx.new_int(msg.msg_flags).into(),
+                address,
+            ]))
+        }
+
         /// Parse ancillary data from a received message header
         #[cfg(all(unix, not(target_os = "redox")))]
         fn parse_ancillary_data(msg: &libc::msghdr, vm: &VirtualMachine) -> PyResult<PyObjectRef> {
@@ -2551,6 +2790,36 @@ mod _socket {
         }
     }
 
+    /// Build an abstract-namespace `sockaddr_un` by hand; `SockAddr::unix`
+    /// refuses paths containing NUL bytes, which is exactly what these are
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn unix_abstract_address(
+        name: &[u8],
+        vm: &VirtualMachine,
+    ) -> Result<socket2::SockAddr, IoOrPyException> {
+        let mut storage: libc::sockaddr_storage = unsafe { core::mem::zeroed() };
+        let un = unsafe {
+            &mut *(&mut storage as *mut libc::sockaddr_storage).cast::<libc::sockaddr_un>()
+        };
+        if name.len() + 1 > un.sun_path.len() {
+            return Err(vm.new_os_error("AF_UNIX path too long".to_owned()).into());
+        }
+        un.sun_family = libc::AF_UNIX as libc::sa_family_t;
+        // sun_path[0] stays NUL; the name follows it without a terminator
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                name.as_ptr(),
+                un.sun_path.as_mut_ptr().add(1).cast::<u8>(),
+                name.len(),
+            );
+        }
+        let len = core::mem::offset_of!(libc::sockaddr_un, sun_path) + 1 + name.len();
+        let storage = unsafe {
+            core::mem::transmute::<libc::sockaddr_storage, socket2::SockAddrStorage>(storage)
+        };
+        Ok(unsafe { socket2::SockAddr::new(storage, len as libc::socklen_t) })
+    }
+
     fn get_addr_tuple(addr: &socket2::SockAddr, vm: &VirtualMachine) -> PyObjectRef {
         if let Some(addr) = addr.as_socket() {
             return get_ip_addr_tuple(&addr, vm);
@@ -2610,6 +2879,49 @@ mod _socket {
                     ])
                     .into();
             }
+            if family == libc::AF_PACKET as libc::sa_family_t {
+                // AF_PACKET address: (ifname, proto, pkttype, hatype, addr)
+                let ll_addr = unsafe { &*(addr.as_ptr() as *const libc::sockaddr_ll) };
+                let ifname = if ll_addr.sll_ifindex == 0 {
+                    String::new()
+                } else {
+                    let mut buf = [0u8; libc::IF_NAMESIZE];
+                    let ret = unsafe {
+                        libc::if_indextoname(
+                            ll_addr.sll_ifindex as libc::c_uint,
+                            buf.as_mut_ptr() as *mut libc::c_char,
+                        )
+                    };
+                    if ret.is_null() {
+                        String::new()
+                    } else {
+                        let nul_pos = memchr::memchr(b'\0', &buf).unwrap_or(buf.len());
+                        String::from_utf8_lossy(&buf[..nul_pos]).into_owned()
+                    }
+                };
+                let halen = (ll_addr.sll_halen as usize).min(ll_addr.sll_addr.len());
+                return vm
+                    .ctx
+                    .new_tuple(vec![
+                        vm.ctx.new_str(ifname).into(),
+                        vm.ctx.new_int(u16::from_be(ll_addr.sll_protocol)).into(),
+                        vm.ctx.new_int(ll_addr.sll_pkttype).into(),
+                        vm.ctx.new_int(ll_addr.sll_hatype).into(),
+                        vm.ctx.new_bytes(ll_addr.sll_addr[..halen].to_vec()).into(),
+                    ])
+                    .into();
+            }
+            if family == libc::AF_NETLINK as libc::sa_family_t {
+                // AF_NETLINK address: (pid, groups)
+                let nl_addr = unsafe { &*(addr.as_ptr() as *const libc::sockaddr_nl) };
+                return vm
+                    .ctx
+                    .new_tuple(vec![
+                        vm.ctx.new_int(nl_addr.nl_pid).into(),
+                        vm.ctx.new_int(nl_addr.nl_groups).into(),
+                    ])
+                    .into();
+            }
         }
         // TODO: support more address families
         (String::new(), 0).to_pyobject(vm)
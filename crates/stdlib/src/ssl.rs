@@ -1913,6 +1913,10 @@ mod _ssl {
                 .into_ref_with_type(vm, vm.class("_ssl", "_SSLSocket"))
                 .map_err(|_| vm.new_type_error("Failed to create SSLSocket"))?;
 
+            // Registered after the transport socket, so finalization sends
+            // close_notify before the socket itself is closed
+            vm.register_shutdown_closer(ssl_socket_ref.as_object())?;
+
             Ok(ssl_socket_ref)
         }
 
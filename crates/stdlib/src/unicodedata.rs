@@ -6,6 +6,8 @@
 
 pub(crate) use unicodedata::module_def;
 
+mod decomposition_data;
+
 use crate::vm::{
     PyObject, PyResult, VirtualMachine, builtins::PyStr, convert::TryFromBorrowedObject,
 };
@@ -64,6 +66,8 @@ mod unicodedata {
             "bidirectional",
             "east_asian_width",
             "normalize",
+            "is_normalized",
+            "decomposition",
             "mirrored",
         ] {
             module.set_attr(attr, ucd.get_attr(attr, vm)?, vm)?;
@@ -189,6 +193,30 @@ mod unicodedata {
             Ok(normalized_text)
         }
 
+        #[pymethod]
+        fn is_normalized(&self, form: super::NormalizeForm, unistr: PyStrRef) -> PyResult<bool> {
+            // ASCII text is already normalized in every form
+            if unistr.as_wtf8().is_ascii() {
+                return Ok(true);
+            }
+            let normalized = self.normalize(form, unistr.clone())?;
+            Ok(&*normalized == unistr.as_wtf8())
+        }
+
+        #[pymethod]
+        fn decomposition(&self, character: PyStrRef, vm: &VirtualMachine) -> PyResult<String> {
+            let decomp = match self.extract_char(character, vm)? {
+                Some(c) => {
+                    let table = super::decomposition_data::DECOMPOSITIONS;
+                    table
+                        .binary_search_by_key(&c.to_u32(), |&(cp, _)| cp)
+                        .map_or("", |i| table[i].1)
+                }
+                None => "",
+            };
+            Ok(decomp.to_owned())
+        }
+
         #[pymethod]
         fn mirrored(&self, character: PyStrRef, vm: &VirtualMachine) -> PyResult<i32> {
             match self.extract_char(character, vm)? {
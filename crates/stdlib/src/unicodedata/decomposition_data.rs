@@ -0,0 +1,5806 @@
+// File generated by scripts/generate_unicodedata_decomp.py
+// Source: CPython unicodedata, UCD 14.0.0
+// spell-checker: disable
+
+/// Decomposition mappings (UnicodeData.txt field 5), sorted by
+/// code point for binary search.
+pub(crate) static DECOMPOSITIONS: &[(u32, &str)] = &[
+    (0x00A0, "<noBreak> 0020"),
+    (0x00A8, "<compat> 0020 0308"),
+    (0x00AA, "<super> 0061"),
+    (0x00AF, "<compat> 0020 0304"),
+    (0x00B2, "<super> 0032"),
+    (0x00B3, "<super> 0033"),
+    (0x00B4, "<compat> 0020 0301"),
+    (0x00B5, "<compat> 03BC"),
+    (0x00B8, "<compat> 0020 0327"),
+    (0x00B9, "<super> 0031"),
+    (0x00BA, "<super> 006F"),
+    (0x00BC, "<fraction> 0031 2044 0034"),
+    (0x00BD, "<fraction> 0031 2044 0032"),
+    (0x00BE, "<fraction> 0033 2044 0034"),
+    (0x00C0, "0041 0300"),
+    (0x00C1, "0041 0301"),
+    (0x00C2, "0041 0302"),
+    (0x00C3, "0041 0303"),
+    (0x00C4, "0041 0308"),
+    (0x00C5, "0041 030A"),
+    (0x00C7, "0043 0327"),
+    (0x00C8, "0045 0300"),
+    (0x00C9, "0045 0301"),
+    (0x00CA, "0045 0302"),
+    (0x00CB, "0045 0308"),
+    (0x00CC, "0049 0300"),
+    (0x00CD, "0049 0301"),
+    (0x00CE, "0049 0302"),
+    (0x00CF, "0049 0308"),
+    (0x00D1, "004E 0303"),
+    (0x00D2, "004F 0300"),
+    (0x00D3, "004F 0301"),
+    (0x00D4, "004F 0302"),
+    (0x00D5, "004F 0303"),
+    (0x00D6, "004F 0308"),
+    (0x00D9, "0055 0300"),
+    (0x00DA, "0055 0301"),
+    (0x00DB, "0055 0302"),
+    (0x00DC, "0055 0308"),
+    (0x00DD, "0059 0301"),
+    (0x00E0, "0061 0300"),
+    (0x00E1, "0061 0301"),
+    (0x00E2, "0061 0302"),
+    (0x00E3, "0061 0303"),
+    (0x00E4, "0061 0308"),
+    (0x00E5, "0061 030A"),
+    (0x00E7, "0063 0327"),
+    (0x00E8, "0065 0300"),
+    (0x00E9, "0065 0301"),
+    (0x00EA, "0065 0302"),
+    (0x00EB, "0065 0308"),
+    (0x00EC, "0069 0300"),
+    (0x00ED, "0069 0301"),
+    (0x00EE, "0069 0302"),
+    (0x00EF, "0069 0308"),
+    (0x00F1, "006E 0303"),
+    (0x00F2, "006F 0300"),
+    (0x00F3, "006F 0301"),
+    (0x00F4, "006F 0302"),
+    (0x00F5, "006F 0303"),
+    (0x00F6, "006F 0308"),
+    (0x00F9, "0075 0300"),
+    (0x00FA, "0075 0301"),
+    (0x00FB, "0075 0302"),
+    (0x00FC, "0075 0308"),
+    (0x00FD, "0079 0301"),
+    (0x00FF, "0079 0308"),
+    (0x0100, "0041 0304"),
+    (0x0101, "0061 0304"),
+    (0x0102, "0041 0306"),
+    (0x0103, "0061 0306"),
+    (0x0104, "0041 0328"),
+    (0x0105, "0061 0328"),
+    (0x0106, "0043 0301"),
+    (0x0107, "0063 0301"),
+    (0x0108, "0043 0302"),
+    (0x0109, "0063 0302"),
+    (0x010A, "0043 0307"),
+    (0x010B, "0063 0307"),
+    (0x010C, "0043 030C"),
+    (0x010D, "0063 030C"),
+    (0x010E, "0044 030C"),
+    (0x010F, "0064 030C"),
+    (0x0112, "0045 0304"),
+    (0x0113, "0065 0304"),
+    (0x0114, "0045 0306"),
+    (0x0115, "0065 0306"),
+    (0x0116, "0045 0307"),
+    (0x0117, "0065 0307"),
+    (0x0118, "0045 0328"),
+    (0x0119, "0065 0328"),
+    (0x011A, "0045 030C"),
+    (0x011B, "0065 030C"),
+    (0x011C, "0047 0302"),
+    (0x011D, "0067 0302"),
+    (0x011E, "0047 0306"),
+    (0x011F, "0067 0306"),
+    (0x0120, "0047 0307"),
+    (0x0121, "0067 0307"),
+    (0x0122, "0047 0327"),
+    (0x0123, "0067 0327"),
+    (0x0124, "0048 0302"),
+    (0x0125, "0068 0302"),
+    (0x0128, "0049 0303"),
+    (0x0129, "0069 0303"),
+    (0x012A, "0049 0304"),
+    (0x012B, "0069 0304"),
+    (0x012C, "0049 0306"),
+    (0x012D, "0069 0306"),
+    (0x012E, "0049 0328"),
+    (0x012F, "0069 0328"),
+    (0x0130, "0049 0307"),
+    (0x0132, "<compat> 0049 004A"),
+    (0x0133, "<compat> 0069 006A"),
+    (0x0134, "004A 0302"),
+    (0x0135, "006A 0302"),
+    (0x0136, "004B 0327"),
+    (0x0137, "006B 0327"),
+    (0x0139, "004C 0301"),
+    (0x013A, "006C 0301"),
+    (0x013B, "004C 0327"),
+    (0x013C, "006C 0327"),
+    (0x013D, "004C 030C"),
+    (0x013E, "006C 030C"),
+    (0x013F, "<compat> 004C 00B7"),
+    (0x0140, "<compat> 006C 00B7"),
+    (0x0143, "004E 0301"),
+    (0x0144, "006E 0301"),
+    (0x0145, "004E 0327"),
+    (0x0146, "006E 0327"),
+    (0x0147, "004E 030C"),
+    (0x0148, "006E 030C"),
+    (0x0149, "<compat> 02BC 006E"),
+    (0x014C, "004F 0304"),
+    (0x014D, "006F 0304"),
+    (0x014E, "004F 0306"),
+    (0x014F, "006F 0306"),
+    (0x0150, "004F 030B"),
+    (0x0151, "006F 030B"),
+    (0x0154, "0052 0301"),
+    (0x0155, "0072 0301"),
+    (0x0156, "0052 0327"),
+    (0x0157, "0072 0327"),
+    (0x0158, "0052 030C"),
+    (0x0159, "0072 030C"),
+    (0x015A, "0053 0301"),
+    (0x015B, "0073 0301"),
+    (0x015C, "0053 0302"),
+    (0x015D, "0073 0302"),
+    (0x015E, "0053 0327"),
+    (0x015F, "0073 0327"),
+    (0x0160, "0053 030C"),
+    (0x0161, "0073 030C"),
+    (0x0162, "0054 0327"),
+    (0x0163, "0074 0327"),
+    (0x0164, "0054 030C"),
+    (0x0165, "0074 030C"),
+    (0x0168, "0055 0303"),
+    (0x0169, "0075 0303"),
+    (0x016A, "0055 0304"),
+    (0x016B, "0075 0304"),
+    (0x016C, "0055 0306"),
+    (0x016D, "0075 0306"),
+    (0x016E, "0055 030A"),
+    (0x016F, "0075 030A"),
+    (0x0170, "0055 030B"),
+    (0x0171, "0075 030B"),
+    (0x0172, "0055 0328"),
+    (0x0173, "0075 0328"),
+    (0x0174, "0057 0302"),
+    (0x0175, "0077 0302"),
+    (0x0176, "0059 0302"),
+    (0x0177, "0079 0302"),
+    (0x0178, "0059 0308"),
+    (0x0179, "005A 0301"),
+    (0x017A, "007A 0301"),
+    (0x017B, "005A 0307"),
+    (0x017C, "007A 0307"),
+    (0x017D, "005A 030C"),
+    (0x017E, "007A 030C"),
+    (0x017F, "<compat> 0073"),
+    (0x01A0, "004F 031B"),
+    (0x01A1, "006F 031B"),
+    (0x01AF, "0055 031B"),
+    (0x01B0, "0075 031B"),
+    (0x01C4, "<compat> 0044 017D"),
+    (0x01C5, "<compat> 0044 017E"),
+    (0x01C6, "<compat> 0064 017E"),
+    (0x01C7, "<compat> 004C 004A"),
+    (0x01C8, "<compat> 004C 006A"),
+    (0x01C9, "<compat> 006C 006A"),
+    (0x01CA, "<compat> 004E 004A"),
+    (0x01CB, "<compat> 004E 006A"),
+    (0x01CC, "<compat> 006E 006A"),
+    (0x01CD, "0041 030C"),
+    (0x01CE, "0061 030C"),
+    (0x01CF, "0049 030C"),
+    (0x01D0, "0069 030C"),
+    (0x01D1, "004F 030C"),
+    (0x01D2, "006F 030C"),
+    (0x01D3, "0055 030C"),
+    (0x01D4, "0075 030C"),
+    (0x01D5, "00DC 0304"),
+    (0x01D6, "00FC 0304"),
+    (0x01D7, "00DC 0301"),
+    (0x01D8, "00FC 0301"),
+    (0x01D9, "00DC 030C"),
+    (0x01DA, "00FC 030C"),
+    (0x01DB, "00DC 0300"),
+    (0x01DC, "00FC 0300"),
+    (0x01DE, "00C4 0304"),
+    (0x01DF, "00E4 0304"),
+    (0x01E0, "0226 0304"),
+    (0x01E1, "0227 0304"),
+    (0x01E2, "00C6 0304"),
+    (0x01E3, "00E6 0304"),
+    (0x01E6, "0047 030C"),
+    (0x01E7, "0067 030C"),
+    (0x01E8, "004B 030C"),
+    (0x01E9, "006B 030C"),
+    (0x01EA, "004F 0328"),
+    (0x01EB, "006F 0328"),
+    (0x01EC, "01EA 0304"),
+    (0x01ED, "01EB 0304"),
+    (0x01EE, "01B7 030C"),
+    (0x01EF, "0292 030C"),
+    (0x01F0, "006A 030C"),
+    (0x01F1, "<compat> 0044 005A"),
+    (0x01F2, "<compat> 0044 007A"),
+    (0x01F3, "<compat> 0064 007A"),
+    (0x01F4, "0047 0301"),
+    (0x01F5, "0067 0301"),
+    (0x01F8, "004E 0300"),
+    (0x01F9, "006E 0300"),
+    (0x01FA, "00C5 0301"),
+    (0x01FB, "00E5 0301"),
+    (0x01FC, "00C6 0301"),
+    (0x01FD, "00E6 0301"),
+    (0x01FE, "00D8 0301"),
+    (0x01FF, "00F8 0301"),
+    (0x0200, "0041 030F"),
+    (0x0201, "0061 030F"),
+    (0x0202, "0041 0311"),
+    (0x0203, "0061 0311"),
+    (0x0204, "0045 030F"),
+    (0x0205, "0065 030F"),
+    (0x0206, "0045 0311"),
+    (0x0207, "0065 0311"),
+    (0x0208, "0049 030F"),
+    (0x0209, "0069 030F"),
+    (0x020A, "0049 0311"),
+    (0x020B, "0069 0311"),
+    (0x020C, "004F 030F"),
+    (0x020D, "006F 030F"),
+    (0x020E, "004F 0311"),
+    (0x020F, "006F 0311"),
+    (0x0210, "0052 030F"),
+    (0x0211, "0072 030F"),
+    (0x0212, "0052 0311"),
+    (0x0213, "0072 0311"),
+    (0x0214, "0055 030F"),
+    (0x0215, "0075 030F"),
+    (0x0216, "0055 0311"),
+    (0x0217, "0075 0311"),
+    (0x0218, "0053 0326"),
+    (0x0219, "0073 0326"),
+    (0x021A, "0054 0326"),
+    (0x021B, "0074 0326"),
+    (0x021E, "0048 030C"),
+    (0x021F, "0068 030C"),
+    (0x0226, "0041 0307"),
+    (0x0227, "0061 0307"),
+    (0x0228, "0045 0327"),
+    (0x0229, "0065 0327"),
+    (0x022A, "00D6 0304"),
+    (0x022B, "00F6 0304"),
+    (0x022C, "00D5 0304"),
+    (0x022D, "00F5 0304"),
+    (0x022E, "004F 0307"),
+    (0x022F, "006F 0307"),
+    (0x0230, "022E 0304"),
+    (0x0231, "022F 0304"),
+    (0x0232, "0059 0304"),
+    (0x0233, "0079 0304"),
+    (0x02B0, "<super> 0068"),
+    (0x02B1, "<super> 0266"),
+    (0x02B2, "<super> 006A"),
+    (0x02B3, "<super> 0072"),
+    (0x02B4, "<super> 0279"),
+    (0x02B5, "<super> 027B"),
+    (0x02B6, "<super> 0281"),
+    (0x02B7, "<super> 0077"),
+    (0x02B8, "<super> 0079"),
+    (0x02D8, "<compat> 0020 0306"),
+    (0x02D9, "<compat> 0020 0307"),
+    (0x02DA, "<compat> 0020 030A"),
+    (0x02DB, "<compat> 0020 0328"),
+    (0x02DC, "<compat> 0020 0303"),
+    (0x02DD, "<compat> 0020 030B"),
+    (0x02E0, "<super> 0263"),
+    (0x02E1, "<super> 006C"),
+    (0x02E2, "<super> 0073"),
+    (0x02E3, "<super> 0078"),
+    (0x02E4, "<super> 0295"),
+    (0x0340, "0300"),
+    (0x0341, "0301"),
+    (0x0343, "0313"),
+    (0x0344, "0308 0301"),
+    (0x0374, "02B9"),
+    (0x037A, "<compat> 0020 0345"),
+    (0x037E, "003B"),
+    (0x0384, "<compat> 0020 0301"),
+    (0x0385, "00A8 0301"),
+    (0x0386, "0391 0301"),
+    (0x0387, "00B7"),
+    (0x0388, "0395 0301"),
+    (0x0389, "0397 0301"),
+    (0x038A, "0399 0301"),
+    (0x038C, "039F 0301"),
+    (0x038E, "03A5 0301"),
+    (0x038F, "03A9 0301"),
+    (0x0390, "03CA 0301"),
+    (0x03AA, "0399 0308"),
+    (0x03AB, "03A5 0308"),
+    (0x03AC, "03B1 0301"),
+    (0x03AD, "03B5 0301"),
+    (0x03AE, "03B7 0301"),
+    (0x03AF, "03B9 0301"),
+    (0x03B0, "03CB 0301"),
+    (0x03CA, "03B9 0308"),
+    (0x03CB, "03C5 0308"),
+    (0x03CC, "03BF 0301"),
+    (0x03CD, "03C5 0301"),
+    (0x03CE, "03C9 0301"),
+    (0x03D0, "<compat> 03B2"),
+    (0x03D1, "<compat> 03B8"),
+    (0x03D2, "<compat> 03A5"),
+    (0x03D3, "03D2 0301"),
+    (0x03D4, "03D2 0308"),
+    (0x03D5, "<compat> 03C6"),
+    (0x03D6, "<compat> 03C0"),
+    (0x03F0, "<compat> 03BA"),
+    (0x03F1, "<compat> 03C1"),
+    (0x03F2, "<compat> 03C2"),
+    (0x03F4, "<compat> 0398"),
+    (0x03F5, "<compat> 03B5"),
+    (0x03F9, "<compat> 03A3"),
+    (0x0400, "0415 0300"),
+    (0x0401, "0415 0308"),
+    (0x0403, "0413 0301"),
+    (0x0407, "0406 0308"),
+    (0x040C, "041A 0301"),
+    (0x040D, "0418 0300"),
+    (0x040E, "0423 0306"),
+    (0x0419, "0418 0306"),
+    (0x0439, "0438 0306"),
+    (0x0450, "0435 0300"),
+    (0x0451, "0435 0308"),
+    (0x0453, "0433 0301"),
+    (0x0457, "0456 0308"),
+    (0x045C, "043A 0301"),
+    (0x045D, "0438 0300"),
+    (0x045E, "0443 0306"),
+    (0x0476, "0474 030F"),
+    (0x0477, "0475 030F"),
+    (0x04C1, "0416 0306"),
+    (0x04C2, "0436 0306"),
+    (0x04D0, "0410 0306"),
+    (0x04D1, "0430 0306"),
+    (0x04D2, "0410 0308"),
+    (0x04D3, "0430 0308"),
+    (0x04D6, "0415 0306"),
+    (0x04D7, "0435 0306"),
+    (0x04DA, "04D8 0308"),
+    (0x04DB, "04D9 0308"),
+    (0x04DC, "0416 0308"),
+    (0x04DD, "0436 0308"),
+    (0x04DE, "0417 0308"),
+    (0x04DF, "0437 0308"),
+    (0x04E2, "0418 0304"),
+    (0x04E3, "0438 0304"),
+    (0x04E4, "0418 0308"),
+    (0x04E5, "0438 0308"),
+    (0x04E6, "041E 0308"),
+    (0x04E7, "043E 0308"),
+    (0x04EA, "04E8 0308"),
+    (0x04EB, "04E9 0308"),
+    (0x04EC, "042D 0308"),
+    (0x04ED, "044D 0308"),
+    (0x04EE, "0423 0304"),
+    (0x04EF, "0443 0304"),
+    (0x04F0, "0423 0308"),
+    (0x04F1, "0443 0308"),
+    (0x04F2, "0423 030B"),
+    (0x04F3, "0443 030B"),
+    (0x04F4, "0427 0308"),
+    (0x04F5, "0447 0308"),
+    (0x04F8, "042B 0308"),
+    (0x04F9, "044B 0308"),
+    (0x0587, "<compat> 0565 0582"),
+    (0x0622, "0627 0653"),
+    (0x0623, "0627 0654"),
+    (0x0624, "0648 0654"),
+    (0x0625, "0627 0655"),
+    (0x0626, "064A 0654"),
+    (0x0675, "<compat> 0627 0674"),
+    (0x0676, "<compat> 0648 0674"),
+    (0x0677, "<compat> 06C7 0674"),
+    (0x0678, "<compat> 064A 0674"),
+    (0x06C0, "06D5 0654"),
+    (0x06C2, "06C1 0654"),
+    (0x06D3, "06D2 0654"),
+    (0x0929, "0928 093C"),
+    (0x0931, "0930 093C"),
+    (0x0934, "0933 093C"),
+    (0x0958, "0915 093C"),
+    (0x0959, "0916 093C"),
+    (0x095A, "0917 093C"),
+    (0x095B, "091C 093C"),
+    (0x095C, "0921 093C"),
+    (0x095D, "0922 093C"),
+    (0x095E, "092B 093C"),
+    (0x095F, "092F 093C"),
+    (0x09CB, "09C7 09BE"),
+    (0x09CC, "09C7 09D7"),
+    (0x09DC, "09A1 09BC"),
+    (0x09DD, "09A2 09BC"),
+    (0x09DF, "09AF 09BC"),
+    (0x0A33, "0A32 0A3C"),
+    (0x0A36, "0A38 0A3C"),
+    (0x0A59, "0A16 0A3C"),
+    (0x0A5A, "0A17 0A3C"),
+    (0x0A5B, "0A1C 0A3C"),
+    (0x0A5E, "0A2B 0A3C"),
+    (0x0B48, "0B47 0B56"),
+    (0x0B4B, "0B47 0B3E"),
+    (0x0B4C, "0B47 0B57"),
+    (0x0B5C, "0B21 0B3C"),
+    (0x0B5D, "0B22 0B3C"),
+    (0x0B94, "0B92 0BD7"),
+    (0x0BCA, "0BC6 0BBE"),
+    (0x0BCB, "0BC7 0BBE"),
+    (0x0BCC, "0BC6 0BD7"),
+    (0x0C48, "0C46 0C56"),
+    (0x0CC0, "0CBF 0CD5"),
+    (0x0CC7, "0CC6 0CD5"),
+    (0x0CC8, "0CC6 0CD6"),
+    (0x0CCA, "0CC6 0CC2"),
+    (0x0CCB, "0CCA 0CD5"),
+    (0x0D4A, "0D46 0D3E"),
+    (0x0D4B, "0D47 0D3E"),
+    (0x0D4C, "0D46 0D57"),
+    (0x0DDA, "0DD9 0DCA"),
+    (0x0DDC, "0DD9 0DCF"),
+    (0x0DDD, "0DDC 0DCA"),
+    (0x0DDE, "0DD9 0DDF"),
+    (0x0E33, "<compat> 0E4D 0E32"),
+    (0x0EB3, "<compat> 0ECD 0EB2"),
+    (0x0EDC, "<compat> 0EAB 0E99"),
+    (0x0EDD, "<compat> 0EAB 0EA1"),
+    (0x0F0C, "<noBreak> 0F0B"),
+    (0x0F43, "0F42 0FB7"),
+    (0x0F4D, "0F4C 0FB7"),
+    (0x0F52, "0F51 0FB7"),
+    (0x0F57, "0F56 0FB7"),
+    (0x0F5C, "0F5B 0FB7"),
+    (0x0F69, "0F40 0FB5"),
+    (0x0F73, "0F71 0F72"),
+    (0x0F75, "0F71 0F74"),
+    (0x0F76, "0FB2 0F80"),
+    (0x0F77, "<compat> 0FB2 0F81"),
+    (0x0F78, "0FB3 0F80"),
+    (0x0F79, "<compat> 0FB3 0F81"),
+    (0x0F81, "0F71 0F80"),
+    (0x0F93, "0F92 0FB7"),
+    (0x0F9D, "0F9C 0FB7"),
+    (0x0FA2, "0FA1 0FB7"),
+    (0x0FA7, "0FA6 0FB7"),
+    (0x0FAC, "0FAB 0FB7"),
+    (0x0FB9, "0F90 0FB5"),
+    (0x1026, "1025 102E"),
+    (0x10FC, "<super> 10DC"),
+    (0x1B06, "1B05 1B35"),
+    (0x1B08, "1B07 1B35"),
+    (0x1B0A, "1B09 1B35"),
+    (0x1B0C, "1B0B 1B35"),
+    (0x1B0E, "1B0D 1B35"),
+    (0x1B12, "1B11 1B35"),
+    (0x1B3B, "1B3A 1B35"),
+    (0x1B3D, "1B3C 1B35"),
+    (0x1B40, "1B3E 1B35"),
+    (0x1B41, "1B3F 1B35"),
+    (0x1B43, "1B42 1B35"),
+    (0x1D2C, "<super> 0041"),
+    (0x1D2D, "<super> 00C6"),
+    (0x1D2E, "<super> 0042"),
+    (0x1D30, "<super> 0044"),
+    (0x1D31, "<super> 0045"),
+    (0x1D32, "<super> 018E"),
+    (0x1D33, "<super> 0047"),
+    (0x1D34, "<super> 0048"),
+    (0x1D35, "<super> 0049"),
+    (0x1D36, "<super> 004A"),
+    (0x1D37, "<super> 004B"),
+    (0x1D38, "<super> 004C"),
+    (0x1D39, "<super> 004D"),
+    (0x1D3A, "<super> 004E"),
+    (0x1D3C, "<super> 004F"),
+    (0x1D3D, "<super> 0222"),
+    (0x1D3E, "<super> 0050"),
+    (0x1D3F, "<super> 0052"),
+    (0x1D40, "<super> 0054"),
+    (0x1D41, "<super> 0055"),
+    (0x1D42, "<super> 0057"),
+    (0x1D43, "<super> 0061"),
+    (0x1D44, "<super> 0250"),
+    (0x1D45, "<super> 0251"),
+    (0x1D46, "<super> 1D02"),
+    (0x1D47, "<super> 0062"),
+    (0x1D48, "<super> 0064"),
+    (0x1D49, "<super> 0065"),
+    (0x1D4A, "<super> 0259"),
+    (0x1D4B, "<super> 025B"),
+    (0x1D4C, "<super> 025C"),
+    (0x1D4D, "<super> 0067"),
+    (0x1D4F, "<super> 006B"),
+    (0x1D50, "<super> 006D"),
+    (0x1D51, "<super> 014B"),
+    (0x1D52, "<super> 006F"),
+    (0x1D53, "<super> 0254"),
+    (0x1D54, "<super> 1D16"),
+    (0x1D55, "<super> 1D17"),
+    (0x1D56, "<super> 0070"),
+    (0x1D57, "<super> 0074"),
+    (0x1D58, "<super> 0075"),
+    (0x1D59, "<super> 1D1D"),
+    (0x1D5A, "<super> 026F"),
+    (0x1D5B, "<super> 0076"),
+    (0x1D5C, "<super> 1D25"),
+    (0x1D5D, "<super> 03B2"),
+    (0x1D5E, "<super> 03B3"),
+    (0x1D5F, "<super> 03B4"),
+    (0x1D60, "<super> 03C6"),
+    (0x1D61, "<super> 03C7"),
+    (0x1D62, "<sub> 0069"),
+    (0x1D63, "<sub> 0072"),
+    (0x1D64, "<sub> 0075"),
+    (0x1D65, "<sub> 0076"),
+    (0x1D66, "<sub> 03B2"),
+    (0x1D67, "<sub> 03B3"),
+    (0x1D68, "<sub> 03C1"),
+    (0x1D69, "<sub> 03C6"),
+    (0x1D6A, "<sub> 03C7"),
+    (0x1D78, "<super> 043D"),
+    (0x1D9B, "<super> 0252"),
+    (0x1D9C, "<super> 0063"),
+    (0x1D9D, "<super> 0255"),
+    (0x1D9E, "<super> 00F0"),
+    (0x1D9F, "<super> 025C"),
+    (0x1DA0, "<super> 0066"),
+    (0x1DA1, "<super> 025F"),
+    (0x1DA2, "<super> 0261"),
+    (0x1DA3, "<super> 0265"),
+    (0x1DA4, "<super> 0268"),
+    (0x1DA5, "<super> 0269"),
+    (0x1DA6, "<super> 026A"),
+    (0x1DA7, "<super> 1D7B"),
+    (0x1DA8, "<super> 029D"),
+    (0x1DA9, "<super> 026D"),
+    (0x1DAA, "<super> 1D85"),
+    (0x1DAB, "<super> 029F"),
+    (0x1DAC, "<super> 0271"),
+    (0x1DAD, "<super> 0270"),
+    (0x1DAE, "<super> 0272"),
+    (0x1DAF, "<super> 0273"),
+    (0x1DB0, "<super> 0274"),
+    (0x1DB1, "<super> 0275"),
+    (0x1DB2, "<super> 0278"),
+    (0x1DB3, "<super> 0282"),
+    (0x1DB4, "<super> 0283"),
+    (0x1DB5, "<super> 01AB"),
+    (0x1DB6, "<super> 0289"),
+    (0x1DB7, "<super> 028A"),
+    (0x1DB8, "<super> 1D1C"),
+    (0x1DB9, "<super> 028B"),
+    (0x1DBA, "<super> 028C"),
+    (0x1DBB, "<super> 007A"),
+    (0x1DBC, "<super> 0290"),
+    (0x1DBD, "<super> 0291"),
+    (0x1DBE, "<super> 0292"),
+    (0x1DBF, "<super> 03B8"),
+    (0x1E00, "0041 0325"),
+    (0x1E01, "0061 0325"),
+    (0x1E02, "0042 0307"),
+    (0x1E03, "0062 0307"),
+    (0x1E04, "0042 0323"),
+    (0x1E05, "0062 0323"),
+    (0x1E06, "0042 0331"),
+    (0x1E07, "0062 0331"),
+    (0x1E08, "00C7 0301"),
+    (0x1E09, "00E7 0301"),
+    (0x1E0A, "0044 0307"),
+    (0x1E0B, "0064 0307"),
+    (0x1E0C, "0044 0323"),
+    (0x1E0D, "0064 0323"),
+    (0x1E0E, "0044 0331"),
+    (0x1E0F, "0064 0331"),
+    (0x1E10, "0044 0327"),
+    (0x1E11, "0064 0327"),
+    (0x1E12, "0044 032D"),
+    (0x1E13, "0064 032D"),
+    (0x1E14, "0112 0300"),
+    (0x1E15, "0113 0300"),
+    (0x1E16, "0112 0301"),
+    (0x1E17, "0113 0301"),
+    (0x1E18, "0045 032D"),
+    (0x1E19, "0065 032D"),
+    (0x1E1A, "0045 0330"),
+    (0x1E1B, "0065 0330"),
+    (0x1E1C, "0228 0306"),
+    (0x1E1D, "0229 0306"),
+    (0x1E1E, "0046 0307"),
+    (0x1E1F, "0066 0307"),
+    (0x1E20, "0047 0304"),
+    (0x1E21, "0067 0304"),
+    (0x1E22, "0048 0307"),
+    (0x1E23, "0068 0307"),
+    (0x1E24, "0048 0323"),
+    (0x1E25, "0068 0323"),
+    (0x1E26, "0048 0308"),
+    (0x1E27, "0068 0308"),
+    (0x1E28, "0048 0327"),
+    (0x1E29, "0068 0327"),
+    (0x1E2A, "0048 032E"),
+    (0x1E2B, "0068 032E"),
+    (0x1E2C, "0049 0330"),
+    (0x1E2D, "0069 0330"),
+    (0x1E2E, "00CF 0301"),
+    (0x1E2F, "00EF 0301"),
+    (0x1E30, "004B 0301"),
+    (0x1E31, "006B 0301"),
+    (0x1E32, "004B 0323"),
+    (0x1E33, "006B 0323"),
+    (0x1E34, "004B 0331"),
+    (0x1E35, "006B 0331"),
+    (0x1E36, "004C 0323"),
+    (0x1E37, "006C 0323"),
+    (0x1E38, "1E36 0304"),
+    (0x1E39, "1E37 0304"),
+    (0x1E3A, "004C 0331"),
+    (0x1E3B, "006C 0331"),
+    (0x1E3C, "004C 032D"),
+    (0x1E3D, "006C 032D"),
+    (0x1E3E, "004D 0301"),
+    (0x1E3F, "006D 0301"),
+    (0x1E40, "004D 0307"),
+    (0x1E41, "006D 0307"),
+    (0x1E42, "004D 0323"),
+    (0x1E43, "006D 0323"),
+    (0x1E44, "004E 0307"),
+    (0x1E45, "006E 0307"),
+    (0x1E46, "004E 0323"),
+    (0x1E47, "006E 0323"),
+    (0x1E48, "004E 0331"),
+    (0x1E49, "006E 0331"),
+    (0x1E4A, "004E 032D"),
+    (0x1E4B, "006E 032D"),
+    (0x1E4C, "00D5 0301"),
+    (0x1E4D, "00F5 0301"),
+    (0x1E4E, "00D5 0308"),
+    (0x1E4F, "00F5 0308"),
+    (0x1E50, "014C 0300"),
+    (0x1E51, "014D 0300"),
+    (0x1E52, "014C 0301"),
+    (0x1E53, "014D 0301"),
+    (0x1E54, "0050 0301"),
+    (0x1E55, "0070 0301"),
+    (0x1E56, "0050 0307"),
+    (0x1E57, "0070 0307"),
+    (0x1E58, "0052 0307"),
+    (0x1E59, "0072 0307"),
+    (0x1E5A, "0052 0323"),
+    (0x1E5B, "0072 0323"),
+    (0x1E5C, "1E5A 0304"),
+    (0x1E5D, "1E5B 0304"),
+    (0x1E5E, "0052 0331"),
+    (0x1E5F, "0072 0331"),
+    (0x1E60, "0053 0307"),
+    (0x1E61, "0073 0307"),
+    (0x1E62, "0053 0323"),
+    (0x1E63, "0073 0323"),
+    (0x1E64, "015A 0307"),
+    (0x1E65, "015B 0307"),
+    (0x1E66, "0160 0307"),
+    (0x1E67, "0161 0307"),
+    (0x1E68, "1E62 0307"),
+    (0x1E69, "1E63 0307"),
+    (0x1E6A, "0054 0307"),
+    (0x1E6B, "0074 0307"),
+    (0x1E6C, "0054 0323"),
+    (0x1E6D, "0074 0323"),
+    (0x1E6E, "0054 0331"),
+    (0x1E6F, "0074 0331"),
+    (0x1E70, "0054 032D"),
+    (0x1E71, "0074 032D"),
+    (0x1E72, "0055 0324"),
+    (0x1E73, "0075 0324"),
+    (0x1E74, "0055 0330"),
+    (0x1E75, "0075 0330"),
+    (0x1E76, "0055 032D"),
+    (0x1E77, "0075 032D"),
+    (0x1E78, "0168 0301"),
+    (0x1E79, "0169 0301"),
+    (0x1E7A, "016A 0308"),
+    (0x1E7B, "016B 0308"),
+    (0x1E7C, "0056 0303"),
+    (0x1E7D, "0076 0303"),
+    (0x1E7E, "0056 0323"),
+    (0x1E7F, "0076 0323"),
+    (0x1E80, "0057 0300"),
+    (0x1E81, "0077 0300"),
+    (0x1E82, "0057 0301"),
+    (0x1E83, "0077 0301"),
+    (0x1E84, "0057 0308"),
+    (0x1E85, "0077 0308"),
+    (0x1E86, "0057 0307"),
+    (0x1E87, "0077 0307"),
+    (0x1E88, "0057 0323"),
+    (0x1E89, "0077 0323"),
+    (0x1E8A, "0058 0307"),
+    (0x1E8B, "0078 0307"),
+    (0x1E8C, "0058 0308"),
+    (0x1E8D, "0078 0308"),
+    (0x1E8E, "0059 0307"),
+    (0x1E8F, "0079 0307"),
+    (0x1E90, "005A 0302"),
+    (0x1E91, "007A 0302"),
+    (0x1E92, "005A 0323"),
+    (0x1E93, "007A 0323"),
+    (0x1E94, "005A 0331"),
+    (0x1E95, "007A 0331"),
+    (0x1E96, "0068 0331"),
+    (0x1E97, "0074 0308"),
+    (0x1E98, "0077 030A"),
+    (0x1E99, "0079 030A"),
+    (0x1E9A, "<compat> 0061 02BE"),
+    (0x1E9B, "017F 0307"),
+    (0x1EA0, "0041 0323"),
+    (0x1EA1, "0061 0323"),
+    (0x1EA2, "0041 0309"),
+    (0x1EA3, "0061 0309"),
+    (0x1EA4, "00C2 0301"),
+    (0x1EA5, "00E2 0301"),
+    (0x1EA6, "00C2 0300"),
+    (0x1EA7, "00E2 0300"),
+    (0x1EA8, "00C2 0309"),
+    (0x1EA9, "00E2 0309"),
+    (0x1EAA, "00C2 0303"),
+    (0x1EAB, "00E2 0303"),
+    (0x1EAC, "1EA0 0302"),
+    (0x1EAD, "1EA1 0302"),
+    (0x1EAE, "0102 0301"),
+    (0x1EAF, "0103 0301"),
+    (0x1EB0, "0102 0300"),
+    (0x1EB1, "0103 0300"),
+    (0x1EB2, "0102 0309"),
+    (0x1EB3, "0103 0309"),
+    (0x1EB4, "0102 0303"),
+    (0x1EB5, "0103 0303"),
+    (0x1EB6, "1EA0 0306"),
+    (0x1EB7, "1EA1 0306"),
+    (0x1EB8, "0045 0323"),
+    (0x1EB9, "0065 0323"),
+    (0x1EBA, "0045 0309"),
+    (0x1EBB, "0065 0309"),
+    (0x1EBC, "0045 0303"),
+    (0x1EBD, "0065 0303"),
+    (0x1EBE, "00CA 0301"),
+    (0x1EBF, "00EA 0301"),
+    (0x1EC0, "00CA 0300"),
+    (0x1EC1, "00EA 0300"),
+    (0x1EC2, "00CA 0309"),
+    (0x1EC3, "00EA 0309"),
+    (0x1EC4, "00CA 0303"),
+    (0x1EC5, "00EA 0303"),
+    (0x1EC6, "1EB8 0302"),
+    (0x1EC7, "1EB9 0302"),
+    (0x1EC8, "0049 0309"),
+    (0x1EC9, "0069 0309"),
+    (0x1ECA, "0049 0323"),
+    (0x1ECB, "0069 0323"),
+    (0x1ECC, "004F 0323"),
+    (0x1ECD, "006F 0323"),
+    (0x1ECE, "004F 0309"),
+    (0x1ECF, "006F 0309"),
+    (0x1ED0, "00D4 0301"),
+    (0x1ED1, "00F4 0301"),
+    (0x1ED2, "00D4 0300"),
+    (0x1ED3, "00F4 0300"),
+    (0x1ED4, "00D4 0309"),
+    (0x1ED5, "00F4 0309"),
+    (0x1ED6, "00D4 0303"),
+    (0x1ED7, "00F4 0303"),
+    (0x1ED8, "1ECC 0302"),
+    (0x1ED9, "1ECD 0302"),
+    (0x1EDA, "01A0 0301"),
+    (0x1EDB, "01A1 0301"),
+    (0x1EDC, "01A0 0300"),
+    (0x1EDD, "01A1 0300"),
+    (0x1EDE, "01A0 0309"),
+    (0x1EDF, "01A1 0309"),
+    (0x1EE0, "01A0 0303"),
+    (0x1EE1, "01A1 0303"),
+    (0x1EE2, "01A0 0323"),
+    (0x1EE3, "01A1 0323"),
+    (0x1EE4, "0055 0323"),
+    (0x1EE5, "0075 0323"),
+    (0x1EE6, "0055 0309"),
+    (0x1EE7, "0075 0309"),
+    (0x1EE8, "01AF 0301"),
+    (0x1EE9, "01B0 0301"),
+    (0x1EEA, "01AF 0300"),
+    (0x1EEB, "01B0 0300"),
+    (0x1EEC, "01AF 0309"),
+    (0x1EED, "01B0 0309"),
+    (0x1EEE, "01AF 0303"),
+    (0x1EEF, "01B0 0303"),
+    (0x1EF0, "01AF 0323"),
+    (0x1EF1, "01B0 0323"),
+    (0x1EF2, "0059 0300"),
+    (0x1EF3, "0079 0300"),
+    (0x1EF4, "0059 0323"),
+    (0x1EF5, "0079 0323"),
+    (0x1EF6, "0059 0309"),
+    (0x1EF7, "0079 0309"),
+    (0x1EF8, "0059 0303"),
+    (0x1EF9, "0079 0303"),
+    (0x1F00, "03B1 0313"),
+    (0x1F01, "03B1 0314"),
+    (0x1F02, "1F00 0300"),
+    (0x1F03, "1F01 0300"),
+    (0x1F04, "1F00 0301"),
+    (0x1F05, "1F01 0301"),
+    (0x1F06, "1F00 0342"),
+    (0x1F07, "1F01 0342"),
+    (0x1F08, "0391 0313"),
+    (0x1F09, "0391 0314"),
+    (0x1F0A, "1F08 0300"),
+    (0x1F0B, "1F09 0300"),
+    (0x1F0C, "1F08 0301"),
+    (0x1F0D, "1F09 0301"),
+    (0x1F0E, "1F08 0342"),
+    (0x1F0F, "1F09 0342"),
+    (0x1F10, "03B5 0313"),
+    (0x1F11, "03B5 0314"),
+    (0x1F12, "1F10 0300"),
+    (0x1F13, "1F11 0300"),
+    (0x1F14, "1F10 0301"),
+    (0x1F15, "1F11 0301"),
+    (0x1F18, "0395 0313"),
+    (0x1F19, "0395 0314"),
+    (0x1F1A, "1F18 0300"),
+    (0x1F1B, "1F19 0300"),
+    (0x1F1C, "1F18 0301"),
+    (0x1F1D, "1F19 0301"),
+    (0x1F20, "03B7 0313"),
+    (0x1F21, "03B7 0314"),
+    (0x1F22, "1F20 0300"),
+    (0x1F23, "1F21 0300"),
+    (0x1F24, "1F20 0301"),
+    (0x1F25, "1F21 0301"),
+    (0x1F26, "1F20 0342"),
+    (0x1F27, "1F21 0342"),
+    (0x1F28, "0397 0313"),
+    (0x1F29, "0397 0314"),
+    (0x1F2A, "1F28 0300"),
+    (0x1F2B, "1F29 0300"),
+    (0x1F2C, "1F28 0301"),
+    (0x1F2D, "1F29 0301"),
+    (0x1F2E, "1F28 0342"),
+    (0x1F2F, "1F29 0342"),
+    (0x1F30, "03B9 0313"),
+    (0x1F31, "03B9 0314"),
+    (0x1F32, "1F30 0300"),
+    (0x1F33, "1F31 0300"),
+    (0x1F34, "1F30 0301"),
+    (0x1F35, "1F31 0301"),
+    (0x1F36, "1F30 0342"),
+    (0x1F37, "1F31 0342"),
+    (0x1F38, "0399 0313"),
+    (0x1F39, "0399 0314"),
+    (0x1F3A, "1F38 0300"),
+    (0x1F3B, "1F39 0300"),
+    (0x1F3C, "1F38 0301"),
+    (0x1F3D, "1F39 0301"),
+    (0x1F3E, "1F38 0342"),
+    (0x1F3F, "1F39 0342"),
+    (0x1F40, "03BF 0313"),
+    (0x1F41, "03BF 0314"),
+    (0x1F42, "1F40 0300"),
+    (0x1F43, "1F41 0300"),
+    (0x1F44, "1F40 0301"),
+    (0x1F45, "1F41 0301"),
+    (0x1F48, "039F 0313"),
+    (0x1F49, "039F 0314"),
+    (0x1F4A, "1F48 0300"),
+    (0x1F4B, "1F49 0300"),
+    (0x1F4C, "1F48 0301"),
+    (0x1F4D, "1F49 0301"),
+    (0x1F50, "03C5 0313"),
+    (0x1F51, "03C5 0314"),
+    (0x1F52, "1F50 0300"),
+    (0x1F53, "1F51 0300"),
+    (0x1F54, "1F50 0301"),
+    (0x1F55, "1F51 0301"),
+    (0x1F56, "1F50 0342"),
+    (0x1F57, "1F51 0342"),
+    (0x1F59, "03A5 0314"),
+    (0x1F5B, "1F59 0300"),
+    (0x1F5D, "1F59 0301"),
+    (0x1F5F, "1F59 0342"),
+    (0x1F60, "03C9 0313"),
+    (0x1F61, "03C9 0314"),
+    (0x1F62, "1F60 0300"),
+    (0x1F63, "1F61 0300"),
+    (0x1F64, "1F60 0301"),
+    (0x1F65, "1F61 0301"),
+    (0x1F66, "1F60 0342"),
+    (0x1F67, "1F61 0342"),
+    (0x1F68, "03A9 0313"),
+    (0x1F69, "03A9 0314"),
+    (0x1F6A, "1F68 0300"),
+    (0x1F6B, "1F69 0300"),
+    (0x1F6C, "1F68 0301"),
+    (0x1F6D, "1F69 0301"),
+    (0x1F6E, "1F68 0342"),
+    (0x1F6F, "1F69 0342"),
+    (0x1F70, "03B1 0300"),
+    (0x1F71, "03AC"),
+    (0x1F72, "03B5 0300"),
+    (0x1F73, "03AD"),
+    (0x1F74, "03B7 0300"),
+    (0x1F75, "03AE"),
+    (0x1F76, "03B9 0300"),
+    (0x1F77, "03AF"),
+    (0x1F78, "03BF 0300"),
+    (0x1F79, "03CC"),
+    (0x1F7A, "03C5 0300"),
+    (0x1F7B, "03CD"),
+    (0x1F7C, "03C9 0300"),
+    (0x1F7D, "03CE"),
+    (0x1F80, "1F00 0345"),
+    (0x1F81, "1F01 0345"),
+    (0x1F82, "1F02 0345"),
+    (0x1F83, "1F03 0345"),
+    (0x1F84, "1F04 0345"),
+    (0x1F85, "1F05 0345"),
+    (0x1F86, "1F06 0345"),
+    (0x1F87, "1F07 0345"),
+    (0x1F88, "1F08 0345"),
+    (0x1F89, "1F09 0345"),
+    (0x1F8A, "1F0A 0345"),
+    (0x1F8B, "1F0B 0345"),
+    (0x1F8C, "1F0C 0345"),
+    (0x1F8D, "1F0D 0345"),
+    (0x1F8E, "1F0E 0345"),
+    (0x1F8F, "1F0F 0345"),
+    (0x1F90, "1F20 0345"),
+    (0x1F91, "1F21 0345"),
+    (0x1F92, "1F22 0345"),
+    (0x1F93, "1F23 0345"),
+    (0x1F94, "1F24 0345"),
+    (0x1F95, "1F25 0345"),
+    (0x1F96, "1F26 0345"),
+    (0x1F97, "1F27 0345"),
+    (0x1F98, "1F28 0345"),
+    (0x1F99, "1F29 0345"),
+    (0x1F9A, "1F2A 0345"),
+    (0x1F9B, "1F2B 0345"),
+    (0x1F9C, "1F2C 0345"),
+    (0x1F9D, "1F2D 0345"),
+    (0x1F9E, "1F2E 0345"),
+    (0x1F9F, "1F2F 0345"),
+    (0x1FA0, "1F60 0345"),
+    (0x1FA1, "1F61 0345"),
+    (0x1FA2, "1F62 0345"),
+    (0x1FA3, "1F63 0345"),
+    (0x1FA4, "1F64 0345"),
+    (0x1FA5, "1F65 0345"),
+    (0x1FA6, "1F66 0345"),
+    (0x1FA7, "1F67 0345"),
+    (0x1FA8, "1F68 0345"),
+    (0x1FA9, "1F69 0345"),
+    (0x1FAA, "1F6A 0345"),
+    (0x1FAB, "1F6B 0345"),
+    (0x1FAC, "1F6C 0345"),
+    (0x1FAD, "1F6D 0345"),
+    (0x1FAE, "1F6E 0345"),
+    (0x1FAF, "1F6F 0345"),
+    (0x1FB0, "03B1 0306"),
+    (0x1FB1, "03B1 0304"),
+    (0x1FB2, "1F70 0345"),
+    (0x1FB3, "03B1 0345"),
+    (0x1FB4, "03AC 0345"),
+    (0x1FB6, "03B1 0342"),
+    (0x1FB7, "1FB6 0345"),
+    (0x1FB8, "0391 0306"),
+    (0x1FB9, "0391 0304"),
+    (0x1FBA, "0391 0300"),
+    (0x1FBB, "0386"),
+    (0x1FBC, "0391 0345"),
+    (0x1FBD, "<compat> 0020 0313"),
+    (0x1FBE, "03B9"),
+    (0x1FBF, "<compat> 0020 0313"),
+    (0x1FC0, "<compat> 0020 0342"),
+    (0x1FC1, "00A8 0342"),
+    (0x1FC2, "1F74 0345"),
+    (0x1FC3, "03B7 0345"),
+    (0x1FC4, "03AE 0345"),
+    (0x1FC6, "03B7 0342"),
+    (0x1FC7, "1FC6 0345"),
+    (0x1FC8, "0395 0300"),
+    (0x1FC9, "0388"),
+    (0x1FCA, "0397 0300"),
+    (0x1FCB, "0389"),
+    (0x1FCC, "0397 0345"),
+    (0x1FCD, "1FBF 0300"),
+    (0x1FCE, "1FBF 0301"),
+    (0x1FCF, "1FBF 0342"),
+    (0x1FD0, "03B9 0306"),
+    (0x1FD1, "03B9 0304"),
+    (0x1FD2, "03CA 0300"),
+    (0x1FD3, "0390"),
+    (0x1FD6, "03B9 0342"),
+    (0x1FD7, "03CA 0342"),
+    (0x1FD8, "0399 0306"),
+    (0x1FD9, "0399 0304"),
+    (0x1FDA, "0399 0300"),
+    (0x1FDB, "038A"),
+    (0x1FDD, "1FFE 0300"),
+    (0x1FDE, "1FFE 0301"),
+    (0x1FDF, "1FFE 0342"),
+    (0x1FE0, "03C5 0306"),
+    (0x1FE1, "03C5 0304"),
+    (0x1FE2, "03CB 0300"),
+    (0x1FE3, "03B0"),
+    (0x1FE4, "03C1 0313"),
+    (0x1FE5, "03C1 0314"),
+    (0x1FE6, "03C5 0342"),
+    (0x1FE7, "03CB 0342"),
+    (0x1FE8, "03A5 0306"),
+    (0x1FE9, "03A5 0304"),
+    (0x1FEA, "03A5 0300"),
+    (0x1FEB, "038E"),
+    (0x1FEC, "03A1 0314"),
+    (0x1FED, "00A8 0300"),
+    (0x1FEE, "0385"),
+    (0x1FEF, "0060"),
+    (0x1FF2, "1F7C 0345"),
+    (0x1FF3, "03C9 0345"),
+    (0x1FF4, "03CE 0345"),
+    (0x1FF6, "03C9 0342"),
+    (0x1FF7, "1FF6 0345"),
+    (0x1FF8, "039F 0300"),
+    (0x1FF9, "038C"),
+    (0x1FFA, "03A9 0300"),
+    (0x1FFB, "038F"),
+    (0x1FFC, "03A9 0345"),
+    (0x1FFD, "00B4"),
+    (0x1FFE, "<compat> 0020 0314"),
+    (0x2000, "2002"),
+    (0x2001, "2003"),
+    (0x2002, "<compat> 0020"),
+    (0x2003, "<compat> 0020"),
+    (0x2004, "<compat> 0020"),
+    (0x2005, "<compat> 0020"),
+    (0x2006, "<compat> 0020"),
+    (0x2007, "<noBreak> 0020"),
+    (0x2008, "<compat> 0020"),
+    (0x2009, "<compat> 0020"),
+    (0x200A, "<compat> 0020"),
+    (0x2011, "<noBreak> 2010"),
+    (0x2017, "<compat> 0020 0333"),
+    (0x2024, "<compat> 002E"),
+    (0x2025, "<compat> 002E 002E"),
+    (0x2026, "<compat> 002E 002E 002E"),
+    (0x202F, "<noBreak> 0020"),
+    (0x2033, "<compat> 2032 2032"),
+    (0x2034, "<compat> 2032 2032 2032"),
+    (0x2036, "<compat> 2035 2035"),
+    (0x2037, "<compat> 2035 2035 2035"),
+    (0x203C, "<compat> 0021 0021"),
+    (0x203E, "<compat> 0020 0305"),
+    (0x2047, "<compat> 003F 003F"),
+    (0x2048, "<compat> 003F 0021"),
+    (0x2049, "<compat> 0021 003F"),
+    (0x2057, "<compat> 2032 2032 2032 2032"),
+    (0x205F, "<compat> 0020"),
+    (0x2070, "<super> 0030"),
+    (0x2071, "<super> 0069"),
+    (0x2074, "<super> 0034"),
+    (0x2075, "<super> 0035"),
+    (0x2076, "<super> 0036"),
+    (0x2077, "<super> 0037"),
+    (0x2078, "<super> 0038"),
+    (0x2079, "<super> 0039"),
+    (0x207A, "<super> 002B"),
+    (0x207B, "<super> 2212"),
+    (0x207C, "<super> 003D"),
+    (0x207D, "<super> 0028"),
+    (0x207E, "<super> 0029"),
+    (0x207F, "<super> 006E"),
+    (0x2080, "<sub> 0030"),
+    (0x2081, "<sub> 0031"),
+    (0x2082, "<sub> 0032"),
+    (0x2083, "<sub> 0033"),
+    (0x2084, "<sub> 0034"),
+    (0x2085, "<sub> 0035"),
+    (0x2086, "<sub> 0036"),
+    (0x2087, "<sub> 0037"),
+    (0x2088, "<sub> 0038"),
+    (0x2089, "<sub> 0039"),
+    (0x208A, "<sub> 002B"),
+    (0x208B, "<sub> 2212"),
+    (0x208C, "<sub> 003D"),
+    (0x208D, "<sub> 0028"),
+    (0x208E, "<sub> 0029"),
+    (0x2090, "<sub> 0061"),
+    (0x2091, "<sub> 0065"),
+    (0x2092, "<sub> 006F"),
+    (0x2093, "<sub> 0078"),
+    (0x2094, "<sub> 0259"),
+    (0x2095, "<sub> 0068"),
+    (0x2096, "<sub> 006B"),
+    (0x2097, "<sub> 006C"),
+    (0x2098, "<sub> 006D"),
+    (0x2099, "<sub> 006E"),
+    (0x209A, "<sub> 0070"),
+    (0x209B, "<sub> 0073"),
+    (0x209C, "<sub> 0074"),
+    (0x20A8, "<compat> 0052 0073"),
+    (0x2100, "<compat> 0061 002F 0063"),
+    (0x2101, "<compat> 0061 002F 0073"),
+    (0x2102, "<font> 0043"),
+    (0x2103, "<compat> 00B0 0043"),
+    (0x2105, "<compat> 0063 002F 006F"),
+    (0x2106, "<compat> 0063 002F 0075"),
+    (0x2107, "<compat> 0190"),
+    (0x2109, "<compat> 00B0 0046"),
+    (0x210A, "<font> 0067"),
+    (0x210B, "<font> 0048"),
+    (0x210C, "<font> 0048"),
+    (0x210D, "<font> 0048"),
+    (0x210E, "<font> 0068"),
+    (0x210F, "<font> 0127"),
+    (0x2110, "<font> 0049"),
+    (0x2111, "<font> 0049"),
+    (0x2112, "<font> 004C"),
+    (0x2113, "<font> 006C"),
+    (0x2115, "<font> 004E"),
+    (0x2116, "<compat> 004E 006F"),
+    (0x2119, "<font> 0050"),
+    (0x211A, "<font> 0051"),
+    (0x211B, "<font> 0052"),
+    (0x211C, "<font> 0052"),
+    (0x211D, "<font> 0052"),
+    (0x2120, "<super> 0053 004D"),
+    (0x2121, "<compat> 0054 0045 004C"),
+    (0x2122, "<super> 0054 004D"),
+    (0x2124, "<font> 005A"),
+    (0x2126, "03A9"),
+    (0x2128, "<font> 005A"),
+    (0x212A, "004B"),
+    (0x212B, "00C5"),
+    (0x212C, "<font> 0042"),
+    (0x212D, "<font> 0043"),
+    (0x212F, "<font> 0065"),
+    (0x2130, "<font> 0045"),
+    (0x2131, "<font> 0046"),
+    (0x2133, "<font> 004D"),
+    (0x2134, "<font> 006F"),
+    (0x2135, "<compat> 05D0"),
+    (0x2136, "<compat> 05D1"),
+    (0x2137, "<compat> 05D2"),
+    (0x2138, "<compat> 05D3"),
+    (0x2139, "<font> 0069"),
+    (0x213B, "<compat> 0046 0041 0058"),
+    (0x213C, "<font> 03C0"),
+    (0x213D, "<font> 03B3"),
+    (0x213E, "<font> 0393"),
+    (0x213F, "<font> 03A0"),
+    (0x2140, "<font> 2211"),
+    (0x2145, "<font> 0044"),
+    (0x2146, "<font> 0064"),
+    (0x2147, "<font> 0065"),
+    (0x2148, "<font> 0069"),
+    (0x2149, "<font> 006A"),
+    (0x2150, "<fraction> 0031 2044 0037"),
+    (0x2151, "<fraction> 0031 2044 0039"),
+    (0x2152, "<fraction> 0031 2044 0031 0030"),
+    (0x2153, "<fraction> 0031 2044 0033"),
+    (0x2154, "<fraction> 0032 2044 0033"),
+    (0x2155, "<fraction> 0031 2044 0035"),
+    (0x2156, "<fraction> 0032 2044 0035"),
+    (0x2157, "<fraction> 0033 2044 0035"),
+    (0x2158, "<fraction> 0034 2044 0035"),
+    (0x2159, "<fraction> 0031 2044 0036"),
+    (0x215A, "<fraction> 0035 2044 0036"),
+    (0x215B, "<fraction> 0031 2044 0038"),
+    (0x215C, "<fraction> 0033 2044 0038"),
+    (0x215D, "<fraction> 0035 2044 0038"),
+    (0x215E, "<fraction> 0037 2044 0038"),
+    (0x215F, "<fraction> 0031 2044"),
+    (0x2160, "<compat> 0049"),
+    (0x2161, "<compat> 0049 0049"),
+    (0x2162, "<compat> 0049 0049 0049"),
+    (0x2163, "<compat> 0049 0056"),
+    (0x2164, "<compat> 0056"),
+    (0x2165, "<compat> 0056 0049"),
+    (0x2166, "<compat> 0056 0049 0049"),
+    (0x2167, "<compat> 0056 0049 0049 0049"),
+    (0x2168, "<compat> 0049 0058"),
+    (0x2169, "<compat> 0058"),
+    (0x216A, "<compat> 0058 0049"),
+    (0x216B, "<compat> 0058 0049 0049"),
+    (0x216C, "<compat> 004C"),
+    (0x216D, "<compat> 0043"),
+    (0x216E, "<compat> 0044"),
+    (0x216F, "<compat> 004D"),
+    (0x2170, "<compat> 0069"),
+    (0x2171, "<compat> 0069 0069"),
+    (0x2172, "<compat> 0069 0069 0069"),
+    (0x2173, "<compat> 0069 0076"),
+    (0x2174, "<compat> 0076"),
+    (0x2175, "<compat> 0076 0069"),
+    (0x2176, "<compat> 0076 0069 0069"),
+    (0x2177, "<compat> 0076 0069 0069 0069"),
+    (0x2178, "<compat> 0069 0078"),
+    (0x2179, "<compat> 0078"),
+    (0x217A, "<compat> 0078 0069"),
+    (0x217B, "<compat> 0078 0069 0069"),
+    (0x217C, "<compat> 006C"),
+    (0x217D, "<compat> 0063"),
+    (0x217E, "<compat> 0064"),
+    (0x217F, "<compat> 006D"),
+    (0x2189, "<fraction> 0030 2044 0033"),
+    (0x219A, "2190 0338"),
+    (0x219B, "2192 0338"),
+    (0x21AE, "2194 0338"),
+    (0x21CD, "21D0 0338"),
+    (0x21CE, "21D4 0338"),
+    (0x21CF, "21D2 0338"),
+    (0x2204, "2203 0338"),
+    (0x2209, "2208 0338"),
+    (0x220C, "220B 0338"),
+    (0x2224, "2223 0338"),
+    (0x2226, "2225 0338"),
+    (0x222C, "<compat> 222B 222B"),
+    (0x222D, "<compat> 222B 222B 222B"),
+    (0x222F, "<compat> 222E 222E"),
+    (0x2230, "<compat> 222E 222E 222E"),
+    (0x2241, "223C 0338"),
+    (0x2244, "2243 0338"),
+    (0x2247, "2245 0338"),
+    (0x2249, "2248 0338"),
+    (0x2260, "003D 0338"),
+    (0x2262, "2261 0338"),
+    (0x226D, "224D 0338"),
+    (0x226E, "003C 0338"),
+    (0x226F, "003E 0338"),
+    (0x2270, "2264 0338"),
+    (0x2271, "2265 0338"),
+    (0x2274, "2272 0338"),
+    (0x2275, "2273 0338"),
+    (0x2278, "2276 0338"),
+    (0x2279, "2277 0338"),
+    (0x2280, "227A 0338"),
+    (0x2281, "227B 0338"),
+    (0x2284, "2282 0338"),
+    (0x2285, "2283 0338"),
+    (0x2288, "2286 0338"),
+    (0x2289, "2287 0338"),
+    (0x22AC, "22A2 0338"),
+    (0x22AD, "22A8 0338"),
+    (0x22AE, "22A9 0338"),
+    (0x22AF, "22AB 0338"),
+    (0x22E0, "227C 0338"),
+    (0x22E1, "227D 0338"),
+    (0x22E2, "2291 0338"),
+    (0x22E3, "2292 0338"),
+    (0x22EA, "22B2 0338"),
+    (0x22EB, "22B3 0338"),
+    (0x22EC, "22B4 0338"),
+    (0x22ED, "22B5 0338"),
+    (0x2329, "3008"),
+    (0x232A, "3009"),
+    (0x2460, "<circle> 0031"),
+    (0x2461, "<circle> 0032"),
+    (0x2462, "<circle> 0033"),
+    (0x2463, "<circle> 0034"),
+    (0x2464, "<circle> 0035"),
+    (0x2465, "<circle> 0036"),
+    (0x2466, "<circle> 0037"),
+    (0x2467, "<circle> 0038"),
+    (0x2468, "<circle> 0039"),
+    (0x2469, "<circle> 0031 0030"),
+    (0x246A, "<circle> 0031 0031"),
+    (0x246B, "<circle> 0031 0032"),
+    (0x246C, "<circle> 0031 0033"),
+    (0x246D, "<circle> 0031 0034"),
+    (0x246E, "<circle> 0031 0035"),
+    (0x246F, "<circle> 0031 0036"),
+    (0x2470, "<circle> 0031 0037"),
+    (0x2471, "<circle> 0031 0038"),
+    (0x2472, "<circle> 0031 0039"),
+    (0x2473, "<circle> 0032 0030"),
+    (0x2474, "<compat> 0028 0031 0029"),
+    (0x2475, "<compat> 0028 0032 0029"),
+    (0x2476, "<compat> 0028 0033 0029"),
+    (0x2477, "<compat> 0028 0034 0029"),
+    (0x2478, "<compat> 0028 0035 0029"),
+    (0x2479, "<compat> 0028 0036 0029"),
+    (0x247A, "<compat> 0028 0037 0029"),
+    (0x247B, "<compat> 0028 0038 0029"),
+    (0x247C, "<compat> 0028 0039 0029"),
+    (0x247D, "<compat> 0028 0031 0030 0029"),
+    (0x247E, "<compat> 0028 0031 0031 0029"),
+    (0x247F, "<compat> 0028 0031 0032 0029"),
+    (0x2480, "<compat> 0028 0031 0033 0029"),
+    (0x2481, "<compat> 0028 0031 0034 0029"),
+    (0x2482, "<compat> 0028 0031 0035 0029"),
+    (0x2483, "<compat> 0028 0031 0036 0029"),
+    (0x2484, "<compat> 0028 0031 0037 0029"),
+    (0x2485, "<compat> 0028 0031 0038 0029"),
+    (0x2486, "<compat> 0028 0031 0039 0029"),
+    (0x2487, "<compat> 0028 0032 0030 0029"),
+    (0x2488, "<compat> 0031 002E"),
+    (0x2489, "<compat> 0032 002E"),
+    (0x248A, "<compat> 0033 002E"),
+    (0x248B, "<compat> 0034 002E"),
+    (0x248C, "<compat> 0035 002E"),
+    (0x248D, "<compat> 0036 002E"),
+    (0x248E, "<compat> 0037 002E"),
+    (0x248F, "<compat> 0038 002E"),
+    (0x2490, "<compat> 0039 002E"),
+    (0x2491, "<compat> 0031 0030 002E"),
+    (0x2492, "<compat> 0031 0031 002E"),
+    (0x2493, "<compat> 0031 0032 002E"),
+    (0x2494, "<compat> 0031 0033 002E"),
+    (0x2495, "<compat> 0031 0034 002E"),
+    (0x2496, "<compat> 0031 0035 002E"),
+    (0x2497, "<compat> 0031 0036 002E"),
+    (0x2498, "<compat> 0031 0037 002E"),
+    (0x2499, "<compat> 0031 0038 002E"),
+    (0x249A, "<compat> 0031 0039 002E"),
+    (0x249B, "<compat> 0032 0030 002E"),
+    (0x249C, "<compat> 0028 0061 0029"),
+    (0x249D, "<compat> 0028 0062 0029"),
+    (0x249E, "<compat> 0028 0063 0029"),
+    (0x249F, "<compat> 0028 0064 0029"),
+    (0x24A0, "<compat> 0028 0065 0029"),
+    (0x24A1, "<compat> 0028 0066 0029"),
+    (0x24A2, "<compat> 0028 0067 0029"),
+    (0x24A3, "<compat> 0028 0068 0029"),
+    (0x24A4, "<compat> 0028 0069 0029"),
+    (0x24A5, "<compat> 0028 006A 0029"),
+    (0x24A6, "<compat> 0028 006B 0029"),
+    (0x24A7, "<compat> 0028 006C 0029"),
+    (0x24A8, "<compat> 0028 006D 0029"),
+    (0x24A9, "<compat> 0028 006E 0029"),
+    (0x24AA, "<compat> 0028 006F 0029"),
+    (0x24AB, "<compat> 0028 0070 0029"),
+    (0x24AC, "<compat> 0028 0071 0029"),
+    (0x24AD, "<compat> 0028 0072 0029"),
+    (0x24AE, "<compat> 0028 0073 0029"),
+    (0x24AF, "<compat> 0028 0074 0029"),
+    (0x24B0, "<compat> 0028 0075 0029"),
+    (0x24B1, "<compat> 0028 0076 0029"),
+    (0x24B2, "<compat> 0028 0077 0029"),
+    (0x24B3, "<compat> 0028 0078 0029"),
+    (0x24B4, "<compat> 0028 0079 0029"),
+    (0x24B5, "<compat> 0028 007A 0029"),
+    (0x24B6, "<circle> 0041"),
+    (0x24B7, "<circle> 0042"),
+    (0x24B8, "<circle> 0043"),
+    (0x24B9, "<circle> 0044"),
+    (0x24BA, "<circle> 0045"),
+    (0x24BB, "<circle> 0046"),
+    (0x24BC, "<circle> 0047"),
+    (0x24BD, "<circle> 0048"),
+    (0x24BE, "<circle> 0049"),
+    (0x24BF, "<circle> 004A"),
+    (0x24C0, "<circle> 004B"),
+    (0x24C1, "<circle> 004C"),
+    (0x24C2, "<circle> 004D"),
+    (0x24C3, "<circle> 004E"),
+    (0x24C4, "<circle> 004F"),
+    (0x24C5, "<circle> 0050"),
+    (0x24C6, "<circle> 0051"),
+    (0x24C7, "<circle> 0052"),
+    (0x24C8, "<circle> 0053"),
+    (0x24C9, "<circle> 0054"),
+    (0x24CA, "<circle> 0055"),
+    (0x24CB, "<circle> 0056"),
+    (0x24CC, "<circle> 0057"),
+    (0x24CD, "<circle> 0058"),
+    (0x24CE, "<circle> 0059"),
+    (0x24CF, "<circle> 005A"),
+    (0x24D0, "<circle> 0061"),
+    (0x24D1, "<circle> 0062"),
+    (0x24D2, "<circle> 0063"),
+    (0x24D3, "<circle> 0064"),
+    (0x24D4, "<circle> 0065"),
+    (0x24D5, "<circle> 0066"),
+    (0x24D6, "<circle> 0067"),
+    (0x24D7, "<circle> 0068"),
+    (0x24D8, "<circle> 0069"),
+    (0x24D9, "<circle> 006A"),
+    (0x24DA, "<circle> 006B"),
+    (0x24DB, "<circle> 006C"),
+    (0x24DC, "<circle> 006D"),
+    (0x24DD, "<circle> 006E"),
+    (0x24DE, "<circle> 006F"),
+    (0x24DF, "<circle> 0070"),
+    (0x24E0, "<circle> 0071"),
+    (0x24E1, "<circle> 0072"),
+    (0x24E2, "<circle> 0073"),
+    (0x24E3, "<circle> 0074"),
+    (0x24E4, "<circle> 0075"),
+    (0x24E5, "<circle> 0076"),
+    (0x24E6, "<circle> 0077"),
+    (0x24E7, "<circle> 0078"),
+    (0x24E8, "<circle> 0079"),
+    (0x24E9, "<circle> 007A"),
+    (0x24EA, "<circle> 0030"),
+    (0x2A0C, "<compat> 222B 222B 222B 222B"),
+    (0x2A74, "<compat> 003A 003A 003D"),
+    (0x2A75, "<compat> 003D 003D"),
+    (0x2A76, "<compat> 003D 003D 003D"),
+    (0x2ADC, "2ADD 0338"),
+    (0x2C7C, "<sub> 006A"),
+    (0x2C7D, "<super> 0056"),
+    (0x2D6F, "<super> 2D61"),
+    (0x2E9F, "<compat> 6BCD"),
+    (0x2EF3, "<compat> 9F9F"),
+    (0x2F00, "<compat> 4E00"),
+    (0x2F01, "<compat> 4E28"),
+    (0x2F02, "<compat> 4E36"),
+    (0x2F03, "<compat> 4E3F"),
+    (0x2F04, "<compat> 4E59"),
+    (0x2F05, "<compat> 4E85"),
+    (0x2F06, "<compat> 4E8C"),
+    (0x2F07, "<compat> 4EA0"),
+    (0x2F08, "<compat> 4EBA"),
+    (0x2F09, "<compat> 513F"),
+    (0x2F0A, "<compat> 5165"),
+    (0x2F0B, "<compat> 516B"),
+    (0x2F0C, "<compat> 5182"),
+    (0x2F0D, "<compat> 5196"),
+    (0x2F0E, "<compat> 51AB"),
+    (0x2F0F, "<compat> 51E0"),
+    (0x2F10, "<compat> 51F5"),
+    (0x2F11, "<compat> 5200"),
+    (0x2F12, "<compat> 529B"),
+    (0x2F13, "<compat> 52F9"),
+    (0x2F14, "<compat> 5315"),
+    (0x2F15, "<compat> 531A"),
+    (0x2F16, "<compat> 5338"),
+    (0x2F17, "<compat> 5341"),
+    (0x2F18, "<compat> 535C"),
+    (0x2F19, "<compat> 5369"),
+    (0x2F1A, "<compat> 5382"),
+    (0x2F1B, "<compat> 53B6"),
+    (0x2F1C, "<compat> 53C8"),
+    (0x2F1D, "<compat> 53E3"),
+    (0x2F1E, "<compat> 56D7"),
+    (0x2F1F, "<compat> 571F"),
+    (0x2F20, "<compat> 58EB"),
+    (0x2F21, "<compat> 5902"),
+    (0x2F22, "<compat> 590A"),
+    (0x2F23, "<compat> 5915"),
+    (0x2F24, "<compat> 5927"),
+    (0x2F25, "<compat> 5973"),
+    (0x2F26, "<compat> 5B50"),
+    (0x2F27, "<compat> 5B80"),
+    (0x2F28, "<compat> 5BF8"),
+    (0x2F29, "<compat> 5C0F"),
+    (0x2F2A, "<compat> 5C22"),
+    (0x2F2B, "<compat> 5C38"),
+    (0x2F2C, "<compat> 5C6E"),
+    (0x2F2D, "<compat> 5C71"),
+    (0x2F2E, "<compat> 5DDB"),
+    (0x2F2F, "<compat> 5DE5"),
+    (0x2F30, "<compat> 5DF1"),
+    (0x2F31, "<compat> 5DFE"),
+    (0x2F32, "<compat> 5E72"),
+    (0x2F33, "<compat> 5E7A"),
+    (0x2F34, "<compat> 5E7F"),
+    (0x2F35, "<compat> 5EF4"),
+    (0x2F36, "<compat> 5EFE"),
+    (0x2F37, "<compat> 5F0B"),
+    (0x2F38, "<compat> 5F13"),
+    (0x2F39, "<compat> 5F50"),
+    (0x2F3A, "<compat> 5F61"),
+    (0x2F3B, "<compat> 5F73"),
+    (0x2F3C, "<compat> 5FC3"),
+    (0x2F3D, "<compat> 6208"),
+    (0x2F3E, "<compat> 6236"),
+    (0x2F3F, "<compat> 624B"),
+    (0x2F40, "<compat> 652F"),
+    (0x2F41, "<compat> 6534"),
+    (0x2F42, "<compat> 6587"),
+    (0x2F43, "<compat> 6597"),
+    (0x2F44, "<compat> 65A4"),
+    (0x2F45, "<compat> 65B9"),
+    (0x2F46, "<compat> 65E0"),
+    (0x2F47, "<compat> 65E5"),
+    (0x2F48, "<compat> 66F0"),
+    (0x2F49, "<compat> 6708"),
+    (0x2F4A, "<compat> 6728"),
+    (0x2F4B, "<compat> 6B20"),
+    (0x2F4C, "<compat> 6B62"),
+    (0x2F4D, "<compat> 6B79"),
+    (0x2F4E, "<compat> 6BB3"),
+    (0x2F4F, "<compat> 6BCB"),
+    (0x2F50, "<compat> 6BD4"),
+    (0x2F51, "<compat> 6BDB"),
+    (0x2F52, "<compat> 6C0F"),
+    (0x2F53, "<compat> 6C14"),
+    (0x2F54, "<compat> 6C34"),
+    (0x2F55, "<compat> 706B"),
+    (0x2F56, "<compat> 722A"),
+    (0x2F57, "<compat> 7236"),
+    (0x2F58, "<compat> 723B"),
+    (0x2F59, "<compat> 723F"),
+    (0x2F5A, "<compat> 7247"),
+    (0x2F5B, "<compat> 7259"),
+    (0x2F5C, "<compat> 725B"),
+    (0x2F5D, "<compat> 72AC"),
+    (0x2F5E, "<compat> 7384"),
+    (0x2F5F, "<compat> 7389"),
+    (0x2F60, "<compat> 74DC"),
+    (0x2F61, "<compat> 74E6"),
+    (0x2F62, "<compat> 7518"),
+    (0x2F63, "<compat> 751F"),
+    (0x2F64, "<compat> 7528"),
+    (0x2F65, "<compat> 7530"),
+    (0x2F66, "<compat> 758B"),
+    (0x2F67, "<compat> 7592"),
+    (0x2F68, "<compat> 7676"),
+    (0x2F69, "<compat> 767D"),
+    (0x2F6A, "<compat> 76AE"),
+    (0x2F6B, "<compat> 76BF"),
+    (0x2F6C, "<compat> 76EE"),
+    (0x2F6D, "<compat> 77DB"),
+    (0x2F6E, "<compat> 77E2"),
+    (0x2F6F, "<compat> 77F3"),
+    (0x2F70, "<compat> 793A"),
+    (0x2F71, "<compat> 79B8"),
+    (0x2F72, "<compat> 79BE"),
+    (0x2F73, "<compat> 7A74"),
+    (0x2F74, "<compat> 7ACB"),
+    (0x2F75, "<compat> 7AF9"),
+    (0x2F76, "<compat> 7C73"),
+    (0x2F77, "<compat> 7CF8"),
+    (0x2F78, "<compat> 7F36"),
+    (0x2F79, "<compat> 7F51"),
+    (0x2F7A, "<compat> 7F8A"),
+    (0x2F7B, "<compat> 7FBD"),
+    (0x2F7C, "<compat> 8001"),
+    (0x2F7D, "<compat> 800C"),
+    (0x2F7E, "<compat> 8012"),
+    (0x2F7F, "<compat> 8033"),
+    (0x2F80, "<compat> 807F"),
+    (0x2F81, "<compat> 8089"),
+    (0x2F82, "<compat> 81E3"),
+    (0x2F83, "<compat> 81EA"),
+    (0x2F84, "<compat> 81F3"),
+    (0x2F85, "<compat> 81FC"),
+    (0x2F86, "<compat> 820C"),
+    (0x2F87, "<compat> 821B"),
+    (0x2F88, "<compat> 821F"),
+    (0x2F89, "<compat> 826E"),
+    (0x2F8A, "<compat> 8272"),
+    (0x2F8B, "<compat> 8278"),
+    (0x2F8C, "<compat> 864D"),
+    (0x2F8D, "<compat> 866B"),
+    (0x2F8E, "<compat> 8840"),
+    (0x2F8F, "<compat> 884C"),
+    (0x2F90, "<compat> 8863"),
+    (0x2F91, "<compat> 897E"),
+    (0x2F92, "<compat> 898B"),
+    (0x2F93, "<compat> 89D2"),
+    (0x2F94, "<compat> 8A00"),
+    (0x2F95, "<compat> 8C37"),
+    (0x2F96, "<compat> 8C46"),
+    (0x2F97, "<compat> 8C55"),
+    (0x2F98, "<compat> 8C78"),
+    (0x2F99, "<compat> 8C9D"),
+    (0x2F9A, "<compat> 8D64"),
+    (0x2F9B, "<compat> 8D70"),
+    (0x2F9C, "<compat> 8DB3"),
+    (0x2F9D, "<compat> 8EAB"),
+    (0x2F9E, "<compat> 8ECA"),
+    (0x2F9F, "<compat> 8F9B"),
+    (0x2FA0, "<compat> 8FB0"),
+    (0x2FA1, "<compat> 8FB5"),
+    (0x2FA2, "<compat> 9091"),
+    (0x2FA3, "<compat> 9149"),
+    (0x2FA4, "<compat> 91C6"),
+    (0x2FA5, "<compat> 91CC"),
+    (0x2FA6, "<compat> 91D1"),
+    (0x2FA7, "<compat> 9577"),
+    (0x2FA8, "<compat> 9580"),
+    (0x2FA9, "<compat> 961C"),
+    (0x2FAA, "<compat> 96B6"),
+    (0x2FAB, "<compat> 96B9"),
+    (0x2FAC, "<compat> 96E8"),
+    (0x2FAD, "<compat> 9751"),
+    (0x2FAE, "<compat> 975E"),
+    (0x2FAF, "<compat> 9762"),
+    (0x2FB0, "<compat> 9769"),
+    (0x2FB1, "<compat> 97CB"),
+    (0x2FB2, "<compat> 97ED"),
+    (0x2FB3, "<compat> 97F3"),
+    (0x2FB4, "<compat> 9801"),
+    (0x2FB5, "<compat> 98A8"),
+    (0x2FB6, "<compat> 98DB"),
+    (0x2FB7, "<compat> 98DF"),
+    (0x2FB8, "<compat> 9996"),
+    (0x2FB9, "<compat> 9999"),
+    (0x2FBA, "<compat> 99AC"),
+    (0x2FBB, "<compat> 9AA8"),
+    (0x2FBC, "<compat> 9AD8"),
+    (0x2FBD, "<compat> 9ADF"),
+    (0x2FBE, "<compat> 9B25"),
+    (0x2FBF, "<compat> 9B2F"),
+    (0x2FC0, "<compat> 9B32"),
+    (0x2FC1, "<compat> 9B3C"),
+    (0x2FC2, "<compat> 9B5A"),
+    (0x2FC3, "<compat> 9CE5"),
+    (0x2FC4, "<compat> 9E75"),
+    (0x2FC5, "<compat> 9E7F"),
+    (0x2FC6, "<compat> 9EA5"),
+    (0x2FC7, "<compat> 9EBB"),
+    (0x2FC8, "<compat> 9EC3"),
+    (0x2FC9, "<compat> 9ECD"),
+    (0x2FCA, "<compat> 9ED1"),
+    (0x2FCB, "<compat> 9EF9"),
+    (0x2FCC, "<compat> 9EFD"),
+    (0x2FCD, "<compat> 9F0E"),
+    (0x2FCE, "<compat> 9F13"),
+    (0x2FCF, "<compat> 9F20"),
+    (0x2FD0, "<compat> 9F3B"),
+    (0x2FD1, "<compat> 9F4A"),
+    (0x2FD2, "<compat> 9F52"),
+    (0x2FD3, "<compat> 9F8D"),
+    (0x2FD4, "<compat> 9F9C"),
+    (0x2FD5, "<compat> 9FA0"),
+    (0x3000, "<wide> 0020"),
+    (0x3036, "<compat> 3012"),
+    (0x3038, "<compat> 5341"),
+    (0x3039, "<compat> 5344"),
+    (0x303A, "<compat> 5345"),
+    (0x304C, "304B 3099"),
+    (0x304E, "304D 3099"),
+    (0x3050, "304F 3099"),
+    (0x3052, "3051 3099"),
+    (0x3054, "3053 3099"),
+    (0x3056, "3055 3099"),
+    (0x3058, "3057 3099"),
+    (0x305A, "3059 3099"),
+    (0x305C, "305B 3099"),
+    (0x305E, "305D 3099"),
+    (0x3060, "305F 3099"),
+    (0x3062, "3061 3099"),
+    (0x3065, "3064 3099"),
+    (0x3067, "3066 3099"),
+    (0x3069, "3068 3099"),
+    (0x3070, "306F 3099"),
+    (0x3071, "306F 309A"),
+    (0x3073, "3072 3099"),
+    (0x3074, "3072 309A"),
+    (0x3076, "3075 3099"),
+    (0x3077, "3075 309A"),
+    (0x3079, "3078 3099"),
+    (0x307A, "3078 309A"),
+    (0x307C, "307B 3099"),
+    (0x307D, "307B 309A"),
+    (0x3094, "3046 3099"),
+    (0x309B, "<compat> 0020 3099"),
+    (0x309C, "<compat> 0020 309A"),
+    (0x309E, "309D 3099"),
+    (0x309F, "<vertical> 3088 308A"),
+    (0x30AC, "30AB 3099"),
+    (0x30AE, "30AD 3099"),
+    (0x30B0, "30AF 3099"),
+    (0x30B2, "30B1 3099"),
+    (0x30B4, "30B3 3099"),
+    (0x30B6, "30B5 3099"),
+    (0x30B8, "30B7 3099"),
+    (0x30BA, "30B9 3099"),
+    (0x30BC, "30BB 3099"),
+    (0x30BE, "30BD 3099"),
+    (0x30C0, "30BF 3099"),
+    (0x30C2, "30C1 3099"),
+    (0x30C5, "30C4 3099"),
+    (0x30C7, "30C6 3099"),
+    (0x30C9, "30C8 3099"),
+    (0x30D0, "30CF 3099"),
+    (0x30D1, "30CF 309A"),
+    (0x30D3, "30D2 3099"),
+    (0x30D4, "30D2 309A"),
+    (0x30D6, "30D5 3099"),
+    (0x30D7, "30D5 309A"),
+    (0x30D9, "30D8 3099"),
+    (0x30DA, "30D8 309A"),
+    (0x30DC, "30DB 3099"),
+    (0x30DD, "30DB 309A"),
+    (0x30F4, "30A6 3099"),
+    (0x30F7, "30EF 3099"),
+    (0x30F8, "30F0 3099"),
+    (0x30F9, "30F1 3099"),
+    (0x30FA, "30F2 3099"),
+    (0x30FE, "30FD 3099"),
+    (0x30FF, "<vertical> 30B3 30C8"),
+    (0x3131, "<compat> 1100"),
+    (0x3132, "<compat> 1101"),
+    (0x3133, "<compat> 11AA"),
+    (0x3134, "<compat> 1102"),
+    (0x3135, "<compat> 11AC"),
+    (0x3136, "<compat> 11AD"),
+    (0x3137, "<compat> 1103"),
+    (0x3138, "<compat> 1104"),
+    (0x3139, "<compat> 1105"),
+    (0x313A, "<compat> 11B0"),
+    (0x313B, "<compat> 11B1"),
+    (0x313C, "<compat> 11B2"),
+    (0x313D, "<compat> 11B3"),
+    (0x313E, "<compat> 11B4"),
+    (0x313F, "<compat> 11B5"),
+    (0x3140, "<compat> 111A"),
+    (0x3141, "<compat> 1106"),
+    (0x3142, "<compat> 1107"),
+    (0x3143, "<compat> 1108"),
+    (0x3144, "<compat> 1121"),
+    (0x3145, "<compat> 1109"),
+    (0x3146, "<compat> 110A"),
+    (0x3147, "<compat> 110B"),
+    (0x3148, "<compat> 110C"),
+    (0x3149, "<compat> 110D"),
+    (0x314A, "<compat> 110E"),
+    (0x314B, "<compat> 110F"),
+    (0x314C, "<compat> 1110"),
+    (0x314D, "<compat> 1111"),
+    (0x314E, "<compat> 1112"),
+    (0x314F, "<compat> 1161"),
+    (0x3150, "<compat> 1162"),
+    (0x3151, "<compat> 1163"),
+    (0x3152, "<compat> 1164"),
+    (0x3153, "<compat> 1165"),
+    (0x3154, "<compat> 1166"),
+    (0x3155, "<compat> 1167"),
+    (0x3156, "<compat> 1168"),
+    (0x3157, "<compat> 1169"),
+    (0x3158, "<compat> 116A"),
+    (0x3159, "<compat> 116B"),
+    (0x315A, "<compat> 116C"),
+    (0x315B, "<compat> 116D"),
+    (0x315C, "<compat> 116E"),
+    (0x315D, "<compat> 116F"),
+    (0x315E, "<compat> 1170"),
+    (0x315F, "<compat> 1171"),
+    (0x3160, "<compat> 1172"),
+    (0x3161, "<compat> 1173"),
+    (0x3162, "<compat> 1174"),
+    (0x3163, "<compat> 1175"),
+    (0x3164, "<compat> 1160"),
+    (0x3165, "<compat> 1114"),
+    (0x3166, "<compat> 1115"),
+    (0x3167, "<compat> 11C7"),
+    (0x3168, "<compat> 11C8"),
+    (0x3169, "<compat> 11CC"),
+    (0x316A, "<compat> 11CE"),
+    (0x316B, "<compat> 11D3"),
+    (0x316C, "<compat> 11D7"),
+    (0x316D, "<compat> 11D9"),
+    (0x316E, "<compat> 111C"),
+    (0x316F, "<compat> 11DD"),
+    (0x3170, "<compat> 11DF"),
+    (0x3171, "<compat> 111D"),
+    (0x3172, "<compat> 111E"),
+    (0x3173, "<compat> 1120"),
+    (0x3174, "<compat> 1122"),
+    (0x3175, "<compat> 1123"),
+    (0x3176, "<compat> 1127"),
+    (0x3177, "<compat> 1129"),
+    (0x3178, "<compat> 112B"),
+    (0x3179, "<compat> 112C"),
+    (0x317A, "<compat> 112D"),
+    (0x317B, "<compat> 112E"),
+    (0x317C, "<compat> 112F"),
+    (0x317D, "<compat> 1132"),
+    (0x317E, "<compat> 1136"),
+    (0x317F, "<compat> 1140"),
+    (0x3180, "<compat> 1147"),
+    (0x3181, "<compat> 114C"),
+    (0x3182, "<compat> 11F1"),
+    (0x3183, "<compat> 11F2"),
+    (0x3184, "<compat> 1157"),
+    (0x3185, "<compat> 1158"),
+    (0x3186, "<compat> 1159"),
+    (0x3187, "<compat> 1184"),
+    (0x3188, "<compat> 1185"),
+    (0x3189, "<compat> 1188"),
+    (0x318A, "<compat> 1191"),
+    (0x318B, "<compat> 1192"),
+    (0x318C, "<compat> 1194"),
+    (0x318D, "<compat> 119E"),
+    (0x318E, "<compat> 11A1"),
+    (0x3192, "<super> 4E00"),
+    (0x3193, "<super> 4E8C"),
+    (0x3194, "<super> 4E09"),
+    (0x3195, "<super> 56DB"),
+    (0x3196, "<super> 4E0A"),
+    (0x3197, "<super> 4E2D"),
+    (0x3198, "<super> 4E0B"),
+    (0x3199, "<super> 7532"),
+    (0x319A, "<super> 4E59"),
+    (0x319B, "<super> 4E19"),
+    (0x319C, "<super> 4E01"),
+    (0x319D, "<super> 5929"),
+    (0x319E, "<super> 5730"),
+    (0x319F, "<super> 4EBA"),
+    (0x3200, "<compat> 0028 1100 0029"),
+    (0x3201, "<compat> 0028 1102 0029"),
+    (0x3202, "<compat> 0028 1103 0029"),
+    (0x3203, "<compat> 0028 1105 0029"),
+    (0x3204, "<compat> 0028 1106 0029"),
+    (0x3205, "<compat> 0028 1107 0029"),
+    (0x3206, "<compat> 0028 1109 0029"),
+    (0x3207, "<compat> 0028 110B 0029"),
+    (0x3208, "<compat> 0028 110C 0029"),
+    (0x3209, "<compat> 0028 110E 0029"),
+    (0x320A, "<compat> 0028 110F 0029"),
+    (0x320B, "<compat> 0028 1110 0029"),
+    (0x320C, "<compat> 0028 1111 0029"),
+    (0x320D, "<compat> 0028 1112 0029"),
+    (0x320E, "<compat> 0028 1100 1161 0029"),
+    (0x320F, "<compat> 0028 1102 1161 0029"),
+    (0x3210, "<compat> 0028 1103 1161 0029"),
+    (0x3211, "<compat> 0028 1105 1161 0029"),
+    (0x3212, "<compat> 0028 1106 1161 0029"),
+    (0x3213, "<compat> 0028 1107 1161 0029"),
+    (0x3214, "<compat> 0028 1109 1161 0029"),
+    (0x3215, "<compat> 0028 110B 1161 0029"),
+    (0x3216, "<compat> 0028 110C 1161 0029"),
+    (0x3217, "<compat> 0028 110E 1161 0029"),
+    (0x3218, "<compat> 0028 110F 1161 0029"),
+    (0x3219, "<compat> 0028 1110 1161 0029"),
+    (0x321A, "<compat> 0028 1111 1161 0029"),
+    (0x321B, "<compat> 0028 1112 1161 0029"),
+    (0x321C, "<compat> 0028 110C 116E 0029"),
+    (0x321D, "<compat> 0028 110B 1169 110C 1165 11AB 0029"),
+    (0x321E, "<compat> 0028 110B 1169 1112 116E 0029"),
+    (0x3220, "<compat> 0028 4E00 0029"),
+    (0x3221, "<compat> 0028 4E8C 0029"),
+    (0x3222, "<compat> 0028 4E09 0029"),
+    (0x3223, "<compat> 0028 56DB 0029"),
+    (0x3224, "<compat> 0028 4E94 0029"),
+    (0x3225, "<compat> 0028 516D 0029"),
+    (0x3226, "<compat> 0028 4E03 0029"),
+    (0x3227, "<compat> 0028 516B 0029"),
+    (0x3228, "<compat> 0028 4E5D 0029"),
+    (0x3229, "<compat> 0028 5341 0029"),
+    (0x322A, "<compat> 0028 6708 0029"),
+    (0x322B, "<compat> 0028 706B 0029"),
+    (0x322C, "<compat> 0028 6C34 0029"),
+    (0x322D, "<compat> 0028 6728 0029"),
+    (0x322E, "<compat> 0028 91D1 0029"),
+    (0x322F, "<compat> 0028 571F 0029"),
+    (0x3230, "<compat> 0028 65E5 0029"),
+    (0x3231, "<compat> 0028 682A 0029"),
+    (0x3232, "<compat> 0028 6709 0029"),
+    (0x3233, "<compat> 0028 793E 0029"),
+    (0x3234, "<compat> 0028 540D 0029"),
+    (0x3235, "<compat> 0028 7279 0029"),
+    (0x3236, "<compat> 0028 8CA1 0029"),
+    (0x3237, "<compat> 0028 795D 0029"),
+    (0x3238, "<compat> 0028 52B4 0029"),
+    (0x3239, "<compat> 0028 4EE3 0029"),
+    (0x323A, "<compat> 0028 547C 0029"),
+    (0x323B, "<compat> 0028 5B66 0029"),
+    (0x323C, "<compat> 0028 76E3 0029"),
+    (0x323D, "<compat> 0028 4F01 0029"),
+    (0x323E, "<compat> 0028 8CC7 0029"),
+    (0x323F, "<compat> 0028 5354 0029"),
+    (0x3240, "<compat> 0028 796D 0029"),
+    (0x3241, "<compat> 0028 4F11 0029"),
+    (0x3242, "<compat> 0028 81EA 0029"),
+    (0x3243, "<compat> 0028 81F3 0029"),
+    (0x3244, "<circle> 554F"),
+    (0x3245, "<circle> 5E7C"),
+    (0x3246, "<circle> 6587"),
+    (0x3247, "<circle> 7B8F"),
+    (0x3250, "<square> 0050 0054 0045"),
+    (0x3251, "<circle> 0032 0031"),
+    (0x3252, "<circle> 0032 0032"),
+    (0x3253, "<circle> 0032 0033"),
+    (0x3254, "<circle> 0032 0034"),
+    (0x3255, "<circle> 0032 0035"),
+    (0x3256, "<circle> 0032 0036"),
+    (0x3257, "<circle> 0032 0037"),
+    (0x3258, "<circle> 0032 0038"),
+    (0x3259, "<circle> 0032 0039"),
+    (0x325A, "<circle> 0033 0030"),
+    (0x325B, "<circle> 0033 0031"),
+    (0x325C, "<circle> 0033 0032"),
+    (0x325D, "<circle> 0033 0033"),
+    (0x325E, "<circle> 0033 0034"),
+    (0x325F, "<circle> 0033 0035"),
+    (0x3260, "<circle> 1100"),
+    (0x3261, "<circle> 1102"),
+    (0x3262, "<circle> 1103"),
+    (0x3263, "<circle> 1105"),
+    (0x3264, "<circle> 1106"),
+    (0x3265, "<circle> 1107"),
+    (0x3266, "<circle> 1109"),
+    (0x3267, "<circle> 110B"),
+    (0x3268, "<circle> 110C"),
+    (0x3269, "<circle> 110E"),
+    (0x326A, "<circle> 110F"),
+    (0x326B, "<circle> 1110"),
+    (0x326C, "<circle> 1111"),
+    (0x326D, "<circle> 1112"),
+    (0x326E, "<circle> 1100 1161"),
+    (0x326F, "<circle> 1102 1161"),
+    (0x3270, "<circle> 1103 1161"),
+    (0x3271, "<circle> 1105 1161"),
+    (0x3272, "<circle> 1106 1161"),
+    (0x3273, "<circle> 1107 1161"),
+    (0x3274, "<circle> 1109 1161"),
+    (0x3275, "<circle> 110B 1161"),
+    (0x3276, "<circle> 110C 1161"),
+    (0x3277, "<circle> 110E 1161"),
+    (0x3278, "<circle> 110F 1161"),
+    (0x3279, "<circle> 1110 1161"),
+    (0x327A, "<circle> 1111 1161"),
+    (0x327B, "<circle> 1112 1161"),
+    (0x327C, "<circle> 110E 1161 11B7 1100 1169"),
+    (0x327D, "<circle> 110C 116E 110B 1174"),
+    (0x327E, "<circle> 110B 116E"),
+    (0x3280, "<circle> 4E00"),
+    (0x3281, "<circle> 4E8C"),
+    (0x3282, "<circle> 4E09"),
+    (0x3283, "<circle> 56DB"),
+    (0x3284, "<circle> 4E94"),
+    (0x3285, "<circle> 516D"),
+    (0x3286, "<circle> 4E03"),
+    (0x3287, "<circle> 516B"),
+    (0x3288, "<circle> 4E5D"),
+    (0x3289, "<circle> 5341"),
+    (0x328A, "<circle> 6708"),
+    (0x328B, "<circle> 706B"),
+    (0x328C, "<circle> 6C34"),
+    (0x328D, "<circle> 6728"),
+    (0x328E, "<circle> 91D1"),
+    (0x328F, "<circle> 571F"),
+    (0x3290, "<circle> 65E5"),
+    (0x3291, "<circle> 682A"),
+    (0x3292, "<circle> 6709"),
+    (0x3293, "<circle> 793E"),
+    (0x3294, "<circle> 540D"),
+    (0x3295, "<circle> 7279"),
+    (0x3296, "<circle> 8CA1"),
+    (0x3297, "<circle> 795D"),
+    (0x3298, "<circle> 52B4"),
+    (0x3299, "<circle> 79D8"),
+    (0x329A, "<circle> 7537"),
+    (0x329B, "<circle> 5973"),
+    (0x329C, "<circle> 9069"),
+    (0x329D, "<circle> 512A"),
+    (0x329E, "<circle> 5370"),
+    (0x329F, "<circle> 6CE8"),
+    (0x32A0, "<circle> 9805"),
+    (0x32A1, "<circle> 4F11"),
+    (0x32A2, "<circle> 5199"),
+    (0x32A3, "<circle> 6B63"),
+    (0x32A4, "<circle> 4E0A"),
+    (0x32A5, "<circle> 4E2D"),
+    (0x32A6, "<circle> 4E0B"),
+    (0x32A7, "<circle> 5DE6"),
+    (0x32A8, "<circle> 53F3"),
+    (0x32A9, "<circle> 533B"),
+    (0x32AA, "<circle> 5B97"),
+    (0x32AB, "<circle> 5B66"),
+    (0x32AC, "<circle> 76E3"),
+    (0x32AD, "<circle> 4F01"),
+    (0x32AE, "<circle> 8CC7"),
+    (0x32AF, "<circle> 5354"),
+    (0x32B0, "<circle> 591C"),
+    (0x32B1, "<circle> 0033 0036"),
+    (0x32B2, "<circle> 0033 0037"),
+    (0x32B3, "<circle> 0033 0038"),
+    (0x32B4, "<circle> 0033 0039"),
+    (0x32B5, "<circle> 0034 0030"),
+    (0x32B6, "<circle> 0034 0031"),
+    (0x32B7, "<circle> 0034 0032"),
+    (0x32B8, "<circle> 0034 0033"),
+    (0x32B9, "<circle> 0034 0034"),
+    (0x32BA, "<circle> 0034 0035"),
+    (0x32BB, "<circle> 0034 0036"),
+    (0x32BC, "<circle> 0034 0037"),
+    (0x32BD, "<circle> 0034 0038"),
+    (0x32BE, "<circle> 0034 0039"),
+    (0x32BF, "<circle> 0035 0030"),
+    (0x32C0, "<compat> 0031 6708"),
+    (0x32C1, "<compat> 0032 6708"),
+    (0x32C2, "<compat> 0033 6708"),
+    (0x32C3, "<compat> 0034 6708"),
+    (0x32C4, "<compat> 0035 6708"),
+    (0x32C5, "<compat> 0036 6708"),
+    (0x32C6, "<compat> 0037 6708"),
+    (0x32C7, "<compat> 0038 6708"),
+    (0x32C8, "<compat> 0039 6708"),
+    (0x32C9, "<compat> 0031 0030 6708"),
+    (0x32CA, "<compat> 0031 0031 6708"),
+    (0x32CB, "<compat> 0031 0032 6708"),
+    (0x32CC, "<square> 0048 0067"),
+    (0x32CD, "<square> 0065 0072 0067"),
+    (0x32CE, "<square> 0065 0056"),
+    (0x32CF, "<square> 004C 0054 0044"),
+    (0x32D0, "<circle> 30A2"),
+    (0x32D1, "<circle> 30A4"),
+    (0x32D2, "<circle> 30A6"),
+    (0x32D3, "<circle> 30A8"),
+    (0x32D4, "<circle> 30AA"),
+    (0x32D5, "<circle> 30AB"),
+    (0x32D6, "<circle> 30AD"),
+    (0x32D7, "<circle> 30AF"),
+    (0x32D8, "<circle> 30B1"),
+    (0x32D9, "<circle> 30B3"),
+    (0x32DA, "<circle> 30B5"),
+    (0x32DB, "<circle> 30B7"),
+    (0x32DC, "<circle> 30B9"),
+    (0x32DD, "<circle> 30BB"),
+    (0x32DE, "<circle> 30BD"),
+    (0x32DF, "<circle> 30BF"),
+    (0x32E0, "<circle> 30C1"),
+    (0x32E1, "<circle> 30C4"),
+    (0x32E2, "<circle> 30C6"),
+    (0x32E3, "<circle> 30C8"),
+    (0x32E4, "<circle> 30CA"),
+    (0x32E5, "<circle> 30CB"),
+    (0x32E6, "<circle> 30CC"),
+    (0x32E7, "<circle> 30CD"),
+    (0x32E8, "<circle> 30CE"),
+    (0x32E9, "<circle> 30CF"),
+    (0x32EA, "<circle> 30D2"),
+    (0x32EB, "<circle> 30D5"),
+    (0x32EC, "<circle> 30D8"),
+    (0x32ED, "<circle> 30DB"),
+    (0x32EE, "<circle> 30DE"),
+    (0x32EF, "<circle> 30DF"),
+    (0x32F0, "<circle> 30E0"),
+    (0x32F1, "<circle> 30E1"),
+    (0x32F2, "<circle> 30E2"),
+    (0x32F3, "<circle> 30E4"),
+    (0x32F4, "<circle> 30E6"),
+    (0x32F5, "<circle> 30E8"),
+    (0x32F6, "<circle> 30E9"),
+    (0x32F7, "<circle> 30EA"),
+    (0x32F8, "<circle> 30EB"),
+    (0x32F9, "<circle> 30EC"),
+    (0x32FA, "<circle> 30ED"),
+    (0x32FB, "<circle> 30EF"),
+    (0x32FC, "<circle> 30F0"),
+    (0x32FD, "<circle> 30F1"),
+    (0x32FE, "<circle> 30F2"),
+    (0x32FF, "<square> 4EE4 548C"),
+    (0x3300, "<square> 30A2 30D1 30FC 30C8"),
+    (0x3301, "<square> 30A2 30EB 30D5 30A1"),
+    (0x3302, "<square> 30A2 30F3 30DA 30A2"),
+    (0x3303, "<square> 30A2 30FC 30EB"),
+    (0x3304, "<square> 30A4 30CB 30F3 30B0"),
+    (0x3305, "<square> 30A4 30F3 30C1"),
+    (0x3306, "<square> 30A6 30A9 30F3"),
+    (0x3307, "<square> 30A8 30B9 30AF 30FC 30C9"),
+    (0x3308, "<square> 30A8 30FC 30AB 30FC"),
+    (0x3309, "<square> 30AA 30F3 30B9"),
+    (0x330A, "<square> 30AA 30FC 30E0"),
+    (0x330B, "<square> 30AB 30A4 30EA"),
+    (0x330C, "<square> 30AB 30E9 30C3 30C8"),
+    (0x330D, "<square> 30AB 30ED 30EA 30FC"),
+    (0x330E, "<square> 30AC 30ED 30F3"),
+    (0x330F, "<square> 30AC 30F3 30DE"),
+    (0x3310, "<square> 30AE 30AC"),
+    (0x3311, "<square> 30AE 30CB 30FC"),
+    (0x3312, "<square> 30AD 30E5 30EA 30FC"),
+    (0x3313, "<square> 30AE 30EB 30C0 30FC"),
+    (0x3314, "<square> 30AD 30ED"),
+    (0x3315, "<square> 30AD 30ED 30B0 30E9 30E0"),
+    (0x3316, "<square> 30AD 30ED 30E1 30FC 30C8 30EB"),
+    (0x3317, "<square> 30AD 30ED 30EF 30C3 30C8"),
+    (0x3318, "<square> 30B0 30E9 30E0"),
+    (0x3319, "<square> 30B0 30E9 30E0 30C8 30F3"),
+    (0x331A, "<square> 30AF 30EB 30BC 30A4 30ED"),
+    (0x331B, "<square> 30AF 30ED 30FC 30CD"),
+    (0x331C, "<square> 30B1 30FC 30B9"),
+    (0x331D, "<square> 30B3 30EB 30CA"),
+    (0x331E, "<square> 30B3 30FC 30DD"),
+    (0x331F, "<square> 30B5 30A4 30AF 30EB"),
+    (0x3320, "<square> 30B5 30F3 30C1 30FC 30E0"),
+    (0x3321, "<square> 30B7 30EA 30F3 30B0"),
+    (0x3322, "<square> 30BB 30F3 30C1"),
+    (0x3323, "<square> 30BB 30F3 30C8"),
+    (0x3324, "<square> 30C0 30FC 30B9"),
+    (0x3325, "<square> 30C7 30B7"),
+    (0x3326, "<square> 30C9 30EB"),
+    (0x3327, "<square> 30C8 30F3"),
+    (0x3328, "<square> 30CA 30CE"),
+    (0x3329, "<square> 30CE 30C3 30C8"),
+    (0x332A, "<square> 30CF 30A4 30C4"),
+    (0x332B, "<square> 30D1 30FC 30BB 30F3 30C8"),
+    (0x332C, "<square> 30D1 30FC 30C4"),
+    (0x332D, "<square> 30D0 30FC 30EC 30EB"),
+    (0x332E, "<square> 30D4 30A2 30B9 30C8 30EB"),
+    (0x332F, "<square> 30D4 30AF 30EB"),
+    (0x3330, "<square> 30D4 30B3"),
+    (0x3331, "<square> 30D3 30EB"),
+    (0x3332, "<square> 30D5 30A1 30E9 30C3 30C9"),
+    (0x3333, "<square> 30D5 30A3 30FC 30C8"),
+    (0x3334, "<square> 30D6 30C3 30B7 30A7 30EB"),
+    (0x3335, "<square> 30D5 30E9 30F3"),
+    (0x3336, "<square> 30D8 30AF 30BF 30FC 30EB"),
+    (0x3337, "<square> 30DA 30BD"),
+    (0x3338, "<square> 30DA 30CB 30D2"),
+    (0x3339, "<square> 30D8 30EB 30C4"),
+    (0x333A, "<square> 30DA 30F3 30B9"),
+    (0x333B, "<square> 30DA 30FC 30B8"),
+    (0x333C, "<square> 30D9 30FC 30BF"),
+    (0x333D, "<square> 30DD 30A4 30F3 30C8"),
+    (0x333E, "<square> 30DC 30EB 30C8"),
+    (0x333F, "<square> 30DB 30F3"),
+    (0x3340, "<square> 30DD 30F3 30C9"),
+    (0x3341, "<square> 30DB 30FC 30EB"),
+    (0x3342, "<square> 30DB 30FC 30F3"),
+    (0x3343, "<square> 30DE 30A4 30AF 30ED"),
+    (0x3344, "<square> 30DE 30A4 30EB"),
+    (0x3345, "<square> 30DE 30C3 30CF"),
+    (0x3346, "<square> 30DE 30EB 30AF"),
+    (0x3347, "<square> 30DE 30F3 30B7 30E7 30F3"),
+    (0x3348, "<square> 30DF 30AF 30ED 30F3"),
+    (0x3349, "<square> 30DF 30EA"),
+    (0x334A, "<square> 30DF 30EA 30D0 30FC 30EB"),
+    (0x334B, "<square> 30E1 30AC"),
+    (0x334C, "<square> 30E1 30AC 30C8 30F3"),
+    (0x334D, "<square> 30E1 30FC 30C8 30EB"),
+    (0x334E, "<square> 30E4 30FC 30C9"),
+    (0x334F, "<square> 30E4 30FC 30EB"),
+    (0x3350, "<square> 30E6 30A2 30F3"),
+    (0x3351, "<square> 30EA 30C3 30C8 30EB"),
+    (0x3352, "<square> 30EA 30E9"),
+    (0x3353, "<square> 30EB 30D4 30FC"),
+    (0x3354, "<square> 30EB 30FC 30D6 30EB"),
+    (0x3355, "<square> 30EC 30E0"),
+    (0x3356, "<square> 30EC 30F3 30C8 30B2 30F3"),
+    (0x3357, "<square> 30EF 30C3 30C8"),
+    (0x3358, "<compat> 0030 70B9"),
+    (0x3359, "<compat> 0031 70B9"),
+    (0x335A, "<compat> 0032 70B9"),
+    (0x335B, "<compat> 0033 70B9"),
+    (0x335C, "<compat> 0034 70B9"),
+    (0x335D, "<compat> 0035 70B9"),
+    (0x335E, "<compat> 0036 70B9"),
+    (0x335F, "<compat> 0037 70B9"),
+    (0x3360, "<compat> 0038 70B9"),
+    (0x3361, "<compat> 0039 70B9"),
+    (0x3362, "<compat> 0031 0030 70B9"),
+    (0x3363, "<compat> 0031 0031 70B9"),
+    (0x3364, "<compat> 0031 0032 70B9"),
+    (0x3365, "<compat> 0031 0033 70B9"),
+    (0x3366, "<compat> 0031 0034 70B9"),
+    (0x3367, "<compat> 0031 0035 70B9"),
+    (0x3368, "<compat> 0031 0036 70B9"),
+    (0x3369, "<compat> 0031 0037 70B9"),
+    (0x336A, "<compat> 0031 0038 70B9"),
+    (0x336B, "<compat> 0031 0039 70B9"),
+    (0x336C, "<compat> 0032 0030 70B9"),
+    (0x336D, "<compat> 0032 0031 70B9"),
+    (0x336E, "<compat> 0032 0032 70B9"),
+    (0x336F, "<compat> 0032 0033 70B9"),
+    (0x3370, "<compat> 0032 0034 70B9"),
+    (0x3371, "<square> 0068 0050 0061"),
+    (0x3372, "<square> 0064 0061"),
+    (0x3373, "<square> 0041 0055"),
+    (0x3374, "<square> 0062 0061 0072"),
+    (0x3375, "<square> 006F 0056"),
+    (0x3376, "<square> 0070 0063"),
+    (0x3377, "<square> 0064 006D"),
+    (0x3378, "<square> 0064 006D 00B2"),
+    (0x3379, "<square> 0064 006D 00B3"),
+    (0x337A, "<square> 0049 0055"),
+    (0x337B, "<square> 5E73 6210"),
+    (0x337C, "<square> 662D 548C"),
+    (0x337D, "<square> 5927 6B63"),
+    (0x337E, "<square> 660E 6CBB"),
+    (0x337F, "<square> 682A 5F0F 4F1A 793E"),
+    (0x3380, "<square> 0070 0041"),
+    (0x3381, "<square> 006E 0041"),
+    (0x3382, "<square> 03BC 0041"),
+    (0x3383, "<square> 006D 0041"),
+    (0x3384, "<square> 006B 0041"),
+    (0x3385, "<square> 004B 0042"),
+    (0x3386, "<square> 004D 0042"),
+    (0x3387, "<square> 0047 0042"),
+    (0x3388, "<square> 0063 0061 006C"),
+    (0x3389, "<square> 006B 0063 0061 006C"),
+    (0x338A, "<square> 0070 0046"),
+    (0x338B, "<square> 006E 0046"),
+    (0x338C, "<square> 03BC 0046"),
+    (0x338D, "<square> 03BC 0067"),
+    (0x338E, "<square> 006D 0067"),
+    (0x338F, "<square> 006B 0067"),
+    (0x3390, "<square> 0048 007A"),
+    (0x3391, "<square> 006B 0048 007A"),
+    (0x3392, "<square> 004D 0048 007A"),
+    (0x3393, "<square> 0047 0048 007A"),
+    (0x3394, "<square> 0054 0048 007A"),
+    (0x3395, "<square> 03BC 2113"),
+    (0x3396, "<square> 006D 2113"),
+    (0x3397, "<square> 0064 2113"),
+    (0x3398, "<square> 006B 2113"),
+    (0x3399, "<square> 0066 006D"),
+    (0x339A, "<square> 006E 006D"),
+    (0x339B, "<square> 03BC 006D"),
+    (0x339C, "<square> 006D 006D"),
+    (0x339D, "<square> 0063 006D"),
+    (0x339E, "<square> 006B 006D"),
+    (0x339F, "<square> 006D 006D 00B2"),
+    (0x33A0, "<square> 0063 006D 00B2"),
+    (0x33A1, "<square> 006D 00B2"),
+    (0x33A2, "<square> 006B 006D 00B2"),
+    (0x33A3, "<square> 006D 006D 00B3"),
+    (0x33A4, "<square> 0063 006D 00B3"),
+    (0x33A5, "<square> 006D 00B3"),
+    (0x33A6, "<square> 006B 006D 00B3"),
+    (0x33A7, "<square> 006D 2215 0073"),
+    (0x33A8, "<square> 006D 2215 0073 00B2"),
+    (0x33A9, "<square> 0050 0061"),
+    (0x33AA, "<square> 006B 0050 0061"),
+    (0x33AB, "<square> 004D 0050 0061"),
+    (0x33AC, "<square> 0047 0050 0061"),
+    (0x33AD, "<square> 0072 0061 0064"),
+    (0x33AE, "<square> 0072 0061 0064 2215 0073"),
+    (0x33AF, "<square> 0072 0061 0064 2215 0073 00B2"),
+    (0x33B0, "<square> 0070 0073"),
+    (0x33B1, "<square> 006E 0073"),
+    (0x33B2, "<square> 03BC 0073"),
+    (0x33B3, "<square> 006D 0073"),
+    (0x33B4, "<square> 0070 0056"),
+    (0x33B5, "<square> 006E 0056"),
+    (0x33B6, "<square> 03BC 0056"),
+    (0x33B7, "<square> 006D 0056"),
+    (0x33B8, "<square> 006B 0056"),
+    (0x33B9, "<square> 004D 0056"),
+    (0x33BA, "<square> 0070 0057"),
+    (0x33BB, "<square> 006E 0057"),
+    (0x33BC, "<square> 03BC 0057"),
+    (0x33BD, "<square> 006D 0057"),
+    (0x33BE, "<square> 006B 0057"),
+    (0x33BF, "<square> 004D 0057"),
+    (0x33C0, "<square> 006B 03A9"),
+    (0x33C1, "<square> 004D 03A9"),
+    (0x33C2, "<square> 0061 002E 006D 002E"),
+    (0x33C3, "<square> 0042 0071"),
+    (0x33C4, "<square> 0063 0063"),
+    (0x33C5, "<square> 0063 0064"),
+    (0x33C6, "<square> 0043 2215 006B 0067"),
+    (0x33C7, "<square> 0043 006F 002E"),
+    (0x33C8, "<square> 0064 0042"),
+    (0x33C9, "<square> 0047 0079"),
+    (0x33CA, "<square> 0068 0061"),
+    (0x33CB, "<square> 0048 0050"),
+    (0x33CC, "<square> 0069 006E"),
+    (0x33CD, "<square> 004B 004B"),
+    (0x33CE, "<square> 004B 004D"),
+    (0x33CF, "<square> 006B 0074"),
+    (0x33D0, "<square> 006C 006D"),
+    (0x33D1, "<square> 006C 006E"),
+    (0x33D2, "<square> 006C 006F 0067"),
+    (0x33D3, "<square> 006C 0078"),
+    (0x33D4, "<square> 006D 0062"),
+    (0x33D5, "<square> 006D 0069 006C"),
+    (0x33D6, "<square> 006D 006F 006C"),
+    (0x33D7, "<square> 0050 0048"),
+    (0x33D8, "<square> 0070 002E 006D 002E"),
+    (0x33D9, "<square> 0050 0050 004D"),
+    (0x33DA, "<square> 0050 0052"),
+    (0x33DB, "<square> 0073 0072"),
+    (0x33DC, "<square> 0053 0076"),
+    (0x33DD, "<square> 0057 0062"),
+    (0x33DE, "<square> 0056 2215 006D"),
+    (0x33DF, "<square> 0041 2215 006D"),
+    (0x33E0, "<compat> 0031 65E5"),
+    (0x33E1, "<compat> 0032 65E5"),
+    (0x33E2, "<compat> 0033 65E5"),
+    (0x33E3, "<compat> 0034 65E5"),
+    (0x33E4, "<compat> 0035 65E5"),
+    (0x33E5, "<compat> 0036 65E5"),
+    (0x33E6, "<compat> 0037 65E5"),
+    (0x33E7, "<compat> 0038 65E5"),
+    (0x33E8, "<compat> 0039 65E5"),
+    (0x33E9, "<compat> 0031 0030 65E5"),
+    (0x33EA, "<compat> 0031 0031 65E5"),
+    (0x33EB, "<compat> 0031 0032 65E5"),
+    (0x33EC, "<compat> 0031 0033 65E5"),
+    (0x33ED, "<compat> 0031 0034 65E5"),
+    (0x33EE, "<compat> 0031 0035 65E5"),
+    (0x33EF, "<compat> 0031 0036 65E5"),
+    (0x33F0, "<compat> 0031 0037 65E5"),
+    (0x33F1, "<compat> 0031 0038 65E5"),
+    (0x33F2, "<compat> 0031 0039 65E5"),
+    (0x33F3, "<compat> 0032 0030 65E5"),
+    (0x33F4, "<compat> 0032 0031 65E5"),
+    (0x33F5, "<compat> 0032 0032 65E5"),
+    (0x33F6, "<compat> 0032 0033 65E5"),
+    (0x33F7, "<compat> 0032 0034 65E5"),
+    (0x33F8, "<compat> 0032 0035 65E5"),
+    (0x33F9, "<compat> 0032 0036 65E5"),
+    (0x33FA, "<compat> 0032 0037 65E5"),
+    (0x33FB, "<compat> 0032 0038 65E5"),
+    (0x33FC, "<compat> 0032 0039 65E5"),
+    (0x33FD, "<compat> 0033 0030 65E5"),
+    (0x33FE, "<compat> 0033 0031 65E5"),
+    (0x33FF, "<square> 0067 0061 006C"),
+    (0xA69C, "<super> 044A"),
+    (0xA69D, "<super> 044C"),
+    (0xA770, "<super> A76F"),
+    (0xA7F2, "<super> 0043"),
+    (0xA7F3, "<super> 0046"),
+    (0xA7F4, "<super> 0051"),
+    (0xA7F8, "<super> 0126"),
+    (0xA7F9, "<super> 0153"),
+    (0xAB5C, "<super> A727"),
+    (0xAB5D, "<super> AB37"),
+    (0xAB5E, "<super> 026B"),
+    (0xAB5F, "<super> AB52"),
+    (0xAB69, "<super> 028D"),
+    (0xF900, "8C48"),
+    (0xF901, "66F4"),
+    (0xF902, "8ECA"),
+    (0xF903, "8CC8"),
+    (0xF904, "6ED1"),
+    (0xF905, "4E32"),
+    (0xF906, "53E5"),
+    (0xF907, "9F9C"),
+    (0xF908, "9F9C"),
+    (0xF909, "5951"),
+    (0xF90A, "91D1"),
+    (0xF90B, "5587"),
+    (0xF90C, "5948"),
+    (0xF90D, "61F6"),
+    (0xF90E, "7669"),
+    (0xF90F, "7F85"),
+    (0xF910, "863F"),
+    (0xF911, "87BA"),
+    (0xF912, "88F8"),
+    (0xF913, "908F"),
+    (0xF914, "6A02"),
+    (0xF915, "6D1B"),
+    (0xF916, "70D9"),
+    (0xF917, "73DE"),
+    (0xF918, "843D"),
+    (0xF919, "916A"),
+    (0xF91A, "99F1"),
+    (0xF91B, "4E82"),
+    (0xF91C, "5375"),
+    (0xF91D, "6B04"),
+    (0xF91E, "721B"),
+    (0xF91F, "862D"),
+    (0xF920, "9E1E"),
+    (0xF921, "5D50"),
+    (0xF922, "6FEB"),
+    (0xF923, "85CD"),
+    (0xF924, "8964"),
+    (0xF925, "62C9"),
+    (0xF926, "81D8"),
+    (0xF927, "881F"),
+    (0xF928, "5ECA"),
+    (0xF929, "6717"),
+    (0xF92A, "6D6A"),
+    (0xF92B, "72FC"),
+    (0xF92C, "90CE"),
+    (0xF92D, "4F86"),
+    (0xF92E, "51B7"),
+    (0xF92F, "52DE"),
+    (0xF930, "64C4"),
+    (0xF931, "6AD3"),
+    (0xF932, "7210"),
+    (0xF933, "76E7"),
+    (0xF934, "8001"),
+    (0xF935, "8606"),
+    (0xF936, "865C"),
+    (0xF937, "8DEF"),
+    (0xF938, "9732"),
+    (0xF939, "9B6F"),
+    (0xF93A, "9DFA"),
+    (0xF93B, "788C"),
+    (0xF93C, "797F"),
+    (0xF93D, "7DA0"),
+    (0xF93E, "83C9"),
+    (0xF93F, "9304"),
+    (0xF940, "9E7F"),
+    (0xF941, "8AD6"),
+    (0xF942, "58DF"),
+    (0xF943, "5F04"),
+    (0xF944, "7C60"),
+    (0xF945, "807E"),
+    (0xF946, "7262"),
+    (0xF947, "78CA"),
+    (0xF948, "8CC2"),
+    (0xF949, "96F7"),
+    (0xF94A, "58D8"),
+    (0xF94B, "5C62"),
+    (0xF94C, "6A13"),
+    (0xF94D, "6DDA"),
+    (0xF94E, "6F0F"),
+    (0xF94F, "7D2F"),
+    (0xF950, "7E37"),
+    (0xF951, "964B"),
+    (0xF952, "52D2"),
+    (0xF953, "808B"),
+    (0xF954, "51DC"),
+    (0xF955, "51CC"),
+    (0xF956, "7A1C"),
+    (0xF957, "7DBE"),
+    (0xF958, "83F1"),
+    (0xF959, "9675"),
+    (0xF95A, "8B80"),
+    (0xF95B, "62CF"),
+    (0xF95C, "6A02"),
+    (0xF95D, "8AFE"),
+    (0xF95E, "4E39"),
+    (0xF95F, "5BE7"),
+    (0xF960, "6012"),
+    (0xF961, "7387"),
+    (0xF962, "7570"),
+    (0xF963, "5317"),
+    (0xF964, "78FB"),
+    (0xF965, "4FBF"),
+    (0xF966, "5FA9"),
+    (0xF967, "4E0D"),
+    (0xF968, "6CCC"),
+    (0xF969, "6578"),
+    (0xF96A, "7D22"),
+    (0xF96B, "53C3"),
+    (0xF96C, "585E"),
+    (0xF96D, "7701"),
+    (0xF96E, "8449"),
+    (0xF96F, "8AAA"),
+    (0xF970, "6BBA"),
+    (0xF971, "8FB0"),
+    (0xF972, "6C88"),
+    (0xF973, "62FE"),
+    (0xF974, "82E5"),
+    (0xF975, "63A0"),
+    (0xF976, "7565"),
+    (0xF977, "4EAE"),
+    (0xF978, "5169"),
+    (0xF979, "51C9"),
+    (0xF97A, "6881"),
+    (0xF97B, "7CE7"),
+    (0xF97C, "826F"),
+    (0xF97D, "8AD2"),
+    (0xF97E, "91CF"),
+    (0xF97F, "52F5"),
+    (0xF980, "5442"),
+    (0xF981, "5973"),
+    (0xF982, "5EEC"),
+    (0xF983, "65C5"),
+    (0xF984, "6FFE"),
+    (0xF985, "792A"),
+    (0xF986, "95AD"),
+    (0xF987, "9A6A"),
+    (0xF988, "9E97"),
+    (0xF989, "9ECE"),
+    (0xF98A, "529B"),
+    (0xF98B, "66C6"),
+    (0xF98C, "6B77"),
+    (0xF98D, "8F62"),
+    (0xF98E, "5E74"),
+    (0xF98F, "6190"),
+    (0xF990, "6200"),
+    (0xF991, "649A"),
+    (0xF992, "6F23"),
+    (0xF993, "7149"),
+    (0xF994, "7489"),
+    (0xF995, "79CA"),
+    (0xF996, "7DF4"),
+    (0xF997, "806F"),
+    (0xF998, "8F26"),
+    (0xF999, "84EE"),
+    (0xF99A, "9023"),
+    (0xF99B, "934A"),
+    (0xF99C, "5217"),
+    (0xF99D, "52A3"),
+    (0xF99E, "54BD"),
+    (0xF99F, "70C8"),
+    (0xF9A0, "88C2"),
+    (0xF9A1, "8AAA"),
+    (0xF9A2, "5EC9"),
+    (0xF9A3, "5FF5"),
+    (0xF9A4, "637B"),
+    (0xF9A5, "6BAE"),
+    (0xF9A6, "7C3E"),
+    (0xF9A7, "7375"),
+    (0xF9A8, "4EE4"),
+    (0xF9A9, "56F9"),
+    (0xF9AA, "5BE7"),
+    (0xF9AB, "5DBA"),
+    (0xF9AC, "601C"),
+    (0xF9AD, "73B2"),
+    (0xF9AE, "7469"),
+    (0xF9AF, "7F9A"),
+    (0xF9B0, "8046"),
+    (0xF9B1, "9234"),
+    (0xF9B2, "96F6"),
+    (0xF9B3, "9748"),
+    (0xF9B4, "9818"),
+    (0xF9B5, "4F8B"),
+    (0xF9B6, "79AE"),
+    (0xF9B7, "91B4"),
+    (0xF9B8, "96B8"),
+    (0xF9B9, "60E1"),
+    (0xF9BA, "4E86"),
+    (0xF9BB, "50DA"),
+    (0xF9BC, "5BEE"),
+    (0xF9BD, "5C3F"),
+    (0xF9BE, "6599"),
+    (0xF9BF, "6A02"),
+    (0xF9C0, "71CE"),
+    (0xF9C1, "7642"),
+    (0xF9C2, "84FC"),
+    (0xF9C3, "907C"),
+    (0xF9C4, "9F8D"),
+    (0xF9C5, "6688"),
+    (0xF9C6, "962E"),
+    (0xF9C7, "5289"),
+    (0xF9C8, "677B"),
+    (0xF9C9, "67F3"),
+    (0xF9CA, "6D41"),
+    (0xF9CB, "6E9C"),
+    (0xF9CC, "7409"),
+    (0xF9CD, "7559"),
+    (0xF9CE, "786B"),
+    (0xF9CF, "7D10"),
+    (0xF9D0, "985E"),
+    (0xF9D1, "516D"),
+    (0xF9D2, "622E"),
+    (0xF9D3, "9678"),
+    (0xF9D4, "502B"),
+    (0xF9D5, "5D19"),
+    (0xF9D6, "6DEA"),
+    (0xF9D7, "8F2A"),
+    (0xF9D8, "5F8B"),
+    (0xF9D9, "6144"),
+    (0xF9DA, "6817"),
+    (0xF9DB, "7387"),
+    (0xF9DC, "9686"),
+    (0xF9DD, "5229"),
+    (0xF9DE, "540F"),
+    (0xF9DF, "5C65"),
+    (0xF9E0, "6613"),
+    (0xF9E1, "674E"),
+    (0xF9E2, "68A8"),
+    (0xF9E3, "6CE5"),
+    (0xF9E4, "7406"),
+    (0xF9E5, "75E2"),
+    (0xF9E6, "7F79"),
+    (0xF9E7, "88CF"),
+    (0xF9E8, "88E1"),
+    (0xF9E9, "91CC"),
+    (0xF9EA, "96E2"),
+    (0xF9EB, "533F"),
+    (0xF9EC, "6EBA"),
+    (0xF9ED, "541D"),
+    (0xF9EE, "71D0"),
+    (0xF9EF, "7498"),
+    (0xF9F0, "85FA"),
+    (0xF9F1, "96A3"),
+    (0xF9F2, "9C57"),
+    (0xF9F3, "9E9F"),
+    (0xF9F4, "6797"),
+    (0xF9F5, "6DCB"),
+    (0xF9F6, "81E8"),
+    (0xF9F7, "7ACB"),
+    (0xF9F8, "7B20"),
+    (0xF9F9, "7C92"),
+    (0xF9FA, "72C0"),
+    (0xF9FB, "7099"),
+    (0xF9FC, "8B58"),
+    (0xF9FD, "4EC0"),
+    (0xF9FE, "8336"),
+    (0xF9FF, "523A"),
+    (0xFA00, "5207"),
+    (0xFA01, "5EA6"),
+    (0xFA02, "62D3"),
+    (0xFA03, "7CD6"),
+    (0xFA04, "5B85"),
+    (0xFA05, "6D1E"),
+    (0xFA06, "66B4"),
+    (0xFA07, "8F3B"),
+    (0xFA08, "884C"),
+    (0xFA09, "964D"),
+    (0xFA0A, "898B"),
+    (0xFA0B, "5ED3"),
+    (0xFA0C, "5140"),
+    (0xFA0D, "55C0"),
+    (0xFA10, "585A"),
+    (0xFA12, "6674"),
+    (0xFA15, "51DE"),
+    (0xFA16, "732A"),
+    (0xFA17, "76CA"),
+    (0xFA18, "793C"),
+    (0xFA19, "795E"),
+    (0xFA1A, "7965"),
+    (0xFA1B, "798F"),
+    (0xFA1C, "9756"),
+    (0xFA1D, "7CBE"),
+    (0xFA1E, "7FBD"),
+    (0xFA20, "8612"),
+    (0xFA22, "8AF8"),
+    (0xFA25, "9038"),
+    (0xFA26, "90FD"),
+    (0xFA2A, "98EF"),
+    (0xFA2B, "98FC"),
+    (0xFA2C, "9928"),
+    (0xFA2D, "9DB4"),
+    (0xFA2E, "90DE"),
+    (0xFA2F, "96B7"),
+    (0xFA30, "4FAE"),
+    (0xFA31, "50E7"),
+    (0xFA32, "514D"),
+    (0xFA33, "52C9"),
+    (0xFA34, "52E4"),
+    (0xFA35, "5351"),
+    (0xFA36, "559D"),
+    (0xFA37, "5606"),
+    (0xFA38, "5668"),
+    (0xFA39, "5840"),
+    (0xFA3A, "58A8"),
+    (0xFA3B, "5C64"),
+    (0xFA3C, "5C6E"),
+    (0xFA3D, "6094"),
+    (0xFA3E, "6168"),
+    (0xFA3F, "618E"),
+    (0xFA40, "61F2"),
+    (0xFA41, "654F"),
+    (0xFA42, "65E2"),
+    (0xFA43, "6691"),
+    (0xFA44, "6885"),
+    (0xFA45, "6D77"),
+    (0xFA46, "6E1A"),
+    (0xFA47, "6F22"),
+    (0xFA48, "716E"),
+    (0xFA49, "722B"),
+    (0xFA4A, "7422"),
+    (0xFA4B, "7891"),
+    (0xFA4C, "793E"),
+    (0xFA4D, "7949"),
+    (0xFA4E, "7948"),
+    (0xFA4F, "7950"),
+    (0xFA50, "7956"),
+    (0xFA51, "795D"),
+    (0xFA52, "798D"),
+    (0xFA53, "798E"),
+    (0xFA54, "7A40"),
+    (0xFA55, "7A81"),
+    (0xFA56, "7BC0"),
+    (0xFA57, "7DF4"),
+    (0xFA58, "7E09"),
+    (0xFA59, "7E41"),
+    (0xFA5A, "7F72"),
+    (0xFA5B, "8005"),
+    (0xFA5C, "81ED"),
+    (0xFA5D, "8279"),
+    (0xFA5E, "8279"),
+    (0xFA5F, "8457"),
+    (0xFA60, "8910"),
+    (0xFA61, "8996"),
+    (0xFA62, "8B01"),
+    (0xFA63, "8B39"),
+    (0xFA64, "8CD3"),
+    (0xFA65, "8D08"),
+    (0xFA66, "8FB6"),
+    (0xFA67, "9038"),
+    (0xFA68, "96E3"),
+    (0xFA69, "97FF"),
+    (0xFA6A, "983B"),
+    (0xFA6B, "6075"),
+    (0xFA6C, "242EE"),
+    (0xFA6D, "8218"),
+    (0xFA70, "4E26"),
+    (0xFA71, "51B5"),
+    (0xFA72, "5168"),
+    (0xFA73, "4F80"),
+    (0xFA74, "5145"),
+    (0xFA75, "5180"),
+    (0xFA76, "52C7"),
+    (0xFA77, "52FA"),
+    (0xFA78, "559D"),
+    (0xFA79, "5555"),
+    (0xFA7A, "5599"),
+    (0xFA7B, "55E2"),
+    (0xFA7C, "585A"),
+    (0xFA7D, "58B3"),
+    (0xFA7E, "5944"),
+    (0xFA7F, "5954"),
+    (0xFA80, "5A62"),
+    (0xFA81, "5B28"),
+    (0xFA82, "5ED2"),
+    (0xFA83, "5ED9"),
+    (0xFA84, "5F69"),
+    (0xFA85, "5FAD"),
+    (0xFA86, "60D8"),
+    (0xFA87, "614E"),
+    (0xFA88, "6108"),
+    (0xFA89, "618E"),
+    (0xFA8A, "6160"),
+    (0xFA8B, "61F2"),
+    (0xFA8C, "6234"),
+    (0xFA8D, "63C4"),
+    (0xFA8E, "641C"),
+    (0xFA8F, "6452"),
+    (0xFA90, "6556"),
+    (0xFA91, "6674"),
+    (0xFA92, "6717"),
+    (0xFA93, "671B"),
+    (0xFA94, "6756"),
+    (0xFA95, "6B79"),
+    (0xFA96, "6BBA"),
+    (0xFA97, "6D41"),
+    (0xFA98, "6EDB"),
+    (0xFA99, "6ECB"),
+    (0xFA9A, "6F22"),
+    (0xFA9B, "701E"),
+    (0xFA9C, "716E"),
+    (0xFA9D, "77A7"),
+    (0xFA9E, "7235"),
+    (0xFA9F, "72AF"),
+    (0xFAA0, "732A"),
+    (0xFAA1, "7471"),
+    (0xFAA2, "7506"),
+    (0xFAA3, "753B"),
+    (0xFAA4, "761D"),
+    (0xFAA5, "761F"),
+    (0xFAA6, "76CA"),
+    (0xFAA7, "76DB"),
+    (0xFAA8, "76F4"),
+    (0xFAA9, "774A"),
+    (0xFAAA, "7740"),
+    (0xFAAB, "78CC"),
+    (0xFAAC, "7AB1"),
+    (0xFAAD, "7BC0"),
+    (0xFAAE, "7C7B"),
+    (0xFAAF, "7D5B"),
+    (0xFAB0, "7DF4"),
+    (0xFAB1, "7F3E"),
+    (0xFAB2, "8005"),
+    (0xFAB3, "8352"),
+    (0xFAB4, "83EF"),
+    (0xFAB5, "8779"),
+    (0xFAB6, "8941"),
+    (0xFAB7, "8986"),
+    (0xFAB8, "8996"),
+    (0xFAB9, "8ABF"),
+    (0xFABA, "8AF8"),
+    (0xFABB, "8ACB"),
+    (0xFABC, "8B01"),
+    (0xFABD, "8AFE"),
+    (0xFABE, "8AED"),
+    (0xFABF, "8B39"),
+    (0xFAC0, "8B8A"),
+    (0xFAC1, "8D08"),
+    (0xFAC2, "8F38"),
+    (0xFAC3, "9072"),
+    (0xFAC4, "9199"),
+    (0xFAC5, "9276"),
+    (0xFAC6, "967C"),
+    (0xFAC7, "96E3"),
+    (0xFAC8, "9756"),
+    (0xFAC9, "97DB"),
+    (0xFACA, "97FF"),
+    (0xFACB, "980B"),
+    (0xFACC, "983B"),
+    (0xFACD, "9B12"),
+    (0xFACE, "9F9C"),
+    (0xFACF, "2284A"),
+    (0xFAD0, "22844"),
+    (0xFAD1, "233D5"),
+    (0xFAD2, "3B9D"),
+    (0xFAD3, "4018"),
+    (0xFAD4, "4039"),
+    (0xFAD5, "25249"),
+    (0xFAD6, "25CD0"),
+    (0xFAD7, "27ED3"),
+    (0xFAD8, "9F43"),
+    (0xFAD9, "9F8E"),
+    (0xFB00, "<compat> 0066 0066"),
+    (0xFB01, "<compat> 0066 0069"),
+    (0xFB02, "<compat> 0066 006C"),
+    (0xFB03, "<compat> 0066 0066 0069"),
+    (0xFB04, "<compat> 0066 0066 006C"),
+    (0xFB05, "<compat> 017F 0074"),
+    (0xFB06, "<compat> 0073 0074"),
+    (0xFB13, "<compat> 0574 0576"),
+    (0xFB14, "<compat> 0574 0565"),
+    (0xFB15, "<compat> 0574 056B"),
+    (0xFB16, "<compat> 057E 0576"),
+    (0xFB17, "<compat> 0574 056D"),
+    (0xFB1D, "05D9 05B4"),
+    (0xFB1F, "05F2 05B7"),
+    (0xFB20, "<font> 05E2"),
+    (0xFB21, "<font> 05D0"),
+    (0xFB22, "<font> 05D3"),
+    (0xFB23, "<font> 05D4"),
+    (0xFB24, "<font> 05DB"),
+    (0xFB25, "<font> 05DC"),
+    (0xFB26, "<font> 05DD"),
+    (0xFB27, "<font> 05E8"),
+    (0xFB28, "<font> 05EA"),
+    (0xFB29, "<font> 002B"),
+    (0xFB2A, "05E9 05C1"),
+    (0xFB2B, "05E9 05C2"),
+    (0xFB2C, "FB49 05C1"),
+    (0xFB2D, "FB49 05C2"),
+    (0xFB2E, "05D0 05B7"),
+    (0xFB2F, "05D0 05B8"),
+    (0xFB30, "05D0 05BC"),
+    (0xFB31, "05D1 05BC"),
+    (0xFB32, "05D2 05BC"),
+    (0xFB33, "05D3 05BC"),
+    (0xFB34, "05D4 05BC"),
+    (0xFB35, "05D5 05BC"),
+    (0xFB36, "05D6 05BC"),
+    (0xFB38, "05D8 05BC"),
+    (0xFB39, "05D9 05BC"),
+    (0xFB3A, "05DA 05BC"),
+    (0xFB3B, "05DB 05BC"),
+    (0xFB3C, "05DC 05BC"),
+    (0xFB3E, "05DE 05BC"),
+    (0xFB40, "05E0 05BC"),
+    (0xFB41, "05E1 05BC"),
+    (0xFB43, "05E3 05BC"),
+    (0xFB44, "05E4 05BC"),
+    (0xFB46, "05E6 05BC"),
+    (0xFB47, "05E7 05BC"),
+    (0xFB48, "05E8 05BC"),
+    (0xFB49, "05E9 05BC"),
+    (0xFB4A, "05EA 05BC"),
+    (0xFB4B, "05D5 05B9"),
+    (0xFB4C, "05D1 05BF"),
+    (0xFB4D, "05DB 05BF"),
+    (0xFB4E, "05E4 05BF"),
+    (0xFB4F, "<compat> 05D0 05DC"),
+    (0xFB50, "<isolated> 0671"),
+    (0xFB51, "<final> 0671"),
+    (0xFB52, "<isolated> 067B"),
+    (0xFB53, "<final> 067B"),
+    (0xFB54, "<initial> 067B"),
+    (0xFB55, "<medial> 067B"),
+    (0xFB56, "<isolated> 067E"),
+    (0xFB57, "<final> 067E"),
+    (0xFB58, "<initial> 067E"),
+    (0xFB59, "<medial> 067E"),
+    (0xFB5A, "<isolated> 0680"),
+    (0xFB5B, "<final> 0680"),
+    (0xFB5C, "<initial> 0680"),
+    (0xFB5D, "<medial> 0680"),
+    (0xFB5E, "<isolated> 067A"),
+    (0xFB5F, "<final> 067A"),
+    (0xFB60, "<initial> 067A"),
+    (0xFB61, "<medial> 067A"),
+    (0xFB62, "<isolated> 067F"),
+    (0xFB63, "<final> 067F"),
+    (0xFB64, "<initial> 067F"),
+    (0xFB65, "<medial> 067F"),
+    (0xFB66, "<isolated> 0679"),
+    (0xFB67, "<final> 0679"),
+    (0xFB68, "<initial> 0679"),
+    (0xFB69, "<medial> 0679"),
+    (0xFB6A, "<isolated> 06A4"),
+    (0xFB6B, "<final> 06A4"),
+    (0xFB6C, "<initial> 06A4"),
+    (0xFB6D, "<medial> 06A4"),
+    (0xFB6E, "<isolated> 06A6"),
+    (0xFB6F, "<final> 06A6"),
+    (0xFB70, "<initial> 06A6"),
+    (0xFB71, "<medial> 06A6"),
+    (0xFB72, "<isolated> 0684"),
+    (0xFB73, "<final> 0684"),
+    (0xFB74, "<initial> 0684"),
+    (0xFB75, "<medial> 0684"),
+    (0xFB76, "<isolated> 0683"),
+    (0xFB77, "<final> 0683"),
+    (0xFB78, "<initial> 0683"),
+    (0xFB79, "<medial> 0683"),
+    (0xFB7A, "<isolated> 0686"),
+    (0xFB7B, "<final> 0686"),
+    (0xFB7C, "<initial> 0686"),
+    (0xFB7D, "<medial> 0686"),
+    (0xFB7E, "<isolated> 0687"),
+    (0xFB7F, "<final> 0687"),
+    (0xFB80, "<initial> 0687"),
+    (0xFB81, "<medial> 0687"),
+    (0xFB82, "<isolated> 068D"),
+    (0xFB83, "<final> 068D"),
+    (0xFB84, "<isolated> 068C"),
+    (0xFB85, "<final> 068C"),
+    (0xFB86, "<isolated> 068E"),
+    (0xFB87, "<final> 068E"),
+    (0xFB88, "<isolated> 0688"),
+    (0xFB89, "<final> 0688"),
+    (0xFB8A, "<isolated> 0698"),
+    (0xFB8B, "<final> 0698"),
+    (0xFB8C, "<isolated> 0691"),
+    (0xFB8D, "<final> 0691"),
+    (0xFB8E, "<isolated> 06A9"),
+    (0xFB8F, "<final> 06A9"),
+    (0xFB90, "<initial> 06A9"),
+    (0xFB91, "<medial> 06A9"),
+    (0xFB92, "<isolated> 06AF"),
+    (0xFB93, "<final> 06AF"),
+    (0xFB94, "<initial> 06AF"),
+    (0xFB95, "<medial> 06AF"),
+    (0xFB96, "<isolated> 06B3"),
+    (0xFB97, "<final> 06B3"),
+    (0xFB98, "<initial> 06B3"),
+    (0xFB99, "<medial> 06B3"),
+    (0xFB9A, "<isolated> 06B1"),
+    (0xFB9B, "<final> 06B1"),
+    (0xFB9C, "<initial> 06B1"),
+    (0xFB9D, "<medial> 06B1"),
+    (0xFB9E, "<isolated> 06BA"),
+    (0xFB9F, "<final> 06BA"),
+    (0xFBA0, "<isolated> 06BB"),
+    (0xFBA1, "<final> 06BB"),
+    (0xFBA2, "<initial> 06BB"),
+    (0xFBA3, "<medial> 06BB"),
+    (0xFBA4, "<isolated> 06C0"),
+    (0xFBA5, "<final> 06C0"),
+    (0xFBA6, "<isolated> 06C1"),
+    (0xFBA7, "<final> 06C1"),
+    (0xFBA8, "<initial> 06C1"),
+    (0xFBA9, "<medial> 06C1"),
+    (0xFBAA, "<isolated> 06BE"),
+    (0xFBAB, "<final> 06BE"),
+    (0xFBAC, "<initial> 06BE"),
+    (0xFBAD, "<medial> 06BE"),
+    (0xFBAE, "<isolated> 06D2"),
+    (0xFBAF, "<final> 06D2"),
+    (0xFBB0, "<isolated> 06D3"),
+    (0xFBB1, "<final> 06D3"),
+    (0xFBD3, "<isolated> 06AD"),
+    (0xFBD4, "<final> 06AD"),
+    (0xFBD5, "<initial> 06AD"),
+    (0xFBD6, "<medial> 06AD"),
+    (0xFBD7, "<isolated> 06C7"),
+    (0xFBD8, "<final> 06C7"),
+    (0xFBD9, "<isolated> 06C6"),
+    (0xFBDA, "<final> 06C6"),
+    (0xFBDB, "<isolated> 06C8"),
+    (0xFBDC, "<final> 06C8"),
+    (0xFBDD, "<isolated> 0677"),
+    (0xFBDE, "<isolated> 06CB"),
+    (0xFBDF, "<final> 06CB"),
+    (0xFBE0, "<isolated> 06C5"),
+    (0xFBE1, "<final> 06C5"),
+    (0xFBE2, "<isolated> 06C9"),
+    (0xFBE3, "<final> 06C9"),
+    (0xFBE4, "<isolated> 06D0"),
+    (0xFBE5, "<final> 06D0"),
+    (0xFBE6, "<initial> 06D0"),
+    (0xFBE7, "<medial> 06D0"),
+    (0xFBE8, "<initial> 0649"),
+    (0xFBE9, "<medial> 0649"),
+    (0xFBEA, "<isolated> 0626 0627"),
+    (0xFBEB, "<final> 0626 0627"),
+    (0xFBEC, "<isolated> 0626 06D5"),
+    (0xFBED, "<final> 0626 06D5"),
+    (0xFBEE, "<isolated> 0626 0648"),
+    (0xFBEF, "<final> 0626 0648"),
+    (0xFBF0, "<isolated> 0626 06C7"),
+    (0xFBF1, "<final> 0626 06C7"),
+    (0xFBF2, "<isolated> 0626 06C6"),
+    (0xFBF3, "<final> 0626 06C6"),
+    (0xFBF4, "<isolated> 0626 06C8"),
+    (0xFBF5, "<final> 0626 06C8"),
+    (0xFBF6, "<isolated> 0626 06D0"),
+    (0xFBF7, "<final> 0626 06D0"),
+    (0xFBF8, "<initial> 0626 06D0"),
+    (0xFBF9, "<isolated> 0626 0649"),
+    (0xFBFA, "<final> 0626 0649"),
+    (0xFBFB, "<initial> 0626 0649"),
+    (0xFBFC, "<isolated> 06CC"),
+    (0xFBFD, "<final> 06CC"),
+    (0xFBFE, "<initial> 06CC"),
+    (0xFBFF, "<medial> 06CC"),
+    (0xFC00, "<isolated> 0626 062C"),
+    (0xFC01, "<isolated> 0626 062D"),
+    (0xFC02, "<isolated> 0626 0645"),
+    (0xFC03, "<isolated> 0626 0649"),
+    (0xFC04, "<isolated> 0626 064A"),
+    (0xFC05, "<isolated> 0628 062C"),
+    (0xFC06, "<isolated> 0628 062D"),
+    (0xFC07, "<isolated> 0628 062E"),
+    (0xFC08, "<isolated> 0628 0645"),
+    (0xFC09, "<isolated> 0628 0649"),
+    (0xFC0A, "<isolated> 0628 064A"),
+    (0xFC0B, "<isolated> 062A 062C"),
+    (0xFC0C, "<isolated> 062A 062D"),
+    (0xFC0D, "<isolated> 062A 062E"),
+    (0xFC0E, "<isolated> 062A 0645"),
+    (0xFC0F, "<isolated> 062A 0649"),
+    (0xFC10, "<isolated> 062A 064A"),
+    (0xFC11, "<isolated> 062B 062C"),
+    (0xFC12, "<isolated> 062B 0645"),
+    (0xFC13, "<isolated> 062B 0649"),
+    (0xFC14, "<isolated> 062B 064A"),
+    (0xFC15, "<isolated> 062C 062D"),
+    (0xFC16, "<isolated> 062C 0645"),
+    (0xFC17, "<isolated> 062D 062C"),
+    (0xFC18, "<isolated> 062D 0645"),
+    (0xFC19, "<isolated> 062E 062C"),
+    (0xFC1A, "<isolated> 062E 062D"),
+    (0xFC1B, "<isolated> 062E 0645"),
+    (0xFC1C, "<isolated> 0633 062C"),
+    (0xFC1D, "<isolated> 0633 062D"),
+    (0xFC1E, "<isolated> 0633 062E"),
+    (0xFC1F, "<isolated> 0633 0645"),
+    (0xFC20, "<isolated> 0635 062D"),
+    (0xFC21, "<isolated> 0635 0645"),
+    (0xFC22, "<isolated> 0636 062C"),
+    (0xFC23, "<isolated> 0636 062D"),
+    (0xFC24, "<isolated> 0636 062E"),
+    (0xFC25, "<isolated> 0636 0645"),
+    (0xFC26, "<isolated> 0637 062D"),
+    (0xFC27, "<isolated> 0637 0645"),
+    (0xFC28, "<isolated> 0638 0645"),
+    (0xFC29, "<isolated> 0639 062C"),
+    (0xFC2A, "<isolated> 0639 0645"),
+    (0xFC2B, "<isolated> 063A 062C"),
+    (0xFC2C, "<isolated> 063A 0645"),
+    (0xFC2D, "<isolated> 0641 062C"),
+    (0xFC2E, "<isolated> 0641 062D"),
+    (0xFC2F, "<isolated> 0641 062E"),
+    (0xFC30, "<isolated> 0641 0645"),
+    (0xFC31, "<isolated> 0641 0649"),
+    (0xFC32, "<isolated> 0641 064A"),
+    (0xFC33, "<isolated> 0642 062D"),
+    (0xFC34, "<isolated> 0642 0645"),
+    (0xFC35, "<isolated> 0642 0649"),
+    (0xFC36, "<isolated> 0642 064A"),
+    (0xFC37, "<isolated> 0643 0627"),
+    (0xFC38, "<isolated> 0643 062C"),
+    (0xFC39, "<isolated> 0643 062D"),
+    (0xFC3A, "<isolated> 0643 062E"),
+    (0xFC3B, "<isolated> 0643 0644"),
+    (0xFC3C, "<isolated> 0643 0645"),
+    (0xFC3D, "<isolated> 0643 0649"),
+    (0xFC3E, "<isolated> 0643 064A"),
+    (0xFC3F, "<isolated> 0644 062C"),
+    (0xFC40, "<isolated> 0644 062D"),
+    (0xFC41, "<isolated> 0644 062E"),
+    (0xFC42, "<isolated> 0644 0645"),
+    (0xFC43, "<isolated> 0644 0649"),
+    (0xFC44, "<isolated> 0644 064A"),
+    (0xFC45, "<isolated> 0645 062C"),
+    (0xFC46, "<isolated> 0645 062D"),
+    (0xFC47, "<isolated> 0645 062E"),
+    (0xFC48, "<isolated> 0645 0645"),
+    (0xFC49, "<isolated> 0645 0649"),
+    (0xFC4A, "<isolated> 0645 064A"),
+    (0xFC4B, "<isolated> 0646 062C"),
+    (0xFC4C, "<isolated> 0646 062D"),
+    (0xFC4D, "<isolated> 0646 062E"),
+    (0xFC4E, "<isolated> 0646 0645"),
+    (0xFC4F, "<isolated> 0646 0649"),
+    (0xFC50, "<isolated> 0646 064A"),
+    (0xFC51, "<isolated> 0647 062C"),
+    (0xFC52, "<isolated> 0647 0645"),
+    (0xFC53, "<isolated> 0647 0649"),
+    (0xFC54, "<isolated> 0647 064A"),
+    (0xFC55, "<isolated> 064A 062C"),
+    (0xFC56, "<isolated> 064A 062D"),
+    (0xFC57, "<isolated> 064A 062E"),
+    (0xFC58, "<isolated> 064A 0645"),
+    (0xFC59, "<isolated> 064A 0649"),
+    (0xFC5A, "<isolated> 064A 064A"),
+    (0xFC5B, "<isolated> 0630 0670"),
+    (0xFC5C, "<isolated> 0631 0670"),
+    (0xFC5D, "<isolated> 0649 0670"),
+    (0xFC5E, "<isolated> 0020 064C 0651"),
+    (0xFC5F, "<isolated> 0020 064D 0651"),
+    (0xFC60, "<isolated> 0020 064E 0651"),
+    (0xFC61, "<isolated> 0020 064F 0651"),
+    (0xFC62, "<isolated> 0020 0650 0651"),
+    (0xFC63, "<isolated> 0020 0651 0670"),
+    (0xFC64, "<final> 0626 0631"),
+    (0xFC65, "<final> 0626 0632"),
+    (0xFC66, "<final> 0626 0645"),
+    (0xFC67, "<final> 0626 0646"),
+    (0xFC68, "<final> 0626 0649"),
+    (0xFC69, "<final> 0626 064A"),
+    (0xFC6A, "<final> 0628 0631"),
+    (0xFC6B, "<final> 0628 0632"),
+    (0xFC6C, "<final> 0628 0645"),
+    (0xFC6D, "<final> 0628 0646"),
+    (0xFC6E, "<final> 0628 0649"),
+    (0xFC6F, "<final> 0628 064A"),
+    (0xFC70, "<final> 062A 0631"),
+    (0xFC71, "<final> 062A 0632"),
+    (0xFC72, "<final> 062A 0645"),
+    (0xFC73, "<final> 062A 0646"),
+    (0xFC74, "<final> 062A 0649"),
+    (0xFC75, "<final> 062A 064A"),
+    (0xFC76, "<final> 062B 0631"),
+    (0xFC77, "<final> 062B 0632"),
+    (0xFC78, "<final> 062B 0645"),
+    (0xFC79, "<final> 062B 0646"),
+    (0xFC7A, "<final> 062B 0649"),
+    (0xFC7B, "<final> 062B 064A"),
+    (0xFC7C, "<final> 0641 0649"),
+    (0xFC7D, "<final> 0641 064A"),
+    (0xFC7E, "<final> 0642 0649"),
+    (0xFC7F, "<final> 0642 064A"),
+    (0xFC80, "<final> 0643 0627"),
+    (0xFC81, "<final> 0643 0644"),
+    (0xFC82, "<final> 0643 0645"),
+    (0xFC83, "<final> 0643 0649"),
+    (0xFC84, "<final> 0643 064A"),
+    (0xFC85, "<final> 0644 0645"),
+    (0xFC86, "<final> 0644 0649"),
+    (0xFC87, "<final> 0644 064A"),
+    (0xFC88, "<final> 0645 0627"),
+    (0xFC89, "<final> 0645 0645"),
+    (0xFC8A, "<final> 0646 0631"),
+    (0xFC8B, "<final> 0646 0632"),
+    (0xFC8C, "<final> 0646 0645"),
+    (0xFC8D, "<final> 0646 0646"),
+    (0xFC8E, "<final> 0646 0649"),
+    (0xFC8F, "<final> 0646 064A"),
+    (0xFC90, "<final> 0649 0670"),
+    (0xFC91, "<final> 064A 0631"),
+    (0xFC92, "<final> 064A 0632"),
+    (0xFC93, "<final> 064A 0645"),
+    (0xFC94, "<final> 064A 0646"),
+    (0xFC95, "<final> 064A 0649"),
+    (0xFC96, "<final> 064A 064A"),
+    (0xFC97, "<initial> 0626 062C"),
+    (0xFC98, "<initial> 0626 062D"),
+    (0xFC99, "<initial> 0626 062E"),
+    (0xFC9A, "<initial> 0626 0645"),
+    (0xFC9B, "<initial> 0626 0647"),
+    (0xFC9C, "<initial> 0628 062C"),
+    (0xFC9D, "<initial> 0628 062D"),
+    (0xFC9E, "<initial> 0628 062E"),
+    (0xFC9F, "<initial> 0628 0645"),
+    (0xFCA0, "<initial> 0628 0647"),
+    (0xFCA1, "<initial> 062A 062C"),
+    (0xFCA2, "<initial> 062A 062D"),
+    (0xFCA3, "<initial> 062A 062E"),
+    (0xFCA4, "<initial> 062A 0645"),
+    (0xFCA5, "<initial> 062A 0647"),
+    (0xFCA6, "<initial> 062B 0645"),
+    (0xFCA7, "<initial> 062C 062D"),
+    (0xFCA8, "<initial> 062C 0645"),
+    (0xFCA9, "<initial> 062D 062C"),
+    (0xFCAA, "<initial> 062D 0645"),
+    (0xFCAB, "<initial> 062E 062C"),
+    (0xFCAC, "<initial> 062E 0645"),
+    (0xFCAD, "<initial> 0633 062C"),
+    (0xFCAE, "<initial> 0633 062D"),
+    (0xFCAF, "<initial> 0633 062E"),
+    (0xFCB0, "<initial> 0633 0645"),
+    (0xFCB1, "<initial> 0635 062D"),
+    (0xFCB2, "<initial> 0635 062E"),
+    (0xFCB3, "<initial> 0635 0645"),
+    (0xFCB4, "<initial> 0636 062C"),
+    (0xFCB5, "<initial> 0636 062D"),
+    (0xFCB6, "<initial> 0636 062E"),
+    (0xFCB7, "<initial> 0636 0645"),
+    (0xFCB8, "<initial> 0637 062D"),
+    (0xFCB9, "<initial> 0638 0645"),
+    (0xFCBA, "<initial> 0639 062C"),
+    (0xFCBB, "<initial> 0639 0645"),
+    (0xFCBC, "<initial> 063A 062C"),
+    (0xFCBD, "<initial> 063A 0645"),
+    (0xFCBE, "<initial> 0641 062C"),
+    (0xFCBF, "<initial> 0641 062D"),
+    (0xFCC0, "<initial> 0641 062E"),
+    (0xFCC1, "<initial> 0641 0645"),
+    (0xFCC2, "<initial> 0642 062D"),
+    (0xFCC3, "<initial> 0642 0645"),
+    (0xFCC4, "<initial> 0643 062C"),
+    (0xFCC5, "<initial> 0643 062D"),
+    (0xFCC6, "<initial> 0643 062E"),
+    (0xFCC7, "<initial> 0643 0644"),
+    (0xFCC8, "<initial> 0643 0645"),
+    (0xFCC9, "<initial> 0644 062C"),
+    (0xFCCA, "<initial> 0644 062D"),
+    (0xFCCB, "<initial> 0644 062E"),
+    (0xFCCC, "<initial> 0644 0645"),
+    (0xFCCD, "<initial> 0644 0647"),
+    (0xFCCE, "<initial> 0645 062C"),
+    (0xFCCF, "<initial> 0645 062D"),
+    (0xFCD0, "<initial> 0645 062E"),
+    (0xFCD1, "<initial> 0645 0645"),
+    (0xFCD2, "<initial> 0646 062C"),
+    (0xFCD3, "<initial> 0646 062D"),
+    (0xFCD4, "<initial> 0646 062E"),
+    (0xFCD5, "<initial> 0646 0645"),
+    (0xFCD6, "<initial> 0646 0647"),
+    (0xFCD7, "<initial> 0647 062C"),
+    (0xFCD8, "<initial> 0647 0645"),
+    (0xFCD9, "<initial> 0647 0670"),
+    (0xFCDA, "<initial> 064A 062C"),
+    (0xFCDB, "<initial> 064A 062D"),
+    (0xFCDC, "<initial> 064A 062E"),
+    (0xFCDD, "<initial> 064A 0645"),
+    (0xFCDE, "<initial> 064A 0647"),
+    (0xFCDF, "<medial> 0626 0645"),
+    (0xFCE0, "<medial> 0626 0647"),
+    (0xFCE1, "<medial> 0628 0645"),
+    (0xFCE2, "<medial> 0628 0647"),
+    (0xFCE3, "<medial> 062A 0645"),
+    (0xFCE4, "<medial> 062A 0647"),
+    (0xFCE5, "<medial> 062B 0645"),
+    (0xFCE6, "<medial> 062B 0647"),
+    (0xFCE7, "<medial> 0633 0645"),
+    (0xFCE8, "<medial> 0633 0647"),
+    (0xFCE9, "<medial> 0634 0645"),
+    (0xFCEA, "<medial> 0634 0647"),
+    (0xFCEB, "<medial> 0643 0644"),
+    (0xFCEC, "<medial> 0643 0645"),
+    (0xFCED, "<medial> 0644 0645"),
+    (0xFCEE, "<medial> 0646 0645"),
+    (0xFCEF, "<medial> 0646 0647"),
+    (0xFCF0, "<medial> 064A 0645"),
+    (0xFCF1, "<medial> 064A 0647"),
+    (0xFCF2, "<medial> 0640 064E 0651"),
+    (0xFCF3, "<medial> 0640 064F 0651"),
+    (0xFCF4, "<medial> 0640 0650 0651"),
+    (0xFCF5, "<isolated> 0637 0649"),
+    (0xFCF6, "<isolated> 0637 064A"),
+    (0xFCF7, "<isolated> 0639 0649"),
+    (0xFCF8, "<isolated> 0639 064A"),
+    (0xFCF9, "<isolated> 063A 0649"),
+    (0xFCFA, "<isolated> 063A 064A"),
+    (0xFCFB, "<isolated> 0633 0649"),
+    (0xFCFC, "<isolated> 0633 064A"),
+    (0xFCFD, "<isolated> 0634 0649"),
+    (0xFCFE, "<isolated> 0634 064A"),
+    (0xFCFF, "<isolated> 062D 0649"),
+    (0xFD00, "<isolated> 062D 064A"),
+    (0xFD01, "<isolated> 062C 0649"),
+    (0xFD02, "<isolated> 062C 064A"),
+    (0xFD03, "<isolated> 062E 0649"),
+    (0xFD04, "<isolated> 062E 064A"),
+    (0xFD05, "<isolated> 0635 0649"),
+    (0xFD06, "<isolated> 0635 064A"),
+    (0xFD07, "<isolated> 0636 0649"),
+    (0xFD08, "<isolated> 0636 064A"),
+    (0xFD09, "<isolated> 0634 062C"),
+    (0xFD0A, "<isolated> 0634 062D"),
+    (0xFD0B, "<isolated> 0634 062E"),
+    (0xFD0C, "<isolated> 0634 0645"),
+    (0xFD0D, "<isolated> 0634 0631"),
+    (0xFD0E, "<isolated> 0633 0631"),
+    (0xFD0F, "<isolated> 0635 0631"),
+    (0xFD10, "<isolated> 0636 0631"),
+    (0xFD11, "<final> 0637 0649"),
+    (0xFD12, "<final> 0637 064A"),
+    (0xFD13, "<final> 0639 0649"),
+    (0xFD14, "<final> 0639 064A"),
+    (0xFD15, "<final> 063A 0649"),
+    (0xFD16, "<final> 063A 064A"),
+    (0xFD17, "<final> 0633 0649"),
+    (0xFD18, "<final> 0633 064A"),
+    (0xFD19, "<final> 0634 0649"),
+    (0xFD1A, "<final> 0634 064A"),
+    (0xFD1B, "<final> 062D 0649"),
+    (0xFD1C, "<final> 062D 064A"),
+    (0xFD1D, "<final> 062C 0649"),
+    (0xFD1E, "<final> 062C 064A"),
+    (0xFD1F, "<final> 062E 0649"),
+    (0xFD20, "<final> 062E 064A"),
+    (0xFD21, "<final> 0635 0649"),
+    (0xFD22, "<final> 0635 064A"),
+    (0xFD23, "<final> 0636 0649"),
+    (0xFD24, "<final> 0636 064A"),
+    (0xFD25, "<final> 0634 062C"),
+    (0xFD26, "<final> 0634 062D"),
+    (0xFD27, "<final> 0634 062E"),
+    (0xFD28, "<final> 0634 0645"),
+    (0xFD29, "<final> 0634 0631"),
+    (0xFD2A, "<final> 0633 0631"),
+    (0xFD2B, "<final> 0635 0631"),
+    (0xFD2C, "<final> 0636 0631"),
+    (0xFD2D, "<initial> 0634 062C"),
+    (0xFD2E, "<initial> 0634 062D"),
+    (0xFD2F, "<initial> 0634 062E"),
+    (0xFD30, "<initial> 0634 0645"),
+    (0xFD31, "<initial> 0633 0647"),
+    (0xFD32, "<initial> 0634 0647"),
+    (0xFD33, "<initial> 0637 0645"),
+    (0xFD34, "<medial> 0633 062C"),
+    (0xFD35, "<medial> 0633 062D"),
+    (0xFD36, "<medial> 0633 062E"),
+    (0xFD37, "<medial> 0634 062C"),
+    (0xFD38, "<medial> 0634 062D"),
+    (0xFD39, "<medial> 0634 062E"),
+    (0xFD3A, "<medial> 0637 0645"),
+    (0xFD3B, "<medial> 0638 0645"),
+    (0xFD3C, "<final> 0627 064B"),
+    (0xFD3D, "<isolated> 0627 064B"),
+    (0xFD50, "<initial> 062A 062C 0645"),
+    (0xFD51, "<final> 062A 062D 062C"),
+    (0xFD52, "<initial> 062A 062D 062C"),
+    (0xFD53, "<initial> 062A 062D 0645"),
+    (0xFD54, "<initial> 062A 062E 0645"),
+    (0xFD55, "<initial> 062A 0645 062C"),
+    (0xFD56, "<initial> 062A 0645 062D"),
+    (0xFD57, "<initial> 062A 0645 062E"),
+    (0xFD58, "<final> 062C 0645 062D"),
+    (0xFD59, "<initial> 062C 0645 062D"),
+    (0xFD5A, "<final> 062D 0645 064A"),
+    (0xFD5B, "<final> 062D 0645 0649"),
+    (0xFD5C, "<initial> 0633 062D 062C"),
+    (0xFD5D, "<initial> 0633 062C 062D"),
+    (0xFD5E, "<final> 0633 062C 0649"),
+    (0xFD5F, "<final> 0633 0645 062D"),
+    (0xFD60, "<initial> 0633 0645 062D"),
+    (0xFD61, "<initial> 0633 0645 062C"),
+    (0xFD62, "<final> 0633 0645 0645"),
+    (0xFD63, "<initial> 0633 0645 0645"),
+    (0xFD64, "<final> 0635 062D 062D"),
+    (0xFD65, "<initial> 0635 062D 062D"),
+    (0xFD66, "<final> 0635 0645 0645"),
+    (0xFD67, "<final> 0634 062D 0645"),
+    (0xFD68, "<initial> 0634 062D 0645"),
+    (0xFD69, "<final> 0634 062C 064A"),
+    (0xFD6A, "<final> 0634 0645 062E"),
+    (0xFD6B, "<initial> 0634 0645 062E"),
+    (0xFD6C, "<final> 0634 0645 0645"),
+    (0xFD6D, "<initial> 0634 0645 0645"),
+    (0xFD6E, "<final> 0636 062D 0649"),
+    (0xFD6F, "<final> 0636 062E 0645"),
+    (0xFD70, "<initial> 0636 062E 0645"),
+    (0xFD71, "<final> 0637 0645 062D"),
+    (0xFD72, "<initial> 0637 0645 062D"),
+    (0xFD73, "<initial> 0637 0645 0645"),
+    (0xFD74, "<final> 0637 0645 064A"),
+    (0xFD75, "<final> 0639 062C 0645"),
+    (0xFD76, "<final> 0639 0645 0645"),
+    (0xFD77, "<initial> 0639 0645 0645"),
+    (0xFD78, "<final> 0639 0645 0649"),
+    (0xFD79, "<final> 063A 0645 0645"),
+    (0xFD7A, "<final> 063A 0645 064A"),
+    (0xFD7B, "<final> 063A 0645 0649"),
+    (0xFD7C, "<final> 0641 062E 0645"),
+    (0xFD7D, "<initial> 0641 062E 0645"),
+    (0xFD7E, "<final> 0642 0645 062D"),
+    (0xFD7F, "<final> 0642 0645 0645"),
+    (0xFD80, "<final> 0644 062D 0645"),
+    (0xFD81, "<final> 0644 062D 064A"),
+    (0xFD82, "<final> 0644 062D 0649"),
+    (0xFD83, "<initial> 0644 062C 062C"),
+    (0xFD84, "<final> 0644 062C 062C"),
+    (0xFD85, "<final> 0644 062E 0645"),
+    (0xFD86, "<initial> 0644 062E 0645"),
+    (0xFD87, "<final> 0644 0645 062D"),
+    (0xFD88, "<initial> 0644 0645 062D"),
+    (0xFD89, "<initial> 0645 062D 062C"),
+    (0xFD8A, "<initial> 0645 062D 0645"),
+    (0xFD8B, "<final> 0645 062D 064A"),
+    (0xFD8C, "<initial> 0645 062C 062D"),
+    (0xFD8D, "<initial> 0645 062C 0645"),
+    (0xFD8E, "<initial> 0645 062E 062C"),
+    (0xFD8F, "<initial> 0645 062E 0645"),
+    (0xFD92, "<initial> 0645 062C 062E"),
+    (0xFD93, "<initial> 0647 0645 062C"),
+    (0xFD94, "<initial> 0647 0645 0645"),
+    (0xFD95, "<initial> 0646 062D 0645"),
+    (0xFD96, "<final> 0646 062D 0649"),
+    (0xFD97, "<final> 0646 062C 0645"),
+    (0xFD98, "<initial> 0646 062C 0645"),
+    (0xFD99, "<final> 0646 062C 0649"),
+    (0xFD9A, "<final> 0646 0645 064A"),
+    (0xFD9B, "<final> 0646 0645 0649"),
+    (0xFD9C, "<final> 064A 0645 0645"),
+    (0xFD9D, "<initial> 064A 0645 0645"),
+    (0xFD9E, "<final> 0628 062E 064A"),
+    (0xFD9F, "<final> 062A 062C 064A"),
+    (0xFDA0, "<final> 062A 062C 0649"),
+    (0xFDA1, "<final> 062A 062E 064A"),
+    (0xFDA2, "<final> 062A 062E 0649"),
+    (0xFDA3, "<final> 062A 0645 064A"),
+    (0xFDA4, "<final> 062A 0645 0649"),
+    (0xFDA5, "<final> 062C 0645 064A"),
+    (0xFDA6, "<final> 062C 062D 0649"),
+    (0xFDA7, "<final> 062C 0645 0649"),
+    (0xFDA8, "<final> 0633 062E 0649"),
+    (0xFDA9, "<final> 0635 062D 064A"),
+    (0xFDAA, "<final> 0634 062D 064A"),
+    (0xFDAB, "<final> 0636 062D 064A"),
+    (0xFDAC, "<final> 0644 062C 064A"),
+    (0xFDAD, "<final> 0644 0645 064A"),
+    (0xFDAE, "<final> 064A 062D 064A"),
+    (0xFDAF, "<final> 064A 062C 064A"),
+    (0xFDB0, "<final> 064A 0645 064A"),
+    (0xFDB1, "<final> 0645 0645 064A"),
+    (0xFDB2, "<final> 0642 0645 064A"),
+    (0xFDB3, "<final> 0646 062D 064A"),
+    (0xFDB4, "<initial> 0642 0645 062D"),
+    (0xFDB5, "<initial> 0644 062D 0645"),
+    (0xFDB6, "<final> 0639 0645 064A"),
+    (0xFDB7, "<final> 0643 0645 064A"),
+    (0xFDB8, "<initial> 0646 062C 062D"),
+    (0xFDB9, "<final> 0645 062E 064A"),
+    (0xFDBA, "<initial> 0644 062C 0645"),
+    (0xFDBB, "<final> 0643 0645 0645"),
+    (0xFDBC, "<final> 0644 062C 0645"),
+    (0xFDBD, "<final> 0646 062C 062D"),
+    (0xFDBE, "<final> 062C 062D 064A"),
+    (0xFDBF, "<final> 062D 062C 064A"),
+    (0xFDC0, "<final> 0645 062C 064A"),
+    (0xFDC1, "<final> 0641 0645 064A"),
+    (0xFDC2, "<final> 0628 062D 064A"),
+    (0xFDC3, "<initial> 0643 0645 0645"),
+    (0xFDC4, "<initial> 0639 062C 0645"),
+    (0xFDC5, "<initial> 0635 0645 0645"),
+    (0xFDC6, "<final> 0633 062E 064A"),
+    (0xFDC7, "<final> 0646 062C 064A"),
+    (0xFDF0, "<isolated> 0635 0644 06D2"),
+    (0xFDF1, "<isolated> 0642 0644 06D2"),
+    (0xFDF2, "<isolated> 0627 0644 0644 0647"),
+    (0xFDF3, "<isolated> 0627 0643 0628 0631"),
+    (0xFDF4, "<isolated> 0645 062D 0645 062F"),
+    (0xFDF5, "<isolated> 0635 0644 0639 0645"),
+    (0xFDF6, "<isolated> 0631 0633 0648 0644"),
+    (0xFDF7, "<isolated> 0639 0644 064A 0647"),
+    (0xFDF8, "<isolated> 0648 0633 0644 0645"),
+    (0xFDF9, "<isolated> 0635 0644 0649"),
+    (
+        0xFDFA,
+        "<isolated> 0635 0644 0649 0020 0627 0644 0644 0647 0020 0639 0644 064A 0647 0020 0648 0633 0644 0645",
+    ),
+    (0xFDFB, "<isolated> 062C 0644 0020 062C 0644 0627 0644 0647"),
+    (0xFDFC, "<isolated> 0631 06CC 0627 0644"),
+    (0xFE10, "<vertical> 002C"),
+    (0xFE11, "<vertical> 3001"),
+    (0xFE12, "<vertical> 3002"),
+    (0xFE13, "<vertical> 003A"),
+    (0xFE14, "<vertical> 003B"),
+    (0xFE15, "<vertical> 0021"),
+    (0xFE16, "<vertical> 003F"),
+    (0xFE17, "<vertical> 3016"),
+    (0xFE18, "<vertical> 3017"),
+    (0xFE19, "<vertical> 2026"),
+    (0xFE30, "<vertical> 2025"),
+    (0xFE31, "<vertical> 2014"),
+    (0xFE32, "<vertical> 2013"),
+    (0xFE33, "<vertical> 005F"),
+    (0xFE34, "<vertical> 005F"),
+    (0xFE35, "<vertical> 0028"),
+    (0xFE36, "<vertical> 0029"),
+    (0xFE37, "<vertical> 007B"),
+    (0xFE38, "<vertical> 007D"),
+    (0xFE39, "<vertical> 3014"),
+    (0xFE3A, "<vertical> 3015"),
+    (0xFE3B, "<vertical> 3010"),
+    (0xFE3C, "<vertical> 3011"),
+    (0xFE3D, "<vertical> 300A"),
+    (0xFE3E, "<vertical> 300B"),
+    (0xFE3F, "<vertical> 3008"),
+    (0xFE40, "<vertical> 3009"),
+    (0xFE41, "<vertical> 300C"),
+    (0xFE42, "<vertical> 300D"),
+    (0xFE43, "<vertical> 300E"),
+    (0xFE44, "<vertical> 300F"),
+    (0xFE47, "<vertical> 005B"),
+    (0xFE48, "<vertical> 005D"),
+    (0xFE49, "<compat> 203E"),
+    (0xFE4A, "<compat> 203E"),
+    (0xFE4B, "<compat> 203E"),
+    (0xFE4C, "<compat> 203E"),
+    (0xFE4D, "<compat> 005F"),
+    (0xFE4E, "<compat> 005F"),
+    (0xFE4F, "<compat> 005F"),
+    (0xFE50, "<small> 002C"),
+    (0xFE51, "<small> 3001"),
+    (0xFE52, "<small> 002E"),
+    (0xFE54, "<small> 003B"),
+    (0xFE55, "<small> 003A"),
+    (0xFE56, "<small> 003F"),
+    (0xFE57, "<small> 0021"),
+    (0xFE58, "<small> 2014"),
+    (0xFE59, "<small> 0028"),
+    (0xFE5A, "<small> 0029"),
+    (0xFE5B, "<small> 007B"),
+    (0xFE5C, "<small> 007D"),
+    (0xFE5D, "<small> 3014"),
+    (0xFE5E, "<small> 3015"),
+    (0xFE5F, "<small> 0023"),
+    (0xFE60, "<small> 0026"),
+    (0xFE61, "<small> 002A"),
+    (0xFE62, "<small> 002B"),
+    (0xFE63, "<small> 002D"),
+    (0xFE64, "<small> 003C"),
+    (0xFE65, "<small> 003E"),
+    (0xFE66, "<small> 003D"),
+    (0xFE68, "<small> 005C"),
+    (0xFE69, "<small> 0024"),
+    (0xFE6A, "<small> 0025"),
+    (0xFE6B, "<small> 0040"),
+    (0xFE70, "<isolated> 0020 064B"),
+    (0xFE71, "<medial> 0640 064B"),
+    (0xFE72, "<isolated> 0020 064C"),
+    (0xFE74, "<isolated> 0020 064D"),
+    (0xFE76, "<isolated> 0020 064E"),
+    (0xFE77, "<medial> 0640 064E"),
+    (0xFE78, "<isolated> 0020 064F"),
+    (0xFE79, "<medial> 0640 064F"),
+    (0xFE7A, "<isolated> 0020 0650"),
+    (0xFE7B, "<medial> 0640 0650"),
+    (0xFE7C, "<isolated> 0020 0651"),
+    (0xFE7D, "<medial> 0640 0651"),
+    (0xFE7E, "<isolated> 0020 0652"),
+    (0xFE7F, "<medial> 0640 0652"),
+    (0xFE80, "<isolated> 0621"),
+    (0xFE81, "<isolated> 0622"),
+    (0xFE82, "<final> 0622"),
+    (0xFE83, "<isolated> 0623"),
+    (0xFE84, "<final> 0623"),
+    (0xFE85, "<isolated> 0624"),
+    (0xFE86, "<final> 0624"),
+    (0xFE87, "<isolated> 0625"),
+    (0xFE88, "<final> 0625"),
+    (0xFE89, "<isolated> 0626"),
+    (0xFE8A, "<final> 0626"),
+    (0xFE8B, "<initial> 0626"),
+    (0xFE8C, "<medial> 0626"),
+    (0xFE8D, "<isolated> 0627"),
+    (0xFE8E, "<final> 0627"),
+    (0xFE8F, "<isolated> 0628"),
+    (0xFE90, "<final> 0628"),
+    (0xFE91, "<initial> 0628"),
+    (0xFE92, "<medial> 0628"),
+    (0xFE93, "<isolated> 0629"),
+    (0xFE94, "<final> 0629"),
+    (0xFE95, "<isolated> 062A"),
+    (0xFE96, "<final> 062A"),
+    (0xFE97, "<initial> 062A"),
+    (0xFE98, "<medial> 062A"),
+    (0xFE99, "<isolated> 062B"),
+    (0xFE9A, "<final> 062B"),
+    (0xFE9B, "<initial> 062B"),
+    (0xFE9C, "<medial> 062B"),
+    (0xFE9D, "<isolated> 062C"),
+    (0xFE9E, "<final> 062C"),
+    (0xFE9F, "<initial> 062C"),
+    (0xFEA0, "<medial> 062C"),
+    (0xFEA1, "<isolated> 062D"),
+    (0xFEA2, "<final> 062D"),
+    (0xFEA3, "<initial> 062D"),
+    (0xFEA4, "<medial> 062D"),
+    (0xFEA5, "<isolated> 062E"),
+    (0xFEA6, "<final> 062E"),
+    (0xFEA7, "<initial> 062E"),
+    (0xFEA8, "<medial> 062E"),
+    (0xFEA9, "<isolated> 062F"),
+    (0xFEAA, "<final> 062F"),
+    (0xFEAB, "<isolated> 0630"),
+    (0xFEAC, "<final> 0630"),
+    (0xFEAD, "<isolated> 0631"),
+    (0xFEAE, "<final> 0631"),
+    (0xFEAF, "<isolated> 0632"),
+    (0xFEB0, "<final> 0632"),
+    (0xFEB1, "<isolated> 0633"),
+    (0xFEB2, "<final> 0633"),
+    (0xFEB3, "<initial> 0633"),
+    (0xFEB4, "<medial> 0633"),
+    (0xFEB5, "<isolated> 0634"),
+    (0xFEB6, "<final> 0634"),
+    (0xFEB7, "<initial> 0634"),
+    (0xFEB8, "<medial> 0634"),
+    (0xFEB9, "<isolated> 0635"),
+    (0xFEBA, "<final> 0635"),
+    (0xFEBB, "<initial> 0635"),
+    (0xFEBC, "<medial> 0635"),
+    (0xFEBD, "<isolated> 0636"),
+    (0xFEBE, "<final> 0636"),
+    (0xFEBF, "<initial> 0636"),
+    (0xFEC0, "<medial> 0636"),
+    (0xFEC1, "<isolated> 0637"),
+    (0xFEC2, "<final> 0637"),
+    (0xFEC3, "<initial> 0637"),
+    (0xFEC4, "<medial> 0637"),
+    (0xFEC5, "<isolated> 0638"),
+    (0xFEC6, "<final> 0638"),
+    (0xFEC7, "<initial> 0638"),
+    (0xFEC8, "<medial> 0638"),
+    (0xFEC9, "<isolated> 0639"),
+    (0xFECA, "<final> 0639"),
+    (0xFECB, "<initial> 0639"),
+    (0xFECC, "<medial> 0639"),
+    (0xFECD, "<isolated> 063A"),
+    (0xFECE, "<final> 063A"),
+    (0xFECF, "<initial> 063A"),
+    (0xFED0, "<medial> 063A"),
+    (0xFED1, "<isolated> 0641"),
+    (0xFED2, "<final> 0641"),
+    (0xFED3, "<initial> 0641"),
+    (0xFED4, "<medial> 0641"),
+    (0xFED5, "<isolated> 0642"),
+    (0xFED6, "<final> 0642"),
+    (0xFED7, "<initial> 0642"),
+    (0xFED8, "<medial> 0642"),
+    (0xFED9, "<isolated> 0643"),
+    (0xFEDA, "<final> 0643"),
+    (0xFEDB, "<initial> 0643"),
+    (0xFEDC, "<medial> 0643"),
+    (0xFEDD, "<isolated> 0644"),
+    (0xFEDE, "<final> 0644"),
+    (0xFEDF, "<initial> 0644"),
+    (0xFEE0, "<medial> 0644"),
+    (0xFEE1, "<isolated> 0645"),
+    (0xFEE2, "<final> 0645"),
+    (0xFEE3, "<initial> 0645"),
+    (0xFEE4, "<medial> 0645"),
+    (0xFEE5, "<isolated> 0646"),
+    (0xFEE6, "<final> 0646"),
+    (0xFEE7, "<initial> 0646"),
+    (0xFEE8, "<medial> 0646"),
+    (0xFEE9, "<isolated> 0647"),
+    (0xFEEA, "<final> 0647"),
+    (0xFEEB, "<initial> 0647"),
+    (0xFEEC, "<medial> 0647"),
+    (0xFEED, "<isolated> 0648"),
+    (0xFEEE, "<final> 0648"),
+    (0xFEEF, "<isolated> 0649"),
+    (0xFEF0, "<final> 0649"),
+    (0xFEF1, "<isolated> 064A"),
+    (0xFEF2, "<final> 064A"),
+    (0xFEF3, "<initial> 064A"),
+    (0xFEF4, "<medial> 064A"),
+    (0xFEF5, "<isolated> 0644 0622"),
+    (0xFEF6, "<final> 0644 0622"),
+    (0xFEF7, "<isolated> 0644 0623"),
+    (0xFEF8, "<final> 0644 0623"),
+    (0xFEF9, "<isolated> 0644 0625"),
+    (0xFEFA, "<final> 0644 0625"),
+    (0xFEFB, "<isolated> 0644 0627"),
+    (0xFEFC, "<final> 0644 0627"),
+    (0xFF01, "<wide> 0021"),
+    (0xFF02, "<wide> 0022"),
+    (0xFF03, "<wide> 0023"),
+    (0xFF04, "<wide> 0024"),
+    (0xFF05, "<wide> 0025"),
+    (0xFF06, "<wide> 0026"),
+    (0xFF07, "<wide> 0027"),
+    (0xFF08, "<wide> 0028"),
+    (0xFF09, "<wide> 0029"),
+    (0xFF0A, "<wide> 002A"),
+    (0xFF0B, "<wide> 002B"),
+    (0xFF0C, "<wide> 002C"),
+    (0xFF0D, "<wide> 002D"),
+    (0xFF0E, "<wide> 002E"),
+    (0xFF0F, "<wide> 002F"),
+    (0xFF10, "<wide> 0030"),
+    (0xFF11, "<wide> 0031"),
+    (0xFF12, "<wide> 0032"),
+    (0xFF13, "<wide> 0033"),
+    (0xFF14, "<wide> 0034"),
+    (0xFF15, "<wide> 0035"),
+    (0xFF16, "<wide> 0036"),
+    (0xFF17, "<wide> 0037"),
+    (0xFF18, "<wide> 0038"),
+    (0xFF19, "<wide> 0039"),
+    (0xFF1A, "<wide> 003A"),
+    (0xFF1B, "<wide> 003B"),
+    (0xFF1C, "<wide> 003C"),
+    (0xFF1D, "<wide> 003D"),
+    (0xFF1E, "<wide> 003E"),
+    (0xFF1F, "<wide> 003F"),
+    (0xFF20, "<wide> 0040"),
+    (0xFF21, "<wide> 0041"),
+    (0xFF22, "<wide> 0042"),
+    (0xFF23, "<wide> 0043"),
+    (0xFF24, "<wide> 0044"),
+    (0xFF25, "<wide> 0045"),
+    (0xFF26, "<wide> 0046"),
+    (0xFF27, "<wide> 0047"),
+    (0xFF28, "<wide> 0048"),
+    (0xFF29, "<wide> 0049"),
+    (0xFF2A, "<wide> 004A"),
+    (0xFF2B, "<wide> 004B"),
+    (0xFF2C, "<wide> 004C"),
+    (0xFF2D, "<wide> 004D"),
+    (0xFF2E, "<wide> 004E"),
+    (0xFF2F, "<wide> 004F"),
+    (0xFF30, "<wide> 0050"),
+    (0xFF31, "<wide> 0051"),
+    (0xFF32, "<wide> 0052"),
+    (0xFF33, "<wide> 0053"),
+    (0xFF34, "<wide> 0054"),
+    (0xFF35, "<wide> 0055"),
+    (0xFF36, "<wide> 0056"),
+    (0xFF37, "<wide> 0057"),
+    (0xFF38, "<wide> 0058"),
+    (0xFF39, "<wide> 0059"),
+    (0xFF3A, "<wide> 005A"),
+    (0xFF3B, "<wide> 005B"),
+    (0xFF3C, "<wide> 005C"),
+    (0xFF3D, "<wide> 005D"),
+    (0xFF3E, "<wide> 005E"),
+    (0xFF3F, "<wide> 005F"),
+    (0xFF40, "<wide> 0060"),
+    (0xFF41, "<wide> 0061"),
+    (0xFF42, "<wide> 0062"),
+    (0xFF43, "<wide> 0063"),
+    (0xFF44, "<wide> 0064"),
+    (0xFF45, "<wide> 0065"),
+    (0xFF46, "<wide> 0066"),
+    (0xFF47, "<wide> 0067"),
+    (0xFF48, "<wide> 0068"),
+    (0xFF49, "<wide> 0069"),
+    (0xFF4A, "<wide> 006A"),
+    (0xFF4B, "<wide> 006B"),
+    (0xFF4C, "<wide> 006C"),
+    (0xFF4D, "<wide> 006D"),
+    (0xFF4E, "<wide> 006E"),
+    (0xFF4F, "<wide> 006F"),
+    (0xFF50, "<wide> 0070"),
+    (0xFF51, "<wide> 0071"),
+    (0xFF52, "<wide> 0072"),
+    (0xFF53, "<wide> 0073"),
+    (0xFF54, "<wide> 0074"),
+    (0xFF55, "<wide> 0075"),
+    (0xFF56, "<wide> 0076"),
+    (0xFF57, "<wide> 0077"),
+    (0xFF58, "<wide> 0078"),
+    (0xFF59, "<wide> 0079"),
+    (0xFF5A, "<wide> 007A"),
+    (0xFF5B, "<wide> 007B"),
+    (0xFF5C, "<wide> 007C"),
+    (0xFF5D, "<wide> 007D"),
+    (0xFF5E, "<wide> 007E"),
+    (0xFF5F, "<wide> 2985"),
+    (0xFF60, "<wide> 2986"),
+    (0xFF61, "<narrow> 3002"),
+    (0xFF62, "<narrow> 300C"),
+    (0xFF63, "<narrow> 300D"),
+    (0xFF64, "<narrow> 3001"),
+    (0xFF65, "<narrow> 30FB"),
+    (0xFF66, "<narrow> 30F2"),
+    (0xFF67, "<narrow> 30A1"),
+    (0xFF68, "<narrow> 30A3"),
+    (0xFF69, "<narrow> 30A5"),
+    (0xFF6A, "<narrow> 30A7"),
+    (0xFF6B, "<narrow> 30A9"),
+    (0xFF6C, "<narrow> 30E3"),
+    (0xFF6D, "<narrow> 30E5"),
+    (0xFF6E, "<narrow> 30E7"),
+    (0xFF6F, "<narrow> 30C3"),
+    (0xFF70, "<narrow> 30FC"),
+    (0xFF71, "<narrow> 30A2"),
+    (0xFF72, "<narrow> 30A4"),
+    (0xFF73, "<narrow> 30A6"),
+    (0xFF74, "<narrow> 30A8"),
+    (0xFF75, "<narrow> 30AA"),
+    (0xFF76, "<narrow> 30AB"),
+    (0xFF77, "<narrow> 30AD"),
+    (0xFF78, "<narrow> 30AF"),
+    (0xFF79, "<narrow> 30B1"),
+    (0xFF7A, "<narrow> 30B3"),
+    (0xFF7B, "<narrow> 30B5"),
+    (0xFF7C, "<narrow> 30B7"),
+    (0xFF7D, "<narrow> 30B9"),
+    (0xFF7E, "<narrow> 30BB"),
+    (0xFF7F, "<narrow> 30BD"),
+    (0xFF80, "<narrow> 30BF"),
+    (0xFF81, "<narrow> 30C1"),
+    (0xFF82, "<narrow> 30C4"),
+    (0xFF83, "<narrow> 30C6"),
+    (0xFF84, "<narrow> 30C8"),
+    (0xFF85, "<narrow> 30CA"),
+    (0xFF86, "<narrow> 30CB"),
+    (0xFF87, "<narrow> 30CC"),
+    (0xFF88, "<narrow> 30CD"),
+    (0xFF89, "<narrow> 30CE"),
+    (0xFF8A, "<narrow> 30CF"),
+    (0xFF8B, "<narrow> 30D2"),
+    (0xFF8C, "<narrow> 30D5"),
+    (0xFF8D, "<narrow> 30D8"),
+    (0xFF8E, "<narrow> 30DB"),
+    (0xFF8F, "<narrow> 30DE"),
+    (0xFF90, "<narrow> 30DF"),
+    (0xFF91, "<narrow> 30E0"),
+    (0xFF92, "<narrow> 30E1"),
+    (0xFF93, "<narrow> 30E2"),
+    (0xFF94, "<narrow> 30E4"),
+    (0xFF95, "<narrow> 30E6"),
+    (0xFF96, "<narrow> 30E8"),
+    (0xFF97, "<narrow> 30E9"),
+    (0xFF98, "<narrow> 30EA"),
+    (0xFF99, "<narrow> 30EB"),
+    (0xFF9A, "<narrow> 30EC"),
+    (0xFF9B, "<narrow> 30ED"),
+    (0xFF9C, "<narrow> 30EF"),
+    (0xFF9D, "<narrow> 30F3"),
+    (0xFF9E, "<narrow> 3099"),
+    (0xFF9F, "<narrow> 309A"),
+    (0xFFA0, "<narrow> 3164"),
+    (0xFFA1, "<narrow> 3131"),
+    (0xFFA2, "<narrow> 3132"),
+    (0xFFA3, "<narrow> 3133"),
+    (0xFFA4, "<narrow> 3134"),
+    (0xFFA5, "<narrow> 3135"),
+    (0xFFA6, "<narrow> 3136"),
+    (0xFFA7, "<narrow> 3137"),
+    (0xFFA8, "<narrow> 3138"),
+    (0xFFA9, "<narrow> 3139"),
+    (0xFFAA, "<narrow> 313A"),
+    (0xFFAB, "<narrow> 313B"),
+    (0xFFAC, "<narrow> 313C"),
+    (0xFFAD, "<narrow> 313D"),
+    (0xFFAE, "<narrow> 313E"),
+    (0xFFAF, "<narrow> 313F"),
+    (0xFFB0, "<narrow> 3140"),
+    (0xFFB1, "<narrow> 3141"),
+    (0xFFB2, "<narrow> 3142"),
+    (0xFFB3, "<narrow> 3143"),
+    (0xFFB4, "<narrow> 3144"),
+    (0xFFB5, "<narrow> 3145"),
+    (0xFFB6, "<narrow> 3146"),
+    (0xFFB7, "<narrow> 3147"),
+    (0xFFB8, "<narrow> 3148"),
+    (0xFFB9, "<narrow> 3149"),
+    (0xFFBA, "<narrow> 314A"),
+    (0xFFBB, "<narrow> 314B"),
+    (0xFFBC, "<narrow> 314C"),
+    (0xFFBD, "<narrow> 314D"),
+    (0xFFBE, "<narrow> 314E"),
+    (0xFFC2, "<narrow> 314F"),
+    (0xFFC3, "<narrow> 3150"),
+    (0xFFC4, "<narrow> 3151"),
+    (0xFFC5, "<narrow> 3152"),
+    (0xFFC6, "<narrow> 3153"),
+    (0xFFC7, "<narrow> 3154"),
+    (0xFFCA, "<narrow> 3155"),
+    (0xFFCB, "<narrow> 3156"),
+    (0xFFCC, "<narrow> 3157"),
+    (0xFFCD, "<narrow> 3158"),
+    (0xFFCE, "<narrow> 3159"),
+    (0xFFCF, "<narrow> 315A"),
+    (0xFFD2, "<narrow> 315B"),
+    (0xFFD3, "<narrow> 315C"),
+    (0xFFD4, "<narrow> 315D"),
+    (0xFFD5, "<narrow> 315E"),
+    (0xFFD6, "<narrow> 315F"),
+    (0xFFD7, "<narrow> 3160"),
+    (0xFFDA, "<narrow> 3161"),
+    (0xFFDB, "<narrow> 3162"),
+    (0xFFDC, "<narrow> 3163"),
+    (0xFFE0, "<wide> 00A2"),
+    (0xFFE1, "<wide> 00A3"),
+    (0xFFE2, "<wide> 00AC"),
+    (0xFFE3, "<wide> 00AF"),
+    (0xFFE4, "<wide> 00A6"),
+    (0xFFE5, "<wide> 00A5"),
+    (0xFFE6, "<wide> 20A9"),
+    (0xFFE8, "<narrow> 2502"),
+    (0xFFE9, "<narrow> 2190"),
+    (0xFFEA, "<narrow> 2191"),
+    (0xFFEB, "<narrow> 2192"),
+    (0xFFEC, "<narrow> 2193"),
+    (0xFFED, "<narrow> 25A0"),
+    (0xFFEE, "<narrow> 25CB"),
+    (0x10781, "<super> 02D0"),
+    (0x10782, "<super> 02D1"),
+    (0x10783, "<super> 00E6"),
+    (0x10784, "<super> 0299"),
+    (0x10785, "<super> 0253"),
+    (0x10787, "<super> 02A3"),
+    (0x10788, "<super> AB66"),
+    (0x10789, "<super> 02A5"),
+    (0x1078A, "<super> 02A4"),
+    (0x1078B, "<super> 0256"),
+    (0x1078C, "<super> 0257"),
+    (0x1078D, "<super> 1D91"),
+    (0x1078E, "<super> 0258"),
+    (0x1078F, "<super> 025E"),
+    (0x10790, "<super> 02A9"),
+    (0x10791, "<super> 0264"),
+    (0x10792, "<super> 0262"),
+    (0x10793, "<super> 0260"),
+    (0x10794, "<super> 029B"),
+    (0x10795, "<super> 0127"),
+    (0x10796, "<super> 029C"),
+    (0x10797, "<super> 0267"),
+    (0x10798, "<super> 0284"),
+    (0x10799, "<super> 02AA"),
+    (0x1079A, "<super> 02AB"),
+    (0x1079B, "<super> 026C"),
+    (0x1079C, "<super> 1DF04"),
+    (0x1079D, "<super> A78E"),
+    (0x1079E, "<super> 026E"),
+    (0x1079F, "<super> 1DF05"),
+    (0x107A0, "<super> 028E"),
+    (0x107A1, "<super> 1DF06"),
+    (0x107A2, "<super> 00F8"),
+    (0x107A3, "<super> 0276"),
+    (0x107A4, "<super> 0277"),
+    (0x107A5, "<super> 0071"),
+    (0x107A6, "<super> 027A"),
+    (0x107A7, "<super> 1DF08"),
+    (0x107A8, "<super> 027D"),
+    (0x107A9, "<super> 027E"),
+    (0x107AA, "<super> 0280"),
+    (0x107AB, "<super> 02A8"),
+    (0x107AC, "<super> 02A6"),
+    (0x107AD, "<super> AB67"),
+    (0x107AE, "<super> 02A7"),
+    (0x107AF, "<super> 0288"),
+    (0x107B0, "<super> 2C71"),
+    (0x107B2, "<super> 028F"),
+    (0x107B3, "<super> 02A1"),
+    (0x107B4, "<super> 02A2"),
+    (0x107B5, "<super> 0298"),
+    (0x107B6, "<super> 01C0"),
+    (0x107B7, "<super> 01C1"),
+    (0x107B8, "<super> 01C2"),
+    (0x107B9, "<super> 1DF0A"),
+    (0x107BA, "<super> 1DF1E"),
+    (0x1109A, "11099 110BA"),
+    (0x1109C, "1109B 110BA"),
+    (0x110AB, "110A5 110BA"),
+    (0x1112E, "11131 11127"),
+    (0x1112F, "11132 11127"),
+    (0x1134B, "11347 1133E"),
+    (0x1134C, "11347 11357"),
+    (0x114BB, "114B9 114BA"),
+    (0x114BC, "114B9 114B0"),
+    (0x114BE, "114B9 114BD"),
+    (0x115BA, "115B8 115AF"),
+    (0x115BB, "115B9 115AF"),
+    (0x11938, "11935 11930"),
+    (0x1D15E, "1D157 1D165"),
+    (0x1D15F, "1D158 1D165"),
+    (0x1D160, "1D15F 1D16E"),
+    (0x1D161, "1D15F 1D16F"),
+    (0x1D162, "1D15F 1D170"),
+    (0x1D163, "1D15F 1D171"),
+    (0x1D164, "1D15F 1D172"),
+    (0x1D1BB, "1D1B9 1D165"),
+    (0x1D1BC, "1D1BA 1D165"),
+    (0x1D1BD, "1D1BB 1D16E"),
+    (0x1D1BE, "1D1BC 1D16E"),
+    (0x1D1BF, "1D1BB 1D16F"),
+    (0x1D1C0, "1D1BC 1D16F"),
+    (0x1D400, "<font> 0041"),
+    (0x1D401, "<font> 0042"),
+    (0x1D402, "<font> 0043"),
+    (0x1D403, "<font> 0044"),
+    (0x1D404, "<font> 0045"),
+    (0x1D405, "<font> 0046"),
+    (0x1D406, "<font> 0047"),
+    (0x1D407, "<font> 0048"),
+    (0x1D408, "<font> 0049"),
+    (0x1D409, "<font> 004A"),
+    (0x1D40A, "<font> 004B"),
+    (0x1D40B, "<font> 004C"),
+    (0x1D40C, "<font> 004D"),
+    (0x1D40D, "<font> 004E"),
+    (0x1D40E, "<font> 004F"),
+    (0x1D40F, "<font> 0050"),
+    (0x1D410, "<font> 0051"),
+    (0x1D411, "<font> 0052"),
+    (0x1D412, "<font> 0053"),
+    (0x1D413, "<font> 0054"),
+    (0x1D414, "<font> 0055"),
+    (0x1D415, "<font> 0056"),
+    (0x1D416, "<font> 0057"),
+    (0x1D417, "<font> 0058"),
+    (0x1D418, "<font> 0059"),
+    (0x1D419, "<font> 005A"),
+    (0x1D41A, "<font> 0061"),
+    (0x1D41B, "<font> 0062"),
+    (0x1D41C, "<font> 0063"),
+    (0x1D41D, "<font> 0064"),
+    (0x1D41E, "<font> 0065"),
+    (0x1D41F, "<font> 0066"),
+    (0x1D420, "<font> 0067"),
+    (0x1D421, "<font> 0068"),
+    (0x1D422, "<font> 0069"),
+    (0x1D423, "<font> 006A"),
+    (0x1D424, "<font> 006B"),
+    (0x1D425, "<font> 006C"),
+    (0x1D426, "<font> 006D"),
+    (0x1D427, "<font> 006E"),
+    (0x1D428, "<font> 006F"),
+    (0x1D429, "<font> 0070"),
+    (0x1D42A, "<font> 0071"),
+    (0x1D42B, "<font> 0072"),
+    (0x1D42C, "<font> 0073"),
+    (0x1D42D, "<font> 0074"),
+    (0x1D42E, "<font> 0075"),
+    (0x1D42F, "<font> 0076"),
+    (0x1D430, "<font> 0077"),
+    (0x1D431, "<font> 0078"),
+    (0x1D432, "<font> 0079"),
+    (0x1D433, "<font> 007A"),
+    (0x1D434, "<font> 0041"),
+    (0x1D435, "<font> 0042"),
+    (0x1D436, "<font> 0043"),
+    (0x1D437, "<font> 0044"),
+    (0x1D438, "<font> 0045"),
+    (0x1D439, "<font> 0046"),
+    (0x1D43A, "<font> 0047"),
+    (0x1D43B, "<font> 0048"),
+    (0x1D43C, "<font> 0049"),
+    (0x1D43D, "<font> 004A"),
+    (0x1D43E, "<font> 004B"),
+    (0x1D43F, "<font> 004C"),
+    (0x1D440, "<font> 004D"),
+    (0x1D441, "<font> 004E"),
+    (0x1D442, "<font> 004F"),
+    (0x1D443, "<font> 0050"),
+    (0x1D444, "<font> 0051"),
+    (0x1D445, "<font> 0052"),
+    (0x1D446, "<font> 0053"),
+    (0x1D447, "<font> 0054"),
+    (0x1D448, "<font> 0055"),
+    (0x1D449, "<font> 0056"),
+    (0x1D44A, "<font> 0057"),
+    (0x1D44B, "<font> 0058"),
+    (0x1D44C, "<font> 0059"),
+    (0x1D44D, "<font> 005A"),
+    (0x1D44E, "<font> 0061"),
+    (0x1D44F, "<font> 0062"),
+    (0x1D450, "<font> 0063"),
+    (0x1D451, "<font> 0064"),
+    (0x1D452, "<font> 0065"),
+    (0x1D453, "<font> 0066"),
+    (0x1D454, "<font> 0067"),
+    (0x1D456, "<font> 0069"),
+    (0x1D457, "<font> 006A"),
+    (0x1D458, "<font> 006B"),
+    (0x1D459, "<font> 006C"),
+    (0x1D45A, "<font> 006D"),
+    (0x1D45B, "<font> 006E"),
+    (0x1D45C, "<font> 006F"),
+    (0x1D45D, "<font> 0070"),
+    (0x1D45E, "<font> 0071"),
+    (0x1D45F, "<font> 0072"),
+    (0x1D460, "<font> 0073"),
+    (0x1D461, "<font> 0074"),
+    (0x1D462, "<font> 0075"),
+    (0x1D463, "<font> 0076"),
+    (0x1D464, "<font> 0077"),
+    (0x1D465, "<font> 0078"),
+    (0x1D466, "<font> 0079"),
+    (0x1D467, "<font> 007A"),
+    (0x1D468, "<font> 0041"),
+    (0x1D469, "<font> 0042"),
+    (0x1D46A, "<font> 0043"),
+    (0x1D46B, "<font> 0044"),
+    (0x1D46C, "<font> 0045"),
+    (0x1D46D, "<font> 0046"),
+    (0x1D46E, "<font> 0047"),
+    (0x1D46F, "<font> 0048"),
+    (0x1D470, "<font> 0049"),
+    (0x1D471, "<font> 004A"),
+    (0x1D472, "<font> 004B"),
+    (0x1D473, "<font> 004C"),
+    (0x1D474, "<font> 004D"),
+    (0x1D475, "<font> 004E"),
+    (0x1D476, "<font> 004F"),
+    (0x1D477, "<font> 0050"),
+    (0x1D478, "<font> 0051"),
+    (0x1D479, "<font> 0052"),
+    (0x1D47A, "<font> 0053"),
+    (0x1D47B, "<font> 0054"),
+    (0x1D47C, "<font> 0055"),
+    (0x1D47D, "<font> 0056"),
+    (0x1D47E, "<font> 0057"),
+    (0x1D47F, "<font> 0058"),
+    (0x1D480, "<font> 0059"),
+    (0x1D481, "<font> 005A"),
+    (0x1D482, "<font> 0061"),
+    (0x1D483, "<font> 0062"),
+    (0x1D484, "<font> 0063"),
+    (0x1D485, "<font> 0064"),
+    (0x1D486, "<font> 0065"),
+    (0x1D487, "<font> 0066"),
+    (0x1D488, "<font> 0067"),
+    (0x1D489, "<font> 0068"),
+    (0x1D48A, "<font> 0069"),
+    (0x1D48B, "<font> 006A"),
+    (0x1D48C, "<font> 006B"),
+    (0x1D48D, "<font> 006C"),
+    (0x1D48E, "<font> 006D"),
+    (0x1D48F, "<font> 006E"),
+    (0x1D490, "<font> 006F"),
+    (0x1D491, "<font> 0070"),
+    (0x1D492, "<font> 0071"),
+    (0x1D493, "<font> 0072"),
+    (0x1D494, "<font> 0073"),
+    (0x1D495, "<font> 0074"),
+    (0x1D496, "<font> 0075"),
+    (0x1D497, "<font> 0076"),
+    (0x1D498, "<font> 0077"),
+    (0x1D499, "<font> 0078"),
+    (0x1D49A, "<font> 0079"),
+    (0x1D49B, "<font> 007A"),
+    (0x1D49C, "<font> 0041"),
+    (0x1D49E, "<font> 0043"),
+    (0x1D49F, "<font> 0044"),
+    (0x1D4A2, "<font> 0047"),
+    (0x1D4A5, "<font> 004A"),
+    (0x1D4A6, "<font> 004B"),
+    (0x1D4A9, "<font> 004E"),
+    (0x1D4AA, "<font> 004F"),
+    (0x1D4AB, "<font> 0050"),
+    (0x1D4AC, "<font> 0051"),
+    (0x1D4AE, "<font> 0053"),
+    (0x1D4AF, "<font> 0054"),
+    (0x1D4B0, "<font> 0055"),
+    (0x1D4B1, "<font> 0056"),
+    (0x1D4B2, "<font> 0057"),
+    (0x1D4B3, "<font> 0058"),
+    (0x1D4B4, "<font> 0059"),
+    (0x1D4B5, "<font> 005A"),
+    (0x1D4B6, "<font> 0061"),
+    (0x1D4B7, "<font> 0062"),
+    (0x1D4B8, "<font> 0063"),
+    (0x1D4B9, "<font> 0064"),
+    (0x1D4BB, "<font> 0066"),
+    (0x1D4BD, "<font> 0068"),
+    (0x1D4BE, "<font> 0069"),
+    (0x1D4BF, "<font> 006A"),
+    (0x1D4C0, "<font> 006B"),
+    (0x1D4C1, "<font> 006C"),
+    (0x1D4C2, "<font> 006D"),
+    (0x1D4C3, "<font> 006E"),
+    (0x1D4C5, "<font> 0070"),
+    (0x1D4C6, "<font> 0071"),
+    (0x1D4C7, "<font> 0072"),
+    (0x1D4C8, "<font> 0073"),
+    (0x1D4C9, "<font> 0074"),
+    (0x1D4CA, "<font> 0075"),
+    (0x1D4CB, "<font> 0076"),
+    (0x1D4CC, "<font> 0077"),
+    (0x1D4CD, "<font> 0078"),
+    (0x1D4CE, "<font> 0079"),
+    (0x1D4CF, "<font> 007A"),
+    (0x1D4D0, "<font> 0041"),
+    (0x1D4D1, "<font> 0042"),
+    (0x1D4D2, "<font> 0043"),
+    (0x1D4D3, "<font> 0044"),
+    (0x1D4D4, "<font> 0045"),
+    (0x1D4D5, "<font> 0046"),
+    (0x1D4D6, "<font> 0047"),
+    (0x1D4D7, "<font> 0048"),
+    (0x1D4D8, "<font> 0049"),
+    (0x1D4D9, "<font> 004A"),
+    (0x1D4DA, "<font> 004B"),
+    (0x1D4DB, "<font> 004C"),
+    (0x1D4DC, "<font> 004D"),
+    (0x1D4DD, "<font> 004E"),
+    (0x1D4DE, "<font> 004F"),
+    (0x1D4DF, "<font> 0050"),
+    (0x1D4E0, "<font> 0051"),
+    (0x1D4E1, "<font> 0052"),
+    (0x1D4E2, "<font> 0053"),
+    (0x1D4E3, "<font> 0054"),
+    (0x1D4E4, "<font> 0055"),
+    (0x1D4E5, "<font> 0056"),
+    (0x1D4E6, "<font> 0057"),
+    (0x1D4E7, "<font> 0058"),
+    (0x1D4E8, "<font> 0059"),
+    (0x1D4E9, "<font> 005A"),
+    (0x1D4EA, "<font> 0061"),
+    (0x1D4EB, "<font> 0062"),
+    (0x1D4EC, "<font> 0063"),
+    (0x1D4ED, "<font> 0064"),
+    (0x1D4EE, "<font> 0065"),
+    (0x1D4EF, "<font> 0066"),
+    (0x1D4F0, "<font> 0067"),
+    (0x1D4F1, "<font> 0068"),
+    (0x1D4F2, "<font> 0069"),
+    (0x1D4F3, "<font> 006A"),
+    (0x1D4F4, "<font> 006B"),
+    (0x1D4F5, "<font> 006C"),
+    (0x1D4F6, "<font> 006D"),
+    (0x1D4F7, "<font> 006E"),
+    (0x1D4F8, "<font> 006F"),
+    (0x1D4F9, "<font> 0070"),
+    (0x1D4FA, "<font> 0071"),
+    (0x1D4FB, "<font> 0072"),
+    (0x1D4FC, "<font> 0073"),
+    (0x1D4FD, "<font> 0074"),
+    (0x1D4FE, "<font> 0075"),
+    (0x1D4FF, "<font> 0076"),
+    (0x1D500, "<font> 0077"),
+    (0x1D501, "<font> 0078"),
+    (0x1D502, "<font> 0079"),
+    (0x1D503, "<font> 007A"),
+    (0x1D504, "<font> 0041"),
+    (0x1D505, "<font> 0042"),
+    (0x1D507, "<font> 0044"),
+    (0x1D508, "<font> 0045"),
+    (0x1D509, "<font> 0046"),
+    (0x1D50A, "<font> 0047"),
+    (0x1D50D, "<font> 004A"),
+    (0x1D50E, "<font> 004B"),
+    (0x1D50F, "<font> 004C"),
+    (0x1D510, "<font> 004D"),
+    (0x1D511, "<font> 004E"),
+    (0x1D512, "<font> 004F"),
+    (0x1D513, "<font> 0050"),
+    (0x1D514, "<font> 0051"),
+    (0x1D516, "<font> 0053"),
+    (0x1D517, "<font> 0054"),
+    (0x1D518, "<font> 0055"),
+    (0x1D519, "<font> 0056"),
+    (0x1D51A, "<font> 0057"),
+    (0x1D51B, "<font> 0058"),
+    (0x1D51C, "<font> 0059"),
+    (0x1D51E, "<font> 0061"),
+    (0x1D51F, "<font> 0062"),
+    (0x1D520, "<font> 0063"),
+    (0x1D521, "<font> 0064"),
+    (0x1D522, "<font> 0065"),
+    (0x1D523, "<font> 0066"),
+    (0x1D524, "<font> 0067"),
+    (0x1D525, "<font> 0068"),
+    (0x1D526, "<font> 0069"),
+    (0x1D527, "<font> 006A"),
+    (0x1D528, "<font> 006B"),
+    (0x1D529, "<font> 006C"),
+    (0x1D52A, "<font> 006D"),
+    (0x1D52B, "<font> 006E"),
+    (0x1D52C, "<font> 006F"),
+    (0x1D52D, "<font> 0070"),
+    (0x1D52E, "<font> 0071"),
+    (0x1D52F, "<font> 0072"),
+    (0x1D530, "<font> 0073"),
+    (0x1D531, "<font> 0074"),
+    (0x1D532, "<font> 0075"),
+    (0x1D533, "<font> 0076"),
+    (0x1D534, "<font> 0077"),
+    (0x1D535, "<font> 0078"),
+    (0x1D536, "<font> 0079"),
+    (0x1D537, "<font> 007A"),
+    (0x1D538, "<font> 0041"),
+    (0x1D539, "<font> 0042"),
+    (0x1D53B, "<font> 0044"),
+    (0x1D53C, "<font> 0045"),
+    (0x1D53D, "<font> 0046"),
+    (0x1D53E, "<font> 0047"),
+    (0x1D540, "<font> 0049"),
+    (0x1D541, "<font> 004A"),
+    (0x1D542, "<font> 004B"),
+    (0x1D543, "<font> 004C"),
+    (0x1D544, "<font> 004D"),
+    (0x1D546, "<font> 004F"),
+    (0x1D54A, "<font> 0053"),
+    (0x1D54B, "<font> 0054"),
+    (0x1D54C, "<font> 0055"),
+    (0x1D54D, "<font> 0056"),
+    (0x1D54E, "<font> 0057"),
+    (0x1D54F, "<font> 0058"),
+    (0x1D550, "<font> 0059"),
+    (0x1D552, "<font> 0061"),
+    (0x1D553, "<font> 0062"),
+    (0x1D554, "<font> 0063"),
+    (0x1D555, "<font> 0064"),
+    (0x1D556, "<font> 0065"),
+    (0x1D557, "<font> 0066"),
+    (0x1D558, "<font> 0067"),
+    (0x1D559, "<font> 0068"),
+    (0x1D55A, "<font> 0069"),
+    (0x1D55B, "<font> 006A"),
+    (0x1D55C, "<font> 006B"),
+    (0x1D55D, "<font> 006C"),
+    (0x1D55E, "<font> 006D"),
+    (0x1D55F, "<font> 006E"),
+    (0x1D560, "<font> 006F"),
+    (0x1D561, "<font> 0070"),
+    (0x1D562, "<font> 0071"),
+    (0x1D563, "<font> 0072"),
+    (0x1D564, "<font> 0073"),
+    (0x1D565, "<font> 0074"),
+    (0x1D566, "<font> 0075"),
+    (0x1D567, "<font> 0076"),
+    (0x1D568, "<font> 0077"),
+    (0x1D569, "<font> 0078"),
+    (0x1D56A, "<font> 0079"),
+    (0x1D56B, "<font> 007A"),
+    (0x1D56C, "<font> 0041"),
+    (0x1D56D, "<font> 0042"),
+    (0x1D56E, "<font> 0043"),
+    (0x1D56F, "<font> 0044"),
+    (0x1D570, "<font> 0045"),
+    (0x1D571, "<font> 0046"),
+    (0x1D572, "<font> 0047"),
+    (0x1D573, "<font> 0048"),
+    (0x1D574, "<font> 0049"),
+    (0x1D575, "<font> 004A"),
+    (0x1D576, "<font> 004B"),
+    (0x1D577, "<font> 004C"),
+    (0x1D578, "<font> 004D"),
+    (0x1D579, "<font> 004E"),
+    (0x1D57A, "<font> 004F"),
+    (0x1D57B, "<font> 0050"),
+    (0x1D57C, "<font> 0051"),
+    (0x1D57D, "<font> 0052"),
+    (0x1D57E, "<font> 0053"),
+    (0x1D57F, "<font> 0054"),
+    (0x1D580, "<font> 0055"),
+    (0x1D581, "<font> 0056"),
+    (0x1D582, "<font> 0057"),
+    (0x1D583, "<font> 0058"),
+    (0x1D584, "<font> 0059"),
+    (0x1D585, "<font> 005A"),
+    (0x1D586, "<font> 0061"),
+    (0x1D587, "<font> 0062"),
+    (0x1D588, "<font> 0063"),
+    (0x1D589, "<font> 0064"),
+    (0x1D58A, "<font> 0065"),
+    (0x1D58B, "<font> 0066"),
+    (0x1D58C, "<font> 0067"),
+    (0x1D58D, "<font> 0068"),
+    (0x1D58E, "<font> 0069"),
+    (0x1D58F, "<font> 006A"),
+    (0x1D590, "<font> 006B"),
+    (0x1D591, "<font> 006C"),
+    (0x1D592, "<font> 006D"),
+    (0x1D593, "<font> 006E"),
+    (0x1D594, "<font> 006F"),
+    (0x1D595, "<font> 0070"),
+    (0x1D596, "<font> 0071"),
+    (0x1D597, "<font> 0072"),
+    (0x1D598, "<font> 0073"),
+    (0x1D599, "<font> 0074"),
+    (0x1D59A, "<font> 0075"),
+    (0x1D59B, "<font> 0076"),
+    (0x1D59C, "<font> 0077"),
+    (0x1D59D, "<font> 0078"),
+    (0x1D59E, "<font> 0079"),
+    (0x1D59F, "<font> 007A"),
+    (0x1D5A0, "<font> 0041"),
+    (0x1D5A1, "<font> 0042"),
+    (0x1D5A2, "<font> 0043"),
+    (0x1D5A3, "<font> 0044"),
+    (0x1D5A4, "<font> 0045"),
+    (0x1D5A5, "<font> 0046"),
+    (0x1D5A6, "<font> 0047"),
+    (0x1D5A7, "<font> 0048"),
+    (0x1D5A8, "<font> 0049"),
+    (0x1D5A9, "<font> 004A"),
+    (0x1D5AA, "<font> 004B"),
+    (0x1D5AB, "<font> 004C"),
+    (0x1D5AC, "<font> 004D"),
+    (0x1D5AD, "<font> 004E"),
+    (0x1D5AE, "<font> 004F"),
+    (0x1D5AF, "<font> 0050"),
+    (0x1D5B0, "<font> 0051"),
+    (0x1D5B1, "<font> 0052"),
+    (0x1D5B2, "<font> 0053"),
+    (0x1D5B3, "<font> 0054"),
+    (0x1D5B4, "<font> 0055"),
+    (0x1D5B5, "<font> 0056"),
+    (0x1D5B6, "<font> 0057"),
+    (0x1D5B7, "<font> 0058"),
+    (0x1D5B8, "<font> 0059"),
+    (0x1D5B9, "<font> 005A"),
+    (0x1D5BA, "<font> 0061"),
+    (0x1D5BB, "<font> 0062"),
+    (0x1D5BC, "<font> 0063"),
+    (0x1D5BD, "<font> 0064"),
+    (0x1D5BE, "<font> 0065"),
+    (0x1D5BF, "<font> 0066"),
+    (0x1D5C0, "<font> 0067"),
+    (0x1D5C1, "<font> 0068"),
+    (0x1D5C2, "<font> 0069"),
+    (0x1D5C3, "<font> 006A"),
+    (0x1D5C4, "<font> 006B"),
+    (0x1D5C5, "<font> 006C"),
+    (0x1D5C6, "<font> 006D"),
+    (0x1D5C7, "<font> 006E"),
+    (0x1D5C8, "<font> 006F"),
+    (0x1D5C9, "<font> 0070"),
+    (0x1D5CA, "<font> 0071"),
+    (0x1D5CB, "<font> 0072"),
+    (0x1D5CC, "<font> 0073"),
+    (0x1D5CD, "<font> 0074"),
+    (0x1D5CE, "<font> 0075"),
+    (0x1D5CF, "<font> 0076"),
+    (0x1D5D0, "<font> 0077"),
+    (0x1D5D1, "<font> 0078"),
+    (0x1D5D2, "<font> 0079"),
+    (0x1D5D3, "<font> 007A"),
+    (0x1D5D4, "<font> 0041"),
+    (0x1D5D5, "<font> 0042"),
+    (0x1D5D6, "<font> 0043"),
+    (0x1D5D7, "<font> 0044"),
+    (0x1D5D8, "<font> 0045"),
+    (0x1D5D9, "<font> 0046"),
+    (0x1D5DA, "<font> 0047"),
+    (0x1D5DB, "<font> 0048"),
+    (0x1D5DC, "<font> 0049"),
+    (0x1D5DD, "<font> 004A"),
+    (0x1D5DE, "<font> 004B"),
+    (0x1D5DF, "<font> 004C"),
+    (0x1D5E0, "<font> 004D"),
+    (0x1D5E1, "<font> 004E"),
+    (0x1D5E2, "<font> 004F"),
+    (0x1D5E3, "<font> 0050"),
+    (0x1D5E4, "<font> 0051"),
+    (0x1D5E5, "<font> 0052"),
+    (0x1D5E6, "<font> 0053"),
+    (0x1D5E7, "<font> 0054"),
+    (0x1D5E8, "<font> 0055"),
+    (0x1D5E9, "<font> 0056"),
+    (0x1D5EA, "<font> 0057"),
+    (0x1D5EB, "<font> 0058"),
+    (0x1D5EC, "<font> 0059"),
+    (0x1D5ED, "<font> 005A"),
+    (0x1D5EE, "<font> 0061"),
+    (0x1D5EF, "<font> 0062"),
+    (0x1D5F0, "<font> 0063"),
+    (0x1D5F1, "<font> 0064"),
+    (0x1D5F2, "<font> 0065"),
+    (0x1D5F3, "<font> 0066"),
+    (0x1D5F4, "<font> 0067"),
+    (0x1D5F5, "<font> 0068"),
+    (0x1D5F6, "<font> 0069"),
+    (0x1D5F7, "<font> 006A"),
+    (0x1D5F8, "<font> 006B"),
+    (0x1D5F9, "<font> 006C"),
+    (0x1D5FA, "<font> 006D"),
+    (0x1D5FB, "<font> 006E"),
+    (0x1D5FC, "<font> 006F"),
+    (0x1D5FD, "<font> 0070"),
+    (0x1D5FE, "<font> 0071"),
+    (0x1D5FF, "<font> 0072"),
+    (0x1D600, "<font> 0073"),
+    (0x1D601, "<font> 0074"),
+    (0x1D602, "<font> 0075"),
+    (0x1D603, "<font> 0076"),
+    (0x1D604, "<font> 0077"),
+    (0x1D605, "<font> 0078"),
+    (0x1D606, "<font> 0079"),
+    (0x1D607, "<font> 007A"),
+    (0x1D608, "<font> 0041"),
+    (0x1D609, "<font> 0042"),
+    (0x1D60A, "<font> 0043"),
+    (0x1D60B, "<font> 0044"),
+    (0x1D60C, "<font> 0045"),
+    (0x1D60D, "<font> 0046"),
+    (0x1D60E, "<font> 0047"),
+    (0x1D60F, "<font> 0048"),
+    (0x1D610, "<font> 0049"),
+    (0x1D611, "<font> 004A"),
+    (0x1D612, "<font> 004B"),
+    (0x1D613, "<font> 004C"),
+    (0x1D614, "<font> 004D"),
+    (0x1D615, "<font> 004E"),
+    (0x1D616, "<font> 004F"),
+    (0x1D617, "<font> 0050"),
+    (0x1D618, "<font> 0051"),
+    (0x1D619, "<font> 0052"),
+    (0x1D61A, "<font> 0053"),
+    (0x1D61B, "<font> 0054"),
+    (0x1D61C, "<font> 0055"),
+    (0x1D61D, "<font> 0056"),
+    (0x1D61E, "<font> 0057"),
+    (0x1D61F, "<font> 0058"),
+    (0x1D620, "<font> 0059"),
+    (0x1D621, "<font> 005A"),
+    (0x1D622, "<font> 0061"),
+    (0x1D623, "<font> 0062"),
+    (0x1D624, "<font> 0063"),
+    (0x1D625, "<font> 0064"),
+    (0x1D626, "<font> 0065"),
+    (0x1D627, "<font> 0066"),
+    (0x1D628, "<font> 0067"),
+    (0x1D629, "<font> 0068"),
+    (0x1D62A, "<font> 0069"),
+    (0x1D62B, "<font> 006A"),
+    (0x1D62C, "<font> 006B"),
+    (0x1D62D, "<font> 006C"),
+    (0x1D62E, "<font> 006D"),
+    (0x1D62F, "<font> 006E"),
+    (0x1D630, "<font> 006F"),
+    (0x1D631, "<font> 0070"),
+    (0x1D632, "<font> 0071"),
+    (0x1D633, "<font> 0072"),
+    (0x1D634, "<font> 0073"),
+    (0x1D635, "<font> 0074"),
+    (0x1D636, "<font> 0075"),
+    (0x1D637, "<font> 0076"),
+    (0x1D638, "<font> 0077"),
+    (0x1D639, "<font> 0078"),
+    (0x1D63A, "<font> 0079"),
+    (0x1D63B, "<font> 007A"),
+    (0x1D63C, "<font> 0041"),
+    (0x1D63D, "<font> 0042"),
+    (0x1D63E, "<font> 0043"),
+    (0x1D63F, "<font> 0044"),
+    (0x1D640, "<font> 0045"),
+    (0x1D641, "<font> 0046"),
+    (0x1D642, "<font> 0047"),
+    (0x1D643, "<font> 0048"),
+    (0x1D644, "<font> 0049"),
+    (0x1D645, "<font> 004A"),
+    (0x1D646, "<font> 004B"),
+    (0x1D647, "<font> 004C"),
+    (0x1D648, "<font> 004D"),
+    (0x1D649, "<font> 004E"),
+    (0x1D64A, "<font> 004F"),
+    (0x1D64B, "<font> 0050"),
+    (0x1D64C, "<font> 0051"),
+    (0x1D64D, "<font> 0052"),
+    (0x1D64E, "<font> 0053"),
+    (0x1D64F, "<font> 0054"),
+    (0x1D650, "<font> 0055"),
+    (0x1D651, "<font> 0056"),
+    (0x1D652, "<font> 0057"),
+    (0x1D653, "<font> 0058"),
+    (0x1D654, "<font> 0059"),
+    (0x1D655, "<font> 005A"),
+    (0x1D656, "<font> 0061"),
+    (0x1D657, "<font> 0062"),
+    (0x1D658, "<font> 0063"),
+    (0x1D659, "<font> 0064"),
+    (0x1D65A, "<font> 0065"),
+    (0x1D65B, "<font> 0066"),
+    (0x1D65C, "<font> 0067"),
+    (0x1D65D, "<font> 0068"),
+    (0x1D65E, "<font> 0069"),
+    (0x1D65F, "<font> 006A"),
+    (0x1D660, "<font> 006B"),
+    (0x1D661, "<font> 006C"),
+    (0x1D662, "<font> 006D"),
+    (0x1D663, "<font> 006E"),
+    (0x1D664, "<font> 006F"),
+    (0x1D665, "<font> 0070"),
+    (0x1D666, "<font> 0071"),
+    (0x1D667, "<font> 0072"),
+    (0x1D668, "<font> 0073"),
+    (0x1D669, "<font> 0074"),
+    (0x1D66A, "<font> 0075"),
+    (0x1D66B, "<font> 0076"),
+    (0x1D66C, "<font> 0077"),
+    (0x1D66D, "<font> 0078"),
+    (0x1D66E, "<font> 0079"),
+    (0x1D66F, "<font> 007A"),
+    (0x1D670, "<font> 0041"),
+    (0x1D671, "<font> 0042"),
+    (0x1D672, "<font> 0043"),
+    (0x1D673, "<font> 0044"),
+    (0x1D674, "<font> 0045"),
+    (0x1D675, "<font> 0046"),
+    (0x1D676, "<font> 0047"),
+    (0x1D677, "<font> 0048"),
+    (0x1D678, "<font> 0049"),
+    (0x1D679, "<font> 004A"),
+    (0x1D67A, "<font> 004B"),
+    (0x1D67B, "<font> 004C"),
+    (0x1D67C, "<font> 004D"),
+    (0x1D67D, "<font> 004E"),
+    (0x1D67E, "<font> 004F"),
+    (0x1D67F, "<font> 0050"),
+    (0x1D680, "<font> 0051"),
+    (0x1D681, "<font> 0052"),
+    (0x1D682, "<font> 0053"),
+    (0x1D683, "<font> 0054"),
+    (0x1D684, "<font> 0055"),
+    (0x1D685, "<font> 0056"),
+    (0x1D686, "<font> 0057"),
+    (0x1D687, "<font> 0058"),
+    (0x1D688, "<font> 0059"),
+    (0x1D689, "<font> 005A"),
+    (0x1D68A, "<font> 0061"),
+    (0x1D68B, "<font> 0062"),
+    (0x1D68C, "<font> 0063"),
+    (0x1D68D, "<font> 0064"),
+    (0x1D68E, "<font> 0065"),
+    (0x1D68F, "<font> 0066"),
+    (0x1D690, "<font> 0067"),
+    (0x1D691, "<font> 0068"),
+    (0x1D692, "<font> 0069"),
+    (0x1D693, "<font> 006A"),
+    (0x1D694, "<font> 006B"),
+    (0x1D695, "<font> 006C"),
+    (0x1D696, "<font> 006D"),
+    (0x1D697, "<font> 006E"),
+    (0x1D698, "<font> 006F"),
+    (0x1D699, "<font> 0070"),
+    (0x1D69A, "<font> 0071"),
+    (0x1D69B, "<font> 0072"),
+    (0x1D69C, "<font> 0073"),
+    (0x1D69D, "<font> 0074"),
+    (0x1D69E, "<font> 0075"),
+    (0x1D69F, "<font> 0076"),
+    (0x1D6A0, "<font> 0077"),
+    (0x1D6A1, "<font> 0078"),
+    (0x1D6A2, "<font> 0079"),
+    (0x1D6A3, "<font> 007A"),
+    (0x1D6A4, "<font> 0131"),
+    (0x1D6A5, "<font> 0237"),
+    (0x1D6A8, "<font> 0391"),
+    (0x1D6A9, "<font> 0392"),
+    (0x1D6AA, "<font> 0393"),
+    (0x1D6AB, "<font> 0394"),
+    (0x1D6AC, "<font> 0395"),
+    (0x1D6AD, "<font> 0396"),
+    (0x1D6AE, "<font> 0397"),
+    (0x1D6AF, "<font> 0398"),
+    (0x1D6B0, "<font> 0399"),
+    (0x1D6B1, "<font> 039A"),
+    (0x1D6B2, "<font> 039B"),
+    (0x1D6B3, "<font> 039C"),
+    (0x1D6B4, "<font> 039D"),
+    (0x1D6B5, "<font> 039E"),
+    (0x1D6B6, "<font> 039F"),
+    (0x1D6B7, "<font> 03A0"),
+    (0x1D6B8, "<font> 03A1"),
+    (0x1D6B9, "<font> 03F4"),
+    (0x1D6BA, "<font> 03A3"),
+    (0x1D6BB, "<font> 03A4"),
+    (0x1D6BC, "<font> 03A5"),
+    (0x1D6BD, "<font> 03A6"),
+    (0x1D6BE, "<font> 03A7"),
+    (0x1D6BF, "<font> 03A8"),
+    (0x1D6C0, "<font> 03A9"),
+    (0x1D6C1, "<font> 2207"),
+    (0x1D6C2, "<font> 03B1"),
+    (0x1D6C3, "<font> 03B2"),
+    (0x1D6C4, "<font> 03B3"),
+    (0x1D6C5, "<font> 03B4"),
+    (0x1D6C6, "<font> 03B5"),
+    (0x1D6C7, "<font> 03B6"),
+    (0x1D6C8, "<font> 03B7"),
+    (0x1D6C9, "<font> 03B8"),
+    (0x1D6CA, "<font> 03B9"),
+    (0x1D6CB, "<font> 03BA"),
+    (0x1D6CC, "<font> 03BB"),
+    (0x1D6CD, "<font> 03BC"),
+    (0x1D6CE, "<font> 03BD"),
+    (0x1D6CF, "<font> 03BE"),
+    (0x1D6D0, "<font> 03BF"),
+    (0x1D6D1, "<font> 03C0"),
+    (0x1D6D2, "<font> 03C1"),
+    (0x1D6D3, "<font> 03C2"),
+    (0x1D6D4, "<font> 03C3"),
+    (0x1D6D5, "<font> 03C4"),
+    (0x1D6D6, "<font> 03C5"),
+    (0x1D6D7, "<font> 03C6"),
+    (0x1D6D8, "<font> 03C7"),
+    (0x1D6D9, "<font> 03C8"),
+    (0x1D6DA, "<font> 03C9"),
+    (0x1D6DB, "<font> 2202"),
+    (0x1D6DC, "<font> 03F5"),
+    (0x1D6DD, "<font> 03D1"),
+    (0x1D6DE, "<font> 03F0"),
+    (0x1D6DF, "<font> 03D5"),
+    (0x1D6E0, "<font> 03F1"),
+    (0x1D6E1, "<font> 03D6"),
+    (0x1D6E2, "<font> 0391"),
+    (0x1D6E3, "<font> 0392"),
+    (0x1D6E4, "<font> 0393"),
+    (0x1D6E5, "<font> 0394"),
+    (0x1D6E6, "<font> 0395"),
+    (0x1D6E7, "<font> 0396"),
+    (0x1D6E8, "<font> 0397"),
+    (0x1D6E9, "<font> 0398"),
+    (0x1D6EA, "<font> 0399"),
+    (0x1D6EB, "<font> 039A"),
+    (0x1D6EC, "<font> 039B"),
+    (0x1D6ED, "<font> 039C"),
+    (0x1D6EE, "<font> 039D"),
+    (0x1D6EF, "<font> 039E"),
+    (0x1D6F0, "<font> 039F"),
+    (0x1D6F1, "<font> 03A0"),
+    (0x1D6F2, "<font> 03A1"),
+    (0x1D6F3, "<font> 03F4"),
+    (0x1D6F4, "<font> 03A3"),
+    (0x1D6F5, "<font> 03A4"),
+    (0x1D6F6, "<font> 03A5"),
+    (0x1D6F7, "<font> 03A6"),
+    (0x1D6F8, "<font> 03A7"),
+    (0x1D6F9, "<font> 03A8"),
+    (0x1D6FA, "<font> 03A9"),
+    (0x1D6FB, "<font> 2207"),
+    (0x1D6FC, "<font> 03B1"),
+    (0x1D6FD, "<font> 03B2"),
+    (0x1D6FE, "<font> 03B3"),
+    (0x1D6FF, "<font> 03B4"),
+    (0x1D700, "<font> 03B5"),
+    (0x1D701, "<font> 03B6"),
+    (0x1D702, "<font> 03B7"),
+    (0x1D703, "<font> 03B8"),
+    (0x1D704, "<font> 03B9"),
+    (0x1D705, "<font> 03BA"),
+    (0x1D706, "<font> 03BB"),
+    (0x1D707, "<font> 03BC"),
+    (0x1D708, "<font> 03BD"),
+    (0x1D709, "<font> 03BE"),
+    (0x1D70A, "<font> 03BF"),
+    (0x1D70B, "<font> 03C0"),
+    (0x1D70C, "<font> 03C1"),
+    (0x1D70D, "<font> 03C2"),
+    (0x1D70E, "<font> 03C3"),
+    (0x1D70F, "<font> 03C4"),
+    (0x1D710, "<font> 03C5"),
+    (0x1D711, "<font> 03C6"),
+    (0x1D712, "<font> 03C7"),
+    (0x1D713, "<font> 03C8"),
+    (0x1D714, "<font> 03C9"),
+    (0x1D715, "<font> 2202"),
+    (0x1D716, "<font> 03F5"),
+    (0x1D717, "<font> 03D1"),
+    (0x1D718, "<font> 03F0"),
+    (0x1D719, "<font> 03D5"),
+    (0x1D71A, "<font> 03F1"),
+    (0x1D71B, "<font> 03D6"),
+    (0x1D71C, "<font> 0391"),
+    (0x1D71D, "<font> 0392"),
+    (0x1D71E, "<font> 0393"),
+    (0x1D71F, "<font> 0394"),
+    (0x1D720, "<font> 0395"),
+    (0x1D721, "<font> 0396"),
+    (0x1D722, "<font> 0397"),
+    (0x1D723, "<font> 0398"),
+    (0x1D724, "<font> 0399"),
+    (0x1D725, "<font> 039A"),
+    (0x1D726, "<font> 039B"),
+    (0x1D727, "<font> 039C"),
+    (0x1D728, "<font> 039D"),
+    (0x1D729, "<font> 039E"),
+    (0x1D72A, "<font> 039F"),
+    (0x1D72B, "<font> 03A0"),
+    (0x1D72C, "<font> 03A1"),
+    (0x1D72D, "<font> 03F4"),
+    (0x1D72E, "<font> 03A3"),
+    (0x1D72F, "<font> 03A4"),
+    (0x1D730, "<font> 03A5"),
+    (0x1D731, "<font> 03A6"),
+    (0x1D732, "<font> 03A7"),
+    (0x1D733, "<font> 03A8"),
+    (0x1D734, "<font> 03A9"),
+    (0x1D735, "<font> 2207"),
+    (0x1D736, "<font> 03B1"),
+    (0x1D737, "<font> 03B2"),
+    (0x1D738, "<font> 03B3"),
+    (0x1D739, "<font> 03B4"),
+    (0x1D73A, "<font> 03B5"),
+    (0x1D73B, "<font> 03B6"),
+    (0x1D73C, "<font> 03B7"),
+    (0x1D73D, "<font> 03B8"),
+    (0x1D73E, "<font> 03B9"),
+    (0x1D73F, "<font> 03BA"),
+    (0x1D740, "<font> 03BB"),
+    (0x1D741, "<font> 03BC"),
+    (0x1D742, "<font> 03BD"),
+    (0x1D743, "<font> 03BE"),
+    (0x1D744, "<font> 03BF"),
+    (0x1D745, "<font> 03C0"),
+    (0x1D746, "<font> 03C1"),
+    (0x1D747, "<font> 03C2"),
+    (0x1D748, "<font> 03C3"),
+    (0x1D749, "<font> 03C4"),
+    (0x1D74A, "<font> 03C5"),
+    (0x1D74B, "<font> 03C6"),
+    (0x1D74C, "<font> 03C7"),
+    (0x1D74D, "<font> 03C8"),
+    (0x1D74E, "<font> 03C9"),
+    (0x1D74F, "<font> 2202"),
+    (0x1D750, "<font> 03F5"),
+    (0x1D751, "<font> 03D1"),
+    (0x1D752, "<font> 03F0"),
+    (0x1D753, "<font> 03D5"),
+    (0x1D754, "<font> 03F1"),
+    (0x1D755, "<font> 03D6"),
+    (0x1D756, "<font> 0391"),
+    (0x1D757, "<font> 0392"),
+    (0x1D758, "<font> 0393"),
+    (0x1D759, "<font> 0394"),
+    (0x1D75A, "<font> 0395"),
+    (0x1D75B, "<font> 0396"),
+    (0x1D75C, "<font> 0397"),
+    (0x1D75D, "<font> 0398"),
+    (0x1D75E, "<font> 0399"),
+    (0x1D75F, "<font> 039A"),
+    (0x1D760, "<font> 039B"),
+    (0x1D761, "<font> 039C"),
+    (0x1D762, "<font> 039D"),
+    (0x1D763, "<font> 039E"),
+    (0x1D764, "<font> 039F"),
+    (0x1D765, "<font> 03A0"),
+    (0x1D766, "<font> 03A1"),
+    (0x1D767, "<font> 03F4"),
+    (0x1D768, "<font> 03A3"),
+    (0x1D769, "<font> 03A4"),
+    (0x1D76A, "<font> 03A5"),
+    (0x1D76B, "<font> 03A6"),
+    (0x1D76C, "<font> 03A7"),
+    (0x1D76D, "<font> 03A8"),
+    (0x1D76E, "<font> 03A9"),
+    (0x1D76F, "<font> 2207"),
+    (0x1D770, "<font> 03B1"),
+    (0x1D771, "<font> 03B2"),
+    (0x1D772, "<font> 03B3"),
+    (0x1D773, "<font> 03B4"),
+    (0x1D774, "<font> 03B5"),
+    (0x1D775, "<font> 03B6"),
+    (0x1D776, "<font> 03B7"),
+    (0x1D777, "<font> 03B8"),
+    (0x1D778, "<font> 03B9"),
+    (0x1D779, "<font> 03BA"),
+    (0x1D77A, "<font> 03BB"),
+    (0x1D77B, "<font> 03BC"),
+    (0x1D77C, "<font> 03BD"),
+    (0x1D77D, "<font> 03BE"),
+    (0x1D77E, "<font> 03BF"),
+    (0x1D77F, "<font> 03C0"),
+    (0x1D780, "<font> 03C1"),
+    (0x1D781, "<font> 03C2"),
+    (0x1D782, "<font> 03C3"),
+    (0x1D783, "<font> 03C4"),
+    (0x1D784, "<font> 03C5"),
+    (0x1D785, "<font> 03C6"),
+    (0x1D786, "<font> 03C7"),
+    (0x1D787, "<font> 03C8"),
+    (0x1D788, "<font> 03C9"),
+    (0x1D789, "<font> 2202"),
+    (0x1D78A, "<font> 03F5"),
+    (0x1D78B, "<font> 03D1"),
+    (0x1D78C, "<font> 03F0"),
+    (0x1D78D, "<font> 03D5"),
+    (0x1D78E, "<font> 03F1"),
+    (0x1D78F, "<font> 03D6"),
+    (0x1D790, "<font> 0391"),
+    (0x1D791, "<font> 0392"),
+    (0x1D792, "<font> 0393"),
+    (0x1D793, "<font> 0394"),
+    (0x1D794, "<font> 0395"),
+    (0x1D795, "<font> 0396"),
+    (0x1D796, "<font> 0397"),
+    (0x1D797, "<font> 0398"),
+    (0x1D798, "<font> 0399"),
+    (0x1D799, "<font> 039A"),
+    (0x1D79A, "<font> 039B"),
+    (0x1D79B, "<font> 039C"),
+    (0x1D79C, "<font> 039D"),
+    (0x1D79D, "<font> 039E"),
+    (0x1D79E, "<font> 039F"),
+    (0x1D79F, "<font> 03A0"),
+    (0x1D7A0, "<font> 03A1"),
+    (0x1D7A1, "<font> 03F4"),
+    (0x1D7A2, "<font> 03A3"),
+    (0x1D7A3, "<font> 03A4"),
+    (0x1D7A4, "<font> 03A5"),
+    (0x1D7A5, "<font> 03A6"),
+    (0x1D7A6, "<font> 03A7"),
+    (0x1D7A7, "<font> 03A8"),
+    (0x1D7A8, "<font> 03A9"),
+    (0x1D7A9, "<font> 2207"),
+    (0x1D7AA, "<font> 03B1"),
+    (0x1D7AB, "<font> 03B2"),
+    (0x1D7AC, "<font> 03B3"),
+    (0x1D7AD, "<font> 03B4"),
+    (0x1D7AE, "<font> 03B5"),
+    (0x1D7AF, "<font> 03B6"),
+    (0x1D7B0, "<font> 03B7"),
+    (0x1D7B1, "<font> 03B8"),
+    (0x1D7B2, "<font> 03B9"),
+    (0x1D7B3, "<font> 03BA"),
+    (0x1D7B4, "<font> 03BB"),
+    (0x1D7B5, "<font> 03BC"),
+    (0x1D7B6, "<font> 03BD"),
+    (0x1D7B7, "<font> 03BE"),
+    (0x1D7B8, "<font> 03BF"),
+    (0x1D7B9, "<font> 03C0"),
+    (0x1D7BA, "<font> 03C1"),
+    (0x1D7BB, "<font> 03C2"),
+    (0x1D7BC, "<font> 03C3"),
+    (0x1D7BD, "<font> 03C4"),
+    (0x1D7BE, "<font> 03C5"),
+    (0x1D7BF, "<font> 03C6"),
+    (0x1D7C0, "<font> 03C7"),
+    (0x1D7C1, "<font> 03C8"),
+    (0x1D7C2, "<font> 03C9"),
+    (0x1D7C3, "<font> 2202"),
+    (0x1D7C4, "<font> 03F5"),
+    (0x1D7C5, "<font> 03D1"),
+    (0x1D7C6, "<font> 03F0"),
+    (0x1D7C7, "<font> 03D5"),
+    (0x1D7C8, "<font> 03F1"),
+    (0x1D7C9, "<font> 03D6"),
+    (0x1D7CA, "<font> 03DC"),
+    (0x1D7CB, "<font> 03DD"),
+    (0x1D7CE, "<font> 0030"),
+    (0x1D7CF, "<font> 0031"),
+    (0x1D7D0, "<font> 0032"),
+    (0x1D7D1, "<font> 0033"),
+    (0x1D7D2, "<font> 0034"),
+    (0x1D7D3, "<font> 0035"),
+    (0x1D7D4, "<font> 0036"),
+    (0x1D7D5, "<font> 0037"),
+    (0x1D7D6, "<font> 0038"),
+    (0x1D7D7, "<font> 0039"),
+    (0x1D7D8, "<font> 0030"),
+    (0x1D7D9, "<font> 0031"),
+    (0x1D7DA, "<font> 0032"),
+    (0x1D7DB, "<font> 0033"),
+    (0x1D7DC, "<font> 0034"),
+    (0x1D7DD, "<font> 0035"),
+    (0x1D7DE, "<font> 0036"),
+    (0x1D7DF, "<font> 0037"),
+    (0x1D7E0, "<font> 0038"),
+    (0x1D7E1, "<font> 0039"),
+    (0x1D7E2, "<font> 0030"),
+    (0x1D7E3, "<font> 0031"),
+    (0x1D7E4, "<font> 0032"),
+    (0x1D7E5, "<font> 0033"),
+    (0x1D7E6, "<font> 0034"),
+    (0x1D7E7, "<font> 0035"),
+    (0x1D7E8, "<font> 0036"),
+    (0x1D7E9, "<font> 0037"),
+    (0x1D7EA, "<font> 0038"),
+    (0x1D7EB, "<font> 0039"),
+    (0x1D7EC, "<font> 0030"),
+    (0x1D7ED, "<font> 0031"),
+    (0x1D7EE, "<font> 0032"),
+    (0x1D7EF, "<font> 0033"),
+    (0x1D7F0, "<font> 0034"),
+    (0x1D7F1, "<font> 0035"),
+    (0x1D7F2, "<font> 0036"),
+    (0x1D7F3, "<font> 0037"),
+    (0x1D7F4, "<font> 0038"),
+    (0x1D7F5, "<font> 0039"),
+    (0x1D7F6, "<font> 0030"),
+    (0x1D7F7, "<font> 0031"),
+    (0x1D7F8, "<font> 0032"),
+    (0x1D7F9, "<font> 0033"),
+    (0x1D7FA, "<font> 0034"),
+    (0x1D7FB, "<font> 0035"),
+    (0x1D7FC, "<font> 0036"),
+    (0x1D7FD, "<font> 0037"),
+    (0x1D7FE, "<font> 0038"),
+    (0x1D7FF, "<font> 0039"),
+    (0x1EE00, "<font> 0627"),
+    (0x1EE01, "<font> 0628"),
+    (0x1EE02, "<font> 062C"),
+    (0x1EE03, "<font> 062F"),
+    (0x1EE05, "<font> 0648"),
+    (0x1EE06, "<font> 0632"),
+    (0x1EE07, "<font> 062D"),
+    (0x1EE08, "<font> 0637"),
+    (0x1EE09, "<font> 064A"),
+    (0x1EE0A, "<font> 0643"),
+    (0x1EE0B, "<font> 0644"),
+    (0x1EE0C, "<font> 0645"),
+    (0x1EE0D, "<font> 0646"),
+    (0x1EE0E, "<font> 0633"),
+    (0x1EE0F, "<font> 0639"),
+    (0x1EE10, "<font> 0641"),
+    (0x1EE11, "<font> 0635"),
+    (0x1EE12, "<font> 0642"),
+    (0x1EE13, "<font> 0631"),
+    (0x1EE14, "<font> 0634"),
+    (0x1EE15, "<font> 062A"),
+    (0x1EE16, "<font> 062B"),
+    (0x1EE17, "<font> 062E"),
+    (0x1EE18, "<font> 0630"),
+    (0x1EE19, "<font> 0636"),
+    (0x1EE1A, "<font> 0638"),
+    (0x1EE1B, "<font> 063A"),
+    (0x1EE1C, "<font> 066E"),
+    (0x1EE1D, "<font> 06BA"),
+    (0x1EE1E, "<font> 06A1"),
+    (0x1EE1F, "<font> 066F"),
+    (0x1EE21, "<font> 0628"),
+    (0x1EE22, "<font> 062C"),
+    (0x1EE24, "<font> 0647"),
+    (0x1EE27, "<font> 062D"),
+    (0x1EE29, "<font> 064A"),
+    (0x1EE2A, "<font> 0643"),
+    (0x1EE2B, "<font> 0644"),
+    (0x1EE2C, "<font> 0645"),
+    (0x1EE2D, "<font> 0646"),
+    (0x1EE2E, "<font> 0633"),
+    (0x1EE2F, "<font> 0639"),
+    (0x1EE30, "<font> 0641"),
+    (0x1EE31, "<font> 0635"),
+    (0x1EE32, "<font> 0642"),
+    (0x1EE34, "<font> 0634"),
+    (0x1EE35, "<font> 062A"),
+    (0x1EE36, "<font> 062B"),
+    (0x1EE37, "<font> 062E"),
+    (0x1EE39, "<font> 0636"),
+    (0x1EE3B, "<font> 063A"),
+    (0x1EE42, "<font> 062C"),
+    (0x1EE47, "<font> 062D"),
+    (0x1EE49, "<font> 064A"),
+    (0x1EE4B, "<font> 0644"),
+    (0x1EE4D, "<font> 0646"),
+    (0x1EE4E, "<font> 0633"),
+    (0x1EE4F, "<font> 0639"),
+    (0x1EE51, "<font> 0635"),
+    (0x1EE52, "<font> 0642"),
+    (0x1EE54, "<font> 0634"),
+    (0x1EE57, "<font> 062E"),
+    (0x1EE59, "<font> 0636"),
+    (0x1EE5B, "<font> 063A"),
+    (0x1EE5D, "<font> 06BA"),
+    (0x1EE5F, "<font> 066F"),
+    (0x1EE61, "<font> 0628"),
+    (0x1EE62, "<font> 062C"),
+    (0x1EE64, "<font> 0647"),
+    (0x1EE67, "<font> 062D"),
+    (0x1EE68, "<font> 0637"),
+    (0x1EE69, "<font> 064A"),
+    (0x1EE6A, "<font> 0643"),
+    (0x1EE6C, "<font> 0645"),
+    (0x1EE6D, "<font> 0646"),
+    (0x1EE6E, "<font> 0633"),
+    (0x1EE6F, "<font> 0639"),
+    (0x1EE70, "<font> 0641"),
+    (0x1EE71, "<font> 0635"),
+    (0x1EE72, "<font> 0642"),
+    (0x1EE74, "<font> 0634"),
+    (0x1EE75, "<font> 062A"),
+    (0x1EE76, "<font> 062B"),
+    (0x1EE77, "<font> 062E"),
+    (0x1EE79, "<font> 0636"),
+    (0x1EE7A, "<font> 0638"),
+    (0x1EE7B, "<font> 063A"),
+    (0x1EE7C, "<font> 066E"),
+    (0x1EE7E, "<font> 06A1"),
+    (0x1EE80, "<font> 0627"),
+    (0x1EE81, "<font> 0628"),
+    (0x1EE82, "<font> 062C"),
+    (0x1EE83, "<font> 062F"),
+    (0x1EE84, "<font> 0647"),
+    (0x1EE85, "<font> 0648"),
+    (0x1EE86, "<font> 0632"),
+    (0x1EE87, "<font> 062D"),
+    (0x1EE88, "<font> 0637"),
+    (0x1EE89, "<font> 064A"),
+    (0x1EE8B, "<font> 0644"),
+    (0x1EE8C, "<font> 0645"),
+    (0x1EE8D, "<font> 0646"),
+    (0x1EE8E, "<font> 0633"),
+    (0x1EE8F, "<font> 0639"),
+    (0x1EE90, "<font> 0641"),
+    (0x1EE91, "<font> 0635"),
+    (0x1EE92, "<font> 0642"),
+    (0x1EE93, "<font> 0631"),
+    (0x1EE94, "<font> 0634"),
+    (0x1EE95, "<font> 062A"),
+    (0x1EE96, "<font> 062B"),
+    (0x1EE97, "<font> 062E"),
+    (0x1EE98, "<font> 0630"),
+    (0x1EE99, "<font> 0636"),
+    (0x1EE9A, "<font> 0638"),
+    (0x1EE9B, "<font> 063A"),
+    (0x1EEA1, "<font> 0628"),
+    (0x1EEA2, "<font> 062C"),
+    (0x1EEA3, "<font> 062F"),
+    (0x1EEA5, "<font> 0648"),
+    (0x1EEA6, "<font> 0632"),
+    (0x1EEA7, "<font> 062D"),
+    (0x1EEA8, "<font> 0637"),
+    (0x1EEA9, "<font> 064A"),
+    (0x1EEAB, "<font> 0644"),
+    (0x1EEAC, "<font> 0645"),
+    (0x1EEAD, "<font> 0646"),
+    (0x1EEAE, "<font> 0633"),
+    (0x1EEAF, "<font> 0639"),
+    (0x1EEB0, "<font> 0641"),
+    (0x1EEB1, "<font> 0635"),
+    (0x1EEB2, "<font> 0642"),
+    (0x1EEB3, "<font> 0631"),
+    (0x1EEB4, "<font> 0634"),
+    (0x1EEB5, "<font> 062A"),
+    (0x1EEB6, "<font> 062B"),
+    (0x1EEB7, "<font> 062E"),
+    (0x1EEB8, "<font> 0630"),
+    (0x1EEB9, "<font> 0636"),
+    (0x1EEBA, "<font> 0638"),
+    (0x1EEBB, "<font> 063A"),
+    (0x1F100, "<compat> 0030 002E"),
+    (0x1F101, "<compat> 0030 002C"),
+    (0x1F102, "<compat> 0031 002C"),
+    (0x1F103, "<compat> 0032 002C"),
+    (0x1F104, "<compat> 0033 002C"),
+    (0x1F105, "<compat> 0034 002C"),
+    (0x1F106, "<compat> 0035 002C"),
+    (0x1F107, "<compat> 0036 002C"),
+    (0x1F108, "<compat> 0037 002C"),
+    (0x1F109, "<compat> 0038 002C"),
+    (0x1F10A, "<compat> 0039 002C"),
+    (0x1F110, "<compat> 0028 0041 0029"),
+    (0x1F111, "<compat> 0028 0042 0029"),
+    (0x1F112, "<compat> 0028 0043 0029"),
+    (0x1F113, "<compat> 0028 0044 0029"),
+    (0x1F114, "<compat> 0028 0045 0029"),
+    (0x1F115, "<compat> 0028 0046 0029"),
+    (0x1F116, "<compat> 0028 0047 0029"),
+    (0x1F117, "<compat> 0028 0048 0029"),
+    (0x1F118, "<compat> 0028 0049 0029"),
+    (0x1F119, "<compat> 0028 004A 0029"),
+    (0x1F11A, "<compat> 0028 004B 0029"),
+    (0x1F11B, "<compat> 0028 004C 0029"),
+    (0x1F11C, "<compat> 0028 004D 0029"),
+    (0x1F11D, "<compat> 0028 004E 0029"),
+    (0x1F11E, "<compat> 0028 004F 0029"),
+    (0x1F11F, "<compat> 0028 0050 0029"),
+    (0x1F120, "<compat> 0028 0051 0029"),
+    (0x1F121, "<compat> 0028 0052 0029"),
+    (0x1F122, "<compat> 0028 0053 0029"),
+    (0x1F123, "<compat> 0028 0054 0029"),
+    (0x1F124, "<compat> 0028 0055 0029"),
+    (0x1F125, "<compat> 0028 0056 0029"),
+    (0x1F126, "<compat> 0028 0057 0029"),
+    (0x1F127, "<compat> 0028 0058 0029"),
+    (0x1F128, "<compat> 0028 0059 0029"),
+    (0x1F129, "<compat> 0028 005A 0029"),
+    (0x1F12A, "<compat> 3014 0053 3015"),
+    (0x1F12B, "<circle> 0043"),
+    (0x1F12C, "<circle> 0052"),
+    (0x1F12D, "<circle> 0043 0044"),
+    (0x1F12E, "<circle> 0057 005A"),
+    (0x1F130, "<square> 0041"),
+    (0x1F131, "<square> 0042"),
+    (0x1F132, "<square> 0043"),
+    (0x1F133, "<square> 0044"),
+    (0x1F134, "<square> 0045"),
+    (0x1F135, "<square> 0046"),
+    (0x1F136, "<square> 0047"),
+    (0x1F137, "<square> 0048"),
+    (0x1F138, "<square> 0049"),
+    (0x1F139, "<square> 004A"),
+    (0x1F13A, "<square> 004B"),
+    (0x1F13B, "<square> 004C"),
+    (0x1F13C, "<square> 004D"),
+    (0x1F13D, "<square> 004E"),
+    (0x1F13E, "<square> 004F"),
+    (0x1F13F, "<square> 0050"),
+    (0x1F140, "<square> 0051"),
+    (0x1F141, "<square> 0052"),
+    (0x1F142, "<square> 0053"),
+    (0x1F143, "<square> 0054"),
+    (0x1F144, "<square> 0055"),
+    (0x1F145, "<square> 0056"),
+    (0x1F146, "<square> 0057"),
+    (0x1F147, "<square> 0058"),
+    (0x1F148, "<square> 0059"),
+    (0x1F149, "<square> 005A"),
+    (0x1F14A, "<square> 0048 0056"),
+    (0x1F14B, "<square> 004D 0056"),
+    (0x1F14C, "<square> 0053 0044"),
+    (0x1F14D, "<square> 0053 0053"),
+    (0x1F14E, "<square> 0050 0050 0056"),
+    (0x1F14F, "<square> 0057 0043"),
+    (0x1F16A, "<super> 004D 0043"),
+    (0x1F16B, "<super> 004D 0044"),
+    (0x1F16C, "<super> 004D 0052"),
+    (0x1F190, "<square> 0044 004A"),
+    (0x1F200, "<square> 307B 304B"),
+    (0x1F201, "<square> 30B3 30B3"),
+    (0x1F202, "<square> 30B5"),
+    (0x1F210, "<square> 624B"),
+    (0x1F211, "<square> 5B57"),
+    (0x1F212, "<square> 53CC"),
+    (0x1F213, "<square> 30C7"),
+    (0x1F214, "<square> 4E8C"),
+    (0x1F215, "<square> 591A"),
+    (0x1F216, "<square> 89E3"),
+    (0x1F217, "<square> 5929"),
+    (0x1F218, "<square> 4EA4"),
+    (0x1F219, "<square> 6620"),
+    (0x1F21A, "<square> 7121"),
+    (0x1F21B, "<square> 6599"),
+    (0x1F21C, "<square> 524D"),
+    (0x1F21D, "<square> 5F8C"),
+    (0x1F21E, "<square> 518D"),
+    (0x1F21F, "<square> 65B0"),
+    (0x1F220, "<square> 521D"),
+    (0x1F221, "<square> 7D42"),
+    (0x1F222, "<square> 751F"),
+    (0x1F223, "<square> 8CA9"),
+    (0x1F224, "<square> 58F0"),
+    (0x1F225, "<square> 5439"),
+    (0x1F226, "<square> 6F14"),
+    (0x1F227, "<square> 6295"),
+    (0x1F228, "<square> 6355"),
+    (0x1F229, "<square> 4E00"),
+    (0x1F22A, "<square> 4E09"),
+    (0x1F22B, "<square> 904A"),
+    (0x1F22C, "<square> 5DE6"),
+    (0x1F22D, "<square> 4E2D"),
+    (0x1F22E, "<square> 53F3"),
+    (0x1F22F, "<square> 6307"),
+    (0x1F230, "<square> 8D70"),
+    (0x1F231, "<square> 6253"),
+    (0x1F232, "<square> 7981"),
+    (0x1F233, "<square> 7A7A"),
+    (0x1F234, "<square> 5408"),
+    (0x1F235, "<square> 6E80"),
+    (0x1F236, "<square> 6709"),
+    (0x1F237, "<square> 6708"),
+    (0x1F238, "<square> 7533"),
+    (0x1F239, "<square> 5272"),
+    (0x1F23A, "<square> 55B6"),
+    (0x1F23B, "<square> 914D"),
+    (0x1F240, "<compat> 3014 672C 3015"),
+    (0x1F241, "<compat> 3014 4E09 3015"),
+    (0x1F242, "<compat> 3014 4E8C 3015"),
+    (0x1F243, "<compat> 3014 5B89 3015"),
+    (0x1F244, "<compat> 3014 70B9 3015"),
+    (0x1F245, "<compat> 3014 6253 3015"),
+    (0x1F246, "<compat> 3014 76D7 3015"),
+    (0x1F247, "<compat> 3014 52DD 3015"),
+    (0x1F248, "<compat> 3014 6557 3015"),
+    (0x1F250, "<circle> 5F97"),
+    (0x1F251, "<circle> 53EF"),
+    (0x1FBF0, "<font> 0030"),
+    (0x1FBF1, "<font> 0031"),
+    (0x1FBF2, "<font> 0032"),
+    (0x1FBF3, "<font> 0033"),
+    (0x1FBF4, "<font> 0034"),
+    (0x1FBF5, "<font> 0035"),
+    (0x1FBF6, "<font> 0036"),
+    (0x1FBF7, "<font> 0037"),
+    (0x1FBF8, "<font> 0038"),
+    (0x1FBF9, "<font> 0039"),
+    (0x2F800, "4E3D"),
+    (0x2F801, "4E38"),
+    (0x2F802, "4E41"),
+    (0x2F803, "20122"),
+    (0x2F804, "4F60"),
+    (0x2F805, "4FAE"),
+    (0x2F806, "4FBB"),
+    (0x2F807, "5002"),
+    (0x2F808, "507A"),
+    (0x2F809, "5099"),
+    (0x2F80A, "50E7"),
+    (0x2F80B, "50CF"),
+    (0x2F80C, "349E"),
+    (0x2F80D, "2063A"),
+    (0x2F80E, "514D"),
+    (0x2F80F, "5154"),
+    (0x2F810, "5164"),
+    (0x2F811, "5177"),
+    (0x2F812, "2051C"),
+    (0x2F813, "34B9"),
+    (0x2F814, "5167"),
+    (0x2F815, "518D"),
+    (0x2F816, "2054B"),
+    (0x2F817, "5197"),
+    (0x2F818, "51A4"),
+    (0x2F819, "4ECC"),
+    (0x2F81A, "51AC"),
+    (0x2F81B, "51B5"),
+    (0x2F81C, "291DF"),
+    (0x2F81D, "51F5"),
+    (0x2F81E, "5203"),
+    (0x2F81F, "34DF"),
+    (0x2F820, "523B"),
+    (0x2F821, "5246"),
+    (0x2F822, "5272"),
+    (0x2F823, "5277"),
+    (0x2F824, "3515"),
+    (0x2F825, "52C7"),
+    (0x2F826, "52C9"),
+    (0x2F827, "52E4"),
+    (0x2F828, "52FA"),
+    (0x2F829, "5305"),
+    (0x2F82A, "5306"),
+    (0x2F82B, "5317"),
+    (0x2F82C, "5349"),
+    (0x2F82D, "5351"),
+    (0x2F82E, "535A"),
+    (0x2F82F, "5373"),
+    (0x2F830, "537D"),
+    (0x2F831, "537F"),
+    (0x2F832, "537F"),
+    (0x2F833, "537F"),
+    (0x2F834, "20A2C"),
+    (0x2F835, "7070"),
+    (0x2F836, "53CA"),
+    (0x2F837, "53DF"),
+    (0x2F838, "20B63"),
+    (0x2F839, "53EB"),
+    (0x2F83A, "53F1"),
+    (0x2F83B, "5406"),
+    (0x2F83C, "549E"),
+    (0x2F83D, "5438"),
+    (0x2F83E, "5448"),
+    (0x2F83F, "5468"),
+    (0x2F840, "54A2"),
+    (0x2F841, "54F6"),
+    (0x2F842, "5510"),
+    (0x2F843, "5553"),
+    (0x2F844, "5563"),
+    (0x2F845, "5584"),
+    (0x2F846, "5584"),
+    (0x2F847, "5599"),
+    (0x2F848, "55AB"),
+    (0x2F849, "55B3"),
+    (0x2F84A, "55C2"),
+    (0x2F84B, "5716"),
+    (0x2F84C, "5606"),
+    (0x2F84D, "5717"),
+    (0x2F84E, "5651"),
+    (0x2F84F, "5674"),
+    (0x2F850, "5207"),
+    (0x2F851, "58EE"),
+    (0x2F852, "57CE"),
+    (0x2F853, "57F4"),
+    (0x2F854, "580D"),
+    (0x2F855, "578B"),
+    (0x2F856, "5832"),
+    (0x2F857, "5831"),
+    (0x2F858, "58AC"),
+    (0x2F859, "214E4"),
+    (0x2F85A, "58F2"),
+    (0x2F85B, "58F7"),
+    (0x2F85C, "5906"),
+    (0x2F85D, "591A"),
+    (0x2F85E, "5922"),
+    (0x2F85F, "5962"),
+    (0x2F860, "216A8"),
+    (0x2F861, "216EA"),
+    (0x2F862, "59EC"),
+    (0x2F863, "5A1B"),
+    (0x2F864, "5A27"),
+    (0x2F865, "59D8"),
+    (0x2F866, "5A66"),
+    (0x2F867, "36EE"),
+    (0x2F868, "36FC"),
+    (0x2F869, "5B08"),
+    (0x2F86A, "5B3E"),
+    (0x2F86B, "5B3E"),
+    (0x2F86C, "219C8"),
+    (0x2F86D, "5BC3"),
+    (0x2F86E, "5BD8"),
+    (0x2F86F, "5BE7"),
+    (0x2F870, "5BF3"),
+    (0x2F871, "21B18"),
+    (0x2F872, "5BFF"),
+    (0x2F873, "5C06"),
+    (0x2F874, "5F53"),
+    (0x2F875, "5C22"),
+    (0x2F876, "3781"),
+    (0x2F877, "5C60"),
+    (0x2F878, "5C6E"),
+    (0x2F879, "5CC0"),
+    (0x2F87A, "5C8D"),
+    (0x2F87B, "21DE4"),
+    (0x2F87C, "5D43"),
+    (0x2F87D, "21DE6"),
+    (0x2F87E, "5D6E"),
+    (0x2F87F, "5D6B"),
+    (0x2F880, "5D7C"),
+    (0x2F881, "5DE1"),
+    (0x2F882, "5DE2"),
+    (0x2F883, "382F"),
+    (0x2F884, "5DFD"),
+    (0x2F885, "5E28"),
+    (0x2F886, "5E3D"),
+    (0x2F887, "5E69"),
+    (0x2F888, "3862"),
+    (0x2F889, "22183"),
+    (0x2F88A, "387C"),
+    (0x2F88B, "5EB0"),
+    (0x2F88C, "5EB3"),
+    (0x2F88D, "5EB6"),
+    (0x2F88E, "5ECA"),
+    (0x2F88F, "2A392"),
+    (0x2F890, "5EFE"),
+    (0x2F891, "22331"),
+    (0x2F892, "22331"),
+    (0x2F893, "8201"),
+    (0x2F894, "5F22"),
+    (0x2F895, "5F22"),
+    (0x2F896, "38C7"),
+    (0x2F897, "232B8"),
+    (0x2F898, "261DA"),
+    (0x2F899, "5F62"),
+    (0x2F89A, "5F6B"),
+    (0x2F89B, "38E3"),
+    (0x2F89C, "5F9A"),
+    (0x2F89D, "5FCD"),
+    (0x2F89E, "5FD7"),
+    (0x2F89F, "5FF9"),
+    (0x2F8A0, "6081"),
+    (0x2F8A1, "393A"),
+    (0x2F8A2, "391C"),
+    (0x2F8A3, "6094"),
+    (0x2F8A4, "226D4"),
+    (0x2F8A5, "60C7"),
+    (0x2F8A6, "6148"),
+    (0x2F8A7, "614C"),
+    (0x2F8A8, "614E"),
+    (0x2F8A9, "614C"),
+    (0x2F8AA, "617A"),
+    (0x2F8AB, "618E"),
+    (0x2F8AC, "61B2"),
+    (0x2F8AD, "61A4"),
+    (0x2F8AE, "61AF"),
+    (0x2F8AF, "61DE"),
+    (0x2F8B0, "61F2"),
+    (0x2F8B1, "61F6"),
+    (0x2F8B2, "6210"),
+    (0x2F8B3, "621B"),
+    (0x2F8B4, "625D"),
+    (0x2F8B5, "62B1"),
+    (0x2F8B6, "62D4"),
+    (0x2F8B7, "6350"),
+    (0x2F8B8, "22B0C"),
+    (0x2F8B9, "633D"),
+    (0x2F8BA, "62FC"),
+    (0x2F8BB, "6368"),
+    (0x2F8BC, "6383"),
+    (0x2F8BD, "63E4"),
+    (0x2F8BE, "22BF1"),
+    (0x2F8BF, "6422"),
+    (0x2F8C0, "63C5"),
+    (0x2F8C1, "63A9"),
+    (0x2F8C2, "3A2E"),
+    (0x2F8C3, "6469"),
+    (0x2F8C4, "647E"),
+    (0x2F8C5, "649D"),
+    (0x2F8C6, "6477"),
+    (0x2F8C7, "3A6C"),
+    (0x2F8C8, "654F"),
+    (0x2F8C9, "656C"),
+    (0x2F8CA, "2300A"),
+    (0x2F8CB, "65E3"),
+    (0x2F8CC, "66F8"),
+    (0x2F8CD, "6649"),
+    (0x2F8CE, "3B19"),
+    (0x2F8CF, "6691"),
+    (0x2F8D0, "3B08"),
+    (0x2F8D1, "3AE4"),
+    (0x2F8D2, "5192"),
+    (0x2F8D3, "5195"),
+    (0x2F8D4, "6700"),
+    (0x2F8D5, "669C"),
+    (0x2F8D6, "80AD"),
+    (0x2F8D7, "43D9"),
+    (0x2F8D8, "6717"),
+    (0x2F8D9, "671B"),
+    (0x2F8DA, "6721"),
+    (0x2F8DB, "675E"),
+    (0x2F8DC, "6753"),
+    (0x2F8DD, "233C3"),
+    (0x2F8DE, "3B49"),
+    (0x2F8DF, "67FA"),
+    (0x2F8E0, "6785"),
+    (0x2F8E1, "6852"),
+    (0x2F8E2, "6885"),
+    (0x2F8E3, "2346D"),
+    (0x2F8E4, "688E"),
+    (0x2F8E5, "681F"),
+    (0x2F8E6, "6914"),
+    (0x2F8E7, "3B9D"),
+    (0x2F8E8, "6942"),
+    (0x2F8E9, "69A3"),
+    (0x2F8EA, "69EA"),
+    (0x2F8EB, "6AA8"),
+    (0x2F8EC, "236A3"),
+    (0x2F8ED, "6ADB"),
+    (0x2F8EE, "3C18"),
+    (0x2F8EF, "6B21"),
+    (0x2F8F0, "238A7"),
+    (0x2F8F1, "6B54"),
+    (0x2F8F2, "3C4E"),
+    (0x2F8F3, "6B72"),
+    (0x2F8F4, "6B9F"),
+    (0x2F8F5, "6BBA"),
+    (0x2F8F6, "6BBB"),
+    (0x2F8F7, "23A8D"),
+    (0x2F8F8, "21D0B"),
+    (0x2F8F9, "23AFA"),
+    (0x2F8FA, "6C4E"),
+    (0x2F8FB, "23CBC"),
+    (0x2F8FC, "6CBF"),
+    (0x2F8FD, "6CCD"),
+    (0x2F8FE, "6C67"),
+    (0x2F8FF, "6D16"),
+    (0x2F900, "6D3E"),
+    (0x2F901, "6D77"),
+    (0x2F902, "6D41"),
+    (0x2F903, "6D69"),
+    (0x2F904, "6D78"),
+    (0x2F905, "6D85"),
+    (0x2F906, "23D1E"),
+    (0x2F907, "6D34"),
+    (0x2F908, "6E2F"),
+    (0x2F909, "6E6E"),
+    (0x2F90A, "3D33"),
+    (0x2F90B, "6ECB"),
+    (0x2F90C, "6EC7"),
+    (0x2F90D, "23ED1"),
+    (0x2F90E, "6DF9"),
+    (0x2F90F, "6F6E"),
+    (0x2F910, "23F5E"),
+    (0x2F911, "23F8E"),
+    (0x2F912, "6FC6"),
+    (0x2F913, "7039"),
+    (0x2F914, "701E"),
+    (0x2F915, "701B"),
+    (0x2F916, "3D96"),
+    (0x2F917, "704A"),
+    (0x2F918, "707D"),
+    (0x2F919, "7077"),
+    (0x2F91A, "70AD"),
+    (0x2F91B, "20525"),
+    (0x2F91C, "7145"),
+    (0x2F91D, "24263"),
+    (0x2F91E, "719C"),
+    (0x2F91F, "243AB"),
+    (0x2F920, "7228"),
+    (0x2F921, "7235"),
+    (0x2F922, "7250"),
+    (0x2F923, "24608"),
+    (0x2F924, "7280"),
+    (0x2F925, "7295"),
+    (0x2F926, "24735"),
+    (0x2F927, "24814"),
+    (0x2F928, "737A"),
+    (0x2F929, "738B"),
+    (0x2F92A, "3EAC"),
+    (0x2F92B, "73A5"),
+    (0x2F92C, "3EB8"),
+    (0x2F92D, "3EB8"),
+    (0x2F92E, "7447"),
+    (0x2F92F, "745C"),
+    (0x2F930, "7471"),
+    (0x2F931, "7485"),
+    (0x2F932, "74CA"),
+    (0x2F933, "3F1B"),
+    (0x2F934, "7524"),
+    (0x2F935, "24C36"),
+    (0x2F936, "753E"),
+    (0x2F937, "24C92"),
+    (0x2F938, "7570"),
+    (0x2F939, "2219F"),
+    (0x2F93A, "7610"),
+    (0x2F93B, "24FA1"),
+    (0x2F93C, "24FB8"),
+    (0x2F93D, "25044"),
+    (0x2F93E, "3FFC"),
+    (0x2F93F, "4008"),
+    (0x2F940, "76F4"),
+    (0x2F941, "250F3"),
+    (0x2F942, "250F2"),
+    (0x2F943, "25119"),
+    (0x2F944, "25133"),
+    (0x2F945, "771E"),
+    (0x2F946, "771F"),
+    (0x2F947, "771F"),
+    (0x2F948, "774A"),
+    (0x2F949, "4039"),
+    (0x2F94A, "778B"),
+    (0x2F94B, "4046"),
+    (0x2F94C, "4096"),
+    (0x2F94D, "2541D"),
+    (0x2F94E, "784E"),
+    (0x2F94F, "788C"),
+    (0x2F950, "78CC"),
+    (0x2F951, "40E3"),
+    (0x2F952, "25626"),
+    (0x2F953, "7956"),
+    (0x2F954, "2569A"),
+    (0x2F955, "256C5"),
+    (0x2F956, "798F"),
+    (0x2F957, "79EB"),
+    (0x2F958, "412F"),
+    (0x2F959, "7A40"),
+    (0x2F95A, "7A4A"),
+    (0x2F95B, "7A4F"),
+    (0x2F95C, "2597C"),
+    (0x2F95D, "25AA7"),
+    (0x2F95E, "25AA7"),
+    (0x2F95F, "7AEE"),
+    (0x2F960, "4202"),
+    (0x2F961, "25BAB"),
+    (0x2F962, "7BC6"),
+    (0x2F963, "7BC9"),
+    (0x2F964, "4227"),
+    (0x2F965, "25C80"),
+    (0x2F966, "7CD2"),
+    (0x2F967, "42A0"),
+    (0x2F968, "7CE8"),
+    (0x2F969, "7CE3"),
+    (0x2F96A, "7D00"),
+    (0x2F96B, "25F86"),
+    (0x2F96C, "7D63"),
+    (0x2F96D, "4301"),
+    (0x2F96E, "7DC7"),
+    (0x2F96F, "7E02"),
+    (0x2F970, "7E45"),
+    (0x2F971, "4334"),
+    (0x2F972, "26228"),
+    (0x2F973, "26247"),
+    (0x2F974, "4359"),
+    (0x2F975, "262D9"),
+    (0x2F976, "7F7A"),
+    (0x2F977, "2633E"),
+    (0x2F978, "7F95"),
+    (0x2F979, "7FFA"),
+    (0x2F97A, "8005"),
+    (0x2F97B, "264DA"),
+    (0x2F97C, "26523"),
+    (0x2F97D, "8060"),
+    (0x2F97E, "265A8"),
+    (0x2F97F, "8070"),
+    (0x2F980, "2335F"),
+    (0x2F981, "43D5"),
+    (0x2F982, "80B2"),
+    (0x2F983, "8103"),
+    (0x2F984, "440B"),
+    (0x2F985, "813E"),
+    (0x2F986, "5AB5"),
+    (0x2F987, "267A7"),
+    (0x2F988, "267B5"),
+    (0x2F989, "23393"),
+    (0x2F98A, "2339C"),
+    (0x2F98B, "8201"),
+    (0x2F98C, "8204"),
+    (0x2F98D, "8F9E"),
+    (0x2F98E, "446B"),
+    (0x2F98F, "8291"),
+    (0x2F990, "828B"),
+    (0x2F991, "829D"),
+    (0x2F992, "52B3"),
+    (0x2F993, "82B1"),
+    (0x2F994, "82B3"),
+    (0x2F995, "82BD"),
+    (0x2F996, "82E6"),
+    (0x2F997, "26B3C"),
+    (0x2F998, "82E5"),
+    (0x2F999, "831D"),
+    (0x2F99A, "8363"),
+    (0x2F99B, "83AD"),
+    (0x2F99C, "8323"),
+    (0x2F99D, "83BD"),
+    (0x2F99E, "83E7"),
+    (0x2F99F, "8457"),
+    (0x2F9A0, "8353"),
+    (0x2F9A1, "83CA"),
+    (0x2F9A2, "83CC"),
+    (0x2F9A3, "83DC"),
+    (0x2F9A4, "26C36"),
+    (0x2F9A5, "26D6B"),
+    (0x2F9A6, "26CD5"),
+    (0x2F9A7, "452B"),
+    (0x2F9A8, "84F1"),
+    (0x2F9A9, "84F3"),
+    (0x2F9AA, "8516"),
+    (0x2F9AB, "273CA"),
+    (0x2F9AC, "8564"),
+    (0x2F9AD, "26F2C"),
+    (0x2F9AE, "455D"),
+    (0x2F9AF, "4561"),
+    (0x2F9B0, "26FB1"),
+    (0x2F9B1, "270D2"),
+    (0x2F9B2, "456B"),
+    (0x2F9B3, "8650"),
+    (0x2F9B4, "865C"),
+    (0x2F9B5, "8667"),
+    (0x2F9B6, "8669"),
+    (0x2F9B7, "86A9"),
+    (0x2F9B8, "8688"),
+    (0x2F9B9, "870E"),
+    (0x2F9BA, "86E2"),
+    (0x2F9BB, "8779"),
+    (0x2F9BC, "8728"),
+    (0x2F9BD, "876B"),
+    (0x2F9BE, "8786"),
+    (0x2F9BF, "45D7"),
+    (0x2F9C0, "87E1"),
+    (0x2F9C1, "8801"),
+    (0x2F9C2, "45F9"),
+    (0x2F9C3, "8860"),
+    (0x2F9C4, "8863"),
+    (0x2F9C5, "27667"),
+    (0x2F9C6, "88D7"),
+    (0x2F9C7, "88DE"),
+    (0x2F9C8, "4635"),
+    (0x2F9C9, "88FA"),
+    (0x2F9CA, "34BB"),
+    (0x2F9CB, "278AE"),
+    (0x2F9CC, "27966"),
+    (0x2F9CD, "46BE"),
+    (0x2F9CE, "46C7"),
+    (0x2F9CF, "8AA0"),
+    (0x2F9D0, "8AED"),
+    (0x2F9D1, "8B8A"),
+    (0x2F9D2, "8C55"),
+    (0x2F9D3, "27CA8"),
+    (0x2F9D4, "8CAB"),
+    (0x2F9D5, "8CC1"),
+    (0x2F9D6, "8D1B"),
+    (0x2F9D7, "8D77"),
+    (0x2F9D8, "27F2F"),
+    (0x2F9D9, "20804"),
+    (0x2F9DA, "8DCB"),
+    (0x2F9DB, "8DBC"),
+    (0x2F9DC, "8DF0"),
+    (0x2F9DD, "208DE"),
+    (0x2F9DE, "8ED4"),
+    (0x2F9DF, "8F38"),
+    (0x2F9E0, "285D2"),
+    (0x2F9E1, "285ED"),
+    (0x2F9E2, "9094"),
+    (0x2F9E3, "90F1"),
+    (0x2F9E4, "9111"),
+    (0x2F9E5, "2872E"),
+    (0x2F9E6, "911B"),
+    (0x2F9E7, "9238"),
+    (0x2F9E8, "92D7"),
+    (0x2F9E9, "92D8"),
+    (0x2F9EA, "927C"),
+    (0x2F9EB, "93F9"),
+    (0x2F9EC, "9415"),
+    (0x2F9ED, "28BFA"),
+    (0x2F9EE, "958B"),
+    (0x2F9EF, "4995"),
+    (0x2F9F0, "95B7"),
+    (0x2F9F1, "28D77"),
+    (0x2F9F2, "49E6"),
+    (0x2F9F3, "96C3"),
+    (0x2F9F4, "5DB2"),
+    (0x2F9F5, "9723"),
+    (0x2F9F6, "29145"),
+    (0x2F9F7, "2921A"),
+    (0x2F9F8, "4A6E"),
+    (0x2F9F9, "4A76"),
+    (0x2F9FA, "97E0"),
+    (0x2F9FB, "2940A"),
+    (0x2F9FC, "4AB2"),
+    (0x2F9FD, "29496"),
+    (0x2F9FE, "980B"),
+    (0x2F9FF, "980B"),
+    (0x2FA00, "9829"),
+    (0x2FA01, "295B6"),
+    (0x2FA02, "98E2"),
+    (0x2FA03, "4B33"),
+    (0x2FA04, "9929"),
+    (0x2FA05, "99A7"),
+    (0x2FA06, "99C2"),
+    (0x2FA07, "99FE"),
+    (0x2FA08, "4BCE"),
+    (0x2FA09, "29B30"),
+    (0x2FA0A, "9B12"),
+    (0x2FA0B, "9C40"),
+    (0x2FA0C, "9CFD"),
+    (0x2FA0D, "4CCE"),
+    (0x2FA0E, "4CED"),
+    (0x2FA0F, "9D67"),
+    (0x2FA10, "2A0CE"),
+    (0x2FA11, "4CF8"),
+    (0x2FA12, "2A105"),
+    (0x2FA13, "2A20E"),
+    (0x2FA14, "2A291"),
+    (0x2FA15, "9EBB"),
+    (0x2FA16, "4D56"),
+    (0x2FA17, "9EF9"),
+    (0x2FA18, "9EFE"),
+    (0x2FA19, "9F05"),
+    (0x2FA1A, "9F0F"),
+    (0x2FA1B, "9F16"),
+    (0x2FA1C, "9F3B"),
+    (0x2FA1D, "2A600"),
+];
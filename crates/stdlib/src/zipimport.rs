@@ -0,0 +1,251 @@
+// Native accelerator for `zipimport`: parses a zip archive's central directory
+// in one pass instead of field-by-field in Python. `Lib/zipimport.py` falls
+// back to its pure-Python reader when this module is unavailable.
+
+pub(crate) use _zipimport::module_def;
+
+#[pymodule]
+mod _zipimport {
+    use crate::vm::{
+        PyObjectRef, PyResult, VirtualMachine, builtins::PyStrRef, convert::ToPyObject,
+    };
+    use std::io::{Read, Seek, SeekFrom};
+
+    const MAX_COMMENT_LEN: u64 = (1 << 16) - 1;
+    const END_CENTRAL_DIR_SIZE: usize = 22;
+    const END_CENTRAL_DIR_SIZE_64: usize = 56;
+    const END_CENTRAL_DIR_LOCATOR_SIZE_64: usize = 20;
+    const STRING_END_ARCHIVE: &[u8] = b"PK\x05\x06";
+    const STRING_END_ZIP_64: &[u8] = b"PK\x06\x06";
+    const STRING_CENTRAL_DIR_ENTRY: &[u8] = b"PK\x01\x02";
+    const MAX_UINT32: u64 = 0xffff_ffff;
+    const ZIP64_EXTRA_TAG: u16 = 1;
+
+    // High half of the cp437 table, used for historical (non-UTF-8) zip entry
+    // names; mirrors the table in Lib/zipimport.py, which exists to avoid
+    // importing the encodings package during bootstrap.
+    #[rustfmt::skip]
+    const CP437_HIGH: [char; 128] = [
+        'Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç', 'ê', 'ë', 'è', 'ï', 'î', 'ì', 'Ä', 'Å',
+        'É', 'æ', 'Æ', 'ô', 'ö', 'ò', 'û', 'ù', 'ÿ', 'Ö', 'Ü', '¢', '£', '¥', '₧', 'ƒ',
+        'á', 'í', 'ó', 'ú', 'ñ', 'Ñ', 'ª', 'º', '¿', '⌐', '¬', '½', '¼', '¡', '«', '»',
+        '░', '▒', '▓', '│', '┤', '╡', '╢', '╖', '╕', '╣', '║', '╗', '╝', '╜', '╛', '┐',
+        '└', '┴', '┬', '├', '─', '┼', '╞', '╟', '╚', '╔', '╩', '╦', '╠', '═', '╬', '╧',
+        '╨', '╤', '╥', '╙', '╘', '╒', '╓', '╫', '╪', '┘', '┌', '█', '▄', '▌', '▐', '▀',
+        'α', 'ß', 'Γ', 'π', 'Σ', 'σ', 'µ', 'τ', 'Φ', 'Θ', 'Ω', 'δ', '∞', 'φ', 'ε', '∩',
+        '≡', '±', '≥', '≤', '⌠', '⌡', '÷', '≈', '°', '∙', '·', '√', 'ⁿ', '²', '■', '\u{a0}',
+    ];
+
+    fn u16le(buf: &[u8]) -> u16 {
+        u16::from_le_bytes(buf[..2].try_into().unwrap())
+    }
+
+    fn u32le(buf: &[u8]) -> u32 {
+        u32::from_le_bytes(buf[..4].try_into().unwrap())
+    }
+
+    fn u64le(buf: &[u8]) -> u64 {
+        u64::from_le_bytes(buf[..8].try_into().unwrap())
+    }
+
+    fn rfind(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+        haystack
+            .windows(needle.len())
+            .rposition(|window| window == needle)
+    }
+
+    fn decode_name(raw: &[u8], flags: u16, vm: &VirtualMachine) -> PyResult<String> {
+        if flags & 0x800 != 0 {
+            // UTF-8 file names extension
+            String::from_utf8(raw.to_vec()).map_err(|_| {
+                vm.new_unicode_decode_error("zipimport: invalid UTF-8 name in Zip file")
+            })
+        } else if raw.is_ascii() {
+            Ok(core::str::from_utf8(raw).unwrap().to_owned())
+        } else {
+            // Historical ZIP filename encoding (cp437)
+            Ok(raw
+                .iter()
+                .map(|&b| {
+                    if b < 0x80 {
+                        b as char
+                    } else {
+                        CP437_HIGH[(b - 0x80) as usize]
+                    }
+                })
+                .collect())
+        }
+    }
+
+    /// Scan the central directory of the archive and return a list of raw
+    /// entries `(name, compress, data_size, file_size, file_offset, time,
+    /// date, crc)` with `file_offset` already adjusted for prepended data
+    /// (self-extracting archives, pex/zipapp launchers). Raises `OSError` for
+    /// I/O failures and `ValueError` for a corrupt archive; the Python caller
+    /// translates both into `ZipImportError`.
+    #[pyfunction]
+    fn _read_directory(archive: PyStrRef, vm: &VirtualMachine) -> PyResult<Vec<PyObjectRef>> {
+        let archive = archive.as_str();
+        let cant_read = || vm.new_os_error(format!("can't read Zip file: '{archive}'"));
+        let corrupt = |what: &str| vm.new_value_error(format!("{what}: '{archive}'"));
+
+        let mut fp = std::fs::File::open(archive)
+            .map_err(|_| vm.new_os_error(format!("can't open Zip file: '{archive}'")))?;
+        let file_size = fp.seek(SeekFrom::End(0)).map_err(|_| cant_read())?;
+
+        let max_comment_plus_dirs_size = MAX_COMMENT_LEN
+            + (END_CENTRAL_DIR_SIZE + END_CENTRAL_DIR_SIZE_64 + END_CENTRAL_DIR_LOCATOR_SIZE_64)
+                as u64;
+        let max_comment_start = file_size.saturating_sub(max_comment_plus_dirs_size);
+        fp.seek(SeekFrom::Start(max_comment_start))
+            .map_err(|_| cant_read())?;
+        let mut data = Vec::new();
+        fp.read_to_end(&mut data).map_err(|_| cant_read())?;
+
+        let pos = rfind(&data, STRING_END_ARCHIVE);
+        let pos64 = rfind(&data, STRING_END_ZIP_64);
+
+        let (header_position, central_directory_size, central_directory_position, num_entries) =
+            if let (Some(pos64), Some(pos)) = (pos64, pos)
+                && pos64 + END_CENTRAL_DIR_SIZE_64 + END_CENTRAL_DIR_LOCATOR_SIZE_64 == pos
+            {
+                // Zip64 EOCD at the "correct" offset from the standard EOCD
+                let buffer = &data[pos64..];
+                if buffer.len() < END_CENTRAL_DIR_SIZE_64 {
+                    return Err(corrupt("corrupt Zip64 file"));
+                }
+                let header_position = file_size - data.len() as u64 + pos64 as u64;
+                (
+                    header_position,
+                    u64le(&buffer[40..48]),
+                    u64le(&buffer[48..56]),
+                    u64le(&buffer[24..32]),
+                )
+            } else if let Some(pos) = pos {
+                let buffer = &data[pos..];
+                if buffer.len() < END_CENTRAL_DIR_SIZE {
+                    return Err(corrupt("corrupt Zip file"));
+                }
+                let header_position = file_size - data.len() as u64 + pos as u64;
+                (
+                    header_position,
+                    u32le(&buffer[12..16]) as u64,
+                    u32le(&buffer[16..20]) as u64,
+                    u16le(&buffer[8..10]) as u64,
+                )
+            } else {
+                return Err(corrupt("not a Zip file"));
+            };
+
+        if header_position < central_directory_size {
+            return Err(corrupt("bad central directory size"));
+        }
+        if header_position < central_directory_position {
+            return Err(corrupt("bad central directory offset"));
+        }
+        let header_position = header_position - central_directory_size;
+        // On just-a-zipfile this is zero; for archives with bytes prepended
+        // (self-extracting .exe, pex) it is the number of such bytes.
+        let arc_offset = header_position
+            .checked_sub(central_directory_position)
+            .ok_or_else(|| corrupt("bad central directory size or offset"))?;
+
+        fp.seek(SeekFrom::Start(header_position))
+            .map_err(|_| cant_read())?;
+        let mut entries = Vec::new();
+        let mut count: u64 = 0;
+        loop {
+            let mut buffer = [0u8; 46];
+            let read = fp.read(&mut buffer).map_err(|_| cant_read())?;
+            if read < 4 {
+                return Err(vm.new_eof_error("EOF read where not expected"));
+            }
+            if &buffer[..4] != STRING_CENTRAL_DIR_ENTRY {
+                if count != num_entries {
+                    return Err(vm.new_value_error(format!(
+                        "mismatched num_entries: {count} should be {num_entries} in '{archive}'"
+                    )));
+                }
+                break;
+            }
+            if read != 46 {
+                return Err(vm.new_eof_error("EOF read where not expected"));
+            }
+            let flags = u16le(&buffer[8..10]);
+            let compress = u16le(&buffer[10..12]);
+            let time = u16le(&buffer[12..14]);
+            let date = u16le(&buffer[14..16]);
+            let crc = u32le(&buffer[16..20]);
+            let mut data_size = u32le(&buffer[20..24]) as u64;
+            let mut file_size = u32le(&buffer[24..28]) as u64;
+            let name_size = u16le(&buffer[28..30]) as usize;
+            let extra_size = u16le(&buffer[30..32]) as usize;
+            let comment_size = u16le(&buffer[32..34]) as usize;
+            let mut file_offset = u32le(&buffer[42..46]) as u64;
+
+            let mut name = vec![0u8; name_size];
+            fp.read_exact(&mut name).map_err(|_| cant_read())?;
+            let mut extra_data = vec![0u8; extra_size + comment_size];
+            fp.read_exact(&mut extra_data).map_err(|_| cant_read())?;
+            let name = decode_name(&name, flags, vm)?;
+
+            if file_size == MAX_UINT32 || data_size == MAX_UINT32 || file_offset == MAX_UINT32 {
+                // Decode the extra data looking for a zip64 extra record
+                // (which might not be present).
+                let bad_extra = || corrupt("can't read header extra");
+                let mut extra = &extra_data[..extra_size];
+                while !extra.is_empty() {
+                    if extra.len() < 4 {
+                        return Err(bad_extra());
+                    }
+                    let tag = u16le(&extra[..2]);
+                    let size = u16le(&extra[2..4]) as usize;
+                    if extra.len() < 4 + size {
+                        return Err(bad_extra());
+                    }
+                    if tag == ZIP64_EXTRA_TAG {
+                        if size % 8 != 0 || size / 8 > 3 {
+                            return Err(bad_extra());
+                        }
+                        let mut values = extra[4..4 + size].chunks_exact(8).map(u64le);
+                        // N.b. the ordering here differs from the header
+                        // fields: size, then compressed size, then offset.
+                        if file_size == MAX_UINT32 {
+                            file_size = values.next().ok_or_else(bad_extra)?;
+                        }
+                        if data_size == MAX_UINT32 {
+                            data_size = values.next().ok_or_else(bad_extra)?;
+                        }
+                        if file_offset == MAX_UINT32 {
+                            file_offset = values.next().ok_or_else(bad_extra)?;
+                        }
+                        break;
+                    }
+                    extra = &extra[4 + size..];
+                }
+            }
+
+            if file_offset > central_directory_position {
+                return Err(corrupt("bad local header offset"));
+            }
+            file_offset += arc_offset;
+
+            entries.push(
+                vm.ctx
+                    .new_tuple(vec![
+                        name.to_pyobject(vm),
+                        compress.to_pyobject(vm),
+                        data_size.to_pyobject(vm),
+                        file_size.to_pyobject(vm),
+                        file_offset.to_pyobject(vm),
+                        time.to_pyobject(vm),
+                        date.to_pyobject(vm),
+                        crc.to_pyobject(vm),
+                    ])
+                    .into(),
+            );
+            count += 1;
+        }
+        Ok(entries)
+    }
+}
@@ -0,0 +1,705 @@
+// spell-checker:ignore zstd ZDICT dctx cctx btlazy btopt btultra dfast
+// spell-checker:ignore pyzstd undigested
+
+pub(crate) use _zstd::module_def;
+
+#[allow(non_upper_case_globals)]
+#[pymodule]
+mod _zstd {
+    use crate::compression::{
+        DecompressArgs, DecompressError, DecompressState, DecompressStatus, Decompressor,
+    };
+    use alloc::fmt;
+    use core::ffi::CStr;
+    use rustpython_common::lock::PyMutex;
+    use rustpython_vm::builtins::{
+        PyBaseExceptionRef, PyBytesRef, PyDictRef, PyTuple, PyType, PyTypeRef,
+    };
+    use rustpython_vm::convert::ToPyException;
+    use rustpython_vm::function::{ArgBytesLike, OptionalArg};
+    use rustpython_vm::types::Constructor;
+    use rustpython_vm::{Py, PyObjectRef, PyPayload, PyRef, PyResult, VirtualMachine};
+
+    const DEF_BUF_SIZE: usize = 32 * 1024;
+
+    // ZSTD_EndDirective
+    const E_CONTINUE: u32 = 0;
+    const E_FLUSH: u32 = 1;
+    const E_END: u32 = 2;
+
+    // the parameter and strategy values below are part of the stable zstd ABI
+    // (zstd.h), mirrored here because zstd-sys spells them as enum constants
+    #[pyattr]
+    const ZSTD_CLEVEL_DEFAULT: i32 = 3;
+    #[pyattr]
+    const ZSTD_c_compressionLevel: u32 = 100;
+    #[pyattr]
+    const ZSTD_c_windowLog: u32 = 101;
+    #[pyattr]
+    const ZSTD_c_hashLog: u32 = 102;
+    #[pyattr]
+    const ZSTD_c_chainLog: u32 = 103;
+    #[pyattr]
+    const ZSTD_c_searchLog: u32 = 104;
+    #[pyattr]
+    const ZSTD_c_minMatch: u32 = 105;
+    #[pyattr]
+    const ZSTD_c_targetLength: u32 = 106;
+    #[pyattr]
+    const ZSTD_c_strategy: u32 = 107;
+    #[pyattr]
+    const ZSTD_c_enableLongDistanceMatching: u32 = 160;
+    #[pyattr]
+    const ZSTD_c_ldmHashLog: u32 = 161;
+    #[pyattr]
+    const ZSTD_c_ldmMinMatch: u32 = 162;
+    #[pyattr]
+    const ZSTD_c_ldmBucketSizeLog: u32 = 163;
+    #[pyattr]
+    const ZSTD_c_ldmHashRateLog: u32 = 164;
+    #[pyattr]
+    const ZSTD_c_contentSizeFlag: u32 = 200;
+    #[pyattr]
+    const ZSTD_c_checksumFlag: u32 = 201;
+    #[pyattr]
+    const ZSTD_c_dictIDFlag: u32 = 202;
+    #[pyattr]
+    const ZSTD_c_nbWorkers: u32 = 400;
+    #[pyattr]
+    const ZSTD_c_jobSize: u32 = 401;
+    #[pyattr]
+    const ZSTD_c_overlapLog: u32 = 402;
+    #[pyattr]
+    const ZSTD_d_windowLogMax: u32 = 100;
+    #[pyattr]
+    const ZSTD_fast: u32 = 1;
+    #[pyattr]
+    const ZSTD_dfast: u32 = 2;
+    #[pyattr]
+    const ZSTD_greedy: u32 = 3;
+    #[pyattr]
+    const ZSTD_lazy: u32 = 4;
+    #[pyattr]
+    const ZSTD_lazy2: u32 = 5;
+    #[pyattr]
+    const ZSTD_btlazy2: u32 = 6;
+    #[pyattr]
+    const ZSTD_btopt: u32 = 7;
+    #[pyattr]
+    const ZSTD_btultra: u32 = 8;
+    #[pyattr]
+    const ZSTD_btultra2: u32 = 9;
+
+    #[pyattr]
+    fn zstd_version(_vm: &VirtualMachine) -> String {
+        unsafe { CStr::from_ptr(zstd_sys::ZSTD_versionString()) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[pyattr]
+    fn zstd_version_number(_vm: &VirtualMachine) -> u32 {
+        unsafe { zstd_sys::ZSTD_versionNumber() }
+    }
+
+    #[pyattr(name = "ZSTD_DStreamOutSize")]
+    fn dstream_out_size(_vm: &VirtualMachine) -> usize {
+        unsafe { zstd_sys::ZSTD_DStreamOutSize() }
+    }
+
+    #[pyattr(once, name = "ZstdError")]
+    fn zstd_error(vm: &VirtualMachine) -> PyTypeRef {
+        vm.ctx.new_exception_type(
+            "_zstd",
+            "ZstdError",
+            Some(vec![vm.ctx.exceptions.exception_type.to_owned()]),
+        )
+    }
+
+    fn new_zstd_error(message: impl Into<String>, vm: &VirtualMachine) -> PyBaseExceptionRef {
+        vm.new_exception_msg(vm.class("_zstd", "ZstdError"), message.into())
+    }
+
+    fn error_name(code: usize) -> String {
+        unsafe { CStr::from_ptr(zstd_sys::ZSTD_getErrorName(code)) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    fn check_error(code: usize, vm: &VirtualMachine) -> PyResult<usize> {
+        if unsafe { zstd_sys::ZSTD_isError(code) } != 0 {
+            Err(new_zstd_error(error_name(code), vm))
+        } else {
+            Ok(code)
+        }
+    }
+
+    struct CCtx(*mut zstd_sys::ZSTD_CCtx);
+
+    unsafe impl Send for CCtx {}
+
+    impl Drop for CCtx {
+        fn drop(&mut self) {
+            unsafe { zstd_sys::ZSTD_freeCCtx(self.0) };
+        }
+    }
+
+    struct DCtx(*mut zstd_sys::ZSTD_DCtx);
+
+    unsafe impl Send for DCtx {}
+
+    impl Drop for DCtx {
+        fn drop(&mut self) {
+            unsafe { zstd_sys::ZSTD_freeDCtx(self.0) };
+        }
+    }
+
+    fn dict_from_obj(obj: PyObjectRef, vm: &VirtualMachine) -> PyResult<PyRef<ZstdDict>> {
+        // the as_digested_dict/as_undigested_dict/as_prefix properties wrap
+        // the dictionary in a (dict, load_type) tuple
+        let obj = match obj.downcast_ref::<PyTuple>() {
+            Some(tuple) if tuple.len() == 2 => tuple[0].clone(),
+            _ => obj,
+        };
+        obj.downcast::<ZstdDict>()
+            .map_err(|_| vm.new_type_error("zstd_dict argument should be a ZstdDict object"))
+    }
+
+    #[pyattr]
+    #[pyclass(name = "ZstdDict")]
+    #[derive(Debug, PyPayload)]
+    struct ZstdDict {
+        dict_content: PyBytesRef,
+        dict_id: u32,
+    }
+
+    #[derive(FromArgs)]
+    struct ZstdDictArgs {
+        #[pyarg(positional)]
+        dict_content: ArgBytesLike,
+        #[pyarg(named, default = false)]
+        is_raw: bool,
+    }
+
+    impl Constructor for ZstdDict {
+        type Args = ZstdDictArgs;
+
+        fn py_new(_cls: &Py<PyType>, args: Self::Args, vm: &VirtualMachine) -> PyResult<Self> {
+            let content = args.dict_content.borrow_buf().to_vec();
+            if content.len() < 8 {
+                return Err(vm.new_value_error("Zstandard dictionary content too short"));
+            }
+            let dict_id = unsafe {
+                zstd_sys::ZSTD_getDictID_fromDict(content.as_ptr().cast(), content.len())
+            };
+            if dict_id == 0 && !args.is_raw {
+                return Err(
+                    vm.new_value_error("dict_content does not look like a Zstandard dictionary")
+                );
+            }
+            Ok(Self {
+                dict_content: vm.ctx.new_bytes(content),
+                dict_id,
+            })
+        }
+    }
+
+    #[pyclass(with(Constructor))]
+    impl ZstdDict {
+        #[pygetset]
+        fn dict_content(&self) -> PyBytesRef {
+            self.dict_content.clone()
+        }
+
+        #[pygetset]
+        fn dict_id(&self) -> u32 {
+            self.dict_id
+        }
+
+        #[pygetset]
+        fn as_digested_dict(zelf: PyRef<Self>) -> (PyRef<Self>, i32) {
+            (zelf, 0)
+        }
+
+        #[pygetset]
+        fn as_undigested_dict(zelf: PyRef<Self>) -> (PyRef<Self>, i32) {
+            (zelf, 1)
+        }
+
+        #[pygetset]
+        fn as_prefix(zelf: PyRef<Self>) -> (PyRef<Self>, i32) {
+            (zelf, 2)
+        }
+
+        #[pymethod]
+        fn __len__(&self) -> usize {
+            self.dict_content.len()
+        }
+    }
+
+    struct CompressorInner {
+        cctx: CCtx,
+        last_mode: i32,
+    }
+
+    #[pyattr]
+    #[pyclass(name = "ZstdCompressor")]
+    #[derive(PyPayload)]
+    struct ZstdCompressor {
+        inner: PyMutex<CompressorInner>,
+    }
+
+    impl fmt::Debug for ZstdCompressor {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "_zstd.ZstdCompressor")
+        }
+    }
+
+    fn set_c_parameter(cctx: &CCtx, param: u32, value: i32, vm: &VirtualMachine) -> PyResult<()> {
+        let code = unsafe { zstd_sys::ZSTD_CCtx_setParameter(cctx.0, param as _, value) };
+        check_error(code, vm).map(drop)
+    }
+
+    fn compress_inner(
+        cctx: &CCtx,
+        data: &[u8],
+        end: u32,
+        vm: &VirtualMachine,
+    ) -> PyResult<Vec<u8>> {
+        let mut buf: Vec<u8> = Vec::new();
+        let mut in_buf = zstd_sys::ZSTD_inBuffer {
+            src: data.as_ptr().cast(),
+            size: data.len(),
+            pos: 0,
+        };
+        loop {
+            if buf.len() == buf.capacity() {
+                buf.reserve(DEF_BUF_SIZE);
+            }
+            let start = buf.len();
+            let spare = buf.spare_capacity_mut();
+            let mut out_buf = zstd_sys::ZSTD_outBuffer {
+                dst: spare.as_mut_ptr().cast(),
+                size: spare.len(),
+                pos: 0,
+            };
+            let remaining = unsafe {
+                zstd_sys::ZSTD_compressStream2(cctx.0, &mut out_buf, &mut in_buf, end as _)
+            };
+            check_error(remaining, vm)?;
+            unsafe { buf.set_len(start + out_buf.pos) };
+            let done = if end == E_CONTINUE {
+                in_buf.pos == in_buf.size
+            } else {
+                remaining == 0 && in_buf.pos == in_buf.size
+            };
+            if done {
+                break;
+            }
+        }
+        buf.shrink_to_fit();
+        Ok(buf)
+    }
+
+    #[derive(FromArgs)]
+    struct ZstdCompressorArgs {
+        #[pyarg(any, optional)]
+        level: Option<i32>,
+        #[pyarg(any, optional)]
+        options: Option<PyDictRef>,
+        #[pyarg(any, optional)]
+        zstd_dict: Option<PyObjectRef>,
+    }
+
+    impl Constructor for ZstdCompressor {
+        type Args = ZstdCompressorArgs;
+
+        fn py_new(_cls: &Py<PyType>, args: Self::Args, vm: &VirtualMachine) -> PyResult<Self> {
+            let cctx = CCtx(unsafe { zstd_sys::ZSTD_createCCtx() });
+            if cctx.0.is_null() {
+                return Err(vm.new_memory_error("Unable to create ZSTD_CCtx instance"));
+            }
+            if let Some(level) = args.level {
+                set_c_parameter(&cctx, ZSTD_c_compressionLevel, level, vm)?;
+            }
+            if let Some(options) = args.options {
+                for (key, value) in &options {
+                    let key: u32 = key.try_to_value(vm)?;
+                    let value: i32 = value.try_to_value(vm)?;
+                    set_c_parameter(&cctx, key, value, vm)?;
+                }
+            }
+            if let Some(zstd_dict) = args.zstd_dict {
+                let zstd_dict = dict_from_obj(zstd_dict, vm)?;
+                let content = zstd_dict.dict_content.as_bytes();
+                let code = unsafe {
+                    zstd_sys::ZSTD_CCtx_loadDictionary(
+                        cctx.0,
+                        content.as_ptr().cast(),
+                        content.len(),
+                    )
+                };
+                check_error(code, vm)?;
+            }
+            Ok(Self {
+                inner: PyMutex::new(CompressorInner {
+                    cctx,
+                    last_mode: Self::CONTINUE,
+                }),
+            })
+        }
+    }
+
+    #[derive(FromArgs)]
+    struct CompressMethodArgs {
+        #[pyarg(positional)]
+        data: ArgBytesLike,
+        #[pyarg(any, default = ZstdCompressor::CONTINUE)]
+        mode: i32,
+    }
+
+    #[pyclass(with(Constructor))]
+    impl ZstdCompressor {
+        #[pyattr]
+        const CONTINUE: i32 = E_CONTINUE as i32;
+        #[pyattr]
+        const FLUSH_BLOCK: i32 = E_FLUSH as i32;
+        #[pyattr]
+        const FLUSH_FRAME: i32 = E_END as i32;
+
+        #[pymethod]
+        fn compress(&self, args: CompressMethodArgs, vm: &VirtualMachine) -> PyResult<Vec<u8>> {
+            if !(Self::CONTINUE..=Self::FLUSH_FRAME).contains(&args.mode) {
+                return Err(vm.new_value_error(
+                    "mode argument wrong value, it should be one of ZstdCompressor.CONTINUE, \
+                     ZstdCompressor.FLUSH_BLOCK, ZstdCompressor.FLUSH_FRAME",
+                ));
+            }
+            let inner = &mut *self.inner.lock();
+            let ret = args
+                .data
+                .with_ref(|data| compress_inner(&inner.cctx, data, args.mode as u32, vm))?;
+            inner.last_mode = args.mode;
+            Ok(ret)
+        }
+
+        #[pymethod]
+        fn flush(&self, mode: OptionalArg<i32>, vm: &VirtualMachine) -> PyResult<Vec<u8>> {
+            let mode = mode.unwrap_or(Self::FLUSH_FRAME);
+            if mode != Self::FLUSH_BLOCK && mode != Self::FLUSH_FRAME {
+                return Err(vm.new_value_error(
+                    "mode argument wrong value, it should be ZstdCompressor.FLUSH_FRAME or \
+                     ZstdCompressor.FLUSH_BLOCK",
+                ));
+            }
+            let inner = &mut *self.inner.lock();
+            let ret = compress_inner(&inner.cctx, &[], mode as u32, vm)?;
+            inner.last_mode = mode;
+            Ok(ret)
+        }
+
+        #[pygetset]
+        fn last_mode(&self) -> i32 {
+            self.inner.lock().last_mode
+        }
+    }
+
+    struct DecompressorInner {
+        dctx: DCtx,
+        total_in: u64,
+    }
+
+    enum ZstdStatus {
+        StreamEnd,
+        Ok,
+    }
+
+    impl DecompressStatus for ZstdStatus {
+        fn is_stream_end(&self) -> bool {
+            matches!(self, Self::StreamEnd)
+        }
+    }
+
+    impl Decompressor for DecompressorInner {
+        type Flush = ();
+        type Status = ZstdStatus;
+        type Error = String;
+
+        fn total_in(&self) -> u64 {
+            self.total_in
+        }
+
+        fn decompress_vec(
+            &mut self,
+            input: &[u8],
+            output: &mut Vec<u8>,
+            (): Self::Flush,
+        ) -> Result<Self::Status, Self::Error> {
+            let mut in_buf = zstd_sys::ZSTD_inBuffer {
+                src: input.as_ptr().cast(),
+                size: input.len(),
+                pos: 0,
+            };
+            let start = output.len();
+            let spare = output.spare_capacity_mut();
+            let mut out_buf = zstd_sys::ZSTD_outBuffer {
+                dst: spare.as_mut_ptr().cast(),
+                size: spare.len(),
+                pos: 0,
+            };
+            let ret =
+                unsafe { zstd_sys::ZSTD_decompressStream(self.dctx.0, &mut out_buf, &mut in_buf) };
+            if unsafe { zstd_sys::ZSTD_isError(ret) } != 0 {
+                return Err(error_name(ret));
+            }
+            unsafe { output.set_len(start + out_buf.pos) };
+            self.total_in += in_buf.pos as u64;
+            Ok(if ret == 0 {
+                ZstdStatus::StreamEnd
+            } else {
+                ZstdStatus::Ok
+            })
+        }
+    }
+
+    #[pyattr]
+    #[pyclass(name = "ZstdDecompressor")]
+    #[derive(PyPayload)]
+    struct ZstdDecompressor {
+        state: PyMutex<DecompressState<DecompressorInner>>,
+    }
+
+    impl fmt::Debug for ZstdDecompressor {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "_zstd.ZstdDecompressor")
+        }
+    }
+
+    #[derive(FromArgs)]
+    struct ZstdDecompressorArgs {
+        #[pyarg(any, optional)]
+        zstd_dict: Option<PyObjectRef>,
+        #[pyarg(any, optional)]
+        options: Option<PyDictRef>,
+    }
+
+    impl Constructor for ZstdDecompressor {
+        type Args = ZstdDecompressorArgs;
+
+        fn py_new(_cls: &Py<PyType>, args: Self::Args, vm: &VirtualMachine) -> PyResult<Self> {
+            let dctx = DCtx(unsafe { zstd_sys::ZSTD_createDCtx() });
+            if dctx.0.is_null() {
+                return Err(vm.new_memory_error("Unable to create ZSTD_DCtx instance"));
+            }
+            if let Some(options) = args.options {
+                for (key, value) in &options {
+                    let key: u32 = key.try_to_value(vm)?;
+                    let value: i32 = value.try_to_value(vm)?;
+                    let code = unsafe { zstd_sys::ZSTD_DCtx_setParameter(dctx.0, key as _, value) };
+                    check_error(code, vm)?;
+                }
+            }
+            if let Some(zstd_dict) = args.zstd_dict {
+                let zstd_dict = dict_from_obj(zstd_dict, vm)?;
+                let content = zstd_dict.dict_content.as_bytes();
+                let code = unsafe {
+                    zstd_sys::ZSTD_DCtx_loadDictionary(
+                        dctx.0,
+                        content.as_ptr().cast(),
+                        content.len(),
+                    )
+                };
+                check_error(code, vm)?;
+            }
+            Ok(Self {
+                state: PyMutex::new(DecompressState::new(
+                    DecompressorInner { dctx, total_in: 0 },
+                    vm,
+                )),
+            })
+        }
+    }
+
+    #[pyclass(with(Constructor))]
+    impl ZstdDecompressor {
+        #[pymethod]
+        fn decompress(&self, args: DecompressArgs, vm: &VirtualMachine) -> PyResult<Vec<u8>> {
+            let max_length = args.max_length();
+            let data = &*args.data();
+
+            let mut state = self.state.lock();
+            state
+                .decompress(data, max_length, DEF_BUF_SIZE, vm)
+                .map_err(|e| match e {
+                    DecompressError::Decompress(err) => new_zstd_error(err, vm),
+                    DecompressError::Eof(err) => err.to_pyexception(vm),
+                })
+        }
+
+        #[pygetset]
+        fn eof(&self) -> bool {
+            self.state.lock().eof()
+        }
+
+        #[pygetset]
+        fn unused_data(&self) -> PyBytesRef {
+            self.state.lock().unused_data()
+        }
+
+        #[pygetset]
+        fn needs_input(&self) -> bool {
+            self.state.lock().needs_input()
+        }
+    }
+
+    #[pyfunction]
+    fn get_frame_info(
+        frame_buffer: ArgBytesLike,
+        vm: &VirtualMachine,
+    ) -> PyResult<(Option<u64>, u32)> {
+        // from zstd.h: -1 means the size is unknown, -2 an invalid frame
+        const CONTENTSIZE_UNKNOWN: u64 = u64::MAX;
+        const CONTENTSIZE_ERROR: u64 = u64::MAX - 1;
+
+        frame_buffer.with_ref(|data| {
+            let size =
+                unsafe { zstd_sys::ZSTD_getFrameContentSize(data.as_ptr().cast(), data.len()) };
+            let decompressed_size = match size {
+                CONTENTSIZE_ERROR => {
+                    return Err(new_zstd_error(
+                        "Error when getting information from the header of a Zstandard frame",
+                        vm,
+                    ));
+                }
+                CONTENTSIZE_UNKNOWN => None,
+                v => Some(v),
+            };
+            let dict_id =
+                unsafe { zstd_sys::ZSTD_getDictID_fromFrame(data.as_ptr().cast(), data.len()) };
+            Ok((decompressed_size, dict_id))
+        })
+    }
+
+    #[pyfunction]
+    fn get_frame_size(frame_buffer: ArgBytesLike, vm: &VirtualMachine) -> PyResult<usize> {
+        frame_buffer.with_ref(|data| {
+            let size =
+                unsafe { zstd_sys::ZSTD_findFrameCompressedSize(data.as_ptr().cast(), data.len()) };
+            check_error(size, vm)
+        })
+    }
+
+    #[derive(FromArgs)]
+    struct GetParamBoundsArgs {
+        #[pyarg(any)]
+        parameter: u32,
+        #[pyarg(named)]
+        is_compress: bool,
+    }
+
+    #[pyfunction]
+    fn get_param_bounds(args: GetParamBoundsArgs, vm: &VirtualMachine) -> PyResult<(i32, i32)> {
+        let bounds = if args.is_compress {
+            unsafe { zstd_sys::ZSTD_cParam_getBounds(args.parameter as _) }
+        } else {
+            unsafe { zstd_sys::ZSTD_dParam_getBounds(args.parameter as _) }
+        };
+        check_error(bounds.error, vm)?;
+        Ok((bounds.lowerBound, bounds.upperBound))
+    }
+
+    fn check_zdict_error(code: usize, vm: &VirtualMachine) -> PyResult<usize> {
+        if unsafe { zstd_sys::ZDICT_isError(code) } != 0 {
+            let name = unsafe { CStr::from_ptr(zstd_sys::ZDICT_getErrorName(code)) };
+            Err(new_zstd_error(name.to_string_lossy(), vm))
+        } else {
+            Ok(code)
+        }
+    }
+
+    #[pyfunction]
+    fn train_dict(
+        samples_bytes: ArgBytesLike,
+        samples_sizes: Vec<usize>,
+        dict_size: usize,
+        vm: &VirtualMachine,
+    ) -> PyResult<Vec<u8>> {
+        if dict_size == 0 {
+            return Err(vm.new_value_error("dict_size argument should be positive number"));
+        }
+        samples_bytes.with_ref(|samples| {
+            if samples_sizes.iter().sum::<usize>() != samples.len() {
+                return Err(vm.new_value_error(
+                    "the samples size tuple doesn't match the concatenation's size",
+                ));
+            }
+            let mut dict_buf = vec![0u8; dict_size];
+            let code = unsafe {
+                zstd_sys::ZDICT_trainFromBuffer(
+                    dict_buf.as_mut_ptr().cast(),
+                    dict_size,
+                    samples.as_ptr().cast(),
+                    samples_sizes.as_ptr(),
+                    samples_sizes.len() as u32,
+                )
+            };
+            let written = check_zdict_error(code, vm)?;
+            dict_buf.truncate(written);
+            Ok(dict_buf)
+        })
+    }
+
+    #[pyfunction]
+    fn finalize_dict(
+        custom_dict_bytes: ArgBytesLike,
+        samples_bytes: ArgBytesLike,
+        samples_sizes: Vec<usize>,
+        dict_size: usize,
+        compression_level: i32,
+        vm: &VirtualMachine,
+    ) -> PyResult<Vec<u8>> {
+        if dict_size == 0 {
+            return Err(vm.new_value_error("dict_size argument should be positive number"));
+        }
+        custom_dict_bytes.with_ref(|dict_content| {
+            samples_bytes.with_ref(|samples| {
+                if samples_sizes.iter().sum::<usize>() != samples.len() {
+                    return Err(vm.new_value_error(
+                        "the samples size tuple doesn't match the concatenation's size",
+                    ));
+                }
+                let params = zstd_sys::ZDICT_params_t {
+                    compressionLevel: compression_level,
+                    notificationLevel: 0,
+                    dictID: 0,
+                };
+                let mut dict_buf = vec![0u8; dict_size];
+                let code = unsafe {
+                    zstd_sys::ZDICT_finalizeDictionary(
+                        dict_buf.as_mut_ptr().cast(),
+                        dict_size,
+                        dict_content.as_ptr().cast(),
+                        dict_content.len(),
+                        samples.as_ptr().cast(),
+                        samples_sizes.as_ptr(),
+                        samples_sizes.len() as u32,
+                        params,
+                    )
+                };
+                let written = check_zdict_error(code, vm)?;
+                dict_buf.truncate(written);
+                Ok(dict_buf)
+            })
+        })
+    }
+
+    #[pyfunction]
+    fn set_parameter_types(
+        _c_parameter_type: PyObjectRef,
+        _d_parameter_type: PyObjectRef,
+        _vm: &VirtualMachine,
+    ) {
+        // CPython keeps the enum types around to pretty-print error messages;
+        // our errors name the raw parameter instead
+    }
+}
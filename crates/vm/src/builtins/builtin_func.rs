@@ -163,7 +163,8 @@ impl PyNativeFunction {
 
     #[pygetset]
     fn __doc__(zelf: NativeFunctionOrMethod) -> Option<&'static str> {
-        zelf.0.value.doc
+        let doc = zelf.0.value.doc?;
+        Some(type_::get_doc_from_internal_doc(zelf.0.value.name, doc))
     }
 
     // meth_get__self__ in CPython
@@ -1,6 +1,7 @@
 //! Infamous code object. The python class `code`
 
-use super::{PyBytesRef, PyStrRef, PyTupleRef, PyType};
+use super::{PyBytesRef, PyStr, PyStrRef, PyTupleRef, PyType};
+use crate::common::lock::PyMutex;
 use crate::{
     AsObject, Context, Py, PyObject, PyObjectRef, PyPayload, PyRef, PyResult, VirtualMachine,
     builtins::PyStrInterned,
@@ -20,6 +21,7 @@ use core::{
 use malachite_bigint::BigInt;
 use num_traits::Zero;
 use rustpython_compiler_core::{OneIndexed, bytecode::CodeUnits, bytecode::PyCodeLocationInfoKind};
+use std::collections::HashMap;
 
 /// State for iterating through code address ranges
 struct PyCodeAddressRange<'a> {
@@ -324,10 +326,22 @@ impl<B: AsRef<[u8]>> IntoCodeObject for frozen::FrozenCodeObject<B> {
     }
 }
 
+/// Inline cache entry for a `LoadGlobal` instruction. Valid as long as both
+/// the globals and builtins dicts still carry the recorded version tags;
+/// version tags are globally unique, so a match also proves dict identity.
+pub(crate) struct GlobalCacheEntry {
+    pub(crate) globals_version: u64,
+    pub(crate) builtins_version: u64,
+    pub(crate) value: PyObjectRef,
+}
+
 #[pyclass(module = false, name = "code")]
 pub struct PyCode {
     pub code: CodeObject,
     source_path: AtomicPtr<PyStrInterned>,
+    /// Per-name-index caches for `LoadGlobal`, shared by all frames running
+    /// this code object. Deoptimizes automatically via dict version tags.
+    pub(crate) global_cache: PyMutex<HashMap<u32, GlobalCacheEntry>>,
 }
 
 impl Deref for PyCode {
@@ -343,6 +357,7 @@ impl PyCode {
         Self {
             code,
             source_path: AtomicPtr::new(sp),
+            global_cache: PyMutex::default(),
         }
     }
 
@@ -993,16 +1008,16 @@ impl PyCode {
         let cellvars = match co_cellvars {
             OptionalArg::Present(cellvars) => cellvars
                 .into_iter()
-                .map(|o| o.as_interned_str(vm).unwrap())
-                .collect(),
+                .map(|o| intern_code_name(&o, vm))
+                .collect::<PyResult<_>>()?,
             OptionalArg::Missing => self.code.cellvars.clone(),
         };
 
         let freevars = match co_freevars {
             OptionalArg::Present(freevars) => freevars
                 .into_iter()
-                .map(|o| o.as_interned_str(vm).unwrap())
-                .collect(),
+                .map(|o| intern_code_name(&o, vm))
+                .collect::<PyResult<_>>()?,
             OptionalArg::Missing => self.code.freevars.clone(),
         };
 
@@ -1035,10 +1050,10 @@ impl PyCode {
             posonlyarg_count,
             arg_count,
             kwonlyarg_count,
-            source_path: source_path.as_object().as_interned_str(vm).unwrap(),
+            source_path: intern_code_name(source_path.as_object(), vm)?,
             first_line_number,
-            obj_name: obj_name.as_object().as_interned_str(vm).unwrap(),
-            qualname: qualname.as_object().as_interned_str(vm).unwrap(),
+            obj_name: intern_code_name(obj_name.as_object(), vm)?,
+            qualname: intern_code_name(qualname.as_object(), vm)?,
 
             max_stackdepth,
             instructions,
@@ -1048,12 +1063,12 @@ impl PyCode {
             constants: constants.into_iter().map(Literal).collect(),
             names: names
                 .into_iter()
-                .map(|o| o.as_interned_str(vm).unwrap())
-                .collect(),
+                .map(|o| intern_code_name(&o, vm))
+                .collect::<PyResult<_>>()?,
             varnames: varnames
                 .into_iter()
-                .map(|o| o.as_interned_str(vm).unwrap())
-                .collect(),
+                .map(|o| intern_code_name(&o, vm))
+                .collect::<PyResult<_>>()?,
             cellvars,
             freevars,
             cell2arg: self.code.cell2arg.clone(),
@@ -1093,6 +1108,16 @@ impl PyCode {
     }
 }
 
+/// Intern a name destined for a code object field, accepting strings that
+/// were never interned before (`as_interned_str` would return `None` for
+/// them). Non-strings raise a SystemError like CPython's code slot checks
+fn intern_code_name(obj: &PyObject, vm: &VirtualMachine) -> PyResult<&'static PyStrInterned> {
+    let s = obj
+        .downcast_ref::<PyStr>()
+        .ok_or_else(|| vm.new_system_error("non-string found in code slot".to_owned()))?;
+    Ok(vm.ctx.intern_str(s.as_wtf8()))
+}
+
 impl fmt::Display for PyCode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         (**self).fmt(f)
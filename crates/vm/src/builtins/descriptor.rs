@@ -129,8 +129,10 @@ impl PyMethodDescriptor {
     }
 
     #[pygetset]
-    const fn __doc__(&self) -> Option<&'static str> {
-        self.method.doc
+    fn __doc__(&self) -> Option<&'static str> {
+        self.method
+            .doc
+            .map(|doc| type_::get_doc_from_internal_doc(self.method.name, doc))
     }
 
     #[pygetset]
@@ -379,10 +381,9 @@ fn set_slot_at_object(
                 obj.set_slot(offset, Some(v));
             }
             PySetterValue::Delete => {
-                if obj.get_slot(offset).is_none() {
+                if obj.take_slot(offset).is_none() {
                     return Err(vm.new_attribute_error(member.name.clone()));
                 }
-                obj.set_slot(offset, None);
             }
         },
     }
@@ -788,6 +789,7 @@ impl PyWrapper {
     #[pygetset]
     fn __doc__(&self) -> Option<&'static str> {
         self.doc
+            .map(|doc| type_::get_doc_from_internal_doc(self.name.as_str(), doc))
     }
 }
 
@@ -78,6 +78,12 @@ impl PyDict {
         &self.entries
     }
 
+    /// ma_version_tag: changes whenever this dict is mutated. Lets
+    /// attribute-access caches validate a cached lookup with one load.
+    pub fn version(&self) -> u64 {
+        self.entries.version()
+    }
+
     /// Returns all keys as a Vec, atomically under a single read lock.
     /// Thread-safe: prevents "dictionary changed size during iteration" errors.
     pub fn keys_vec(&self) -> Vec<PyObjectRef> {
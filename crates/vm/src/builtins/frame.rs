@@ -55,9 +55,16 @@ impl Frame {
     }
 
     #[pygetset]
-    fn f_lasti(&self) -> u32 {
-        // Return byte offset (each instruction is 2 bytes) for compatibility
-        self.lasti() * 2
+    fn f_lasti(&self) -> i32 {
+        // `lasti` points one past the instruction being executed; CPython's
+        // f_lasti is the byte offset (each instruction is 2 bytes) of that
+        // instruction itself, or -1 before execution starts. Traceback entries
+        // already use the same adjustment, and inspect/traceback index
+        // co_positions() with `f_lasti // 2`.
+        match self.lasti() {
+            0 => -1,
+            lasti => (lasti as i32 - 1) * 2,
+        }
     }
 
     #[pygetset]
@@ -737,15 +737,19 @@ impl PyFunction {
                     ))
                 })?;
 
-            // Cache the result
-            *self.annotations.lock() = Some(ann_dict.clone());
-            return Ok(ann_dict);
+            // Cache the result, keeping whatever another thread cached first so
+            // every caller sees the same dict
+            return Ok(self.annotations.lock().get_or_insert(ann_dict).clone());
         }
 
-        // No __annotate__ or not callable, create empty dict
-        let new_dict = vm.ctx.new_dict();
-        *self.annotations.lock() = Some(new_dict.clone());
-        Ok(new_dict)
+        // No __annotate__ or not callable, lazily create the empty dict.
+        // Create it under the lock: if two threads raced here with separate
+        // dicts, writes into the losing dict would be silently dropped.
+        Ok(self
+            .annotations
+            .lock()
+            .get_or_insert_with(|| vm.ctx.new_dict())
+            .clone())
     }
 
     #[pygetset(setter)]
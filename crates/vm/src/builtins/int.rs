@@ -2,7 +2,7 @@ use super::{PyByteArray, PyBytes, PyStr, PyType, PyTypeRef, float};
 use crate::{
     AsObject, Context, Py, PyObject, PyObjectRef, PyPayload, PyRef, PyRefExact, PyResult,
     TryFromBorrowedObject, VirtualMachine,
-    builtins::PyStrRef,
+    builtins::{PyBaseExceptionRef, PyStrRef},
     bytes_inner::PyBytesInner,
     class::PyClassImpl,
     common::{
@@ -600,11 +600,35 @@ impl Comparable for PyInt {
 
 impl Representable for PyInt {
     #[inline]
-    fn repr_str(zelf: &Py<Self>, _vm: &VirtualMachine) -> PyResult<String> {
-        Ok(zelf.value.to_string())
+    fn repr_str(zelf: &Py<Self>, vm: &VirtualMachine) -> PyResult<String> {
+        let limit = vm.state.int_max_str_digits.load();
+        if limit > 0 {
+            // Quick reject on bit length before paying for the conversion;
+            // bits * log10(2) underestimates the digit count by at most one.
+            let min_digits =
+                (zelf.value.bits().saturating_sub(1) as f64 * 0.301_029_995_663_981) as usize + 1;
+            if min_digits > limit {
+                return Err(new_digit_limit_error(limit, vm));
+            }
+        }
+        let repr = zelf.value.to_string();
+        if limit > 0 {
+            let digits = repr.len() - usize::from(repr.starts_with('-'));
+            if digits > limit {
+                return Err(new_digit_limit_error(limit, vm));
+            }
+        }
+        Ok(repr)
     }
 }
 
+#[cold]
+fn new_digit_limit_error(limit: usize, vm: &VirtualMachine) -> PyBaseExceptionRef {
+    vm.new_value_error(format!(
+        "Exceeds the limit ({limit} digits) for integer string conversion; use sys.set_int_max_str_digits() to increase the limit"
+    ))
+}
+
 impl Hashable for PyInt {
     #[inline]
     fn hash(zelf: &Py<Self>, _vm: &VirtualMachine) -> PyResult<hash::PyHash> {
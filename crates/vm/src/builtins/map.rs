@@ -47,6 +47,32 @@ impl PyMap {
         })
     }
 
+    /// Fused fast path for `sum(map(f, iterable))`: folds addition over the
+    /// mapped items without per-element iterator slot dispatch. Returns
+    /// `None` when the fusion doesn't apply (multiple iterables).
+    pub(crate) fn fold_sum(&self, mut acc: PyObjectRef, vm: &VirtualMachine) -> Option<PyResult> {
+        let [iterator] = self.iterators.as_slice() else {
+            return None;
+        };
+        Some(loop {
+            let item = match iterator.next(vm) {
+                Ok(PyIterReturn::Return(item)) => item,
+                Ok(PyIterReturn::StopIteration(_)) => break Ok(acc),
+                Err(e) => break Err(e),
+            };
+            // the mapper itself can raise StopIteration which does stop the map iteration
+            let mapped = match PyIterReturn::from_pyresult(self.mapper.call((item,), vm), vm) {
+                Ok(PyIterReturn::Return(value)) => value,
+                Ok(PyIterReturn::StopIteration(_)) => break Ok(acc),
+                Err(e) => break Err(e),
+            };
+            acc = match vm._add(&acc, &mapped) {
+                Ok(value) => value,
+                Err(e) => break Err(e),
+            };
+        })
+    }
+
     #[pymethod]
     fn __reduce__(&self, vm: &VirtualMachine) -> (PyTypeRef, PyTupleRef) {
         let mut vec = vec![self.mapper.clone()];
@@ -193,7 +193,7 @@ impl PyMappingProxy {
 
     fn __ior__(&self, _args: PyObjectRef, vm: &VirtualMachine) -> PyResult {
         Err(vm.new_type_error(format!(
-            r#""'|=' is not supported by {}; use '|' instead""#,
+            "'|=' is not supported by {}; use '|' instead",
             Self::class(&vm.ctx)
         )))
     }
@@ -258,11 +258,17 @@ impl AsNumber for PyMappingProxy {
     fn as_number() -> &'static PyNumberMethods {
         static AS_NUMBER: PyNumberMethods = PyNumberMethods {
             or: Some(|a, b, vm| {
-                if let Some(a) = a.downcast_ref::<PyMappingProxy>() {
-                    a.__or__(b.to_pyobject(vm), vm)
-                } else {
-                    Ok(vm.ctx.not_implemented())
-                }
+                // Like CPython, unwrap the proxy on whichever side it appears
+                // so both `proxy | mapping` and `mapping | proxy` work
+                let unwrap = |obj: &PyObject, vm: &VirtualMachine| -> PyResult<PyObjectRef> {
+                    match obj.downcast_ref::<PyMappingProxy>() {
+                        Some(proxy) => proxy.to_object(vm),
+                        None => Ok(obj.to_owned()),
+                    }
+                };
+                let a = unwrap(a, vm)?;
+                let b = unwrap(b, vm)?;
+                vm._or(&a, &b)
             }),
             inplace_or: Some(|a, b, vm| {
                 if let Some(a) = a.downcast_ref::<PyMappingProxy>() {
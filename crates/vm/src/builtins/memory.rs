@@ -32,6 +32,9 @@ use crossbeam_utils::atomic::AtomicCell;
 use itertools::Itertools;
 use rustpython_common::lock::PyMutex;
 
+/// Maximum number of dimensions (same limit as CPython's Objects/memoryobject.c)
+const MAX_NDIM: usize = 64;
+
 #[derive(FromArgs)]
 pub struct PyMemoryViewNewArgs {
     object: PyObjectRef,
@@ -140,18 +143,21 @@ impl PyMemoryView {
     }
 
     fn getitem_by_idx(&self, i: isize, vm: &VirtualMachine) -> PyResult {
-        if self.desc.ndim() != 1 {
-            return Err(
-                vm.new_not_implemented_error("multi-dimensional sub-views are not implemented")
-            );
-        }
         let (shape, stride, suboffset) = self.desc.dim_desc[0];
         let index = i
             .wrapped_at(shape)
             .ok_or_else(|| vm.new_index_error("index out of range"))?;
         let index = index as isize * stride + suboffset;
         let pos = (index + self.start as isize) as usize;
-        self.unpack_single(pos, vm)
+        if self.desc.ndim() == 1 {
+            return self.unpack_single(pos, vm);
+        }
+        // indexing an N-dimensional view yields an (N-1)-dimensional sub-view
+        let mut other = self.new_view();
+        other.start = pos;
+        other.desc.dim_desc.remove(0);
+        other.init_len();
+        Ok(other.into_ref(&vm.ctx).into())
     }
 
     fn getitem_by_slice(&self, slice: &PySlice, vm: &VirtualMachine) -> PyResult {
@@ -860,7 +866,11 @@ impl PyMemoryView {
             };
 
             let shape_ndim = shape.len();
-            // TODO: MAX_NDIM
+            if shape_ndim > MAX_NDIM {
+                return Err(vm.new_value_error(format!(
+                    "memoryview: number of dimensions must not exceed {MAX_NDIM}"
+                )));
+            }
             if self.desc.ndim() != 1 && shape_ndim != 1 {
                 return Err(vm.new_type_error("memoryview: cast must be 1D -> ND or ND -> 1D"));
             }
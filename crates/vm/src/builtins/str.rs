@@ -1158,7 +1158,13 @@ impl PyStr {
     // FIXME: two traversals of str is expensive
     #[inline]
     fn _to_char_idx(r: &Wtf8, byte_idx: usize) -> usize {
-        r[..byte_idx].code_points().count()
+        // The code point index equals the number of non-continuation bytes
+        // before `byte_idx`; counting those avoids decoding every code point
+        // just to translate the byte offset the two-way search returned.
+        r.as_bytes()[..byte_idx]
+            .iter()
+            .filter(|&&b| (b & 0xc0) != 0x80)
+            .count()
     }
 
     #[inline]
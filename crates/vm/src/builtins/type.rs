@@ -988,10 +988,15 @@ impl PyType {
             vm.ctx.new_dict().into()
         };
 
-        // Cache the result in __annotations_cache__
-        self.attributes
+        // Cache the result in __annotations_cache__, keeping the value another
+        // thread may have cached while we weren't holding the lock so every
+        // caller sees (and mutates) the same dict
+        let annotations = self
+            .attributes
             .write()
-            .insert(identifier!(vm, __annotations_cache__), annotations.clone());
+            .entry(identifier!(vm, __annotations_cache__))
+            .or_insert(annotations)
+            .clone();
         Ok(annotations)
     }
 
@@ -1676,7 +1681,7 @@ pub(crate) fn get_text_signature_from_internal_doc<'a>(
 }
 
 // _PyType_GetDocFromInternalDoc in CPython
-fn get_doc_from_internal_doc<'a>(name: &str, internal_doc: &'a str) -> &'a str {
+pub(crate) fn get_doc_from_internal_doc<'a>(name: &str, internal_doc: &'a str) -> &'a str {
     // Similar to CPython's _PyType_DocWithoutSignature
     // If the doc starts with the type name and a '(', it's a signature
     if let Some(doc_without_sig) = find_signature(name, internal_doc) {
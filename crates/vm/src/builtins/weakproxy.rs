@@ -166,13 +166,91 @@ impl SetAttr for PyWeakProxy {
     }
 }
 
+/// proxy_checkref + unwrapping for operator forwarding: replace a proxy
+/// operand by its referent, leaving other objects untouched.
+fn unwrap_proxy(obj: &PyObject, vm: &VirtualMachine) -> PyResult<PyObjectRef> {
+    if let Some(proxy) = obj.downcast_ref::<PyWeakProxy>() {
+        proxy.try_upgrade(vm)
+    } else {
+        Ok(obj.to_owned())
+    }
+}
+
+macro_rules! proxy_binary_op {
+    ($op:ident) => {
+        Some(|a: &PyObject, b: &PyObject, vm: &VirtualMachine| {
+            let a = unwrap_proxy(a, vm)?;
+            let b = unwrap_proxy(b, vm)?;
+            vm.$op(&a, &b)
+        })
+    };
+}
+
+macro_rules! proxy_unary_op {
+    ($op:ident) => {
+        Some(
+            |number: crate::protocol::PyNumber<'_>, vm: &VirtualMachine| {
+                let zelf = number.obj.downcast_ref::<PyWeakProxy>().unwrap();
+                vm.$op(&zelf.try_upgrade(vm)?)
+            },
+        )
+    };
+}
+
 impl AsNumber for PyWeakProxy {
     fn as_number() -> &'static PyNumberMethods {
         static AS_NUMBER: LazyLock<PyNumberMethods> = LazyLock::new(|| PyNumberMethods {
+            add: proxy_binary_op!(_add),
+            subtract: proxy_binary_op!(_sub),
+            multiply: proxy_binary_op!(_mul),
+            remainder: proxy_binary_op!(_mod),
+            divmod: proxy_binary_op!(_divmod),
+            power: Some(|a, b, c, vm| {
+                let a = unwrap_proxy(a, vm)?;
+                let b = unwrap_proxy(b, vm)?;
+                let c = unwrap_proxy(c, vm)?;
+                vm._pow(&a, &b, &c)
+            }),
+            negative: proxy_unary_op!(_neg),
+            positive: proxy_unary_op!(_pos),
+            absolute: proxy_unary_op!(_abs),
             boolean: Some(|number, vm| {
                 let zelf = number.obj.downcast_ref::<PyWeakProxy>().unwrap();
                 zelf.try_upgrade(vm)?.is_true(vm)
             }),
+            invert: proxy_unary_op!(_invert),
+            lshift: proxy_binary_op!(_lshift),
+            rshift: proxy_binary_op!(_rshift),
+            and: proxy_binary_op!(_and),
+            xor: proxy_binary_op!(_xor),
+            or: proxy_binary_op!(_or),
+            int: Some(|number, vm| {
+                let zelf = number.obj.downcast_ref::<PyWeakProxy>().unwrap();
+                zelf.try_upgrade(vm)?.try_int(vm).map(Into::into)
+            }),
+            float: Some(|number, vm| {
+                let zelf = number.obj.downcast_ref::<PyWeakProxy>().unwrap();
+                zelf.try_upgrade(vm)?.try_float(vm).map(Into::into)
+            }),
+            inplace_add: proxy_binary_op!(_iadd),
+            inplace_subtract: proxy_binary_op!(_isub),
+            inplace_multiply: proxy_binary_op!(_imul),
+            inplace_remainder: proxy_binary_op!(_imod),
+            inplace_lshift: proxy_binary_op!(_ilshift),
+            inplace_rshift: proxy_binary_op!(_irshift),
+            inplace_and: proxy_binary_op!(_iand),
+            inplace_xor: proxy_binary_op!(_ixor),
+            inplace_or: proxy_binary_op!(_ior),
+            floor_divide: proxy_binary_op!(_floordiv),
+            true_divide: proxy_binary_op!(_truediv),
+            inplace_floor_divide: proxy_binary_op!(_ifloordiv),
+            inplace_true_divide: proxy_binary_op!(_itruediv),
+            index: Some(|number, vm| {
+                let zelf = number.obj.downcast_ref::<PyWeakProxy>().unwrap();
+                zelf.try_upgrade(vm)?.try_index(vm).map(Into::into)
+            }),
+            matrix_multiply: proxy_binary_op!(_matmul),
+            inplace_matrix_multiply: proxy_binary_op!(_imatmul),
             ..PyNumberMethods::NOT_IMPLEMENTED
         });
         &AS_NUMBER
@@ -78,6 +78,23 @@ struct DictInner<T> {
     filled: usize,
     indices: Vec<IndexEntry>,
     entries: Vec<Option<DictEntry<T>>>,
+    /// ma_version_tag: globally unique, bumped on every mutation. Lets
+    /// caches detect that a dict is unchanged with a single load.
+    version: u64,
+}
+
+/// Source of dict version tags; never hands out the same value twice.
+fn next_dict_version() -> u64 {
+    use core::sync::atomic::{AtomicU64, Ordering};
+    static DICT_VERSION: AtomicU64 = AtomicU64::new(1);
+    DICT_VERSION.fetch_add(1, Ordering::Relaxed)
+}
+
+impl<T> DictInner<T> {
+    #[inline]
+    fn bump_version(&mut self) {
+        self.version = next_dict_version();
+    }
 }
 
 unsafe impl<T: Traverse> Traverse for DictInner<T> {
@@ -96,8 +113,10 @@ unsafe impl<T: Traverse> Traverse for DictInner<T> {
 
 impl<T: Clone> Clone for Dict<T> {
     fn clone(&self) -> Self {
+        let mut inner = self.inner.read().clone();
+        inner.bump_version();
         Self {
-            inner: PyRwLock::new(self.inner.read().clone()),
+            inner: PyRwLock::new(inner),
         }
     }
 }
@@ -110,6 +129,7 @@ impl<T> Default for Dict<T> {
                 filled: 0,
                 indices: vec![IndexEntry::FREE; 8],
                 entries: Vec::new(),
+                version: next_dict_version(),
             }),
         }
     }
@@ -215,6 +235,7 @@ impl<T> DictInner<T> {
             // grow to `usize-2` because hash tables cannot full its index
             IndexEntry::from_index_unchecked(entry_index)
         };
+        self.bump_version();
         self.used += 1;
         if let IndexEntry::FREE = index_entry {
             self.filled += 1;
@@ -283,6 +304,7 @@ impl<T: Clone> Dict<T> {
                     };
                     if entry.index == index_index {
                         let removed = core::mem::replace(&mut entry.value, value);
+                        inner.bump_version();
                         // defer dec RC
                         break Some(removed);
                     } else {
@@ -357,6 +379,7 @@ impl<T: Clone> Dict<T> {
             inner.indices.resize(8, IndexEntry::FREE);
             inner.used = 0;
             inner.filled = 0;
+            inner.bump_version();
             // defer dec rc
             core::mem::take(&mut inner.entries)
         };
@@ -510,6 +533,11 @@ impl<T: Clone> Dict<T> {
         self.read().used
     }
 
+    /// The dict's current version tag; changes on every mutation.
+    pub fn version(&self) -> u64 {
+        self.read().version
+    }
+
     pub fn size(&self) -> DictSize {
         self.read().size()
     }
@@ -684,6 +712,7 @@ impl<T: Clone> Dict<T> {
             inner.indices.get_unchecked_mut(index_index)
         } = IndexEntry::DUMMY;
         inner.used -= 1;
+        inner.bump_version();
         let removed = slot.take();
         Ok(ControlFlow::Break(removed))
     }
@@ -714,6 +743,7 @@ impl<T: Clone> Dict<T> {
             // entry.index always refers valid index
             inner.indices.get_unchecked_mut(entry.index)
         } = IndexEntry::DUMMY;
+        inner.bump_version();
         Some((entry.key, entry.value))
     }
 
@@ -733,6 +763,7 @@ impl<T: Clone> Dict<T> {
         inner.used = 0;
         inner.filled = 0;
         inner.indices.iter_mut().for_each(|i| *i = IndexEntry::FREE);
+        inner.bump_version();
         inner.entries.drain(..).flatten().map(|e| (e.key, e.value))
     }
 }
@@ -805,6 +836,9 @@ impl DictKey for Py<PyStr> {
     fn key_eq(&self, vm: &VirtualMachine, other_key: &PyObject) -> PyResult<bool> {
         if self.is(other_key) {
             Ok(true)
+        } else if self.as_object().is_interned() && other_key.is_interned() {
+            // the pool deduplicates, so two distinct interned strings differ
+            Ok(false)
         } else if let Some(pystr) = other_key.downcast_ref_if_exact::<PyStr>(vm) {
             Ok(self.as_wtf8() == pystr.as_wtf8())
         } else {
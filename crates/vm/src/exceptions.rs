@@ -362,12 +362,12 @@ fn print_source_line<W: Write>(
     output: &mut W,
     filename: &str,
     lineno: usize,
-) -> Result<(), W::Error> {
+) -> Result<Option<String>, W::Error> {
     // TODO: use io.open() method instead, when available, according to https://github.com/python/cpython/blob/main/Python/traceback.c#L393
     // TODO: support different encodings
     let file = match std::fs::File::open(filename) {
         Ok(file) => file,
-        Err(_) => return Ok(()),
+        Err(_) => return Ok(None),
     };
     let file = BufReader::new(file);
 
@@ -376,12 +376,119 @@ fn print_source_line<W: Write>(
             if let Ok(line) = line {
                 // Indented with 4 spaces
                 writeln!(output, "    {}", line.trim_start())?;
+                return Ok(Some(line));
             }
-            return Ok(());
+            return Ok(None);
         }
     }
 
-    Ok(())
+    Ok(None)
+}
+
+/// Compute the `^` anchor range inside a PEP 657 source segment.
+///
+/// This is a textual approximation of CPython's anchor extraction: for
+/// trailing subscripts (`a[k]`) and attribute accesses (`a.b`) the primary
+/// part is highlighted with `^` while the value being subscripted or
+/// accessed keeps `~`. Returns the char offset (relative to the segment
+/// start) where the `^` run begins, or `None` if the whole segment should
+/// be underlined with carets.
+fn caret_anchor_offset(segment: &[char]) -> Option<usize> {
+    match segment.last()? {
+        ']' => {
+            // Find the `[` matching the final `]`
+            let mut depth = 0usize;
+            for (i, &c) in segment.iter().enumerate().rev() {
+                match c {
+                    ']' => depth += 1,
+                    '[' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return (i > 0).then_some(i);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            None
+        }
+        c if c.is_alphanumeric() || *c == '_' => {
+            // Trailing attribute access: highlight from the last
+            // top-level `.` onwards
+            let mut depth = 0isize;
+            let mut anchor = None;
+            for (i, &c) in segment.iter().enumerate() {
+                match c {
+                    '(' | '[' | '{' => depth += 1,
+                    ')' | ']' | '}' => depth -= 1,
+                    '.' if depth == 0 => anchor = Some(i),
+                    _ => {}
+                }
+            }
+            anchor.filter(|&i| {
+                i > 0
+                    && segment[i + 1..]
+                        .iter()
+                        .all(|c| c.is_alphanumeric() || *c == '_')
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Write the PEP 657 `~~~^^^` underline for a traceback entry, if the
+/// instruction's source range is confined to the displayed line.
+fn write_caret_line<W: Write>(
+    output: &mut W,
+    tb_entry: &Py<PyTraceback>,
+    line: &str,
+) -> Result<(), W::Error> {
+    let idx = (tb_entry.lasti / 2) as usize;
+    let Some(&(start, end)) = tb_entry.frame.code.locations.get(idx) else {
+        return Ok(());
+    };
+    if start.line != end.line || start.line != tb_entry.lineno {
+        return Ok(());
+    }
+
+    let chars: Vec<char> = line.chars().collect();
+    let indent = chars.iter().take_while(|c| c.is_whitespace()).count();
+    let stripped_len = chars.len() - indent;
+
+    let start_col = start.character_offset.to_zero_indexed();
+    let end_col = end.character_offset.to_zero_indexed();
+    if end_col <= start_col || start_col < indent || end_col > chars.len() {
+        return Ok(());
+    }
+
+    // Like CPython, don't underline when the range covers the whole
+    // (stripped) line and there is no finer-grained anchor to show
+    let segment = &chars[start_col..end_col];
+    let anchor = caret_anchor_offset(segment);
+    if anchor.is_none() && end_col - start_col >= stripped_len {
+        return Ok(());
+    }
+
+    let mut underline = String::with_capacity(end_col - indent);
+    for _ in indent..start_col {
+        underline.push(' ');
+    }
+    match anchor {
+        Some(offset) => {
+            for _ in 0..offset {
+                underline.push('~');
+            }
+            for _ in offset..segment.len() {
+                underline.push('^');
+            }
+        }
+        None => {
+            for _ in 0..segment.len() {
+                underline.push('^');
+            }
+        }
+    }
+    writeln!(output, "    {underline}")
 }
 
 /// Print exception occurrence location from traceback element
@@ -397,7 +504,9 @@ fn write_traceback_entry<W: Write>(
         tb_entry.lineno,
         tb_entry.frame.code.obj_name
     )?;
-    print_source_line(output, filename, tb_entry.lineno.get())?;
+    if let Some(line) = print_source_line(output, filename, tb_entry.lineno.get())? {
+        write_caret_line(output, tb_entry, &line)?;
+    }
 
     Ok(())
 }
@@ -512,6 +621,7 @@ pub struct ExceptionZoo {
     pub not_implemented_error: &'static Py<PyType>,
     pub recursion_error: &'static Py<PyType>,
     pub python_finalization_error: &'static Py<PyType>,
+    pub resource_limit_error: &'static Py<PyType>,
     pub syntax_error: &'static Py<PyType>,
     pub incomplete_input_error: &'static Py<PyType>,
     pub indentation_error: &'static Py<PyType>,
@@ -824,6 +934,7 @@ impl ExceptionZoo {
         let not_implemented_error = PyNotImplementedError::init_builtin_type();
         let recursion_error = PyRecursionError::init_builtin_type();
         let python_finalization_error = PyPythonFinalizationError::init_builtin_type();
+        let resource_limit_error = PyResourceLimitError::init_builtin_type();
 
         let syntax_error = PySyntaxError::init_builtin_type();
         let incomplete_input_error = PyIncompleteInputError::init_builtin_type();
@@ -900,6 +1011,7 @@ impl ExceptionZoo {
             not_implemented_error,
             recursion_error,
             python_finalization_error,
+            resource_limit_error,
             syntax_error,
             incomplete_input_error,
             indentation_error,
@@ -1021,6 +1133,7 @@ impl ExceptionZoo {
             ctx,
             excs.python_finalization_error
         );
+        extend_exception!(PyResourceLimitError, ctx, excs.resource_limit_error);
 
         extend_exception!(PySyntaxError, ctx, excs.syntax_error, {
             "msg" => ctx.new_static_getset(
@@ -2288,6 +2401,13 @@ pub(super) mod types {
     #[repr(transparent)]
     pub struct PyPythonFinalizationError(PyRuntimeError);
 
+    /// RustPython extension: raised when a `Settings` execution resource
+    /// limit (instruction budget or memory cap) is exceeded.
+    #[pyexception(name, base = PyRuntimeError, ctx = "resource_limit_error", impl)]
+    #[derive(Debug)]
+    #[repr(transparent)]
+    pub struct PyResourceLimitError(PyRuntimeError);
+
     #[pyexception(name, base = PyException, ctx = "syntax_error")]
     #[derive(Debug)]
     #[repr(transparent)]
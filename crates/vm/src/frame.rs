@@ -4,8 +4,8 @@ use crate::{
     AsObject, Py, PyObject, PyObjectRef, PyPayload, PyRef, PyResult, TryFromObject, VirtualMachine,
     builtins::{
         PyBaseException, PyBaseExceptionRef, PyCode, PyCoroutine, PyDict, PyDictRef, PyGenerator,
-        PyInterpolation, PyList, PySet, PySlice, PyStr, PyStrInterned, PyTemplate, PyTraceback,
-        PyType,
+        PyInt, PyInterpolation, PyList, PySet, PySlice, PyStr, PyStrInterned, PyTemplate,
+        PyTraceback, PyType,
         asyncgenerator::PyAsyncGenWrappedValue,
         function::{PyCell, PyCellRef, PyFunction},
         tuple::{PyTuple, PyTupleRef},
@@ -754,6 +754,7 @@ impl ExecutingFrame<'_> {
         vm: &VirtualMachine,
     ) -> FrameResult {
         vm.check_signals()?;
+        vm.check_resource_limits()?;
 
         flame_guard!(format!(
             "Frame::execute_instruction({})",
@@ -793,13 +794,18 @@ impl ExecutingFrame<'_> {
                 let stop = self.pop_value();
                 let start = self.pop_value();
                 let container = self.pop_value();
-                let slice: PyObjectRef = PySlice {
-                    start: Some(start),
-                    stop,
-                    step: None,
-                }
-                .into_ref(&vm.ctx)
-                .into();
+                // `obj[:]` reuses the cached full slice instead of allocating
+                let slice: PyObjectRef = if vm.is_none(&start) && vm.is_none(&stop) {
+                    vm.ctx.full_slice.clone().into()
+                } else {
+                    PySlice {
+                        start: Some(start),
+                        stop,
+                        step: None,
+                    }
+                    .into_ref(&vm.ctx)
+                    .into()
+                };
                 let result = container.get_item(&*slice, vm)?;
                 self.push_value(result);
                 Ok(None)
@@ -897,8 +903,21 @@ impl ExecutingFrame<'_> {
             }
             Instruction::Call { nargs } => {
                 // Stack: [callable, self_or_null, arg1, ..., argN]
-                let args = self.collect_positional_args(nargs.get(arg));
-                self.execute_call(args, vm)
+                // Vectorcall-style fast path: a bound `self` sits directly
+                // under the arguments, so collect it together with them in a
+                // single pass instead of prepending it to a rebuilt vector.
+                let nargs = nargs.get(arg) as usize;
+                let stack_len = self.state.stack.len();
+                let has_self = self.state.stack[stack_len - nargs - 1].is_some();
+                let count = if has_self { nargs + 1 } else { nargs };
+                let args: Vec<PyObjectRef> = self.pop_multiple(count).collect();
+                if !has_self {
+                    self.pop_value_opt(); // discard the NULL self slot
+                }
+                let callable = self.pop_value();
+                let value = callable.call(args, vm)?;
+                self.push_value(value);
+                Ok(None)
             }
             Instruction::CallKw { nargs } => {
                 // Stack: [callable, self_or_null, arg1, ..., argN, kwarg_names]
@@ -1592,8 +1611,9 @@ impl ExecutingFrame<'_> {
                 Ok(None)
             }
             Instruction::LoadGlobal(idx) => {
-                let name = &self.code.names[idx.get(arg) as usize];
-                let x = self.load_global_or_builtin(name, vm)?;
+                let idx = idx.get(arg);
+                let name = &self.code.names[idx as usize];
+                let x = self.load_global_cached(idx, name, vm)?;
                 self.push_value(x);
                 Ok(None)
             }
@@ -2288,6 +2308,33 @@ impl ExecutingFrame<'_> {
         }
     }
 
+    /// `LoadGlobal` with an inline cache: version tags of the globals and
+    /// builtins dicts validate the cached value; any mutation of either dict
+    /// changes its tag and transparently deoptimizes the entry.
+    fn load_global_cached(&self, idx: u32, name: &Py<PyStr>, vm: &VirtualMachine) -> PyResult {
+        let Some(builtins_dict) = self.builtins.downcast_ref::<PyDict>() else {
+            return self.load_global_or_builtin(name, vm);
+        };
+        let globals_version = self.globals.version();
+        let builtins_version = builtins_dict.version();
+        if let Some(entry) = self.code.global_cache.lock().get(&idx)
+            && entry.globals_version == globals_version
+            && entry.builtins_version == builtins_version
+        {
+            return Ok(entry.value.clone());
+        }
+        let value = self.load_global_or_builtin(name, vm)?;
+        self.code.global_cache.lock().insert(
+            idx,
+            crate::builtins::code::GlobalCacheEntry {
+                globals_version,
+                builtins_version,
+                value: value.clone(),
+            },
+        );
+        Ok(value)
+    }
+
     #[inline]
     fn load_global_or_builtin(&self, name: &Py<PyStr>, vm: &VirtualMachine) -> PyResult {
         if let Some(builtins_dict) = self.builtins.downcast_ref::<PyDict>() {
@@ -2571,23 +2618,24 @@ impl ExecutingFrame<'_> {
         let stop = self.pop_value();
         let start = self.pop_value();
 
-        let obj = PySlice {
-            start: Some(start),
-            stop,
-            step,
-        }
-        .into_ref(&vm.ctx);
+        // `obj[::]` and friends reuse the cached full slice
+        let all_none = vm.is_none(&start)
+            && vm.is_none(&stop)
+            && step.as_ref().is_none_or(|step| vm.is_none(step));
+        let obj = if all_none {
+            vm.ctx.full_slice.clone()
+        } else {
+            PySlice {
+                start: Some(start),
+                stop,
+                step,
+            }
+            .into_ref(&vm.ctx)
+        };
         self.push_value(obj.into());
         Ok(None)
     }
 
-    fn collect_positional_args(&mut self, nargs: u32) -> FuncArgs {
-        FuncArgs {
-            args: self.pop_multiple(nargs as usize).collect(),
-            kwargs: IndexMap::new(),
-        }
-    }
-
     fn collect_keyword_args(&mut self, nargs: u32) -> FuncArgs {
         let kwarg_names = self
             .pop_value()
@@ -2708,20 +2756,12 @@ impl ExecutingFrame<'_> {
         let callable = self.pop_value();
 
         // If self_or_null is Some (not NULL), prepend it to args
-        let final_args = if let Some(self_val) = self_or_null {
-            // Method call: prepend self to args
-            let mut all_args = vec![self_val];
-            all_args.extend(args.args);
-            FuncArgs {
-                args: all_args,
-                kwargs: args.kwargs,
-            }
-        } else {
-            // Regular attribute call: self_or_null is NULL
-            args
-        };
+        let mut args = args;
+        if let Some(self_val) = self_or_null {
+            args.prepend_arg(self_val);
+        }
 
-        let value = callable.call(final_args, vm)?;
+        let value = callable.call(args, vm)?;
         self.push_value(value);
         Ok(None)
     }
@@ -3092,6 +3132,32 @@ impl ExecutingFrame<'_> {
     ) -> FrameResult {
         let b = self.pop_value();
         let a = self.pop_value();
+        // Fast path for exact int/str (in)equality, the compare that literal
+        // `match` cases and dispatch-style if/elif chains emit. Exact-type
+        // checks keep subclasses (including bool) on the generic path.
+        if matches!(
+            op,
+            bytecode::ComparisonOperator::Equal | bytecode::ComparisonOperator::NotEqual
+        ) {
+            let eq = if let (Some(a), Some(b)) = (
+                a.downcast_ref_if_exact::<PyInt>(vm),
+                b.downcast_ref_if_exact::<PyInt>(vm),
+            ) {
+                Some(a.as_bigint() == b.as_bigint())
+            } else if let (Some(a), Some(b)) = (
+                a.downcast_ref_if_exact::<PyStr>(vm),
+                b.downcast_ref_if_exact::<PyStr>(vm),
+            ) {
+                Some(a.as_wtf8() == b.as_wtf8())
+            } else {
+                None
+            };
+            if let Some(eq) = eq {
+                let value = eq == matches!(op, bytecode::ComparisonOperator::Equal);
+                self.push_value(vm.ctx.new_bool(value).into());
+                return Ok(None);
+            }
+        }
         let value = a.rich_compare(b, op.into(), vm)?;
         self.push_value(value);
         Ok(None)
@@ -3180,6 +3246,10 @@ impl ExecutingFrame<'_> {
     #[inline]
     #[track_caller] // not a real track_caller but push_value is less useful for debugging
     fn push_value_opt(&mut self, obj: Option<PyObjectRef>) {
+        #[cfg(feature = "debug-checks")]
+        if let Some(obj) = &obj {
+            obj.debug_sanity_check();
+        }
         match self.state.stack.try_push(obj) {
             Ok(()) => {}
             Err(_e) => self.fatal("tried to push value onto stack but overflowed max_stackdepth"),
@@ -3201,7 +3271,14 @@ impl ExecutingFrame<'_> {
     #[inline]
     fn pop_value_opt(&mut self) -> Option<PyObjectRef> {
         match self.state.stack.pop() {
-            Some(slot) => slot, // slot is Option<PyObjectRef>
+            Some(slot) => {
+                // slot is Option<PyObjectRef>
+                #[cfg(feature = "debug-checks")]
+                if let Some(obj) = &slot {
+                    obj.debug_sanity_check();
+                }
+                slot
+            }
             None => self.fatal("tried to pop from empty stack"),
         }
     }
@@ -4,10 +4,10 @@
 //! for RustPython, using an intrusive doubly-linked list approach.
 
 use crate::common::lock::PyMutex;
-use crate::{PyObject, PyObjectRef};
+use crate::{AsObject, PyObject, PyObjectRef};
 use core::ptr::NonNull;
 use core::sync::atomic::{AtomicBool, AtomicU32, AtomicUsize, Ordering};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Mutex, RwLock};
 
 bitflags::bitflags! {
@@ -387,23 +387,156 @@ impl GcState {
 
     /// Perform garbage collection on the given generation.
     /// Returns (collected_count, uncollectable_count).
-    ///
-    /// Currently a stub — the actual collection algorithm requires EBR
-    /// and will be added in a follow-up.
-    pub fn collect(&self, _generation: usize) -> (usize, usize) {
-        // gc_collect_main
-        // Reset gen0 count even though we're not actually collecting
-        self.generations[0].count.store(0, Ordering::SeqCst);
-        (0, 0)
+    pub fn collect(&self, generation: usize) -> (usize, usize) {
+        self.collect_inner(generation)
     }
 
     /// Force collection even if GC is disabled (for manual gc.collect() calls).
     /// gc.collect() always runs regardless of gc.isenabled()
-    /// Currently a stub.
-    pub fn collect_force(&self, _generation: usize) -> (usize, usize) {
-        // Reset gen0 count even though we're not actually collecting
-        self.generations[0].count.store(0, Ordering::SeqCst);
-        (0, 0)
+    pub fn collect_force(&self, generation: usize) -> (usize, usize) {
+        self.collect_inner(generation)
+    }
+
+    /// The actual cycle collection algorithm (gc_collect_main).
+    ///
+    /// This is the classic CPython `gc_refs` scheme built on the
+    /// `#[pytraverse]` infrastructure: every candidate starts with its
+    /// external reference count, references originating from other
+    /// candidates are subtracted away, and reachability is then propagated
+    /// from the candidates that are still referenced from outside. Whatever
+    /// remains is cyclic garbage; its cycles are broken with `gc_clear`
+    /// (tp_clear) so the objects die through the normal dealloc path, which
+    /// runs `__del__` and clears weakrefs.
+    fn collect_inner(&self, generation: usize) -> (usize, usize) {
+        // Prevent concurrent/reentrant collections (e.g. from an allocation
+        // made by a `__del__` finalizer while we're clearing garbage).
+        let Ok(_guard) = self.collecting.try_lock() else {
+            return (0, 0);
+        };
+        let generation = generation.min(2);
+        let debug = self.get_debug();
+        if debug.contains(GcDebugFlags::STATS) {
+            eprintln!("gc: collecting generation {generation}...");
+        }
+
+        // Snapshot candidates from the collected generations, holding strong
+        // references so nothing is freed while we look at it.
+        let mut candidates: Vec<PyObjectRef> = Vec::new();
+        for gen_idx in 0..=generation {
+            if let Ok(gen_set) = self.generation_objects[gen_idx].read() {
+                for ptr in gen_set.iter() {
+                    let obj = unsafe { ptr.0.as_ref() };
+                    if obj.strong_count() > 0 {
+                        candidates.push(obj.to_owned());
+                    }
+                }
+            }
+        }
+
+        // With debug-checks on, validate every candidate and each object its
+        // traverse edges reach before touching anything: a dead or
+        // out-of-range referent here means a container is holding a stale
+        // pointer, which the collection below would turn into a use-after-free
+        #[cfg(feature = "debug-checks")]
+        for obj in &candidates {
+            obj.debug_sanity_check();
+            for child in obj.gc_get_referents() {
+                child.debug_sanity_check();
+            }
+        }
+
+        // subtract_refs: start from the reference count minus the reference
+        // we hold in `candidates`, then subtract every reference that
+        // originates from another candidate. Candidates left with
+        // gc_refs > 0 are referenced from outside the candidate set.
+        let mut gc_refs: HashMap<usize, isize> = candidates
+            .iter()
+            .map(|obj| (obj.get_id(), obj.strong_count() as isize - 1))
+            .collect();
+        for obj in &candidates {
+            for child in obj.gc_get_referents() {
+                if let Some(refs) = gc_refs.get_mut(&child.get_id()) {
+                    *refs -= 1;
+                }
+            }
+        }
+
+        // move_unreachable: propagate reachability from the externally
+        // referenced roots through traverse edges.
+        let mut reachable: HashSet<usize> = gc_refs
+            .iter()
+            .filter(|(_, &refs)| refs > 0)
+            .map(|(&id, _)| id)
+            .collect();
+        let mut stack: Vec<PyObjectRef> = candidates
+            .iter()
+            .filter(|obj| reachable.contains(&obj.get_id()))
+            .cloned()
+            .collect();
+        while let Some(obj) = stack.pop() {
+            for child in obj.gc_get_referents() {
+                let id = child.get_id();
+                if gc_refs.contains_key(&id) && reachable.insert(id) {
+                    stack.push(child);
+                }
+            }
+        }
+
+        let (survivors, unreachable): (Vec<_>, Vec<_>) = candidates
+            .into_iter()
+            .partition(|obj| reachable.contains(&obj.get_id()));
+        let collected = unreachable.len();
+
+        // Rebuild the generation lists before any object can die, so the
+        // dealloc path's untrack never races with our bookkeeping: drain
+        // the collected generations and promote survivors.
+        let target = (generation + 1).min(2);
+        for gen_idx in 0..=generation {
+            if let Ok(mut gen_set) = self.generation_objects[gen_idx].write() {
+                gen_set.clear();
+            }
+            self.generations[gen_idx].count.store(0, Ordering::SeqCst);
+        }
+        if let Ok(mut target_set) = self.generation_objects[target].write() {
+            for obj in &survivors {
+                target_set.insert(GcObjectPtr(NonNull::from(&**obj)));
+            }
+            self.generations[target]
+                .count
+                .store(survivors.len(), Ordering::SeqCst);
+        }
+
+        if debug.contains(GcDebugFlags::COLLECTABLE) {
+            for obj in &unreachable {
+                eprintln!(
+                    "gc: collectable <{} {:#x}>",
+                    obj.class().name(),
+                    obj.get_id()
+                );
+            }
+        }
+
+        if debug.contains(GcDebugFlags::SAVEALL) {
+            // Keep the garbage alive in gc.garbage instead of clearing it
+            self.garbage.lock().extend(unreachable.iter().cloned());
+        } else {
+            // Break the cycles. The extracted edges are dropped after the
+            // loop, at which point reference counts inside the cycles reach
+            // zero and the objects are freed normally.
+            let mut edges = Vec::new();
+            for obj in &unreachable {
+                edges.extend(unsafe { obj.gc_clear() });
+            }
+            drop(edges);
+        }
+        drop(unreachable);
+
+        self.alloc_count.store(0, Ordering::SeqCst);
+        self.generations[generation].update_stats(collected, 0);
+        if debug.contains(GcDebugFlags::STATS) {
+            eprintln!("gc: done, {collected} unreachable, 0 uncollectable");
+        }
+        (collected, 0)
     }
 
     /// Get count of frozen objects
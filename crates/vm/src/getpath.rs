@@ -110,7 +110,7 @@ pub fn init_path_config(settings: &Settings) -> Paths {
 
     // Step 0: Get executable path
     let executable = get_executable_path();
-    let real_executable = executable
+    let invoked_executable = executable
         .as_ref()
         .map(|p| p.to_string_lossy().into_owned())
         .unwrap_or_default();
@@ -122,10 +122,17 @@ pub fn init_path_config(settings: &Settings) -> Paths {
     //   - sys._base_executable should be the real Python executable
     let exe_dir = if let Ok(launcher) = env::var("__PYVENV_LAUNCHER__") {
         paths.executable = launcher.clone();
-        paths.base_executable = real_executable;
+        // multiprocessing spawn and venv re-exec through sys._base_executable;
+        // hand them the symlink-resolved interpreter, not the launcher target.
+        paths.base_executable = executable
+            .as_ref()
+            .map(|p| resolve_symlinks(p).to_string_lossy().into_owned())
+            .unwrap_or_default();
         PathBuf::from(&launcher).parent().map(PathBuf::from)
     } else {
-        paths.executable = real_executable;
+        // Keep the path as invoked; a venv's bin/python symlink must survive
+        // into sys.executable so the venv stays active across re-exec.
+        paths.executable = invoked_executable;
         executable
             .as_ref()
             .and_then(|p| p.parent().map(PathBuf::from))
@@ -133,7 +140,15 @@ pub fn init_path_config(settings: &Settings) -> Paths {
 
     // Step 2: Check for venv (pyvenv.cfg) and get 'home'
     let (venv_prefix, home_dir) = detect_venv(&exe_dir);
-    let search_dir = home_dir.clone().or(exe_dir.clone());
+
+    // Step 2.5: Resolve symlinks in the executable so the landmark search
+    // starts from the real installation directory (ref: getpath.py
+    // "Resolve symlinks in executable")
+    let real_exe_dir = executable
+        .as_ref()
+        .map(|p| resolve_symlinks(p))
+        .and_then(|p| p.parent().map(PathBuf::from));
+    let search_dir = home_dir.clone().or(real_exe_dir).or(exe_dir.clone());
 
     // Step 3: Check for build directory
     let build_prefix = detect_build_directory(&search_dir);
@@ -295,6 +310,17 @@ fn calculate_base_executable(executable: Option<&PathBuf>, home_dir: &Option<Pat
         && let Some(exe_name) = exe.file_name()
     {
         let base = home.join(exe_name);
+        if base.is_file() {
+            return base.to_string_lossy().into_owned();
+        }
+        // The venv may have been entered through an aliased symlink
+        // (bin/python -> rustpython); try the names the venv builder lays out.
+        for name in ["rustpython", "python3", "python"] {
+            let candidate = home.join(name);
+            if candidate.is_file() {
+                return candidate.to_string_lossy().into_owned();
+            }
+        }
         return base.to_string_lossy().into_owned();
     }
 
@@ -355,12 +381,22 @@ fn build_module_search_paths(settings: &Settings, prefix: &str, exec_prefix: &st
     paths
 }
 
+/// Resolve symlinks in a path, returning it unchanged on failure
+fn resolve_symlinks(path: &Path) -> PathBuf {
+    std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
 /// Get the current executable path
 fn get_executable_path() -> Option<PathBuf> {
     #[cfg(not(target_arch = "wasm32"))]
     {
         let exec_arg = env::args_os().next()?;
-        which::which(exec_arg).ok()
+        // argv[0] may be a bare name that is no longer on PATH after a
+        // re-exec; fall back to the OS-reported executable in that case.
+        match which::which(exec_arg) {
+            Ok(path) => Some(path),
+            Err(_) => env::current_exe().ok(),
+        }
     }
     #[cfg(target_arch = "wasm32")]
     {
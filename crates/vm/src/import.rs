@@ -97,8 +97,10 @@ pub fn import_builtin(vm: &VirtualMachine, module_name: &str) -> PyResult {
         return Ok(module);
     }
 
-    // Try multi-phase init first (preferred for modules that import other modules)
-    if let Some(&def) = vm.state.module_defs.get(module_name) {
+    // Try multi-phase init first (preferred for modules that import other modules).
+    // Copy the def out so the lock isn't held while the module initializes
+    let def = vm.state.module_defs.lock().get(module_name).copied();
+    if let Some(def) = def {
         // Phase 1: Create and initialize module
         let module = def.create_module(vm)?;
 
@@ -89,6 +89,14 @@ pub(super) unsafe fn default_dealloc<T: PyPayload>(obj: *mut PyObject) {
         return; // resurrected by __del__
     }
 
+    // Remove the object from the GC lists before its memory goes away so the
+    // tracked sets never hold a dangling pointer.
+    if obj_ref.is_gc_tracked() {
+        unsafe {
+            crate::gc_state::gc_state().untrack_object(NonNull::new_unchecked(obj));
+        }
+    }
+
     // Extract child references before deallocation to break circular refs (tp_clear).
     // This ensures that when edges are dropped after the object is freed,
     // any pointers back to this object are already gone.
@@ -97,6 +105,11 @@ pub(super) unsafe fn default_dealloc<T: PyPayload>(obj: *mut PyObject) {
         unsafe { clear_fn(obj, &mut edges) };
     }
 
+    crate::stdlib::tracemalloc::record_free(core::mem::size_of::<PyInner<T>>());
+    crate::object::limits::record_free(core::mem::size_of::<PyInner<T>>());
+    #[cfg(feature = "count-objects")]
+    crate::object::count::record_free(obj_ref.class());
+
     // Deallocate the object memory
     drop(unsafe { Box::from_raw(obj as *mut PyInner<T>) });
 
@@ -583,6 +596,26 @@ impl PyWeak {
         self.wr_object.load(Ordering::Acquire).is_null()
     }
 
+    /// The callback registered for this weakref, if any; cleared once the
+    /// referent dies. Exposed to Python as `__callback__`.
+    pub(crate) fn callback(&self) -> Option<PyObjectRef> {
+        let obj_ptr = self.wr_object.load(Ordering::Acquire);
+        if obj_ptr.is_null() {
+            return None;
+        }
+
+        let _lock = weakref_lock::lock(obj_ptr as usize);
+
+        // Double-check under lock (clear may have run in between)
+        if self.wr_object.load(Ordering::Relaxed).is_null() {
+            return None;
+        }
+
+        // SAFETY: the stripe lock guards all callback accesses while the
+        // referent is alive.
+        unsafe { (*self.callback.get()).clone() }
+    }
+
     /// weakref_dealloc: remove from list if still linked.
     fn drop_inner(&self) {
         let obj_ptr = self.wr_object.load(Ordering::Acquire);
@@ -1004,6 +1037,25 @@ impl PyObject {
         self.0.ref_count.get()
     }
 
+    /// Py_DEBUG-style invariant check: a reachable object must have a live,
+    /// in-range refcount. Violations mean a premature drop or a stale pointer
+    /// and panic immediately instead of corrupting memory further down
+    #[cfg(feature = "debug-checks")]
+    pub(crate) fn debug_sanity_check(&self) {
+        // mask off the leak marker bit set for interned objects
+        let count = self.strong_count() & (isize::MAX as usize);
+        assert!(
+            count > 0,
+            "invariant violation: reachable {} object has refcount 0",
+            self.class().name()
+        );
+        assert!(
+            count < isize::MAX as usize,
+            "invariant violation: {} object refcount {count:#x} out of range",
+            self.class().name()
+        );
+    }
+
     #[inline]
     pub fn weak_count(&self) -> Option<usize> {
         self.weak_ref_list().map(|wrl| wrl.count(self))
@@ -1129,7 +1181,16 @@ impl PyObject {
     }
 
     pub(crate) fn set_slot(&self, offset: usize, value: Option<PyObjectRef>) {
-        *self.0.slots[offset].write() = value;
+        let old = core::mem::replace(&mut *self.0.slots[offset].write(), value);
+        // drop the old value after the slot lock is released; its destructor
+        // may run arbitrary python code that touches this slot again
+        drop(old);
+    }
+
+    /// Clear a slot, returning the previous value. A single atomic swap, so
+    /// concurrent deletes cannot both observe the slot as filled.
+    pub(crate) fn take_slot(&self, offset: usize) -> Option<PyObjectRef> {
+        self.0.slots[offset].write().take()
     }
 
     /// _PyObject_GC_IS_TRACKED
@@ -1465,6 +1526,11 @@ impl<T: PyPayload + crate::object::MaybeTraverse + core::fmt::Debug> PyRef<T> {
         let inner = Box::into_raw(PyInner::new(payload, typ, dict));
         let ptr = unsafe { NonNull::new_unchecked(inner.cast::<Py<T>>()) };
 
+        crate::stdlib::tracemalloc::record_alloc(core::mem::size_of::<PyInner<T>>());
+        crate::object::limits::record_alloc(core::mem::size_of::<PyInner<T>>());
+        #[cfg(feature = "count-objects")]
+        crate::object::count::record_alloc(unsafe { ptr.as_ref() }.class());
+
         // Track object if:
         // - HAS_TRAVERSE is true (Rust payload implements Traverse), OR
         // - has instance dict (user-defined class instances), OR
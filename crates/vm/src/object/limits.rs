@@ -0,0 +1,43 @@
+//! Live-byte accounting for `Settings::memory_limit`.
+//!
+//! Tracking is hooked into the same allocation/deallocation paths in
+//! `object::core` as tracemalloc, counting the inline size of every
+//! `PyInner<T>`. It stays a single flag test per allocation until an
+//! interpreter with a memory limit is created; like tracemalloc, the
+//! accounting is process-wide because the allocator has no interpreter
+//! reference. The cap itself is enforced in the eval loop, where an
+//! exception can be raised.
+
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+static TRACKING: AtomicBool = AtomicBool::new(false);
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// Start counting live bytes; called when an interpreter with
+/// `Settings::memory_limit` set is created. Never turned off again.
+pub(crate) fn enable_tracking() {
+    TRACKING.store(true, Ordering::Relaxed);
+}
+
+/// Record an object allocation of `size` bytes; called from `PyRef::new_ref`.
+#[inline]
+pub(crate) fn record_alloc(size: usize) {
+    if TRACKING.load(Ordering::Relaxed) {
+        LIVE_BYTES.fetch_add(size, Ordering::Relaxed);
+    }
+}
+
+/// Record an object deallocation of `size` bytes; called from dealloc.
+#[inline]
+pub(crate) fn record_free(size: usize) {
+    if TRACKING.load(Ordering::Relaxed) {
+        let _ = LIVE_BYTES.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |live| {
+            Some(live.saturating_sub(size))
+        });
+    }
+}
+
+/// Bytes of objects currently live, counted since tracking was enabled.
+pub(crate) fn live_bytes() -> usize {
+    LIVE_BYTES.load(Ordering::Relaxed)
+}
@@ -1,5 +1,8 @@
 mod core;
+#[cfg(feature = "count-objects")]
+pub(crate) mod count;
 mod ext;
+pub(crate) mod limits;
 mod payload;
 mod traverse;
 mod traverse_object;
@@ -157,6 +157,7 @@ impl PathConverter {
             s @ PyStr => {
                 check_nul(s.as_bytes())?;
                 let path = vm.fsencode(&s)?.into_owned();
+                vm.check_fs_capability(Path::new(&path))?;
                 Ok(Ok(OsPath {
                     path,
                     origin: Some(s.into()),
@@ -165,6 +166,7 @@ impl PathConverter {
             b @ PyBytes => {
                 check_nul(&b)?;
                 let path = FsPath::bytes_as_os_str(&b, vm)?.to_owned();
+                vm.check_fs_capability(Path::new(&path))?;
                 Ok(Ok(OsPath {
                     path,
                     origin: Some(b.into()),
@@ -217,6 +219,7 @@ impl OsPath {
 
     pub(crate) fn from_fspath(fspath: FsPath, vm: &VirtualMachine) -> PyResult<Self> {
         let path = fspath.as_os_str(vm)?.into_owned();
+        vm.check_fs_capability(Path::new(&path))?;
         let origin = match fspath {
             FsPath::Str(s) => s.into(),
             FsPath::Bytes(b) => b.into(),
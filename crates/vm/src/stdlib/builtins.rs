@@ -110,6 +110,76 @@ mod builtins {
         _feature_version: OptionalArg<i32>,
     }
 
+    /// PEP 263: find `coding[:=]<name>` in a comment line.
+    #[cfg(all(feature = "parser", feature = "ast"))]
+    fn find_coding_cookie(line: &[u8]) -> Option<&str> {
+        let line = core::str::from_utf8(line).ok()?;
+        let rest = line
+            .trim_start_matches([' ', '\t', '\x0c'])
+            .strip_prefix('#')?;
+        let rest = &rest[rest.find("coding")? + "coding".len()..];
+        let rest = rest
+            .strip_prefix(':')
+            .or_else(|| rest.strip_prefix('='))?
+            .trim_start_matches([' ', '\t']);
+        let name = rest
+            .split(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')))
+            .next()
+            .unwrap_or("");
+        (!name.is_empty()).then_some(name)
+    }
+
+    /// Decode a bytes argument to compile() the way the tokenizer reads source
+    /// files: honor a UTF-8 BOM and a PEP 263 coding cookie on one of the
+    /// first two lines, defaulting to UTF-8.
+    #[cfg(all(feature = "parser", feature = "ast"))]
+    fn decode_source_bytes(source: &[u8], vm: &VirtualMachine) -> PyResult<String> {
+        fn is_blank_or_comment(line: &[u8]) -> bool {
+            let trimmed = line
+                .iter()
+                .position(|&b| !matches!(b, b' ' | b'\t' | b'\x0c' | b'\r'))
+                .map_or(&[] as &[u8], |i| &line[i..]);
+            trimmed.is_empty() || trimmed[0] == b'#'
+        }
+
+        let (has_bom, source) = match source.strip_prefix(b"\xef\xbb\xbf".as_slice()) {
+            Some(rest) => (true, rest),
+            None => (false, source),
+        };
+        let mut lines = source.split(|&b| b == b'\n');
+        let first = lines.next().unwrap_or(&[]);
+        let mut cookie = find_coding_cookie(first);
+        // like the tokenizer, only look at the second line when the first one
+        // carries no code
+        if cookie.is_none() && is_blank_or_comment(first) {
+            cookie = lines.next().and_then(find_coding_cookie);
+        }
+        if let Some(enc) = cookie {
+            let normalized = enc.to_ascii_lowercase().replace('_', "-");
+            if !matches!(normalized.as_str(), "utf-8" | "utf8" | "utf-8-sig") {
+                if has_bom {
+                    return Err(vm.new_exception_msg(
+                        vm.ctx.exceptions.syntax_error.to_owned(),
+                        format!("encoding problem: {enc} with BOM"),
+                    ));
+                }
+                let bytes = vm.ctx.new_bytes(source.to_vec());
+                let decoded =
+                    vm.state
+                        .codec_registry
+                        .decode_text(bytes.into(), &normalized, None, vm)?;
+                return decoded.to_str().map(str::to_owned).ok_or_else(|| {
+                    vm.new_unicode_decode_error(format!(
+                        "'{normalized}' codec produced unencodable source"
+                    ))
+                });
+            }
+        }
+        core::str::from_utf8(source)
+            .map(str::to_owned)
+            .map_err(|e| vm.new_unicode_decode_error(e.to_string()))
+    }
+
     #[cfg(any(feature = "parser", feature = "compiler"))]
     #[pyfunction]
     fn compile(args: CompileArgs, vm: &VirtualMachine) -> PyResult {
@@ -201,11 +271,20 @@ mod builtins {
                 use ruff_python_parser as parser;
 
                 let source = ArgStrOrBytesLike::try_from_object(vm, args.source)?;
-                let source = source.borrow_bytes();
+                let source_bytes = source.borrow_bytes();
 
                 // TODO: compiler::compile should probably get bytes
-                let source = core::str::from_utf8(&source)
-                    .map_err(|e| vm.new_unicode_decode_error(e.to_string()))?;
+                let decoded;
+                let source = match &source {
+                    // str sources are already decoded; PEP 263 cookies and
+                    // BOMs only apply to bytes
+                    ArgStrOrBytesLike::Str(_) => core::str::from_utf8(&source_bytes)
+                        .map_err(|e| vm.new_unicode_decode_error(e.to_string()))?,
+                    ArgStrOrBytesLike::Buf(_) => {
+                        decoded = decode_source_bytes(&source_bytes, vm)?;
+                        &decoded
+                    }
+                };
 
                 let flags = args.flags.map_or(Ok(0), |v| v.try_to_primitive(vm))?;
 
@@ -453,8 +532,7 @@ mod builtins {
         // Determine code object:
         let code_obj = match source {
             #[cfg(feature = "rustpython-compiler")]
-            Either::A(string) => vm
-                .compile(string.as_str(), mode, "<string>".to_owned())
+            Either::A(string) => compile_cached(vm, string.as_str(), mode)
                 .map_err(|err| vm.new_syntax_error(&err, Some(string.as_str())))?,
             #[cfg(not(feature = "rustpython-compiler"))]
             Either::A(_) => return Err(vm.new_type_error(CODEGEN_NOT_SUPPORTED.to_owned())),
@@ -471,6 +549,36 @@ mod builtins {
         vm.run_code_obj(code_obj, scope)
     }
 
+    /// Compile an eval()/exec() string source, reusing the bounded LRU in
+    /// [`PyGlobalState::code_cache`](crate::vm::PyGlobalState) when the same
+    /// source was compiled before with the same mode and optimization level
+    #[cfg(feature = "rustpython-compiler")]
+    fn compile_cached(
+        vm: &VirtualMachine,
+        source: &str,
+        mode: crate::compiler::Mode,
+    ) -> Result<PyRef<crate::builtins::PyCode>, crate::compiler::CompileError> {
+        const CODE_CACHE_CAPACITY: usize = 128;
+
+        let opts = vm.compile_opts();
+        let key = (source.to_owned(), mode as u8, opts.optimize);
+        {
+            let mut cache = vm.state.code_cache.lock();
+            // a hit moves to the back so eviction drops the least recently used
+            if let Some(code) = cache.shift_remove(&key) {
+                cache.insert(key, code.clone());
+                return Ok(code);
+            }
+        }
+        let code = vm.compile_with_opts(source, mode, "<string>".to_owned(), opts)?;
+        let mut cache = vm.state.code_cache.lock();
+        if cache.len() >= CODE_CACHE_CAPACITY {
+            cache.shift_remove_index(0);
+        }
+        cache.insert(key, code.clone());
+        Ok(code)
+    }
+
     #[pyfunction]
     fn format(
         value: PyObjectRef,
@@ -985,6 +1093,16 @@ mod builtins {
             _ => (),
         });
 
+        // Fused fast path for sum(map(f, iterable)): drive the underlying
+        // iterator directly instead of dispatching through map's iternext
+        // slot per element. Exact `map` only; subclasses may override.
+        if iterable.obj().class().is(vm.ctx.types.map_type)
+            && let Some(map) = iterable.obj().downcast_ref::<crate::builtins::PyMap>()
+            && let Some(result) = map.fold_sum(sum.clone(), vm)
+        {
+            return result;
+        }
+
         for item in iterable.iter(vm)? {
             sum = vm._add(&sum, &*item?)?;
         }
@@ -1109,13 +1227,18 @@ mod builtins {
 
         let bases: PyObjectRef = bases.into();
 
-        // Prepare uses full __getattribute__ resolution chain.
-        let namespace = vm
-            .get_attribute_opt(metaclass.clone(), identifier!(vm, __prepare__))?
-            .map_or(Ok(vm.ctx.new_dict().into()), |prepare| {
-                let args = FuncArgs::new(vec![name_obj.clone(), bases.clone()], kwargs.clone());
-                prepare.call(args, vm)
-            })?;
+        // Fast path: type.__prepare__ just returns a fresh dict, so skip the
+        // full attribute resolution when the metaclass is exactly `type`.
+        let namespace = if metaclass.is(vm.ctx.types.type_type) {
+            vm.ctx.new_dict().into()
+        } else {
+            // Prepare uses full __getattribute__ resolution chain.
+            vm.get_attribute_opt(metaclass.clone(), identifier!(vm, __prepare__))?
+                .map_or(Ok(vm.ctx.new_dict().into()), |prepare| {
+                    let args = FuncArgs::new(vec![name_obj.clone(), bases.clone()], kwargs.clone());
+                    prepare.call(args, vm)
+                })?
+        };
 
         // Accept any PyMapping as namespace.
         let namespace = ArgMapping::try_from_object(vm, namespace.clone()).map_err(|_| {
@@ -1292,6 +1415,8 @@ pub fn init_module(vm: &VirtualMachine, module: &Py<PyModule>) {
         "RuntimeError" => ctx.exceptions.runtime_error.to_owned(),
         "NotImplementedError" => ctx.exceptions.not_implemented_error.to_owned(),
         "RecursionError" => ctx.exceptions.recursion_error.to_owned(),
+        // RustPython extension: raised when a Settings resource limit is exceeded
+        "ResourceLimitError" => ctx.exceptions.resource_limit_error.to_owned(),
         "SyntaxError" =>  ctx.exceptions.syntax_error.to_owned(),
         "_IncompleteInputError" =>  ctx.exceptions.incomplete_input_error.to_owned(),
         "IndentationError" =>  ctx.exceptions.indentation_error.to_owned(),
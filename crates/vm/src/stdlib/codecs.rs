@@ -11,10 +11,11 @@ mod _codecs {
     use crate::common::wtf8::Wtf8Buf;
     use crate::{
         AsObject, PyObjectRef, PyResult, VirtualMachine,
-        builtins::{PyStrRef, PyUtf8StrRef},
+        builtins::{PyDictRef, PyStr, PyStrRef, PyUtf8StrRef},
         codecs,
+        convert::ToPyObject,
         exceptions::cstring_error,
-        function::{ArgBytesLike, FuncArgs},
+        function::{ArgBytesLike, FuncArgs, OptionalArg},
     };
 
     #[pyfunction]
@@ -292,11 +293,65 @@ mod _codecs {
     }
     #[pyfunction]
     fn charmap_decode(args: FuncArgs, vm: &VirtualMachine) -> PyResult {
+        // Fast path for the common case: bytes-like input, a str decoding
+        // table (what every Lib/encodings charmap codec passes) and a builtin
+        // error mode. Dict mappings, custom error handlers and undefined
+        // characters under "strict" still go through _pycodecs, which raises
+        // the fully populated UnicodeDecodeError.
+        if let Ok((data, errors, mapping)) = args.clone().bind::<(
+            ArgBytesLike,
+            OptionalArg<PyUtf8StrRef>,
+            OptionalArg<PyObjectRef>,
+        )>(vm)
+        {
+            let errors = errors.as_ref().map_or("strict", |s| s.as_str());
+            // to_str() is None when the table contains lone surrogates; those
+            // tables are rare enough to leave to _pycodecs.
+            if let OptionalArg::Present(mapping) = &mapping
+                && let Some(table) = mapping.downcast_ref::<PyStr>()
+                && let Some(table) = table.to_str()
+                && matches!(errors, "strict" | "replace" | "ignore")
+            {
+                let table: Vec<char> = table.chars().collect();
+                let bytes = data.borrow_buf();
+                let mut out = String::with_capacity(bytes.len());
+                let mut decoded = true;
+                for &b in bytes.iter() {
+                    match table.get(b as usize) {
+                        Some(&c) if c != '\u{fffe}' => out.push(c),
+                        _ => match errors {
+                            "replace" => out.push('\u{fffd}'),
+                            "ignore" => {}
+                            _ => {
+                                decoded = false;
+                                break;
+                            }
+                        },
+                    }
+                }
+                if decoded {
+                    return Ok((out, bytes.len()).to_pyobject(vm));
+                }
+            }
+        }
         delegate_pycodecs!(charmap_decode, args, vm)
     }
     #[pyfunction]
-    fn charmap_build(args: FuncArgs, vm: &VirtualMachine) -> PyResult {
-        delegate_pycodecs!(charmap_build, args, vm)
+    fn charmap_build(table: PyStrRef, vm: &VirtualMachine) -> PyResult<PyDictRef> {
+        // Same layout _pycodecs.charmap_build produces ({ord(c): index}),
+        // built natively so importing a charmap codec doesn't pull in the
+        // whole _pycodecs module.
+        let dict = vm.ctx.new_dict();
+        // iterate code points rather than chars so tables containing lone
+        // surrogates build the same mapping as _pycodecs
+        for (i, c) in table.as_wtf8().code_points().enumerate() {
+            dict.set_item(
+                vm.ctx.new_int(c.to_u32()).as_object(),
+                vm.ctx.new_int(i).into(),
+                vm,
+            )?;
+        }
+        Ok(dict)
     }
     #[pyfunction]
     fn utf_16_le_encode(args: FuncArgs, vm: &VirtualMachine) -> PyResult {
@@ -540,6 +540,10 @@ pub(crate) mod _ctypes {
         _load_flags: OptionalArg<i32>,
         vm: &VirtualMachine,
     ) -> PyResult<usize> {
+        vm.check_capability(
+            vm.state.config.settings.capabilities.allow_ctypes,
+            "loading foreign libraries",
+        )?;
         // TODO: audit functions first
         // TODO: load_flags
         let cache = library::libcache();
@@ -555,6 +559,10 @@ pub(crate) mod _ctypes {
         load_flags: OptionalArg<i32>,
         vm: &VirtualMachine,
     ) -> PyResult<usize> {
+        vm.check_capability(
+            vm.state.config.settings.capabilities.allow_ctypes,
+            "loading foreign libraries",
+        )?;
         // Default mode: RTLD_NOW | RTLD_LOCAL, always force RTLD_NOW
         let mode = load_flags.unwrap_or(libc::RTLD_NOW | libc::RTLD_LOCAL) | libc::RTLD_NOW;
 
@@ -3,7 +3,7 @@ pub(crate) use gc::module_def;
 #[pymodule]
 mod gc {
     use crate::{
-        PyObjectRef, PyResult, VirtualMachine,
+        AsObject, PyObjectRef, PyResult, VirtualMachine,
         builtins::PyListRef,
         function::{FuncArgs, OptionalArg},
         gc_state,
@@ -189,10 +189,24 @@ mod gc {
     /// Return the list of objects that directly refer to any of the arguments.
     #[pyfunction]
     fn get_referrers(args: FuncArgs, vm: &VirtualMachine) -> PyListRef {
-        // This is expensive: we need to scan all tracked objects
-        // For now, return an empty list (would need full object tracking to implement)
-        let _ = args;
-        vm.ctx.new_list(vec![])
+        // Scan every tracked object and keep the ones that have a traverse
+        // edge to any of the arguments. This is as expensive as it sounds,
+        // but so is CPython's version.
+        let targets = args.args;
+        let mut result = Vec::new();
+        for obj in gc_state::gc_state().get_objects(None) {
+            if targets.iter().any(|t| obj.is(t)) {
+                continue;
+            }
+            if obj
+                .gc_get_referents()
+                .iter()
+                .any(|child| targets.iter().any(|t| child.is(t)))
+            {
+                result.push(obj);
+            }
+        }
+        vm.ctx.new_list(result)
     }
 
     /// Return True if the object is tracked by the garbage collector.
@@ -128,7 +128,7 @@ mod _imp {
 
     #[pyfunction]
     fn is_builtin(name: PyStrRef, vm: &VirtualMachine) -> bool {
-        vm.state.module_defs.contains_key(name.as_str())
+        vm.state.module_defs.lock().contains_key(name.as_str())
     }
 
     #[pyfunction]
@@ -146,8 +146,10 @@ mod _imp {
             return Ok(module);
         }
 
-        // Try multi-phase init modules first (they need special handling)
-        if let Some(&def) = vm.state.module_defs.get(name.as_str()) {
+        // Try multi-phase init modules first (they need special handling).
+        // Copy the def out so the lock isn't held while the module initializes
+        let def = vm.state.module_defs.lock().get(name.as_str()).copied();
+        if let Some(def) = def {
             // Phase 1: Create module (use create slot if provided, else default creation)
             let module = if let Some(create) = def.slots.create {
                 // Custom module creation
@@ -276,6 +278,40 @@ mod _imp {
         Ok(Some((None, info.package, origname)))
     }
 
+    /// Snapshot a directory in a single native call: returns `(mtime, contents)`
+    /// where `mtime` is the directory's modification time in seconds (-1.0 if it
+    /// cannot be stat'ed, matching `st_mtime` semantics). When `mtime` equals
+    /// `cached_mtime` the directory is unchanged and `contents` is `None`;
+    /// otherwise it lists the entry names, fsdecoded like `os.listdir` (empty if
+    /// the directory cannot be read). `FileFinder` uses this to refresh its cache
+    /// without a separate stat + listdir round trip through the `os` module.
+    #[cfg(feature = "host_env")]
+    #[pyfunction]
+    fn _dir_snapshot(
+        path: crate::ospath::OsPath,
+        cached_mtime: crate::function::ArgIntoFloat,
+        vm: &VirtualMachine,
+    ) -> (f64, Option<Vec<PyStrRef>>) {
+        use std::time::UNIX_EPOCH;
+        let cached_mtime = cached_mtime.into_float();
+        let mtime = std::fs::metadata(&path.path)
+            .ok()
+            .and_then(|meta| meta.modified().ok())
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .map_or(-1.0, |dur| dur.as_secs_f64());
+        if mtime == cached_mtime {
+            return (mtime, None);
+        }
+        let contents = std::fs::read_dir(&path.path)
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| Some(vm.fsdecode(entry.ok()?.file_name())))
+                    .collect()
+            })
+            .unwrap_or_default();
+        (mtime, Some(contents))
+    }
+
     #[pyfunction]
     fn source_hash(key: u64, source: PyBytesRef) -> Vec<u8> {
         let hash: u64 = crate::common::hash::keyed_hash(key, source.as_bytes());
@@ -4794,7 +4794,11 @@ mod _io {
 
     #[pyfunction]
     fn open_code(file: PyObjectRef, vm: &VirtualMachine) -> PyResult {
-        // TODO: lifecycle hooks or something?
+        // PyFile_OpenCodeObject: give the embedder hook first crack at the
+        // path, otherwise fall back to a plain audited binary open
+        if let Some(hook) = vm.state.open_code_hook.get() {
+            return hook(file, vm);
+        }
         io_open(file, Some("rb"), OpenArgs::default(), vm)
     }
 
@@ -4839,6 +4843,15 @@ mod _io {
             .parse::<Mode>()
             .map_err(|e| vm.new_value_error(e.error_msg(mode_string)))?;
 
+        vm.audit(
+            "open",
+            vec![
+                file.clone(),
+                vm.ctx.new_str(mode_string).into(),
+                vm.ctx.new_int(0).into(),
+            ],
+        )?;
+
         if let EncodeMode::Bytes = mode.encode {
             let msg = if opts.encoding.is_some() {
                 Some("binary mode doesn't take an encoding argument")
@@ -5595,11 +5608,18 @@ mod fileio {
 
             let mut handle = zelf.get_fd(vm)?;
 
-            let len = match obj.with_ref(|b| handle.write(b)) {
-                Ok(n) => n,
-                // Non-blocking mode: return None if EAGAIN
-                Err(e) if e.raw_os_error() == Some(libc::EAGAIN) => return Ok(None),
-                Err(e) => return Err(Self::io_error(zelf, e, vm)),
+            // Loop on EINTR (PEP 475)
+            let len = loop {
+                match obj.with_ref(|b| handle.write(b)) {
+                    Ok(n) => break n,
+                    Err(e) if e.raw_os_error() == Some(libc::EINTR) => {
+                        vm.check_signals()?;
+                        continue;
+                    }
+                    // Non-blocking mode: return None if EAGAIN
+                    Err(e) if e.raw_os_error() == Some(libc::EAGAIN) => return Ok(None),
+                    Err(e) => return Err(Self::io_error(zelf, e, vm)),
+                }
             };
 
             //return number of bytes written
@@ -240,6 +240,9 @@ mod decl {
             marshal::MarshalError::BadType => {
                 vm.new_value_error("bad marshal data (unknown type code)")
             }
+            marshal::MarshalError::InvalidReference => {
+                vm.new_value_error("bad marshal data (invalid reference)")
+            }
         })
     }
 
@@ -26,6 +26,7 @@ mod sysconfigdata;
 #[cfg(feature = "threading")]
 pub mod thread;
 pub mod time;
+mod tracemalloc;
 mod typevar;
 pub mod typing;
 pub mod warnings;
@@ -122,6 +123,7 @@ pub fn builtin_module_defs(ctx: &Context) -> Vec<&'static PyModuleDef> {
         #[cfg(feature = "threading")]
         thread::module_def(ctx),
         time::module_def(ctx),
+        tracemalloc::module_def(ctx),
         typing::module_def(ctx),
         warnings::module_def(ctx),
         weakref::module_def(ctx),
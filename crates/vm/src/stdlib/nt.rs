@@ -62,6 +62,80 @@ pub(crate) mod module {
         LOAD_LIBRARY_SEARCH_USER_DIRS as _LOAD_LIBRARY_SEARCH_USER_DIRS,
     };
 
+    #[pyfunction]
+    fn _add_dll_directory(path: OsPath, vm: &VirtualMachine) -> PyResult<usize> {
+        use windows_sys::Win32::System::LibraryLoader::AddDllDirectory;
+        let wide_path = path.to_wide_cstring(vm)?;
+        let cookie = unsafe { AddDllDirectory(wide_path.as_ptr()) };
+        if cookie.is_null() {
+            let err = io::Error::last_os_error();
+            return Err(OSErrorBuilder::with_filename(&err, path, vm));
+        }
+        // the opaque cookie is handed back to _remove_dll_directory
+        Ok(cookie as usize)
+    }
+
+    #[pyfunction]
+    fn _remove_dll_directory(cookie: usize, vm: &VirtualMachine) -> PyResult<()> {
+        use windows_sys::Win32::System::LibraryLoader::RemoveDllDirectory;
+        if unsafe { RemoveDllDirectory(cookie as *mut _) } == 0 {
+            return Err(vm.new_last_os_error());
+        }
+        Ok(())
+    }
+
+    #[derive(FromArgs)]
+    struct StartFileArgs {
+        #[pyarg(any)]
+        filepath: OsPath,
+        #[pyarg(any, optional)]
+        operation: OptionalArg<PyStrRef>,
+        #[pyarg(any, optional)]
+        arguments: OptionalArg<PyStrRef>,
+        #[pyarg(any, optional)]
+        cwd: OptionalArg<OsPath>,
+        #[pyarg(any, default = 1)] // SW_SHOWNORMAL
+        show_cmd: i32,
+    }
+
+    #[pyfunction]
+    fn startfile(args: StartFileArgs, vm: &VirtualMachine) -> PyResult<()> {
+        use windows_sys::Win32::UI::Shell::ShellExecuteW;
+
+        let wide_path = args.filepath.to_wide_cstring(vm)?;
+        let operation = args
+            .operation
+            .into_option()
+            .map(|op| op.as_str().to_wide_with_nul());
+        let arguments = args
+            .arguments
+            .into_option()
+            .map(|a| a.as_str().to_wide_with_nul());
+        let cwd = match args.cwd.into_option() {
+            Some(cwd) => Some(cwd.to_wide_cstring(vm)?),
+            None => None,
+        };
+        let null_or =
+            |opt: &Option<Vec<u16>>| opt.as_ref().map_or(core::ptr::null(), |wide| wide.as_ptr());
+
+        let rc = unsafe {
+            ShellExecuteW(
+                core::ptr::null_mut(),
+                null_or(&operation),
+                wide_path.as_ptr(),
+                null_or(&arguments),
+                cwd.as_ref().map_or(core::ptr::null(), |wide| wide.as_ptr()),
+                args.show_cmd,
+            )
+        };
+        // per the ShellExecute docs, values up to 32 are error codes
+        if rc as isize <= 32 {
+            let err = io::Error::last_os_error();
+            return Err(OSErrorBuilder::with_filename(&err, args.filepath, vm));
+        }
+        Ok(())
+    }
+
     #[pyfunction]
     pub(super) fn access(path: OsPath, mode: u8, vm: &VirtualMachine) -> PyResult<bool> {
         let attr = unsafe { FileSystem::GetFileAttributesW(path.to_wide_cstring(vm)?.as_ptr()) };
@@ -130,7 +204,15 @@ pub(crate) mod module {
         if unsafe { Console::GetConsoleMode(handle, &mut mode) } == 0 {
             return Ok(false);
         }
-        Ok(mode & Console::ENABLE_VIRTUAL_TERMINAL_PROCESSING != 0)
+        if mode & Console::ENABLE_VIRTUAL_TERMINAL_PROCESSING != 0 {
+            return Ok(true);
+        }
+        // Modern consoles support VT processing but don't always have it
+        // enabled; try to turn it on before reporting no support.
+        let enabled = unsafe {
+            Console::SetConsoleMode(handle, mode | Console::ENABLE_VIRTUAL_TERMINAL_PROCESSING)
+        };
+        Ok(enabled != 0)
     }
 
     #[derive(FromArgs)]
@@ -1597,6 +1679,17 @@ pub(crate) mod module {
         let is_sep = |c: u16| c == SEP || c == ALTSEP;
         let sep_or_end = |input: &[u16], idx: usize| idx >= input.len() || is_sep(input[idx]);
 
+        // Device drives (\\.\PhysicalDrive0) and device paths (\\?\volume{}/)
+        // are left entirely unchanged, separators included, matching CPython.
+        if path.len() >= 4
+            && is_sep(path[0])
+            && is_sep(path[1])
+            && (path[2] == DOT || path[2] == b'?' as u16)
+            && is_sep(path[3])
+        {
+            return path.to_vec();
+        }
+
         // Work on a mutable copy with normalized separators
         let mut buf: Vec<u16> = path
             .iter()
@@ -159,7 +159,8 @@ pub(super) mod _os {
     use crate::{
         AsObject, Py, PyObjectRef, PyPayload, PyRef, PyResult, TryFromObject,
         builtins::{
-            PyBytesRef, PyGenericAlias, PyIntRef, PyStrRef, PyTuple, PyTupleRef, PyTypeRef,
+            PyBytesRef, PyGenericAlias, PyIntRef, PyListRef, PyStrRef, PyTuple, PyTupleRef,
+            PyTypeRef,
         },
         common::{
             crt_fd,
@@ -182,6 +183,9 @@ pub(super) mod _os {
     use std::{env, fs, fs::OpenOptions, io, path::PathBuf, time::SystemTime};
 
     const OPEN_DIR_FD: bool = cfg!(not(any(windows, target_os = "redox")));
+    pub(crate) const UNLINK_DIR_FD: bool = cfg!(all(unix, not(target_os = "redox")));
+    pub(crate) const CHMOD_DIR_FD: bool = cfg!(all(unix, not(target_os = "redox")));
+    const RENAME_DIR_FD: bool = cfg!(all(unix, not(target_os = "redox")));
     pub(crate) const MKDIR_DIR_FD: bool = cfg!(not(any(windows, target_os = "redox")));
     const STAT_DIR_FD: bool = cfg!(not(any(windows, target_os = "redox")));
     const UTIME_DIR_FD: bool = cfg!(not(any(windows, target_os = "redox")));
@@ -361,7 +365,24 @@ pub(super) mod _os {
     }
 
     #[pyfunction]
-    fn rmdir(path: OsPath, dir_fd: DirFd<'_, 0>, vm: &VirtualMachine) -> PyResult<()> {
+    fn rmdir(
+        path: OsPath,
+        dir_fd: DirFd<'_, { UNLINK_DIR_FD as usize }>,
+        vm: &VirtualMachine,
+    ) -> PyResult<()> {
+        #[cfg(all(unix, not(target_os = "redox")))]
+        if let Some(fd) = dir_fd.raw_opt() {
+            let c_path = path.clone().into_cstring(vm)?;
+            if unsafe { libc::unlinkat(fd, c_path.as_ptr(), libc::AT_REMOVEDIR) } != 0 {
+                return Err(OSErrorBuilder::with_filename(
+                    &io::Error::last_os_error(),
+                    path,
+                    vm,
+                ));
+            }
+            return Ok(());
+        }
+        #[cfg(not(all(unix, not(target_os = "redox"))))]
         let [] = dir_fd.0;
         fs::remove_dir(&path).map_err(|err| OSErrorBuilder::with_filename(&err, path, vm))
     }
@@ -566,6 +587,11 @@ pub(super) mod _os {
         file_name: std::ffi::OsString,
         pathval: PathBuf,
         file_type: io::Result<fs::FileType>,
+        /// set for entries produced by scandir(fd): the caller's directory
+        /// descriptor and the readdir d_type, since `pathval` is just the
+        /// bare name and every stat has to go through fstatat
+        #[cfg(all(unix, not(target_os = "redox")))]
+        fd_entry: Option<(i32, Option<nix::dir::Type>)>,
         mode: OutputMode,
         stat: OnceCell<PyObjectRef>,
         lstat: OnceCell<PyObjectRef>,
@@ -597,6 +623,19 @@ pub(super) mod _os {
             {
                 return Ok(file_type.is_dir());
             }
+            #[cfg(all(unix, not(target_os = "redox")))]
+            if let Some((dir_fd, d_type)) = self.fd_entry {
+                if let Some(t) = d_type
+                    && (!follow_symlinks.0 || t != nix::dir::Type::Symlink)
+                {
+                    return Ok(t == nix::dir::Type::Directory);
+                }
+                return match self.fstatat_mode(dir_fd, follow_symlinks.0) {
+                    Ok(mode) => Ok(mode & libc::S_IFMT as u32 == libc::S_IFDIR as u32),
+                    Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(false),
+                    Err(e) => Err(e.into_pyexception(vm)),
+                };
+            }
             match super::fs_metadata(&self.pathval, follow_symlinks.0) {
                 Ok(meta) => Ok(meta.is_dir()),
                 Err(e) => {
@@ -617,6 +656,19 @@ pub(super) mod _os {
             {
                 return Ok(file_type.is_file());
             }
+            #[cfg(all(unix, not(target_os = "redox")))]
+            if let Some((dir_fd, d_type)) = self.fd_entry {
+                if let Some(t) = d_type
+                    && (!follow_symlinks.0 || t != nix::dir::Type::Symlink)
+                {
+                    return Ok(t == nix::dir::Type::RegularFile);
+                }
+                return match self.fstatat_mode(dir_fd, follow_symlinks.0) {
+                    Ok(mode) => Ok(mode & libc::S_IFMT as u32 == libc::S_IFREG as u32),
+                    Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(false),
+                    Err(e) => Err(e.into_pyexception(vm)),
+                };
+            }
             match super::fs_metadata(&self.pathval, follow_symlinks.0) {
                 Ok(meta) => Ok(meta.is_file()),
                 Err(e) => {
@@ -631,6 +683,16 @@ pub(super) mod _os {
 
         #[pymethod]
         fn is_symlink(&self, vm: &VirtualMachine) -> PyResult<bool> {
+            #[cfg(all(unix, not(target_os = "redox")))]
+            if let Some((dir_fd, d_type)) = self.fd_entry {
+                return match d_type {
+                    Some(t) => Ok(t == nix::dir::Type::Symlink),
+                    None => self
+                        .fstatat_mode(dir_fd, false)
+                        .map(|mode| mode & libc::S_IFMT as u32 == libc::S_IFLNK as u32)
+                        .map_err(|e| e.into_pyexception(vm)),
+                };
+            }
             Ok(self
                 .file_type
                 .as_ref()
@@ -638,6 +700,25 @@ pub(super) mod _os {
                 .is_symlink())
         }
 
+        /// stat the entry relative to the scandir(fd) directory descriptor
+        #[cfg(all(unix, not(target_os = "redox")))]
+        fn fstatat_mode(&self, dir_fd: i32, follow_symlinks: bool) -> io::Result<u32> {
+            use rustpython_common::os::ffi::OsStrExt;
+            let name = std::ffi::CString::new(self.file_name.as_bytes())
+                .map_err(|_| io::Error::from_raw_os_error(libc::EINVAL))?;
+            let mut st = core::mem::MaybeUninit::<libc::stat>::uninit();
+            let flags = if follow_symlinks {
+                0
+            } else {
+                libc::AT_SYMLINK_NOFOLLOW
+            };
+            let ret = unsafe { libc::fstatat(dir_fd, name.as_ptr(), st.as_mut_ptr(), flags) };
+            if ret != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(unsafe { st.assume_init() }.st_mode as u32)
+        }
+
         #[pymethod]
         fn stat(
             &self,
@@ -646,6 +727,13 @@ pub(super) mod _os {
             vm: &VirtualMachine,
         ) -> PyResult {
             let do_stat = |follow_symlinks| {
+                // entries from scandir(fd) resolve relative to the directory
+                // descriptor they were read from, not the caller's dir_fd
+                #[cfg(all(unix, not(target_os = "redox")))]
+                let dir_fd = match self.fd_entry {
+                    Some((raw, _)) => DirFd([unsafe { crt_fd::Borrowed::borrow_raw(raw) }]),
+                    None => dir_fd,
+                };
                 stat(
                     OsPath {
                         path: self.pathval.as_os_str().to_owned(),
@@ -782,11 +870,22 @@ pub(super) mod _os {
             }
         }
     }
+    #[derive(Debug)]
+    enum ScandirEntries {
+        Path(fs::ReadDir),
+        #[cfg(all(unix, not(target_os = "redox")))]
+        Fd {
+            iter: nix::dir::OwningIter,
+            /// the caller's descriptor, borrowed by every yielded entry
+            dir_fd: i32,
+        },
+    }
+
     #[pyattr]
     #[pyclass(name = "ScandirIter")]
     #[derive(Debug, PyPayload)]
     struct ScandirIterator {
-        entries: PyRwLock<Option<fs::ReadDir>>,
+        entries: PyRwLock<Option<ScandirEntries>>,
         mode: OutputMode,
     }
 
@@ -794,7 +893,7 @@ pub(super) mod _os {
     impl ScandirIterator {
         #[pymethod]
         fn close(&self) {
-            let entryref: &mut Option<fs::ReadDir> = &mut self.entries.write();
+            let entryref: &mut Option<ScandirEntries> = &mut self.entries.write();
             let _dropped = entryref.take();
         }
 
@@ -831,11 +930,48 @@ pub(super) mod _os {
     impl SelfIter for ScandirIterator {}
     impl IterNext for ScandirIterator {
         fn next(zelf: &crate::Py<Self>, vm: &VirtualMachine) -> PyResult<PyIterReturn> {
-            let entryref: &mut Option<fs::ReadDir> = &mut zelf.entries.write();
+            let entryref: &mut Option<ScandirEntries> = &mut zelf.entries.write();
 
             match entryref {
                 None => Ok(PyIterReturn::StopIteration(None)),
-                Some(inner) => match inner.next() {
+                #[cfg(all(unix, not(target_os = "redox")))]
+                Some(ScandirEntries::Fd { iter, dir_fd }) => {
+                    let dir_fd = *dir_fd;
+                    loop {
+                        match iter.next() {
+                            Some(Ok(entry)) => {
+                                use rustpython_common::os::ffi::OsStrExt;
+                                let fname = entry.file_name().to_bytes();
+                                if fname == b"." || fname == b".." {
+                                    continue;
+                                }
+                                let file_name = std::ffi::OsStr::from_bytes(fname).to_owned();
+                                return Ok(PyIterReturn::Return(
+                                    DirEntry {
+                                        pathval: PathBuf::from(&file_name),
+                                        file_name,
+                                        // never consulted for fd entries;
+                                        // is_dir and friends use fstatat
+                                        file_type: Err(io::ErrorKind::Unsupported.into()),
+                                        fd_entry: Some((dir_fd, entry.file_type())),
+                                        mode: zelf.mode,
+                                        lstat: OnceCell::new(),
+                                        stat: OnceCell::new(),
+                                        ino: AtomicCell::new(entry.ino()),
+                                    }
+                                    .into_ref(&vm.ctx)
+                                    .into(),
+                                ));
+                            }
+                            Some(Err(err)) => return Err(err.into_pyexception(vm)),
+                            None => {
+                                let _dropped = entryref.take();
+                                return Ok(PyIterReturn::StopIteration(None));
+                            }
+                        }
+                    }
+                }
+                Some(ScandirEntries::Path(inner)) => match inner.next() {
                     Some(entry) => match entry {
                         Ok(entry) => {
                             #[cfg(unix)]
@@ -876,6 +1012,8 @@ pub(super) mod _os {
                                     file_name: entry.file_name(),
                                     pathval,
                                     file_type: entry.file_type(),
+                                    #[cfg(all(unix, not(target_os = "redox")))]
+                                    fd_entry: None,
                                     mode: zelf.mode,
                                     lstat,
                                     stat: OnceCell::new(),
@@ -896,17 +1034,228 @@ pub(super) mod _os {
         }
     }
 
+    const SCANDIR_FD: bool = cfg!(all(unix, not(target_os = "redox")));
+
+    #[pyfunction]
+    fn scandir(path: OptionalArg<Option<OsPathOrFd<'_>>>, vm: &VirtualMachine) -> PyResult {
+        let path = path
+            .flatten()
+            .unwrap_or_else(|| OsPathOrFd::Path(OsPath::new_str(".")));
+        match path {
+            OsPathOrFd::Path(path) => {
+                let entries = fs::read_dir(&path.path)
+                    .map_err(|err| OSErrorBuilder::with_filename(&err, path.clone(), vm))?;
+                Ok(ScandirIterator {
+                    entries: PyRwLock::new(Some(ScandirEntries::Path(entries))),
+                    mode: path.mode(),
+                }
+                .into_ref(&vm.ctx)
+                .into())
+            }
+            OsPathOrFd::Fd(fno) => {
+                #[cfg(not(all(unix, not(target_os = "redox"))))]
+                {
+                    let _ = fno;
+                    Err(vm.new_not_implemented_error("can't pass fd to scandir on this platform"))
+                }
+                #[cfg(all(unix, not(target_os = "redox")))]
+                {
+                    // iterate a dup so the caller's descriptor keeps its
+                    // position; entries remember the original fd for fstatat
+                    let new_fd = nix::unistd::dup(fno).map_err(|e| e.into_pyexception(vm))?;
+                    let dir = nix::dir::Dir::from_fd(new_fd).map_err(|e| e.into_pyexception(vm))?;
+                    Ok(ScandirIterator {
+                        entries: PyRwLock::new(Some(ScandirEntries::Fd {
+                            iter: dir.into_iter(),
+                            dir_fd: fno.as_raw(),
+                        })),
+                        mode: OutputMode::String,
+                    }
+                    .into_ref(&vm.ctx)
+                    .into())
+                }
+            }
+        }
+    }
+
+    #[derive(Debug)]
+    enum WalkItem {
+        /// A directory that still has to be scanned
+        Walk(PathBuf),
+        /// A yielded topdown directory whose (possibly caller-pruned) dirnames
+        /// list decides what gets traversed next
+        Resume(PathBuf, PyListRef),
+        /// A finished bottom-up triple waiting for its subtree to be emitted
+        Yield(PyObjectRef),
+    }
+
+    #[pyattr]
+    #[pyclass(name = "_WalkIter")]
+    #[derive(Debug, PyPayload)]
+    struct WalkIterator {
+        stack: PyRwLock<Vec<WalkItem>>,
+        topdown: bool,
+        follow_links: bool,
+        onerror: Option<PyObjectRef>,
+        mode: OutputMode,
+    }
+
+    #[pyclass(flags(DISALLOW_INSTANTIATION), with(IterNext, Iterable))]
+    impl WalkIterator {
+        /// Report a scandir failure like os.walk: pass the OSError to the
+        /// onerror callback if one was given, otherwise ignore it.
+        fn handle_error(
+            &self,
+            err: io::Error,
+            path: &PathBuf,
+            vm: &VirtualMachine,
+        ) -> PyResult<()> {
+            if let Some(onerror) = &self.onerror {
+                let exc = OSErrorBuilder::with_filename(
+                    &err,
+                    OsPath {
+                        path: path.as_os_str().to_owned(),
+                        origin: None,
+                    },
+                    vm,
+                );
+                onerror.call((exc,), vm)?;
+            }
+            Ok(())
+        }
+
+        /// Scan one directory into (dirs, nondirs, walk_dirs); `None` means the
+        /// scan failed and was handed to onerror, so the directory is skipped.
+        #[allow(clippy::type_complexity)]
+        fn scan_dir(
+            &self,
+            path: &PathBuf,
+            vm: &VirtualMachine,
+        ) -> PyResult<Option<(PyListRef, PyListRef, Vec<PathBuf>)>> {
+            let entries = match fs::read_dir(path) {
+                Ok(entries) => entries,
+                Err(err) => {
+                    self.handle_error(err, path, vm)?;
+                    return Ok(None);
+                }
+            };
+            let mut dirs = Vec::new();
+            let mut nondirs = Vec::new();
+            let mut walk_dirs = Vec::new();
+            for entry in entries {
+                let entry = match entry {
+                    Ok(entry) => entry,
+                    Err(err) => {
+                        self.handle_error(err, path, vm)?;
+                        return Ok(None);
+                    }
+                };
+                let file_type = entry.file_type();
+                // Like os.path.isdir, a failing stat means "not a directory"
+                let is_dir = match &file_type {
+                    Ok(t) if t.is_symlink() => fs::metadata(entry.path())
+                        .map(|meta| meta.is_dir())
+                        .unwrap_or(false),
+                    Ok(t) => t.is_dir(),
+                    Err(_) => false,
+                };
+                if is_dir {
+                    dirs.push(entry.file_name());
+                    if !self.topdown {
+                        // Bottom-up traversal decides recursion now; symlinked
+                        // directories are only entered with followlinks
+                        let is_symlink = file_type.map(|t| t.is_symlink()).unwrap_or(false);
+                        if self.follow_links || !is_symlink {
+                            walk_dirs.push(entry.path());
+                        }
+                    }
+                } else {
+                    nondirs.push(entry.file_name());
+                }
+            }
+            let to_list = |names: Vec<std::ffi::OsString>| {
+                vm.ctx.new_list(
+                    names
+                        .into_iter()
+                        .map(|name| self.mode.process_path(name, vm))
+                        .collect(),
+                )
+            };
+            Ok(Some((to_list(dirs), to_list(nondirs), walk_dirs)))
+        }
+    }
+
+    impl SelfIter for WalkIterator {}
+    impl IterNext for WalkIterator {
+        fn next(zelf: &Py<Self>, vm: &VirtualMachine) -> PyResult<PyIterReturn> {
+            loop {
+                let item = match zelf.stack.write().pop() {
+                    Some(item) => item,
+                    None => return Ok(PyIterReturn::StopIteration(None)),
+                };
+                match item {
+                    WalkItem::Yield(triple) => return Ok(PyIterReturn::Return(triple)),
+                    WalkItem::Resume(path, dirnames) => {
+                        // Read the list only now so caller pruning during the
+                        // yield takes effect, and re-check for symlinks since
+                        // entries may have been replaced in the meantime
+                        let names = dirnames.borrow_vec().to_vec();
+                        let mut new_paths = Vec::with_capacity(names.len());
+                        for name in names.into_iter().rev() {
+                            let name = OsPath::try_from_object(vm, name)?;
+                            let new_path = path.join(name.as_path());
+                            let is_link = fs::symlink_metadata(&new_path)
+                                .map(|meta| meta.file_type().is_symlink())
+                                .unwrap_or(false);
+                            if zelf.follow_links || !is_link {
+                                new_paths.push(WalkItem::Walk(new_path));
+                            }
+                        }
+                        zelf.stack.write().extend(new_paths);
+                    }
+                    WalkItem::Walk(path) => {
+                        let Some((dirs, nondirs, walk_dirs)) = zelf.scan_dir(&path, vm)? else {
+                            continue;
+                        };
+                        let dirpath = zelf.mode.process_path(&path, vm);
+                        let triple: PyObjectRef =
+                            vm.new_tuple((dirpath, dirs.clone(), nondirs)).into();
+                        let mut stack = zelf.stack.write();
+                        if zelf.topdown {
+                            stack.push(WalkItem::Resume(path, dirs));
+                            drop(stack);
+                            return Ok(PyIterReturn::Return(triple));
+                        }
+                        stack.push(WalkItem::Yield(triple));
+                        for new_path in walk_dirs.into_iter().rev() {
+                            stack.push(WalkItem::Walk(new_path));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Native backend for os.walk: same triples, same onerror and followlinks
+    /// semantics, but iterating directly over the filesystem from Rust.
     #[pyfunction]
-    fn scandir(path: OptionalArg<OsPath>, vm: &VirtualMachine) -> PyResult {
-        let path = path.unwrap_or_else(|| OsPath::new_str("."));
-        let entries = fs::read_dir(&path.path)
-            .map_err(|err| OSErrorBuilder::with_filename(&err, path.clone(), vm))?;
-        Ok(ScandirIterator {
-            entries: PyRwLock::new(Some(entries)),
-            mode: path.mode(),
+    fn _walk(
+        top: OsPath,
+        topdown: bool,
+        onerror: PyObjectRef,
+        followlinks: bool,
+        vm: &VirtualMachine,
+    ) -> PyResult<PyRef<WalkIterator>> {
+        let mode = top.mode();
+        let stack = vec![WalkItem::Walk(top.path.into())];
+        Ok(WalkIterator {
+            stack: PyRwLock::new(stack),
+            topdown,
+            follow_links: followlinks,
+            onerror: (!vm.is_none(&onerror)).then_some(onerror),
+            mode,
         }
-        .into_ref(&vm.ctx)
-        .into())
+        .into_ref(&vm.ctx))
     }
 
     #[derive(Debug, FromArgs)]
@@ -1216,17 +1565,50 @@ pub(super) mod _os {
         FsPath::try_from_path_like(path, false, vm)
     }
 
+    #[derive(FromArgs)]
+    struct RenameArgs {
+        #[pyarg(positional)]
+        src: PyObjectRef,
+        #[pyarg(positional)]
+        dst: PyObjectRef,
+        #[pyarg(named, default)]
+        src_dir_fd: Option<i32>,
+        #[pyarg(named, default)]
+        dst_dir_fd: Option<i32>,
+    }
+
     #[pyfunction]
     #[pyfunction(name = "replace")]
-    fn rename(src: PyObjectRef, dst: PyObjectRef, vm: &VirtualMachine) -> PyResult<()> {
+    fn rename(args: RenameArgs, vm: &VirtualMachine) -> PyResult<()> {
         let src = PathConverter::new()
             .function("rename")
             .argument("src")
-            .try_path(src, vm)?;
+            .try_path(args.src, vm)?;
         let dst = PathConverter::new()
             .function("rename")
             .argument("dst")
-            .try_path(dst, vm)?;
+            .try_path(args.dst, vm)?;
+
+        if args.src_dir_fd.is_some() || args.dst_dir_fd.is_some() {
+            #[cfg(not(all(unix, not(target_os = "redox"))))]
+            return Err(vm.new_not_implemented_error("dir_fd unavailable on this platform"));
+            #[cfg(all(unix, not(target_os = "redox")))]
+            {
+                let src_fd = args.src_dir_fd.unwrap_or(libc::AT_FDCWD);
+                let dst_fd = args.dst_dir_fd.unwrap_or(libc::AT_FDCWD);
+                let src_c = src.clone().into_cstring(vm)?;
+                let dst_c = dst.clone().into_cstring(vm)?;
+                let ret = unsafe { libc::renameat(src_fd, src_c.as_ptr(), dst_fd, dst_c.as_ptr()) };
+                if ret != 0 {
+                    let err = io::Error::last_os_error();
+                    let builder = err.to_os_error_builder(vm);
+                    let builder = builder.filename(src.filename(vm));
+                    let builder = builder.filename2(dst.filename(vm));
+                    return Err(builder.build(vm).upcast());
+                }
+                return Ok(());
+            }
+        }
 
         fs::rename(&src.path, &dst.path).map_err(|err| {
             let builder = err.to_os_error_builder(vm);
@@ -1705,6 +2087,55 @@ pub(super) mod _os {
         usize::try_from(ret).map_err(|_| vm.new_last_errno_error())
     }
 
+    #[cfg(target_os = "linux")]
+    #[derive(FromArgs)]
+    struct SpliceArgs<'fd> {
+        #[pyarg(positional)]
+        src: crt_fd::Borrowed<'fd>,
+        #[pyarg(positional)]
+        dst: crt_fd::Borrowed<'fd>,
+        #[pyarg(positional)]
+        count: i64,
+        #[pyarg(any, default)]
+        offset_src: Option<crt_fd::Offset>,
+        #[pyarg(any, default)]
+        offset_dst: Option<crt_fd::Offset>,
+        #[pyarg(any, default)]
+        flags: Option<u32>,
+    }
+
+    #[cfg(target_os = "linux")]
+    #[pyfunction]
+    fn splice(args: SpliceArgs<'_>, vm: &VirtualMachine) -> PyResult<usize> {
+        #[allow(clippy::unnecessary_option_map_or_else)]
+        let p_offset_src = args.offset_src.as_ref().map_or_else(core::ptr::null, |x| x);
+        #[allow(clippy::unnecessary_option_map_or_else)]
+        let p_offset_dst = args.offset_dst.as_ref().map_or_else(core::ptr::null, |x| x);
+        let count: usize = args
+            .count
+            .try_into()
+            .map_err(|_| vm.new_value_error("count should >= 0"))?;
+        let flags = args.flags.unwrap_or(0);
+
+        // Safety: p_offset_src and p_offset_dst are unique pointers for
+        // offset_src and offset_dst respectively, and will only be freed after
+        // this function ends. Raw syscall for the same musl-libc reason as
+        // copy_file_range above.
+        let ret = unsafe {
+            libc::syscall(
+                libc::SYS_splice,
+                args.src,
+                p_offset_src as *mut i64,
+                args.dst,
+                p_offset_dst as *mut i64,
+                count,
+                flags,
+            )
+        };
+
+        usize::try_from(ret).map_err(|_| vm.new_last_errno_error())
+    }
+
     #[pyfunction]
     fn strerror(e: i32) -> String {
         unsafe { core::ffi::CStr::from_ptr(libc::strerror(e)) }
@@ -1963,12 +2394,12 @@ pub(super) mod _os {
             // mkfifo Some Some None
             // mknod Some Some None
             SupportFunc::new("readlink", Some(false), None, Some(false)),
-            SupportFunc::new("remove", Some(false), None, Some(false)),
-            SupportFunc::new("unlink", Some(false), None, Some(false)),
-            SupportFunc::new("rename", Some(false), None, Some(false)),
-            SupportFunc::new("replace", Some(false), None, Some(false)), // TODO: Fix replace
-            SupportFunc::new("rmdir", Some(false), None, Some(false)),
-            SupportFunc::new("scandir", None, Some(false), Some(false)),
+            SupportFunc::new("remove", Some(false), Some(UNLINK_DIR_FD), Some(false)),
+            SupportFunc::new("unlink", Some(false), Some(UNLINK_DIR_FD), Some(false)),
+            SupportFunc::new("rename", Some(false), Some(RENAME_DIR_FD), Some(false)),
+            SupportFunc::new("replace", Some(false), Some(RENAME_DIR_FD), Some(false)),
+            SupportFunc::new("rmdir", Some(false), Some(UNLINK_DIR_FD), Some(false)),
+            SupportFunc::new("scandir", Some(SCANDIR_FD), Some(false), Some(false)),
             SupportFunc::new("stat", Some(true), Some(STAT_DIR_FD), Some(true)),
             SupportFunc::new("fstat", Some(true), Some(STAT_DIR_FD), Some(true)),
             SupportFunc::new("symlink", Some(false), Some(SYMLINK_DIR_FD), Some(false)),
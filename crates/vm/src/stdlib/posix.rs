@@ -58,6 +58,9 @@ pub mod module {
     };
     use strum_macros::{EnumIter, EnumString};
 
+    #[cfg(not(target_os = "redox"))]
+    use crate::types::PyStructSequence;
+
     #[cfg(any(target_os = "android", target_os = "linux"))]
     #[pyattr]
     use libc::{SCHED_DEADLINE, SCHED_NORMAL};
@@ -70,6 +73,13 @@ pub mod module {
     #[pyattr]
     use libc::PIDFD_NONBLOCK;
 
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[pyattr]
+    use libc::{
+        ST_APPEND, ST_MANDLOCK, ST_NOATIME, ST_NODEV, ST_NODIRATIME, ST_NOEXEC, ST_NOSUID,
+        ST_RDONLY, ST_RELATIME, ST_SYNCHRONOUS, ST_WRITE,
+    };
+
     #[cfg(target_os = "macos")]
     #[pyattr]
     use libc::{
@@ -99,6 +109,14 @@ pub mod module {
     #[pyattr]
     use libc::O_SYMLINK;
 
+    // TMP_MAX from <stdio.h>; tempfile caps its name-generation attempts on it
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[pyattr]
+    const TMP_MAX: i32 = 238328;
+    #[cfg(target_os = "macos")]
+    #[pyattr]
+    const TMP_MAX: i32 = 308915776;
+
     #[cfg(any(target_os = "android", target_os = "freebsd", target_os = "linux"))]
     #[pyattr]
     use libc::{
@@ -507,7 +525,24 @@ pub mod module {
 
     #[pyfunction]
     #[pyfunction(name = "unlink")]
-    fn remove(path: OsPath, dir_fd: DirFd<'_, 0>, vm: &VirtualMachine) -> PyResult<()> {
+    fn remove(
+        path: OsPath,
+        dir_fd: DirFd<'_, { _os::UNLINK_DIR_FD as usize }>,
+        vm: &VirtualMachine,
+    ) -> PyResult<()> {
+        #[cfg(not(target_os = "redox"))]
+        if let Some(fd) = dir_fd.raw_opt() {
+            let c_path = path.clone().into_cstring(vm)?;
+            if unsafe { libc::unlinkat(fd, c_path.as_ptr(), 0) } != 0 {
+                return Err(OSErrorBuilder::with_filename(
+                    &io::Error::last_os_error(),
+                    path,
+                    vm,
+                ));
+            }
+            return Ok(());
+        }
+        #[cfg(target_os = "redox")]
         let [] = dir_fd.0;
         fs::remove_file(&path).map_err(|err| OSErrorBuilder::with_filename(&err, path, vm))
     }
@@ -609,6 +644,356 @@ pub mod module {
         )
     }
 
+    #[pyfunction]
+    fn pread(
+        fd: crate::common::crt_fd::Borrowed<'_>,
+        n: usize,
+        offset: crate::common::crt_fd::Offset,
+        vm: &VirtualMachine,
+    ) -> PyResult<Vec<u8>> {
+        let mut buffer = vec![0u8; n];
+        let res = unsafe { libc::pread(fd.as_raw(), buffer.as_mut_ptr().cast(), n, offset) };
+        if res < 0 {
+            return Err(io::Error::last_os_error().into_pyexception(vm));
+        }
+        buffer.truncate(res as usize);
+        Ok(buffer)
+    }
+
+    #[pyfunction]
+    fn pwrite(
+        fd: crate::common::crt_fd::Borrowed<'_>,
+        data: crate::function::ArgBytesLike,
+        offset: crate::common::crt_fd::Offset,
+        vm: &VirtualMachine,
+    ) -> PyResult<usize> {
+        let res = data.with_ref(|data| unsafe {
+            libc::pwrite(fd.as_raw(), data.as_ptr().cast(), data.len(), offset)
+        });
+        if res < 0 {
+            return Err(io::Error::last_os_error().into_pyexception(vm));
+        }
+        Ok(res as usize)
+    }
+
+    #[pyfunction]
+    fn readv(
+        fd: crate::common::crt_fd::Borrowed<'_>,
+        buffers: Vec<crate::function::ArgMemoryBuffer>,
+        vm: &VirtualMachine,
+    ) -> PyResult<usize> {
+        let mut borrows: Vec<_> = buffers.iter().map(|b| b.borrow_buf_mut()).collect();
+        let iovs: Vec<libc::iovec> = borrows
+            .iter_mut()
+            .map(|buf| libc::iovec {
+                iov_base: buf.as_mut_ptr().cast(),
+                iov_len: buf.len(),
+            })
+            .collect();
+        let res = unsafe { libc::readv(fd.as_raw(), iovs.as_ptr(), iovs.len() as _) };
+        if res < 0 {
+            return Err(io::Error::last_os_error().into_pyexception(vm));
+        }
+        Ok(res as usize)
+    }
+
+    #[pyfunction]
+    fn writev(
+        fd: crate::common::crt_fd::Borrowed<'_>,
+        buffers: Vec<crate::function::ArgBytesLike>,
+        vm: &VirtualMachine,
+    ) -> PyResult<usize> {
+        let borrows: Vec<_> = buffers.iter().map(|b| b.borrow_buf()).collect();
+        let iovs: Vec<libc::iovec> = borrows
+            .iter()
+            .map(|buf| libc::iovec {
+                iov_base: buf.as_ptr() as *mut _,
+                iov_len: buf.len(),
+            })
+            .collect();
+        let res = unsafe { libc::writev(fd.as_raw(), iovs.as_ptr(), iovs.len() as _) };
+        if res < 0 {
+            return Err(io::Error::last_os_error().into_pyexception(vm));
+        }
+        Ok(res as usize)
+    }
+
+    /// Resolve an `OsPathOrFd` for the xattr family: a C path for the
+    /// path-based variants (respecting `follow_symlinks`) or a raw fd.
+    #[cfg(any(target_os = "android", target_os = "linux", target_os = "macos"))]
+    enum XattrTarget {
+        Path(CString, bool),
+        Fd(i32),
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux", target_os = "macos"))]
+    impl XattrTarget {
+        fn new(
+            path: &OsPathOrFd<'_>,
+            follow_symlinks: FollowSymlinks,
+            vm: &VirtualMachine,
+        ) -> PyResult<Self> {
+            Ok(match path {
+                OsPathOrFd::Path(p) => Self::Path(p.clone().into_cstring(vm)?, follow_symlinks.0),
+                OsPathOrFd::Fd(fd) => Self::Fd(fd.as_raw()),
+            })
+        }
+
+        // Thin wrappers over the raw syscalls. macOS folds the no-follow and
+        // create/replace behavior into a trailing options argument, while
+        // Linux spells them as l*-prefixed variants and plain flags.
+
+        #[cfg(target_os = "macos")]
+        const fn nofollow_option(follow: bool) -> libc::c_int {
+            if follow { 0 } else { libc::XATTR_NOFOLLOW }
+        }
+
+        unsafe fn get(&self, attr: &CString, ptr: *mut libc::c_void, size: usize) -> libc::ssize_t {
+            #[cfg(not(target_os = "macos"))]
+            match self {
+                Self::Path(p, true) => unsafe {
+                    libc::getxattr(p.as_ptr(), attr.as_ptr(), ptr, size)
+                },
+                Self::Path(p, false) => unsafe {
+                    libc::lgetxattr(p.as_ptr(), attr.as_ptr(), ptr, size)
+                },
+                Self::Fd(fd) => unsafe { libc::fgetxattr(*fd, attr.as_ptr(), ptr, size) },
+            }
+            #[cfg(target_os = "macos")]
+            match self {
+                Self::Path(p, follow) => unsafe {
+                    libc::getxattr(
+                        p.as_ptr(),
+                        attr.as_ptr(),
+                        ptr,
+                        size,
+                        0,
+                        Self::nofollow_option(*follow),
+                    )
+                },
+                Self::Fd(fd) => unsafe { libc::fgetxattr(*fd, attr.as_ptr(), ptr, size, 0, 0) },
+            }
+        }
+
+        unsafe fn set(
+            &self,
+            attr: &CString,
+            ptr: *const libc::c_void,
+            size: usize,
+            flags: libc::c_int,
+        ) -> libc::c_int {
+            #[cfg(not(target_os = "macos"))]
+            match self {
+                Self::Path(p, true) => unsafe {
+                    libc::setxattr(p.as_ptr(), attr.as_ptr(), ptr, size, flags)
+                },
+                Self::Path(p, false) => unsafe {
+                    libc::lsetxattr(p.as_ptr(), attr.as_ptr(), ptr, size, flags)
+                },
+                Self::Fd(fd) => unsafe { libc::fsetxattr(*fd, attr.as_ptr(), ptr, size, flags) },
+            }
+            #[cfg(target_os = "macos")]
+            match self {
+                Self::Path(p, follow) => unsafe {
+                    libc::setxattr(
+                        p.as_ptr(),
+                        attr.as_ptr(),
+                        ptr,
+                        size,
+                        0,
+                        flags | Self::nofollow_option(*follow),
+                    )
+                },
+                Self::Fd(fd) => unsafe { libc::fsetxattr(*fd, attr.as_ptr(), ptr, size, 0, flags) },
+            }
+        }
+
+        unsafe fn remove(&self, attr: &CString) -> libc::c_int {
+            #[cfg(not(target_os = "macos"))]
+            match self {
+                Self::Path(p, true) => unsafe { libc::removexattr(p.as_ptr(), attr.as_ptr()) },
+                Self::Path(p, false) => unsafe { libc::lremovexattr(p.as_ptr(), attr.as_ptr()) },
+                Self::Fd(fd) => unsafe { libc::fremovexattr(*fd, attr.as_ptr()) },
+            }
+            #[cfg(target_os = "macos")]
+            match self {
+                Self::Path(p, follow) => unsafe {
+                    libc::removexattr(p.as_ptr(), attr.as_ptr(), Self::nofollow_option(*follow))
+                },
+                Self::Fd(fd) => unsafe { libc::fremovexattr(*fd, attr.as_ptr(), 0) },
+            }
+        }
+
+        unsafe fn list(&self, ptr: *mut libc::c_char, size: usize) -> libc::ssize_t {
+            #[cfg(not(target_os = "macos"))]
+            match self {
+                Self::Path(p, true) => unsafe { libc::listxattr(p.as_ptr(), ptr, size) },
+                Self::Path(p, false) => unsafe { libc::llistxattr(p.as_ptr(), ptr, size) },
+                Self::Fd(fd) => unsafe { libc::flistxattr(*fd, ptr, size) },
+            }
+            #[cfg(target_os = "macos")]
+            match self {
+                Self::Path(p, follow) => unsafe {
+                    libc::listxattr(p.as_ptr(), ptr, size, Self::nofollow_option(*follow))
+                },
+                Self::Fd(fd) => unsafe { libc::flistxattr(*fd, ptr, size, 0) },
+            }
+        }
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux", target_os = "macos"))]
+    #[pyfunction]
+    fn getxattr(
+        path: OsPathOrFd<'_>,
+        attribute: OsPath,
+        follow_symlinks: FollowSymlinks,
+        vm: &VirtualMachine,
+    ) -> PyResult<Vec<u8>> {
+        let attr = attribute.into_cstring(vm)?;
+        let target = XattrTarget::new(&path, follow_symlinks, vm)?;
+        let call =
+            |buf: &mut [u8]| unsafe { target.get(&attr, buf.as_mut_ptr().cast(), buf.len()) };
+        loop {
+            let size = call(&mut []);
+            if size < 0 {
+                let err = io::Error::last_os_error();
+                return Err(OSErrorBuilder::with_filename(&err, path, vm));
+            }
+            let mut buf = vec![0u8; size as usize];
+            let res = call(&mut buf);
+            if res < 0 {
+                let err = io::Error::last_os_error();
+                if err.raw_os_error() == Some(libc::ERANGE) {
+                    // the attribute grew between the two calls; retry
+                    continue;
+                }
+                return Err(OSErrorBuilder::with_filename(&err, path, vm));
+            }
+            buf.truncate(res as usize);
+            return Ok(buf);
+        }
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux", target_os = "macos"))]
+    #[pyfunction]
+    fn setxattr(
+        path: OsPathOrFd<'_>,
+        attribute: OsPath,
+        value: crate::function::ArgBytesLike,
+        flags: OptionalArg<libc::c_int>,
+        follow_symlinks: FollowSymlinks,
+        vm: &VirtualMachine,
+    ) -> PyResult<()> {
+        let attr = attribute.into_cstring(vm)?;
+        let flags = flags.unwrap_or(0);
+        let target = XattrTarget::new(&path, follow_symlinks, vm)?;
+        let res = value.with_ref(|value| unsafe {
+            target.set(&attr, value.as_ptr().cast(), value.len(), flags)
+        });
+        if res < 0 {
+            let err = io::Error::last_os_error();
+            return Err(OSErrorBuilder::with_filename(&err, path, vm));
+        }
+        Ok(())
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux", target_os = "macos"))]
+    #[pyfunction]
+    fn removexattr(
+        path: OsPathOrFd<'_>,
+        attribute: OsPath,
+        follow_symlinks: FollowSymlinks,
+        vm: &VirtualMachine,
+    ) -> PyResult<()> {
+        let attr = attribute.into_cstring(vm)?;
+        let target = XattrTarget::new(&path, follow_symlinks, vm)?;
+        let res = unsafe { target.remove(&attr) };
+        if res < 0 {
+            let err = io::Error::last_os_error();
+            return Err(OSErrorBuilder::with_filename(&err, path, vm));
+        }
+        Ok(())
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux", target_os = "macos"))]
+    #[pyfunction]
+    fn listxattr(
+        path: OptionalArg<OsPathOrFd<'_>>,
+        follow_symlinks: FollowSymlinks,
+        vm: &VirtualMachine,
+    ) -> PyResult<Vec<PyObjectRef>> {
+        use std::os::unix::ffi::OsStrExt;
+
+        // path=None lists the attributes of the current directory
+        let path = path
+            .into_option()
+            .unwrap_or_else(|| OsPathOrFd::Path(OsPath::new_str(".".to_owned())));
+        let target = XattrTarget::new(&path, follow_symlinks, vm)?;
+        let call = |buf: &mut [u8]| unsafe { target.list(buf.as_mut_ptr().cast(), buf.len()) };
+        let buf = loop {
+            let size = call(&mut []);
+            if size < 0 {
+                let err = io::Error::last_os_error();
+                return Err(OSErrorBuilder::with_filename(&err, path, vm));
+            }
+            let mut buf = vec![0u8; size as usize];
+            let res = call(&mut buf);
+            if res < 0 {
+                let err = io::Error::last_os_error();
+                if err.raw_os_error() == Some(libc::ERANGE) {
+                    continue;
+                }
+                return Err(OSErrorBuilder::with_filename(&err, path, vm));
+            }
+            buf.truncate(res as usize);
+            break buf;
+        };
+        Ok(buf
+            .split(|&b| b == 0)
+            .filter(|name| !name.is_empty())
+            .map(|name| vm.fsdecode(std::ffi::OsStr::from_bytes(name)).into())
+            .collect())
+    }
+
+    #[cfg(any(
+        target_os = "macos",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "dragonfly"
+    ))]
+    #[pyfunction]
+    fn chflags(
+        path: OsPath,
+        flags: libc::c_ulong,
+        follow_symlinks: FollowSymlinks,
+        vm: &VirtualMachine,
+    ) -> PyResult<()> {
+        let c_path = path.clone().into_cstring(vm)?;
+        let res = if follow_symlinks.0 {
+            unsafe { libc::chflags(c_path.as_ptr(), flags as _) }
+        } else {
+            unsafe { libc::lchflags(c_path.as_ptr(), flags as _) }
+        };
+        if res < 0 {
+            let err = io::Error::last_os_error();
+            return Err(OSErrorBuilder::with_filename(&err, path, vm));
+        }
+        Ok(())
+    }
+
+    #[cfg(any(
+        target_os = "macos",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "dragonfly"
+    ))]
+    #[pyfunction]
+    fn lchflags(path: OsPath, flags: libc::c_ulong, vm: &VirtualMachine) -> PyResult<()> {
+        chflags(path, flags, FollowSymlinks(false), vm)
+    }
+
     #[derive(FromArgs)]
     struct RegisterAtForkArgs {
         #[pyarg(named, optional)]
@@ -968,11 +1353,30 @@ pub mod module {
 
     fn _chmod(
         path: OsPath,
-        dir_fd: DirFd<'_, 0>,
+        dir_fd: DirFd<'_, { _os::CHMOD_DIR_FD as usize }>,
         mode: u32,
         follow_symlinks: FollowSymlinks,
         vm: &VirtualMachine,
     ) -> PyResult<()> {
+        #[cfg(not(target_os = "redox"))]
+        if let Some(fd) = dir_fd.raw_opt() {
+            let c_path = path.clone().into_cstring(vm)?;
+            let flags = if follow_symlinks.0 {
+                0
+            } else {
+                libc::AT_SYMLINK_NOFOLLOW
+            };
+            let res = unsafe { libc::fchmodat(fd, c_path.as_ptr(), mode as libc::mode_t, flags) };
+            if res != 0 {
+                return Err(OSErrorBuilder::with_filename(
+                    &io::Error::last_os_error(),
+                    path,
+                    vm,
+                ));
+            }
+            return Ok(());
+        }
+        #[cfg(target_os = "redox")]
         let [] = dir_fd.0;
         let err_path = path.clone();
         let body = move || {
@@ -998,7 +1402,7 @@ pub mod module {
     #[pyfunction]
     fn chmod(
         path: OsPathOrFd<'_>,
-        dir_fd: DirFd<'_, 0>,
+        dir_fd: DirFd<'_, { _os::CHMOD_DIR_FD as usize }>,
         mode: u32,
         follow_symlinks: FollowSymlinks,
         vm: &VirtualMachine,
@@ -1019,7 +1423,7 @@ pub mod module {
     #[pyfunction]
     fn chmod(
         path: OsPath,
-        dir_fd: DirFd<0>,
+        dir_fd: DirFd<'_, { _os::CHMOD_DIR_FD as usize }>,
         mode: u32,
         follow_symlinks: FollowSymlinks,
         vm: &VirtualMachine,
@@ -1716,6 +2120,195 @@ pub mod module {
         }
     }
 
+    #[cfg(any(target_os = "android", target_os = "freebsd", target_os = "linux"))]
+    #[pyfunction]
+    fn memfd_create(
+        name: OsPath,
+        flags: OptionalArg<u32>,
+        vm: &VirtualMachine,
+    ) -> PyResult<OwnedFd> {
+        let flags = flags.unwrap_or(libc::MFD_CLOEXEC);
+        let c_name = name.into_cstring(vm)?;
+        let fd = unsafe { libc::memfd_create(c_name.as_ptr(), flags) };
+        if fd == -1 {
+            Err(vm.new_last_errno_error())
+        } else {
+            // Safety: memfd_create returns a new owned file descriptor.
+            Ok(unsafe { OwnedFd::from_raw_fd(fd) })
+        }
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[pyfunction]
+    fn eventfd(initval: u32, flags: OptionalArg<i32>, vm: &VirtualMachine) -> PyResult<OwnedFd> {
+        let flags = flags.unwrap_or(libc::EFD_CLOEXEC);
+        let fd = unsafe { libc::eventfd(initval, flags) };
+        if fd == -1 {
+            Err(vm.new_last_errno_error())
+        } else {
+            // Safety: eventfd returns a new owned file descriptor.
+            Ok(unsafe { OwnedFd::from_raw_fd(fd) })
+        }
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[pyfunction]
+    fn eventfd_read(fd: i32, vm: &VirtualMachine) -> PyResult<u64> {
+        let mut value = 0;
+        if unsafe { libc::eventfd_read(fd, &mut value) } == -1 {
+            Err(vm.new_last_errno_error())
+        } else {
+            Ok(value)
+        }
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[pyfunction]
+    fn eventfd_write(fd: i32, value: u64, vm: &VirtualMachine) -> PyResult<()> {
+        if unsafe { libc::eventfd_write(fd, value) } == -1 {
+            Err(vm.new_last_errno_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux", target_os = "netbsd"))]
+    fn timespec_from_secs(secs: f64) -> libc::timespec {
+        let tv_sec = secs as libc::time_t;
+        let tv_nsec = ((secs - tv_sec as f64) * 1e9).round() as libc::c_long;
+        libc::timespec { tv_sec, tv_nsec }
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux", target_os = "netbsd"))]
+    fn timespec_from_ns(ns: i64) -> libc::timespec {
+        libc::timespec {
+            tv_sec: (ns / 1_000_000_000) as libc::time_t,
+            tv_nsec: (ns % 1_000_000_000) as libc::c_long,
+        }
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux", target_os = "netbsd"))]
+    const fn timespec_secs(ts: &libc::timespec) -> f64 {
+        ts.tv_sec as f64 + ts.tv_nsec as f64 * 1e-9
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux", target_os = "netbsd"))]
+    const fn timespec_ns(ts: &libc::timespec) -> i64 {
+        ts.tv_sec as i64 * 1_000_000_000 + ts.tv_nsec as i64
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux", target_os = "netbsd"))]
+    #[pyfunction]
+    fn timerfd_create(
+        clockid: i32,
+        flags: OptionalArg<i32>,
+        vm: &VirtualMachine,
+    ) -> PyResult<OwnedFd> {
+        // like CPython, always create the descriptor non-inheritable
+        let flags = flags.unwrap_or(0) | libc::TFD_CLOEXEC;
+        let fd = unsafe { libc::timerfd_create(clockid, flags) };
+        if fd == -1 {
+            Err(vm.new_last_errno_error())
+        } else {
+            // Safety: timerfd_create returns a new owned file descriptor.
+            Ok(unsafe { OwnedFd::from_raw_fd(fd) })
+        }
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux", target_os = "netbsd"))]
+    fn timerfd_settime_inner(
+        fd: i32,
+        flags: i32,
+        new_value: &libc::itimerspec,
+        vm: &VirtualMachine,
+    ) -> PyResult<libc::itimerspec> {
+        let mut old_value = unsafe { core::mem::zeroed::<libc::itimerspec>() };
+        let ret = unsafe { libc::timerfd_settime(fd, flags, new_value, &mut old_value) };
+        if ret == -1 {
+            Err(vm.new_last_errno_error())
+        } else {
+            Ok(old_value)
+        }
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux", target_os = "netbsd"))]
+    #[derive(FromArgs)]
+    struct TimerfdSettimeArgs {
+        #[pyarg(positional)]
+        fd: i32,
+        #[pyarg(named, default = 0)]
+        flags: i32,
+        #[pyarg(named, default = 0.0)]
+        initial: f64,
+        #[pyarg(named, default = 0.0)]
+        interval: f64,
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux", target_os = "netbsd"))]
+    #[pyfunction]
+    fn timerfd_settime(args: TimerfdSettimeArgs, vm: &VirtualMachine) -> PyResult<(f64, f64)> {
+        let new_value = libc::itimerspec {
+            it_interval: timespec_from_secs(args.interval),
+            it_value: timespec_from_secs(args.initial),
+        };
+        let old = timerfd_settime_inner(args.fd, args.flags, &new_value, vm)?;
+        Ok((
+            timespec_secs(&old.it_value),
+            timespec_secs(&old.it_interval),
+        ))
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux", target_os = "netbsd"))]
+    #[derive(FromArgs)]
+    struct TimerfdSettimeNsArgs {
+        #[pyarg(positional)]
+        fd: i32,
+        #[pyarg(named, default = 0)]
+        flags: i32,
+        #[pyarg(named, default = 0)]
+        initial: i64,
+        #[pyarg(named, default = 0)]
+        interval: i64,
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux", target_os = "netbsd"))]
+    #[pyfunction]
+    fn timerfd_settime_ns(args: TimerfdSettimeNsArgs, vm: &VirtualMachine) -> PyResult<(i64, i64)> {
+        let new_value = libc::itimerspec {
+            it_interval: timespec_from_ns(args.interval),
+            it_value: timespec_from_ns(args.initial),
+        };
+        let old = timerfd_settime_inner(args.fd, args.flags, &new_value, vm)?;
+        Ok((timespec_ns(&old.it_value), timespec_ns(&old.it_interval)))
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux", target_os = "netbsd"))]
+    fn timerfd_gettime_inner(fd: i32, vm: &VirtualMachine) -> PyResult<libc::itimerspec> {
+        let mut curr_value = unsafe { core::mem::zeroed::<libc::itimerspec>() };
+        if unsafe { libc::timerfd_gettime(fd, &mut curr_value) } == -1 {
+            Err(vm.new_last_errno_error())
+        } else {
+            Ok(curr_value)
+        }
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux", target_os = "netbsd"))]
+    #[pyfunction]
+    fn timerfd_gettime(fd: i32, vm: &VirtualMachine) -> PyResult<(f64, f64)> {
+        let curr = timerfd_gettime_inner(fd, vm)?;
+        Ok((
+            timespec_secs(&curr.it_value),
+            timespec_secs(&curr.it_interval),
+        ))
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux", target_os = "netbsd"))]
+    #[pyfunction]
+    fn timerfd_gettime_ns(fd: i32, vm: &VirtualMachine) -> PyResult<(i64, i64)> {
+        let curr = timerfd_gettime_inner(fd, vm)?;
+        Ok((timespec_ns(&curr.it_value), timespec_ns(&curr.it_interval)))
+    }
+
     #[pyfunction]
     fn waitpid(pid: libc::pid_t, opt: i32, vm: &VirtualMachine) -> PyResult<(libc::pid_t, i32)> {
         let mut status = 0;
@@ -1826,7 +2419,7 @@ pub mod module {
             SupportFunc::new(
                 "chmod",
                 Some(false),
-                Some(false),
+                Some(_os::CHMOD_DIR_FD),
                 Some(cfg!(any(
                     target_os = "macos",
                     target_os = "freebsd",
@@ -1846,8 +2439,18 @@ pub mod module {
             SupportFunc::new("umask", Some(false), Some(false), Some(false)),
             SupportFunc::new("execv", None, None, None),
             SupportFunc::new("pathconf", Some(true), None, None),
+            #[cfg(not(target_os = "redox"))]
+            SupportFunc::new("statvfs", Some(true), None, None),
             SupportFunc::new("fpathconf", Some(true), None, None),
             SupportFunc::new("fchdir", Some(true), None, None),
+            #[cfg(any(target_os = "android", target_os = "linux", target_os = "macos"))]
+            SupportFunc::new("getxattr", Some(true), None, Some(true)),
+            #[cfg(any(target_os = "android", target_os = "linux", target_os = "macos"))]
+            SupportFunc::new("setxattr", Some(true), None, Some(true)),
+            #[cfg(any(target_os = "android", target_os = "linux", target_os = "macos"))]
+            SupportFunc::new("removexattr", Some(true), None, Some(true)),
+            #[cfg(any(target_os = "android", target_os = "linux", target_os = "macos"))]
+            SupportFunc::new("listxattr", Some(true), None, Some(true)),
         ]
     }
 
@@ -2384,6 +2987,187 @@ pub mod module {
         names
     }
 
+    #[cfg(not(target_os = "redox"))]
+    #[derive(Debug)]
+    #[pystruct_sequence_data]
+    struct StatvfsResultData {
+        pub f_bsize: u64,
+        pub f_frsize: u64,
+        pub f_blocks: u64,
+        pub f_bfree: u64,
+        pub f_bavail: u64,
+        pub f_files: u64,
+        pub f_ffree: u64,
+        pub f_favail: u64,
+        pub f_flag: u64,
+        pub f_namemax: u64,
+        // attribute-only field, not part of the sequence (same as CPython)
+        #[pystruct_sequence(skip)]
+        pub f_fsid: u64,
+    }
+
+    #[cfg(not(target_os = "redox"))]
+    #[pyattr]
+    #[pystruct_sequence(name = "statvfs_result", module = "os", data = "StatvfsResultData")]
+    struct PyStatvfsResult;
+
+    #[cfg(not(target_os = "redox"))]
+    #[pyclass(with(PyStructSequence))]
+    impl PyStatvfsResult {}
+
+    #[cfg(not(target_os = "redox"))]
+    impl From<libc::statvfs> for StatvfsResultData {
+        fn from(st: libc::statvfs) -> Self {
+            Self {
+                f_bsize: st.f_bsize as u64,
+                f_frsize: st.f_frsize as u64,
+                f_blocks: st.f_blocks as u64,
+                f_bfree: st.f_bfree as u64,
+                f_bavail: st.f_bavail as u64,
+                f_files: st.f_files as u64,
+                f_ffree: st.f_ffree as u64,
+                f_favail: st.f_favail as u64,
+                f_flag: st.f_flag as u64,
+                f_namemax: st.f_namemax as u64,
+                f_fsid: st.f_fsid as u64,
+            }
+        }
+    }
+
+    #[cfg(not(target_os = "redox"))]
+    #[pyfunction]
+    fn statvfs(path: OsPathOrFd<'_>, vm: &VirtualMachine) -> PyResult {
+        let mut st = core::mem::MaybeUninit::<libc::statvfs>::uninit();
+        let ret = match &path {
+            OsPathOrFd::Path(path) => {
+                let c_path = path.clone().into_cstring(vm)?;
+                unsafe { libc::statvfs(c_path.as_ptr(), st.as_mut_ptr()) }
+            }
+            OsPathOrFd::Fd(fd) => unsafe { libc::fstatvfs(fd.as_raw(), st.as_mut_ptr()) },
+        };
+        if ret == -1 {
+            return Err(OSErrorBuilder::with_filename(
+                &io::Error::last_os_error(),
+                path,
+                vm,
+            ));
+        }
+        let st = unsafe { st.assume_init() };
+        Ok(StatvfsResultData::from(st).to_pyobject(vm))
+    }
+
+    #[cfg(not(target_os = "redox"))]
+    #[pyfunction]
+    fn fstatvfs(fd: BorrowedFd<'_>, vm: &VirtualMachine) -> PyResult {
+        statvfs(OsPathOrFd::Fd(fd.into()), vm)
+    }
+
+    #[cfg(any(
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "linux",
+        target_os = "netbsd",
+        target_vendor = "apple"
+    ))]
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, EnumIter, EnumString)]
+    #[repr(i32)]
+    #[allow(non_camel_case_types)]
+    pub enum ConfstrVar {
+        /// A value for the PATH environment variable that finds all standard
+        /// utilities.
+        CS_PATH = libc::_CS_PATH,
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        /// The version of the GNU C library.
+        CS_GNU_LIBC_VERSION = libc::_CS_GNU_LIBC_VERSION,
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        /// The name and version of the threading implementation.
+        CS_GNU_LIBPTHREAD_VERSION = libc::_CS_GNU_LIBPTHREAD_VERSION,
+    }
+
+    #[cfg(any(
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "linux",
+        target_os = "netbsd",
+        target_vendor = "apple"
+    ))]
+    struct ConfstrName(i32);
+
+    #[cfg(any(
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "linux",
+        target_os = "netbsd",
+        target_vendor = "apple"
+    ))]
+    impl TryFromObject for ConfstrName {
+        fn try_from_object(vm: &VirtualMachine, obj: PyObjectRef) -> PyResult<Self> {
+            let i = match obj.downcast::<PyInt>() {
+                Ok(int) => int.try_to_primitive(vm)?,
+                Err(obj) => {
+                    let s = obj.downcast::<PyStr>().map_err(|_| {
+                        vm.new_type_error(
+                            "configuration names must be strings or integers".to_owned(),
+                        )
+                    })?;
+                    s.as_str()
+                        .parse::<ConfstrVar>()
+                        .map_err(|_| vm.new_value_error("unrecognized configuration name"))?
+                        as i32
+                }
+            };
+            Ok(Self(i))
+        }
+    }
+
+    #[cfg(any(
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "linux",
+        target_os = "netbsd",
+        target_vendor = "apple"
+    ))]
+    #[pyfunction]
+    fn confstr(name: ConfstrName, vm: &VirtualMachine) -> PyResult<Option<String>> {
+        crate::common::os::set_errno(0);
+        let len = unsafe { libc::confstr(name.0, core::ptr::null_mut(), 0) };
+        if len == 0 {
+            if crate::common::os::get_errno() != 0 {
+                return Err(vm.new_last_errno_error());
+            }
+            // the name is valid but has no value defined
+            return Ok(None);
+        }
+        let mut buf = vec![0u8; len];
+        unsafe { libc::confstr(name.0, buf.as_mut_ptr().cast(), len) };
+        // drop the terminating NUL
+        buf.truncate(len - 1);
+        Ok(Some(String::from_utf8_lossy(&buf).into_owned()))
+    }
+
+    #[cfg(any(
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "linux",
+        target_os = "netbsd",
+        target_vendor = "apple"
+    ))]
+    #[pyattr]
+    fn confstr_names(vm: &VirtualMachine) -> PyDictRef {
+        use strum::IntoEnumIterator;
+        let names = vm.ctx.new_dict();
+        for variant in ConfstrVar::iter() {
+            // get the name of variant as a string to use as the dictionary key
+            let key = vm.ctx.new_str(format!("{variant:?}"));
+            // get the enum from the string and convert it to an integer for the dictionary value
+            let value = vm.ctx.new_int(variant as i32);
+            names
+                .set_item(&*key, value.into(), vm)
+                .expect("dict set_item unexpectedly failed");
+        }
+        names
+    }
+
     #[cfg(any(target_os = "linux", target_os = "macos"))]
     #[derive(FromArgs)]
     struct SendFileArgs<'fd> {
@@ -622,13 +622,13 @@ pub(crate) mod _signal {
         Ok(set.into())
     }
 
+    /// Build a C sigset_t from an iterable of signal numbers, validating
+    /// each one is in range [1, NSIG).
     #[cfg(unix)]
-    #[pyfunction]
-    fn pthread_sigmask(
-        how: i32,
+    fn pyset_to_sigset(
         mask: crate::function::ArgIterable,
         vm: &VirtualMachine,
-    ) -> PyResult {
+    ) -> PyResult<libc::sigset_t> {
         use crate::convert::IntoPyException;
 
         // Initialize sigset
@@ -662,6 +662,20 @@ pub(crate) mod _signal {
             }
         }
 
+        Ok(sigset)
+    }
+
+    #[cfg(unix)]
+    #[pyfunction]
+    fn pthread_sigmask(
+        how: i32,
+        mask: crate::function::ArgIterable,
+        vm: &VirtualMachine,
+    ) -> PyResult {
+        use crate::convert::IntoPyException;
+
+        let sigset = pyset_to_sigset(mask, vm)?;
+
         // Call pthread_sigmask
         let mut old_mask: libc::sigset_t = unsafe { core::mem::zeroed() };
         // SAFETY: all pointers are valid
@@ -677,6 +691,37 @@ pub(crate) mod _signal {
         sigset_to_pyset(&old_mask, vm)
     }
 
+    /// Examine pending signals: the set of signals delivered while blocked.
+    #[cfg(unix)]
+    #[pyfunction]
+    fn sigpending(vm: &VirtualMachine) -> PyResult {
+        use crate::convert::IntoPyException;
+
+        let mut sigset: libc::sigset_t = unsafe { core::mem::zeroed() };
+        // SAFETY: sigset is a valid pointer
+        if unsafe { libc::sigpending(&mut sigset) } != 0 {
+            return Err(std::io::Error::last_os_error().into_pyexception(vm));
+        }
+        sigset_to_pyset(&sigset, vm)
+    }
+
+    /// Wait for a signal in the given set to be delivered and return its
+    /// number. The signals should be blocked before calling this.
+    #[cfg(unix)]
+    #[pyfunction]
+    fn sigwait(sigset: crate::function::ArgIterable, vm: &VirtualMachine) -> PyResult<i32> {
+        use crate::convert::IntoPyException;
+
+        let sigset = pyset_to_sigset(sigset, vm)?;
+        let mut signum: libc::c_int = 0;
+        // SAFETY: both pointers are valid; sigwait blocks until delivery
+        let err = unsafe { libc::sigwait(&sigset, &mut signum) };
+        if err != 0 {
+            return Err(std::io::Error::from_raw_os_error(err).into_pyexception(vm));
+        }
+        Ok(signum)
+    }
+
     #[cfg(any(unix, windows))]
     pub extern "C" fn run_signal(signum: i32) {
         signal::TRIGGERS[signum as usize].store(true, Ordering::Relaxed);
@@ -883,7 +883,7 @@ mod _sre {
 
         #[pymethod(name = "match")]
         fn py_match(&self, vm: &VirtualMachine) -> PyResult<Option<PyRef<Match>>> {
-            with_sre_str!(self.pattern, &self.string.clone(), vm, |s| {
+            with_sre_str!(self.pattern, &self.string, vm, |s| {
                 let mut req = s.create_request(&self.pattern, self.start.load(), self.end);
                 let mut state = State::default();
                 req.must_advance = self.must_advance.load();
@@ -905,7 +905,7 @@ mod _sre {
             if self.start.load() > self.end {
                 return Ok(None);
             }
-            with_sre_str!(self.pattern, &self.string.clone(), vm, |s| {
+            with_sre_str!(self.pattern, &self.string, vm, |s| {
                 let mut req = s.create_request(&self.pattern, self.start.load(), self.end);
                 let mut state = State::default();
                 req.must_advance = self.must_advance.load();
@@ -311,8 +311,13 @@ mod sys {
 
     #[pyattr]
     fn builtin_module_names(vm: &VirtualMachine) -> PyTupleRef {
-        let mut module_names: Vec<String> =
-            vm.state.module_defs.keys().map(|&s| s.to_owned()).collect();
+        let mut module_names: Vec<String> = vm
+            .state
+            .module_defs
+            .lock()
+            .keys()
+            .map(|&s| s.to_owned())
+            .collect();
         module_names.push("sys".to_owned());
         module_names.push("builtins".to_owned());
 
@@ -690,7 +695,16 @@ mod sys {
 
     #[pyattr]
     fn orig_argv(vm: &VirtualMachine) -> Vec<PyObjectRef> {
-        env::args().map(|arg| vm.ctx.new_str(arg).into()).collect()
+        let orig_argv = &vm.state.config.settings.orig_argv;
+        if orig_argv.is_empty() {
+            // Embedders don't go through the CLI driver; fall back to the OS argv.
+            env::args().map(|arg| vm.ctx.new_str(arg).into()).collect()
+        } else {
+            orig_argv
+                .iter()
+                .map(|arg| vm.ctx.new_str(arg.as_str()).into())
+                .collect()
+        }
     }
 
     #[pyattr]
@@ -772,8 +786,22 @@ mod sys {
     }
 
     #[pyfunction]
-    fn audit(_args: FuncArgs) {
-        // TODO: sys.audit implementation
+    fn audit(mut args: FuncArgs, vm: &VirtualMachine) -> PyResult<()> {
+        if args.args.is_empty() {
+            return Err(
+                vm.new_type_error("audit() missing 1 required positional argument: 'event'")
+            );
+        }
+        let event: PyStrRef = args.args.remove(0).try_into_value(vm)?;
+        vm.audit(event.as_str(), args.args)
+    }
+
+    /// Adds a new audit hook callback.
+    #[pyfunction]
+    fn addaudithook(hook: PyObjectRef, vm: &VirtualMachine) -> PyResult<()> {
+        vm.audit("sys.addaudithook", vec![])?;
+        vm.state.audit_hooks.lock().push(hook);
+        Ok(())
     }
 
     #[pyfunction]
@@ -929,6 +957,27 @@ mod sys {
         obj.strong_count()
     }
 
+    /// The number of currently live objects. Like CPython's debug-build
+    /// sys.gettotalrefcount(), only present in leak-hunting builds; the exact
+    /// value is meaningless, only deltas between two calls matter.
+    #[cfg(feature = "count-objects")]
+    #[pyfunction]
+    fn gettotalrefcount() -> i64 {
+        crate::object::count::total_live()
+    }
+
+    /// A dict mapping type names to their live instance counts, for per-test
+    /// leak detection. Only present in leak-hunting builds.
+    #[cfg(feature = "count-objects")]
+    #[pyfunction]
+    fn _object_counts(vm: &VirtualMachine) -> PyResult<crate::builtins::PyDictRef> {
+        let counts = vm.ctx.new_dict();
+        for (name, count) in crate::object::count::per_type_snapshot() {
+            counts.set_item(name.as_str(), vm.ctx.new_int(count).into(), vm)?;
+        }
+        Ok(counts)
+    }
+
     #[pyfunction]
     fn getrecursionlimit(vm: &VirtualMachine) -> usize {
         vm.recursion_limit.get()
@@ -1264,6 +1313,11 @@ mod sys {
         vm.ctx.intern_str(s).to_owned()
     }
 
+    #[pyfunction]
+    fn _is_interned(s: PyRefExact<PyStr>) -> bool {
+        s.as_object().is_interned()
+    }
+
     #[pyattr]
     fn int_info(vm: &VirtualMachine) -> PyTupleRef {
         PyIntInfo::from_data(IntInfoData::INFO, vm)
@@ -1371,6 +1425,21 @@ mod sys {
         crate::vm::thread::COROUTINE_ORIGIN_TRACKING_DEPTH.get() as i32
     }
 
+    #[pyfunction]
+    fn _clear_internal_caches(vm: &VirtualMachine) {
+        // drops the eval()/exec() code object cache; specialization caches
+        // CPython also clears here don't exist in this implementation
+        vm.state.code_cache.lock().clear();
+    }
+
+    #[pyfunction]
+    fn _clear_type_cache(vm: &VirtualMachine) {
+        // Deprecated alias of _clear_internal_caches, kept for tools that
+        // still call it. Types keep no separate method cache here: slot and
+        // MRO updates invalidate eagerly, so there is nothing extra to drop
+        _clear_internal_caches(vm);
+    }
+
     #[pyfunction]
     fn _clear_type_descriptors(type_obj: PyTypeRef, vm: &VirtualMachine) -> PyResult<()> {
         use crate::types::PyTypeFlags;
@@ -1537,7 +1606,9 @@ mod sys {
                 verbose: settings.verbose,
                 bytes_warning: settings.bytes_warning,
                 quiet: settings.quiet as u8,
-                hash_randomization: settings.hash_seed.is_none() as u8,
+                // only PYTHONHASHSEED=0 disables randomization; a fixed
+                // nonzero seed still counts as randomized in CPython
+                hash_randomization: (settings.hash_seed != Some(0)) as u8,
                 isolated: settings.isolated as u8,
                 dev_mode: settings.dev_mode,
                 utf8_mode: settings.utf8_mode,
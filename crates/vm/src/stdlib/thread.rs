@@ -499,11 +499,16 @@ pub(crate) mod _thread {
         lock
     }
 
+    // The minimum stack size accepted by CPython's pythread implementations.
+    const THREAD_STACK_MIN: usize = 0x8000; // 32 KiB
+
     #[pyfunction]
-    fn stack_size(size: OptionalArg<usize>, vm: &VirtualMachine) -> usize {
+    fn stack_size(size: OptionalArg<usize>, vm: &VirtualMachine) -> PyResult<usize> {
         let size = size.unwrap_or(0);
-        // TODO: do validation on this to make sure it's not too small
-        vm.state.stacksize.swap(size)
+        if size != 0 && size < THREAD_STACK_MIN {
+            return Err(vm.new_value_error(format!("size not valid: {size} bytes")));
+        }
+        Ok(vm.state.stacksize.swap(size))
     }
 
     #[pyfunction]
@@ -0,0 +1,118 @@
+//! `_tracemalloc` — trace memory allocated for Python objects.
+//!
+//! The accounting is hooked into object allocation/deallocation in
+//! `object::core`, counting the inline size of every `PyInner<T>` while
+//! tracing is active. Per-allocation tracebacks are not recorded yet, so
+//! `_get_traces` returns an empty list and `_get_object_traceback` returns
+//! `None`; `tracemalloc.py` copes with both.
+
+pub(crate) use _tracemalloc::module_def;
+
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+static TRACING: AtomicBool = AtomicBool::new(false);
+static TRACEBACK_LIMIT: AtomicUsize = AtomicUsize::new(1);
+static TRACED_CURRENT: AtomicUsize = AtomicUsize::new(0);
+static TRACED_PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// Record an object allocation of `size` bytes. Cheap no-op while tracing
+/// is off; called from `PyRef::new_ref`.
+#[inline]
+pub(crate) fn record_alloc(size: usize) {
+    if !TRACING.load(Ordering::Relaxed) {
+        return;
+    }
+    let current = TRACED_CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    TRACED_PEAK.fetch_max(current, Ordering::Relaxed);
+}
+
+/// Record an object deallocation of `size` bytes; called from dealloc.
+#[inline]
+pub(crate) fn record_free(size: usize) {
+    if !TRACING.load(Ordering::Relaxed) {
+        return;
+    }
+    let _ = TRACED_CURRENT.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |current| {
+        Some(current.saturating_sub(size))
+    });
+}
+
+#[pymodule]
+mod _tracemalloc {
+    use super::{Ordering, TRACEBACK_LIMIT, TRACED_CURRENT, TRACED_PEAK, TRACING};
+    use crate::{PyObjectRef, PyResult, VirtualMachine, function::OptionalArg};
+
+    /// Start tracing Python memory allocations.
+    #[pyfunction]
+    fn start(nframe: OptionalArg<isize>, vm: &VirtualMachine) -> PyResult<()> {
+        let nframe = nframe.unwrap_or(1);
+        if nframe < 1 {
+            return Err(vm.new_value_error(format!(
+                "the number of frames must be in range [1; 255], not {nframe}"
+            )));
+        }
+        TRACEBACK_LIMIT.store(nframe as usize, Ordering::Relaxed);
+        TRACING.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Stop tracing Python memory allocations.
+    #[pyfunction]
+    fn stop() {
+        TRACING.store(false, Ordering::Relaxed);
+        TRACED_CURRENT.store(0, Ordering::Relaxed);
+        TRACED_PEAK.store(0, Ordering::Relaxed);
+    }
+
+    /// Return True if tracemalloc is tracing.
+    #[pyfunction]
+    fn is_tracing() -> bool {
+        TRACING.load(Ordering::Relaxed)
+    }
+
+    /// Clear traces and reset the traced memory counters.
+    #[pyfunction]
+    fn clear_traces() {
+        TRACED_CURRENT.store(0, Ordering::Relaxed);
+        TRACED_PEAK.store(0, Ordering::Relaxed);
+    }
+
+    /// Return (current, peak) traced memory in bytes.
+    #[pyfunction]
+    fn get_traced_memory() -> (usize, usize) {
+        (
+            TRACED_CURRENT.load(Ordering::Relaxed),
+            TRACED_PEAK.load(Ordering::Relaxed),
+        )
+    }
+
+    /// Set the peak traced memory to the current traced memory.
+    #[pyfunction]
+    fn reset_peak() {
+        TRACED_PEAK.store(TRACED_CURRENT.load(Ordering::Relaxed), Ordering::Relaxed);
+    }
+
+    /// Return the maximum number of frames stored per trace.
+    #[pyfunction]
+    fn get_traceback_limit() -> usize {
+        TRACEBACK_LIMIT.load(Ordering::Relaxed)
+    }
+
+    /// Return the memory used by tracemalloc itself, in bytes.
+    #[pyfunction]
+    fn get_tracemalloc_memory() -> usize {
+        0
+    }
+
+    /// Return the list of recorded traces. Tracebacks are not recorded yet.
+    #[pyfunction]
+    fn _get_traces(vm: &VirtualMachine) -> crate::builtins::PyListRef {
+        vm.ctx.new_list(vec![])
+    }
+
+    /// Return the traceback where `obj` was allocated, or None.
+    #[pyfunction]
+    fn _get_object_traceback(_obj: PyObjectRef, vm: &VirtualMachine) -> PyObjectRef {
+        vm.ctx.none()
+    }
+}
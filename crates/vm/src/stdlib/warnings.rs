@@ -63,6 +63,18 @@ mod _warnings {
             .clone()
     }
 
+    #[pyfunction]
+    fn _setoption(arg: PyStrRef, vm: &VirtualMachine) -> PyResult<()> {
+        crate::warn::set_warn_option(arg.as_str(), vm)
+    }
+
+    #[pyfunction]
+    fn _processoptions(args: Vec<PyStrRef>, vm: &VirtualMachine) {
+        for arg in args {
+            crate::warn::apply_warn_option(arg.as_str(), vm);
+        }
+    }
+
     #[pyfunction]
     fn _acquire_lock(vm: &VirtualMachine) {
         vm.state.warnings.acquire_lock();
@@ -2,8 +2,8 @@ use crate::{
     PyResult, VirtualMachine,
     builtins::{
         PyByteArray, PyBytes, PyComplex, PyDict, PyDictRef, PyEllipsis, PyFloat, PyFrozenSet,
-        PyInt, PyIntRef, PyList, PyListRef, PyNone, PyNotImplemented, PyStr, PyStrInterned,
-        PyTuple, PyTupleRef, PyType, PyTypeRef,
+        PyInt, PyIntRef, PyList, PyListRef, PyNone, PyNotImplemented, PySlice, PyStr,
+        PyStrInterned, PyTuple, PyTupleRef, PyType, PyTypeRef,
         bool_::PyBool,
         code::{self, PyCode},
         descriptor::{
@@ -39,6 +39,8 @@ pub struct Context {
     pub empty_frozenset: PyRef<PyFrozenSet>,
     pub empty_str: &'static PyStrInterned,
     pub empty_bytes: PyRef<PyBytes>,
+    /// Cached `slice(None, None, None)`, shared by the `obj[:]` idiom
+    pub full_slice: PyRef<PySlice>,
     pub ellipsis: PyRef<PyEllipsis>,
     pub not_implemented: PyRef<PyNotImplemented>,
 
@@ -47,6 +49,8 @@ pub struct Context {
     pub types: TypeZoo,
     pub exceptions: exceptions::ExceptionZoo,
     pub int_cache_pool: Vec<PyIntRef>,
+    /// Pre-allocated single-character strings for U+0000..=U+00FF
+    pub str_cache_pool: Vec<PyRef<PyStr>>,
     // there should only be exact objects of str in here, no non-str objects and no subclasses
     pub(crate) string_pool: StringPool,
     pub(crate) slot_new_wrapper: PyMethodDef,
@@ -317,6 +321,10 @@ impl Context {
             })
             .collect();
 
+        let str_cache_pool = (0..=u8::MAX)
+            .map(|ch| PyRef::new_ref(PyStr::from(char::from(ch)), types.str_type.to_owned(), None))
+            .collect();
+
         let true_value = create_object(PyBool(PyInt::from(1)), types.bool_type);
         let false_value = create_object(PyBool(PyInt::from(0)), types.bool_type);
 
@@ -342,6 +350,14 @@ impl Context {
 
         let empty_str = unsafe { string_pool.intern("", types.str_type.to_owned()) };
         let empty_bytes = create_object(PyBytes::from(Vec::new()), types.bytes_type);
+        let full_slice = create_object(
+            PySlice {
+                start: None,
+                stop: none.clone().into(),
+                step: None,
+            },
+            types.slice_type,
+        );
 
         // GC callbacks and garbage lists
         let gc_callbacks = PyRef::new_ref(PyList::default(), types.list_type.to_owned(), None);
@@ -355,6 +371,7 @@ impl Context {
             empty_frozenset,
             empty_str,
             empty_bytes,
+            full_slice,
             ellipsis,
 
             not_implemented,
@@ -363,6 +380,7 @@ impl Context {
             types,
             exceptions,
             int_cache_pool,
+            str_cache_pool,
             string_pool,
             slot_new_wrapper,
             names,
@@ -444,7 +462,16 @@ impl Context {
 
     #[inline]
     pub fn new_str(&self, s: impl Into<pystr::PyStr>) -> PyRef<PyStr> {
-        s.into().into_ref(self)
+        let s = s.into();
+        // Reuse the pre-allocated empty and latin-1 single-character strings.
+        match *s.as_bytes() {
+            [] => self.empty_str.to_owned(),
+            [b] => self.str_cache_pool[b as usize].clone(),
+            // the wtf-8 encodings of U+0080..=U+00BF and U+00C0..=U+00FF
+            [0xC2, b] => self.str_cache_pool[b as usize].clone(),
+            [0xC3, b] => self.str_cache_pool[b as usize + 0x40].clone(),
+            _ => s.into_ref(self),
+        }
     }
 
     pub fn interned_or_new_str<S, M>(&self, s: S) -> PyRef<PyStr>
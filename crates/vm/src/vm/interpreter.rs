@@ -46,7 +46,7 @@ where
     use crate::common::hash::HashSecret;
     use crate::common::lock::PyMutex;
     use crate::warn::WarningsState;
-    use core::sync::atomic::AtomicBool;
+    use core::sync::atomic::{AtomicBool, AtomicU64};
     use crossbeam_utils::atomic::AtomicCell;
 
     let paths = getpath::init_path_config(&settings);
@@ -99,7 +99,7 @@ where
     // Create PyGlobalState
     let global_state = PyRc::new(PyGlobalState {
         config,
-        module_defs: all_module_defs,
+        module_defs: PyMutex::new(all_module_defs),
         frozen,
         stacksize: AtomicCell::new(0),
         thread_count: AtomicCell::new(0),
@@ -114,6 +114,7 @@ where
         after_forkers_parent: PyMutex::default(),
         int_max_str_digits,
         switch_interval: AtomicCell::new(0.005),
+        instruction_count: AtomicU64::new(0),
         global_trace_func: PyMutex::default(),
         global_profile_func: PyMutex::default(),
         #[cfg(feature = "threading")]
@@ -124,8 +125,17 @@ where
         thread_handles: parking_lot::Mutex::new(Vec::new()),
         #[cfg(feature = "threading")]
         shutdown_handles: parking_lot::Mutex::new(Vec::new()),
+        audit_hooks: PyMutex::default(),
+        shutdown_closers: PyMutex::default(),
+        open_code_hook: std::sync::OnceLock::new(),
+        code_cache: PyMutex::default(),
     });
 
+    // A memory cap needs the allocator-side live-byte accounting switched on
+    if global_state.config.settings.memory_limit.is_some() {
+        crate::object::limits::enable_tracking();
+    }
+
     // Create VM with the global state
     // Note: Don't clone here - init_hooks need exclusive access to mutate state
     let mut vm = VirtualMachine::new(ctx, global_state);
@@ -334,6 +344,40 @@ impl Interpreter {
         Self { global_state, vm }
     }
 
+    /// Register a native module after interpreter creation.
+    ///
+    /// The module is inserted under `def.name` into the builtin table the
+    /// importer consults, and the materialized `sys.builtin_module_names`
+    /// tuple is refreshed, so the module is importable immediately. This
+    /// lets plugin hosts expose host APIs lazily instead of registering
+    /// everything up front with [`InterpreterBuilder::add_native_modules`].
+    pub fn add_native_module(&self, def: &'static builtins::PyModuleDef) {
+        self.enter(|vm| {
+            vm.state.module_defs.lock().insert(def.name.as_str(), def);
+
+            // sys.builtin_module_names was computed at init; rebuild it
+            let mut names: Vec<String> = vm
+                .state
+                .module_defs
+                .lock()
+                .keys()
+                .map(|&s| s.to_owned())
+                .collect();
+            names.push("sys".to_owned());
+            names.push("builtins".to_owned());
+            names.sort();
+            let names = vm.ctx.new_tuple(
+                names
+                    .into_iter()
+                    .map(|n| vm.ctx.new_str(n).into())
+                    .collect(),
+            );
+            vm.sys_module
+                .set_attr("builtin_module_names", names, vm)
+                .expect("failed to refresh sys.builtin_module_names");
+        })
+    }
+
     /// Run a function with the main virtual machine and return a PyResult of the result.
     ///
     /// To enter vm context multiple times or to avoid buffer/exception management, this function is preferred.
@@ -425,6 +469,10 @@ impl Interpreter {
             // Run atexit exit functions
             atexit::_run_exitfuncs(vm);
 
+            // Close lingering sockets/SSL streams before modules (and with
+            // them the openssl structures) are torn down
+            vm.run_shutdown_closers();
+
             // Finalize modules: clear module dicts in reverse import order
             vm.finalize_modules();
 
@@ -42,7 +42,7 @@ use crate::{
 use alloc::{borrow::Cow, collections::BTreeMap};
 use core::{
     cell::{Cell, OnceCell, Ref, RefCell},
-    sync::atomic::{AtomicBool, Ordering},
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
 };
 use crossbeam_utils::atomic::AtomicCell;
 #[cfg(unix)]
@@ -58,7 +58,7 @@ use std::{
 pub use context::Context;
 pub use interpreter::{Interpreter, InterpreterBuilder};
 pub(crate) use method::PyMethod;
-pub use setting::{CheckHashPycsMode, Paths, PyConfig, Settings};
+pub use setting::{Capabilities, CheckHashPycsMode, Paths, PyConfig, Settings};
 
 pub const MAX_MEMORY_SIZE: usize = isize::MAX as usize;
 
@@ -107,7 +107,9 @@ struct ExceptionStack {
 
 pub struct PyGlobalState {
     pub config: PyConfig,
-    pub module_defs: BTreeMap<&'static str, &'static builtins::PyModuleDef>,
+    /// Builtin (native) module definitions. Normally populated before init;
+    /// embedders can add entries at runtime via `Interpreter::add_native_module`
+    pub module_defs: PyMutex<BTreeMap<&'static str, &'static builtins::PyModuleDef>>,
     pub frozen: HashMap<&'static str, FrozenModule, ahash::RandomState>,
     pub stacksize: AtomicCell<usize>,
     pub thread_count: AtomicCell<usize>,
@@ -122,6 +124,9 @@ pub struct PyGlobalState {
     pub after_forkers_parent: PyMutex<Vec<PyObjectRef>>,
     pub int_max_str_digits: AtomicCell<usize>,
     pub switch_interval: AtomicCell<f64>,
+    /// Bytecode instructions executed so far; only counted while
+    /// `Settings::instruction_limit` is set
+    pub instruction_count: AtomicU64,
     /// Global trace function for all threads (set by sys._settraceallthreads)
     pub global_trace_func: PyMutex<Option<PyObjectRef>>,
     /// Global profile function for all threads (set by sys._setprofileallthreads)
@@ -138,8 +143,29 @@ pub struct PyGlobalState {
     /// Registry for non-daemon threads that need to be joined at shutdown
     #[cfg(feature = "threading")]
     pub shutdown_handles: parking_lot::Mutex<Vec<stdlib::thread::ShutdownEntry>>,
+    /// Audit hooks registered via sys.addaudithook (PySys_Audit)
+    pub audit_hooks: PyMutex<Vec<PyObjectRef>>,
+    /// Weak references to objects (sockets, SSL streams) that native modules
+    /// registered to be shut down during interpreter finalization
+    pub shutdown_closers: PyMutex<Vec<PyRef<PyWeak>>>,
+    /// Embedder hook consulted by io.open_code before falling back to a
+    /// plain binary open (PyFile_SetOpenCodeHook). Set once, before running
+    /// any Python code.
+    pub open_code_hook: std::sync::OnceLock<OpenCodeHook>,
+    /// Bounded LRU of code objects compiled for string sources passed to
+    /// eval()/exec(), keyed by source, mode and optimization level, so
+    /// workloads that re-evaluate identical snippets (templating engines)
+    /// skip recompilation. Cleared by sys._clear_internal_caches()
+    pub code_cache: PyMutex<indexmap::IndexMap<CodeCacheKey, PyRef<PyCode>, ahash::RandomState>>,
 }
 
+/// Key for [`PyGlobalState::code_cache`]: (source, compile mode, optimize level)
+pub type CodeCacheKey = (String, u8, u8);
+
+/// Hook type for [`PyGlobalState::open_code_hook`]: receives the path object
+/// passed to io.open_code and returns an open, readable file object.
+pub type OpenCodeHook = Box<dyn Fn(PyObjectRef, &VirtualMachine) -> PyResult + Send + Sync>;
+
 pub fn process_hash_secret_seed() -> u32 {
     use std::sync::OnceLock;
     static SEED: OnceLock<u32> = OnceLock::new();
@@ -479,6 +505,10 @@ impl VirtualMachine {
             );
         }
 
+        // Apply -W/PYTHONWARNINGS options natively so they take effect even
+        // if the warnings module is never imported
+        crate::warn::process_warn_options(self);
+
         self.initialized = true;
     }
 
@@ -550,6 +580,132 @@ impl VirtualMachine {
         self.run_frame(frame)
     }
 
+    /// Invoke every audit hook registered via sys.addaudithook with the
+    /// given event and arguments (PySys_Audit). An error raised by a hook
+    /// propagates to the caller, aborting the audited operation.
+    pub fn audit(&self, event: &str, args: Vec<PyObjectRef>) -> PyResult<()> {
+        let hooks = self.state.audit_hooks.lock().clone();
+        if hooks.is_empty() {
+            return Ok(());
+        }
+        let event: PyObjectRef = self.ctx.new_str(event).into();
+        let args: PyObjectRef = self.ctx.new_tuple(args).into();
+        for hook in hooks {
+            hook.call((event.clone(), args.clone()), self)?;
+        }
+        Ok(())
+    }
+
+    /// Enforce [`Capabilities::fs_roots`] for a native filesystem operation
+    /// on `path`. The check is lexical: the path is absolutized and `..`
+    /// segments are resolved without touching the filesystem, so a symlink
+    /// inside an allowed root can still point outside it — don't put
+    /// attacker-controlled symlinks under a root.
+    pub fn check_fs_capability(&self, path: &std::path::Path) -> PyResult<()> {
+        use std::path::Component;
+
+        let Some(roots) = &self.state.config.settings.capabilities.fs_roots else {
+            return Ok(());
+        };
+        let mut absolute = if path.is_absolute() {
+            std::path::PathBuf::new()
+        } else {
+            std::env::current_dir().map_err(|err| self.new_os_error(err.to_string()))?
+        };
+        for component in path.components() {
+            match component {
+                Component::CurDir => {}
+                Component::ParentDir => {
+                    absolute.pop();
+                }
+                other => absolute.push(other),
+            }
+        }
+        if roots.iter().any(|root| absolute.starts_with(root)) {
+            Ok(())
+        } else {
+            Err(self.new_exception_msg(
+                self.ctx.exceptions.permission_error.to_owned(),
+                format!("filesystem access to {} is not allowed", absolute.display()),
+            ))
+        }
+    }
+
+    /// Enforce [`Settings::instruction_limit`] and
+    /// [`Settings::memory_limit`]; called once per bytecode instruction from
+    /// the eval loop. A no-op (two `Option` tests) when neither limit is
+    /// configured. Note that a handler catching the resulting
+    /// `ResourceLimitError` runs under the same limits: it must release
+    /// objects to get back under a memory cap, while an exhausted
+    /// instruction budget cannot be recovered from in Python code.
+    #[inline]
+    pub fn check_resource_limits(&self) -> PyResult<()> {
+        let settings = &self.state.config.settings;
+        if let Some(limit) = settings.instruction_limit {
+            let executed = self.state.instruction_count.fetch_add(1, Ordering::Relaxed) + 1;
+            if executed > limit {
+                return Err(self.new_resource_limit_error(format!(
+                    "instruction budget of {limit} bytecode instructions exhausted"
+                )));
+            }
+        }
+        if let Some(limit) = settings.memory_limit {
+            let live = crate::object::limits::live_bytes();
+            if live > limit {
+                return Err(self.new_resource_limit_error(format!(
+                    "memory limit of {limit} bytes exceeded ({live} bytes of objects live)"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Register an object to be shut down when the interpreter finalizes.
+    /// The object is held weakly; at finalization every still-live
+    /// registrant is closed (via `close`, or `shutdown` for objects without
+    /// a close method) in reverse registration order, so e.g. an SSL stream
+    /// sends its close_notify before the socket under it is torn down.
+    pub fn register_shutdown_closer(&self, obj: &PyObject) -> PyResult<()> {
+        let weak = obj.downgrade(None, self)?;
+        let mut closers = self.state.shutdown_closers.lock();
+        closers.retain(|w| w.upgrade().is_some());
+        closers.push(weak);
+        Ok(())
+    }
+
+    /// Close registered objects that are still alive; called during
+    /// finalization after atexit handlers, before modules are torn down.
+    pub(crate) fn run_shutdown_closers(&self) {
+        let closers = core::mem::take(&mut *self.state.shutdown_closers.lock());
+        for weak in closers.iter().rev() {
+            let Some(obj) = weak.upgrade() else { continue };
+            let result = obj
+                .get_attr(self.ctx.intern_str("close"), self)
+                .or_else(|_| obj.get_attr(self.ctx.intern_str("shutdown"), self))
+                .and_then(|method| method.call((), self));
+            if let Err(e) = result {
+                self.run_unraisable(
+                    e,
+                    Some("Exception ignored while closing at interpreter shutdown".to_owned()),
+                    obj,
+                );
+            }
+        }
+    }
+
+    /// Deny a native operation disabled by [`Capabilities`], raising
+    /// `PermissionError` when `allowed` is false.
+    pub fn check_capability(&self, allowed: bool, what: &str) -> PyResult<()> {
+        if allowed {
+            Ok(())
+        } else {
+            Err(self.new_exception_msg(
+                self.ctx.exceptions.permission_error.to_owned(),
+                format!("{what} is not allowed in this interpreter"),
+            ))
+        }
+    }
+
     #[cold]
     pub fn run_unraisable(&self, e: PyBaseExceptionRef, msg: Option<String>, object: PyObjectRef) {
         // During interpreter finalization, sys.unraisablehook may not be available,
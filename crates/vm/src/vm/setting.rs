@@ -69,7 +69,9 @@ pub struct Settings {
     // wchar_t *filesystem_errors;
     // wchar_t *pycache_prefix;
     // int parse_argv;
-    // PyWideStringList orig_argv;
+    /// sys.orig_argv: the command line as originally passed to the
+    /// interpreter, before any option processing
+    pub orig_argv: Vec<String>,
     /// sys.argv
     pub argv: Vec<String>,
 
@@ -155,6 +157,18 @@ pub struct Settings {
     /// false for wasm. Not a command-line option
     pub allow_external_library: bool,
 
+    /// Capability restrictions for running untrusted code. Not a command-line option
+    pub capabilities: Capabilities,
+
+    /// Maximum number of bytecode instructions this interpreter may execute;
+    /// exceeding it raises `ResourceLimitError`. Not a command-line option
+    pub instruction_limit: Option<u64>,
+
+    /// Cap in bytes on live Python object allocations; once exceeded, the
+    /// eval loop raises `ResourceLimitError` until enough objects are
+    /// released. Not a command-line option
+    pub memory_limit: Option<usize>,
+
     #[cfg(feature = "flame-it")]
     pub profile_output: Option<OsString>,
     #[cfg(feature = "flame-it")]
@@ -170,6 +184,37 @@ pub enum CheckHashPycsMode {
     Never,
 }
 
+/// Capability restrictions enforced inside the native modules rather than by
+/// hiding them, so untrusted code cannot get around them by digging a native
+/// function out of an unexpected place. Checks are performed by
+/// [`VirtualMachine::check_fs_capability`] and
+/// [`VirtualMachine::check_capability`](crate::VirtualMachine).
+#[derive(Debug, Clone)]
+pub struct Capabilities {
+    /// When set, filesystem paths passed from Python to native functions
+    /// (`open`, the `os` module, ...) must resolve to locations under one of
+    /// these roots
+    pub fs_roots: Option<Vec<std::path::PathBuf>>,
+    /// Allow creating sockets
+    pub allow_socket: bool,
+    /// Allow spawning subprocesses
+    pub allow_subprocess: bool,
+    /// Allow loading foreign libraries via ctypes
+    pub allow_ctypes: bool,
+}
+
+/// Everything allowed, like a regular interpreter.
+impl Default for Capabilities {
+    fn default() -> Self {
+        Self {
+            fs_roots: None,
+            allow_socket: true,
+            allow_subprocess: true,
+            allow_ctypes: true,
+        }
+    }
+}
+
 impl Settings {
     pub fn with_path(mut self, path: String) -> Self {
         self.path_list.push(path);
@@ -202,6 +247,7 @@ impl Default for Settings {
             context_aware_warnings: false,
             warnoptions: vec![],
             path_list: vec![],
+            orig_argv: vec![],
             argv: vec![],
             hash_seed: None,
             faulthandler: false,
@@ -209,6 +255,9 @@ impl Default for Settings {
             buffered_stdio: true,
             check_hash_pycs_mode: CheckHashPycsMode::Default,
             allow_external_library: cfg!(feature = "importlib"),
+            capabilities: Capabilities::default(),
+            instruction_limit: None,
+            memory_limit: None,
             stdio_encoding: None,
             stdio_errors: None,
             utf8_mode: 1,
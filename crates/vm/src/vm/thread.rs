@@ -214,7 +214,69 @@ impl ThreadedVirtualMachine {
     }
 }
 
+/// A `Send + Sync` handle to an interpreter for calling into the VM from
+/// threads the embedder created (C callback trampolines, async executors,
+/// ...), analogous to CPython's `PyGILState_Ensure`/`PyGILState_Release`.
+///
+/// Unlike [`ThreadedVirtualMachine`], which must be created on a VM thread and
+/// moved into exactly one new thread, a `ThreadStateHandle` can be cloned and
+/// stashed in state shared between arbitrary threads; each
+/// [`attach`](Self::attach) call sets up a thread state for the current OS
+/// thread, runs the closure, and unwinds the context again.
+#[cfg(feature = "threading")]
+#[derive(Clone)]
+pub struct ThreadStateHandle {
+    template: Arc<parking_lot::Mutex<ThreadedVirtualMachine>>,
+}
+
+#[cfg(feature = "threading")]
+impl ThreadStateHandle {
+    /// Run `f` with a VM attached to the current thread.
+    ///
+    /// May be called from any thread, including ones that have never touched
+    /// the interpreter, and nests fine with an already-entered VM.
+    pub fn attach<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&VirtualMachine) -> R,
+    {
+        let thread_vm = self.template.lock().vm.new_thread();
+        thread_vm.run(f)
+    }
+
+    /// Remove the frame-tracking state registered for the current thread, so
+    /// `sys._current_frames()` stops reporting it. Optional; call it from a
+    /// foreign thread that is done calling [`attach`](Self::attach) before the
+    /// thread exits.
+    pub fn detach(&self) {
+        cleanup_current_thread_frames(&self.template.lock().vm);
+    }
+}
+
 impl VirtualMachine {
+    /// Create a handle that threads not spawned by the interpreter can use to
+    /// call into it; see [`ThreadStateHandle`].
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// # rustpython_vm::Interpreter::without_stdlib(Default::default()).enter(|vm| {
+    /// let handle = vm.thread_state_handle();
+    /// let thread = std::thread::spawn(move || {
+    ///     // e.g. a callback invoked by a foreign library on its own thread
+    ///     let is_none = handle.attach(|vm| vm.is_none(&vm.ctx.none()));
+    ///     handle.detach();
+    ///     is_none
+    /// });
+    /// assert!(thread.join().unwrap());
+    /// # })
+    /// ```
+    #[cfg(feature = "threading")]
+    pub fn thread_state_handle(&self) -> ThreadStateHandle {
+        ThreadStateHandle {
+            template: Arc::new(parking_lot::Mutex::new(self.new_thread())),
+        }
+    }
+
     /// Start a new thread with access to the same interpreter.
     ///
     /// # Note
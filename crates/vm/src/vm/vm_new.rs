@@ -332,6 +332,18 @@ impl VirtualMachine {
         source: Option<&str>,
         allow_incomplete: bool,
     ) -> PyBaseExceptionRef {
+        // The compiler's native stack guard trips on deeply nested source;
+        // surface that as RecursionError like CPython, not as SyntaxError.
+        #[cfg(feature = "codegen")]
+        if let crate::compiler::CompileError::Codegen(codegen_error) = error
+            && matches!(
+                codegen_error.error,
+                rustpython_codegen::error::CodegenErrorType::RecursionLimit
+            )
+        {
+            return self.new_recursion_error(codegen_error.error.to_string());
+        }
+
         let incomplete_or_syntax = |allow| -> &'static Py<crate::builtins::PyType> {
             if allow {
                 self.ctx.exceptions.incomplete_input_error
@@ -706,5 +718,6 @@ impl VirtualMachine {
     define_exception_fn!(fn new_overflow_error, overflow_error, OverflowError);
     define_exception_fn!(fn new_runtime_error, runtime_error, RuntimeError);
     define_exception_fn!(fn new_python_finalization_error, python_finalization_error, PythonFinalizationError);
+    define_exception_fn!(fn new_resource_limit_error, resource_limit_error, ResourceLimitError);
     define_exception_fn!(fn new_memory_error, memory_error, MemoryError);
 }
@@ -256,6 +256,130 @@ fn normalize_module(filename: &Py<PyStr>, vm: &VirtualMachine) -> PyObjectRef {
     }
 }
 
+/// Resolve a possibly abbreviated `-W` action to its full name
+/// (warnings._getaction).
+fn get_warn_action(action: &str, vm: &VirtualMachine) -> PyResult<&'static str> {
+    if action.is_empty() {
+        return Ok("default");
+    }
+    [
+        "default", "always", "all", "ignore", "module", "once", "error",
+    ]
+    .into_iter()
+    .find(|a| a.starts_with(action))
+    .ok_or_else(|| vm.new_value_error(format!("invalid action: {action:?}")))
+}
+
+/// Resolve the category field of a `-W` option to a Warning subclass
+/// (warnings._getcategory).
+fn get_warn_category(category: &str, vm: &VirtualMachine) -> PyResult<PyTypeRef> {
+    if category.is_empty() {
+        return Ok(vm.ctx.exceptions.warning.to_owned());
+    }
+    let (owner, klass): (PyObjectRef, &str) = match category.rsplit_once('.') {
+        None => (vm.builtins.clone().into(), category),
+        Some((module, klass)) => {
+            let module = vm
+                .import(module, 0)
+                .map_err(|_| vm.new_value_error(format!("invalid module name: {module:?}")))?;
+            (module, klass)
+        }
+    };
+    owner
+        .get_attr(vm.ctx.intern_str(klass), vm)
+        .map_err(|_| vm.new_value_error(format!("unknown warning category: {category:?}")))?
+        .downcast::<crate::builtins::PyType>()
+        .ok()
+        .filter(|cat| cat.fast_issubclass(vm.ctx.exceptions.warning))
+        .ok_or_else(|| vm.new_value_error(format!("invalid warning category: {category:?}")))
+}
+
+/// Parse one `-W` option of the form `action:message:category:module:lineno`
+/// and install the resulting filter at the front of the filters list
+/// (warnings._setoption). Message and module are compiled to regex patterns
+/// via the `re` module, so the installed tuple is indistinguishable from one
+/// added by `warnings.filterwarnings`.
+pub(crate) fn set_warn_option(arg: &str, vm: &VirtualMachine) -> PyResult<()> {
+    let parts: Vec<&str> = arg.split(':').collect();
+    if parts.len() > 5 {
+        return Err(vm.new_value_error(format!("too many fields (max 5): {arg:?}")));
+    }
+    let field = |i: usize| parts.get(i).map_or("", |s| s.trim());
+    let action = get_warn_action(field(0), vm)?;
+    let category = get_warn_category(field(2), vm)?;
+    let lineno = match field(4) {
+        "" => 0usize,
+        s => s
+            .parse()
+            .map_err(|_| vm.new_value_error(format!("invalid lineno {s:?}")))?,
+    };
+
+    let compile_pattern = |text: &str, ignore_case: bool| -> PyResult {
+        let re = vm.import("re", 0)?;
+        let escaped: PyStrRef = vm
+            .call_method(&re, "escape", (text.to_owned(),))?
+            .try_into_value(vm)?;
+        if ignore_case {
+            let flags = re.get_attr("IGNORECASE", vm)?;
+            vm.call_method(&re, "compile", (escaped, flags))
+        } else {
+            // module patterns must match the whole module name
+            vm.call_method(&re, "compile", (format!("{escaped}\\z"),))
+        }
+    };
+    let message = match field(1) {
+        "" => vm.ctx.none(),
+        text => compile_pattern(text, true)?,
+    };
+    let module = match field(3) {
+        "" => vm.ctx.none(),
+        text => compile_pattern(text, false)?,
+    };
+
+    let filter: PyObjectRef = PyTuple::new_ref(
+        vec![
+            vm.ctx.new_str(action).into(),
+            message,
+            category.into(),
+            module,
+            vm.ctx.new_int(lineno).into(),
+        ],
+        &vm.ctx,
+    )
+    .into();
+
+    // _add_filter(append=False): drop an existing duplicate, insert at front
+    let filters = get_warnings_filters(vm)?;
+    let _ = vm.call_method(filters.as_object(), "remove", (filter.clone(),));
+    filters.insert(0, filter);
+    vm.state.warnings.filters_mutated();
+    Ok(())
+}
+
+/// Apply and report one `-W` option; invalid options are reported on stderr
+/// and ignored (warnings._processoptions).
+pub(crate) fn apply_warn_option(arg: &str, vm: &VirtualMachine) {
+    if let Err(exc) = set_warn_option(arg, vm) {
+        let args = exc.args();
+        let msg = args
+            .as_slice()
+            .first()
+            .and_then(|m| m.downcast_ref::<PyStr>())
+            .map_or_else(|| arg.to_owned(), |s| s.as_str().to_owned());
+        let stderr = crate::stdlib::sys::PyStderr(vm);
+        writeln!(stderr, "Invalid -W option ignored: {msg}");
+    }
+}
+
+/// Apply `Settings::warnoptions` (`-W`, `PYTHONWARNINGS`) to the filters
+/// list. Called at the end of interpreter initialization so the options
+/// take effect without the `warnings` module ever being imported.
+pub fn process_warn_options(vm: &VirtualMachine) {
+    for arg in vm.state.config.settings.warnoptions.clone() {
+        apply_warn_option(&arg, vm);
+    }
+}
+
 /// Search the global filters list for a matching action.
 // TODO: split into filter_search() + get_filter() and support
 //       context-aware filters (get_warnings_context_filters).
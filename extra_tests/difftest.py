@@ -0,0 +1,209 @@
+"""Property-based differential tester: RustPython vs. the host CPython.
+
+Generates random expressions from a small grammar over the builtin types and
+functions, evaluates every expression under both interpreters, and diffs the
+outcomes (repr of the value, or the type of the raised exception). Anything
+that diverges is a semantic gap in the builtins or the compiler.
+
+Usage:
+
+    python difftest.py [--iterations N] [--seed SEED] [--rustpython PATH]
+
+The whole batch of expressions is shipped to each interpreter as a single
+generated script, so one subprocess per interpreter suffices per batch.
+Every reported divergence includes the expression and the seed, so a failure
+is reproducible with `--seed`.
+"""
+
+import argparse
+import os
+import random
+import subprocess
+import sys
+import tempfile
+
+RUSTPYTHON_BINARY = os.environ.get("RUSTPYTHON") or os.path.join(
+    os.path.dirname(__file__), "..", "target", "release", "rustpython"
+)
+
+# Leaf values. Only value-ish builtins whose repr is stable across runs:
+# no object(), no functions, nothing whose repr embeds an address.
+LITERALS = [
+    "0",
+    "1",
+    "-1",
+    "7",
+    "255",
+    "2**63",
+    "-(2**63)",
+    "0.0",
+    "-0.0",
+    "1.5",
+    "1e308",
+    "float('inf')",
+    "float('nan')",
+    "True",
+    "False",
+    "None",
+    "''",
+    "'a'",
+    "'abc'",
+    "'\\u00e9\\u20ac'",
+    "b''",
+    "b'ab'",
+    "[]",
+    "[1, 2]",
+    "()",
+    "(1,)",
+    "{}",
+    "{1: 'a'}",
+    "{1, 2}",
+    "range(3)",
+    "slice(1, 3)",
+    "frozenset([1])",
+]
+
+BINARY_OPS = ["+", "-", "*", "/", "//", "%", "**", "&", "|", "^", "<<", ">>"]
+COMPARE_OPS = ["<", "<=", "==", "!=", ">=", ">", "is", "in"]
+UNARY_OPS = ["-", "+", "~", "not "]
+
+# One-argument builtins worth fuzzing; higher-arity forms are produced by
+# the method/call rules below.
+UNARY_BUILTINS = [
+    "abs",
+    "ascii",
+    "bin",
+    "bool",
+    "bytes",
+    "chr",
+    "complex",
+    "float",
+    "hash",
+    "hex",
+    "int",
+    "len",
+    "list",
+    "oct",
+    "ord",
+    "repr",
+    "round",
+    "set",
+    "sorted",
+    "str",
+    "sum",
+    "tuple",
+    "type",
+]
+
+BINARY_BUILTINS = ["divmod", "pow", "min", "max", "isinstance"]
+
+METHODS = [
+    ".count({})",
+    ".index({})",
+    ".startswith({})",
+    ".strip({})",
+    ".split({})",
+    ".join({})",
+    ".get({})",
+    ".find({})",
+    ".replace({0}, {0})",
+]
+
+
+def gen_expr(rng, depth):
+    """Generate one random expression, at most `depth` levels deep."""
+    if depth == 0:
+        return rng.choice(LITERALS)
+    sub = lambda: gen_expr(rng, depth - 1)
+    rule = rng.randrange(8)
+    if rule == 0:
+        return f"({sub()} {rng.choice(BINARY_OPS)} {sub()})"
+    if rule == 1:
+        return f"({sub()} {rng.choice(COMPARE_OPS)} {sub()})"
+    if rule == 2:
+        return f"({rng.choice(UNARY_OPS)}{sub()})"
+    if rule == 3:
+        return f"{rng.choice(UNARY_BUILTINS)}({sub()})"
+    if rule == 4:
+        name = rng.choice(BINARY_BUILTINS)
+        if name == "isinstance":
+            return f"isinstance({sub()}, type({sub()}))"
+        return f"{name}({sub()}, {sub()})"
+    if rule == 5:
+        return f"({sub()})" + rng.choice(METHODS).format(sub())
+    if rule == 6:
+        return f"({sub()})[{sub()}]"
+    return f"({sub()} if {sub()} else {sub()})"
+
+
+DRIVER_PREAMBLE = """\
+import sys
+for expr in EXPRESSIONS:
+    try:
+        print("OK", repr(eval(expr, {"__builtins__": __builtins__})))
+    except BaseException as exc:
+        print("EXC", type(exc).__name__)
+    sys.stdout.flush()
+"""
+
+
+def run_batch(interpreter, expressions):
+    """Evaluate every expression under `interpreter`, one outcome per line."""
+    with tempfile.NamedTemporaryFile("w", suffix=".py", delete=False) as f:
+        f.write(f"EXPRESSIONS = {expressions!r}\n")
+        f.write(DRIVER_PREAMBLE)
+        path = f.name
+    # Pin hash randomization, or hash() outcomes differ between processes
+    # even for two runs of the same interpreter.
+    env = dict(os.environ, PYTHONHASHSEED="0")
+    try:
+        proc = subprocess.run(
+            [interpreter, path], capture_output=True, text=True, timeout=120, env=env
+        )
+        if proc.returncode != 0:
+            raise RuntimeError(
+                f"{interpreter} exited with {proc.returncode}:\n{proc.stderr}"
+            )
+        return proc.stdout.splitlines()
+    finally:
+        os.unlink(path)
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument("--iterations", type=int, default=1000)
+    parser.add_argument("--seed", type=int, default=None)
+    parser.add_argument("--max-depth", type=int, default=3)
+    parser.add_argument("--rustpython", default=RUSTPYTHON_BINARY)
+    args = parser.parse_args()
+
+    seed = args.seed if args.seed is not None else random.randrange(2**32)
+    rng = random.Random(seed)
+    expressions = [
+        gen_expr(rng, rng.randint(1, args.max_depth)) for _ in range(args.iterations)
+    ]
+
+    cpython_results = run_batch(sys.executable, expressions)
+    rustpython_results = run_batch(args.rustpython, expressions)
+    if len(cpython_results) != len(expressions):
+        raise RuntimeError("CPython driver output is truncated")
+    if len(rustpython_results) != len(expressions):
+        raise RuntimeError("RustPython driver output is truncated")
+
+    divergences = 0
+    for expr, expected, actual in zip(expressions, cpython_results, rustpython_results):
+        if expected != actual:
+            divergences += 1
+            print(f"MISMATCH: {expr}")
+            print(f"  cpython:    {expected}")
+            print(f"  rustpython: {actual}")
+
+    print(
+        f"{len(expressions)} expressions, {divergences} divergences (seed={seed})",
+        file=sys.stderr,
+    )
+    return 1 if divergences else 0
+
+
+if __name__ == "__main__":
+    sys.exit(main())
@@ -408,3 +408,26 @@ expected_keys = ["x", "y", "w", "z"]
 assert list(result.keys()) == expected_keys, (
     f"Expected {expected_keys}, got {list(result.keys())}"
 )
+
+# Set algebra on key/item views and the mappingproxy union operator.
+import types
+
+d1 = {"a": 1, "b": 2}
+d2 = {"b": 2, "c": 3}
+assert d1.keys() & d2.keys() == {"b"}
+assert d1.keys() | d2.keys() == {"a", "b", "c"}
+assert d1.keys() ^ d2.keys() == {"a", "c"}
+assert d1.keys() - d2.keys() == {"a"}
+assert d1.items() & d2.items() == {("b", 2)}
+assert d1.items() - d2.items() == {("a", 1)}
+# either operand may be a plain iterable
+assert d1.keys() | ["c"] == {"a", "b", "c"}
+assert ["c"] | d1.keys() == {"a", "b", "c"}
+assert list(reversed(d1.keys())) == ["b", "a"]
+
+proxy = types.MappingProxyType(d1)
+assert proxy | d2 == {"a": 1, "b": 2, "c": 3}
+assert d2 | proxy == {"b": 2, "c": 3, "a": 1}
+assert proxy | proxy == d1
+with assert_raises(TypeError):
+    proxy |= d2
@@ -366,3 +366,21 @@ class SubInt(int):
 subint = int.__new__(SubInt, 11)
 assert subint.real is not subint
 assert type(subint.real) is int
+
+# int -> str conversions respect sys.set_int_max_str_digits, like int(str).
+import sys
+
+_old_limit = sys.get_int_max_str_digits()
+sys.set_int_max_str_digits(700)
+try:
+    big = 10**800
+    with assert_raises(ValueError):
+        str(big)
+    with assert_raises(ValueError):
+        repr(big)
+    # a value exactly at the limit still converts
+    assert len(str(10**699)) == 700
+    # non-decimal conversions are exempt
+    assert hex(big).startswith("0x")
+finally:
+    sys.set_int_max_str_digits(_old_limit)
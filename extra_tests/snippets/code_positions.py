@@ -0,0 +1,61 @@
+"""Position tables (co_positions/co_lines), co_qualname and CodeType.replace()."""
+
+from testutils import assert_raises
+
+
+def sample(a, b):
+    c = a + b
+    return c * 2
+
+
+code = sample.__code__
+
+# co_qualname keeps the full dotted path, co_name only the final segment
+assert code.co_name == "sample"
+assert code.co_qualname == "sample"
+
+
+class Outer:
+    def method(self):
+        pass
+
+
+assert Outer.method.__code__.co_qualname == "Outer.method"
+
+# co_positions yields one (line, end_line, col, end_col) tuple per instruction
+positions = list(code.co_positions())
+assert len(positions) == len(code.co_code) // 2
+for line, end_line, col, end_col in positions:
+    if line is not None:
+        assert code.co_firstlineno <= line <= end_line
+        assert col is None or end_col is None or col <= end_col
+
+# every line reported by co_lines falls inside the function body
+lines = list(code.co_lines())
+assert lines, "co_lines() should not be empty"
+prev_end = 0
+for start, end, line in lines:
+    assert start == prev_end, "ranges must be contiguous"
+    assert start < end
+    prev_end = end
+assert prev_end == len(code.co_code)
+assert all(
+    line is None or line >= code.co_firstlineno for _, _, line in lines
+)
+
+# replace() accepts brand-new strings and leaves everything else untouched
+renamed = code.replace(co_name="renamed_sample_xyz", co_filename="made_up_file.py")
+assert renamed.co_name == "renamed_sample_xyz"
+assert renamed.co_filename == "made_up_file.py"
+assert renamed.co_code == code.co_code
+assert renamed.co_varnames == code.co_varnames
+
+# the replaced code object still runs
+import types
+
+func = types.FunctionType(renamed, globals())
+assert func(2, 3) == 10
+
+# non-string names are rejected, not crashed on
+with assert_raises(SystemError):
+    code.replace(co_names=(1, 2))
@@ -0,0 +1,113 @@
+import importlib
+import importlib.abc
+import importlib.util
+import os
+import sys
+import tempfile
+
+
+# A meta path finder takes precedence over the default machinery when it is
+# listed earlier in sys.meta_path and its find_spec returns a spec.
+class FakeLoader(importlib.abc.Loader):
+    def create_module(self, spec):
+        return None  # use default module creation
+
+    def exec_module(self, module):
+        module.marker = "from_meta_finder"
+
+
+class MetaFinder:
+    def __init__(self):
+        self.invalidated = 0
+
+    def find_spec(self, fullname, path, target=None):
+        if fullname == "fake_meta_module":
+            return importlib.util.spec_from_loader(fullname, FakeLoader())
+        return None
+
+    def invalidate_caches(self):
+        self.invalidated += 1
+
+
+meta_finder = MetaFinder()
+sys.meta_path.insert(0, meta_finder)
+try:
+    import fake_meta_module
+
+    assert fake_meta_module.marker == "from_meta_finder"
+    assert sys.modules["fake_meta_module"] is fake_meta_module
+
+    # importlib.invalidate_caches() calls invalidate_caches() on every meta
+    # path finder that implements it
+    importlib.invalidate_caches()
+    assert meta_finder.invalidated == 1
+finally:
+    sys.meta_path.remove(meta_finder)
+    del sys.modules["fake_meta_module"]
+
+
+# A path hook recognizing a sentinel path entry returns a path entry finder;
+# PathFinder caches it in sys.path_importer_cache and propagates
+# invalidate_caches() to it.
+SENTINEL = os.path.abspath("#fake-path-entry")
+
+
+class PathEntryFinder:
+    invalidated = 0
+
+    def find_spec(self, fullname, target=None):
+        if fullname == "fake_path_module":
+            return importlib.util.spec_from_loader(fullname, FakeLoader())
+        return None
+
+    def invalidate_caches(self):
+        PathEntryFinder.invalidated += 1
+
+
+def path_hook(entry):
+    if entry == SENTINEL:
+        return PathEntryFinder()
+    raise ImportError
+
+
+sys.path_hooks.insert(0, path_hook)
+sys.path.insert(0, SENTINEL)
+try:
+    import fake_path_module
+
+    assert fake_path_module.marker == "from_meta_finder"
+    assert isinstance(sys.path_importer_cache[SENTINEL], PathEntryFinder)
+
+    importlib.invalidate_caches()
+    assert PathEntryFinder.invalidated == 1
+
+    # stale None entries (path entries no hook accepted) are dropped
+    sys.path_importer_cache["#no-such-entry"] = None
+    importlib.invalidate_caches()
+    assert "#no-such-entry" not in sys.path_importer_cache
+finally:
+    sys.path.remove(SENTINEL)
+    sys.path_hooks.remove(path_hook)
+    sys.path_importer_cache.pop(SENTINEL, None)
+    del sys.modules["fake_path_module"]
+
+
+# Namespace packages merge portions from every sys.path entry that
+# contributes a directory of the same name.
+with tempfile.TemporaryDirectory() as tmp:
+    portions = []
+    for part in ("a", "b"):
+        root = os.path.join(tmp, part)
+        portion = os.path.join(root, "fake_ns_pkg")
+        os.makedirs(portion)
+        portions.append(portion)
+        sys.path.insert(0, root)
+    try:
+        import fake_ns_pkg
+
+        assert fake_ns_pkg.__spec__.origin is None
+        assert sorted(fake_ns_pkg.__path__) == sorted(portions)
+    finally:
+        for part in ("a", "b"):
+            sys.path.remove(os.path.join(tmp, part))
+        del sys.modules["fake_ns_pkg"]
@@ -11,3 +11,36 @@ foo = Foo()
 # Since the default __str__ implementation calls __repr__ and __repr__ is
 # actually __str__, str(foo) should raise a RecursionError.
 assert_raises(RecursionError, str, foo)
+
+import sys
+
+
+# deep call recursion raises RecursionError instead of overflowing the stack
+def countdown(n):
+    return n if n == 0 else countdown(n - 1)
+
+
+assert_raises(RecursionError, countdown, 1 << 30)
+# the interpreter stays usable after the guard fires
+assert countdown(10) == 0
+
+# the depth limit is configurable and observed by the eval loop
+old_limit = sys.getrecursionlimit()
+try:
+    sys.setrecursionlimit(old_limit * 2)
+    assert sys.getrecursionlimit() == old_limit * 2
+finally:
+    sys.setrecursionlimit(old_limit)
+
+# the compiler's own stack guard turns deeply nested source into a
+# RecursionError instead of crashing the process
+with assert_raises(RecursionError):
+    compile("0" + "+0" * 100000, "<nested>", "eval")
+
+# recursive comparison of self-referential containers is guarded too
+a = []
+a.append(a)
+b = []
+b.append(b)
+with assert_raises(RecursionError):
+    a == b
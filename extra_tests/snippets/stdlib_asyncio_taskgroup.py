@@ -0,0 +1,78 @@
+import asyncio
+
+
+async def test_taskgroup_runs_children():
+    results = []
+
+    async def child(n):
+        await asyncio.sleep(0)
+        results.append(n)
+
+    async with asyncio.TaskGroup() as tg:
+        for n in range(3):
+            tg.create_task(child(n))
+    assert sorted(results) == [0, 1, 2]
+
+
+async def test_taskgroup_collects_errors():
+    async def ok():
+        await asyncio.sleep(0)
+
+    async def boom():
+        await asyncio.sleep(0)
+        raise ValueError("boom")
+
+    try:
+        async with asyncio.TaskGroup() as tg:
+            tg.create_task(ok())
+            tg.create_task(boom())
+    except* ValueError as eg:
+        assert len(eg.exceptions) == 1
+        assert str(eg.exceptions[0]) == "boom"
+    else:
+        assert False, "TaskGroup should have raised an ExceptionGroup"
+
+    # The internal cancellation of the parent task must have been undone.
+    assert asyncio.current_task().cancelling() == 0
+
+
+async def test_cancel_count_bookkeeping():
+    task = asyncio.current_task()
+    assert task.cancelling() == 0
+    task.cancel()
+    assert task.cancelling() == 1
+    # uncancel drops the count and, at zero, withdraws the pending cancellation
+    assert task.uncancel() == 0
+    assert task.cancelling() == 0
+    # uncancel below zero clamps
+    assert task.uncancel() == 0
+    await asyncio.sleep(0)
+
+
+async def test_timeout_expires():
+    try:
+        async with asyncio.timeout(0.01):
+            await asyncio.sleep(10)
+    except TimeoutError:
+        pass
+    else:
+        assert False, "timeout should have raised TimeoutError"
+    # The CancelledError used to interrupt the sleep was uncancelled.
+    assert asyncio.current_task().cancelling() == 0
+
+
+async def test_timeout_not_expired():
+    async with asyncio.timeout(10) as cm:
+        await asyncio.sleep(0)
+    assert not cm.expired()
+
+
+async def main():
+    await test_taskgroup_runs_children()
+    await test_taskgroup_collects_errors()
+    await test_cancel_count_bookkeeping()
+    await test_timeout_expires()
+    await test_timeout_not_expired()
+
+
+asyncio.run(main())
@@ -51,6 +51,13 @@ assert_equal(
 )
 assert_equal(dec_b64(b"3d=="), b"\xdd")
 
+# round trips cover the quad fast path, every remainder length and
+# decoding that falls back to the byte-wise loop mid-stream
+for size in range(0, 12):
+    data = bytes(range(240, 240 + size))
+    assert_equal(dec_b64(enc_b64(data)), data)
+assert_equal(dec_b64(b"TWFu\nTWFu\n"), b"ManMan")
+
 for exc, expected_name in [
     (binascii.Error, "Error"),
     (binascii.Incomplete, "Incomplete"),
@@ -0,0 +1,126 @@
+import ctypes
+import sys
+
+from testutils import assert_raises
+
+# basic type sizes and conversions work without any loaded library
+assert ctypes.sizeof(ctypes.c_int8) == 1
+assert ctypes.sizeof(ctypes.c_int32) == 4
+assert ctypes.sizeof(ctypes.c_double) == 8
+assert ctypes.c_int(42).value == 42
+assert ctypes.c_char_p(b"hello").value == b"hello"
+
+buf = ctypes.create_string_buffer(16)
+ctypes.memmove(buf, b"abc\0", 4)
+assert buf.value == b"abc"
+
+
+class Point(ctypes.Structure):
+    _fields_ = [("x", ctypes.c_int), ("y", ctypes.c_int)]
+
+
+p = Point(1, 2)
+assert (p.x, p.y) == (1, 2)
+assert ctypes.sizeof(Point) == 2 * ctypes.sizeof(ctypes.c_int)
+
+pp = ctypes.pointer(p)
+pp.contents.x = 7
+assert p.x == 7
+
+
+class IntOrBytes(ctypes.Union):
+    _fields_ = [("n", ctypes.c_uint32), ("raw", ctypes.c_uint8 * 4)]
+
+
+u = IntOrBytes()
+u.n = 0x01020304
+assert sorted(u.raw) == [1, 2, 3, 4]
+
+arr = (ctypes.c_int * 4)(10, 20, 30, 40)
+assert list(arr) == [10, 20, 30, 40]
+assert len(arr) == 4
+
+# foreign calls, callbacks and errno against the C library
+if sys.platform == "linux":
+    import errno
+
+    libc = ctypes.CDLL("libc.so.6", use_errno=True)
+
+    strlen = libc.strlen
+    strlen.argtypes = [ctypes.c_char_p]
+    strlen.restype = ctypes.c_size_t
+    assert strlen(b"hello world") == 11
+
+    labs = libc.labs
+    labs.argtypes = [ctypes.c_long]
+    labs.restype = ctypes.c_long
+    assert labs(-5) == 5
+
+    # byref passes a pointer to existing storage
+    n = ctypes.c_int(0)
+    sscanf = libc.sscanf
+    sscanf.restype = ctypes.c_int
+    assert sscanf(b"42", b"%d", ctypes.byref(n)) == 1
+    assert n.value == 42
+
+    # qsort drives a CFUNCTYPE callback from C
+    CMPFUNC = ctypes.CFUNCTYPE(
+        ctypes.c_int, ctypes.POINTER(ctypes.c_int), ctypes.POINTER(ctypes.c_int)
+    )
+
+    def py_cmp(a, b):
+        return a[0] - b[0]
+
+    data = (ctypes.c_int * 5)(5, 1, 4, 2, 3)
+    libc.qsort(
+        data,
+        len(data),
+        ctypes.sizeof(ctypes.c_int),
+        CMPFUNC(py_cmp),
+    )
+    assert list(data) == [1, 2, 3, 4, 5]
+
+    # use_errno=True makes the C errno observable through get_errno
+    ctypes.set_errno(0)
+    open_ = libc.open
+    open_.argtypes = [ctypes.c_char_p, ctypes.c_int]
+    open_.restype = ctypes.c_int
+    assert open_(b"/nonexistent-rustpython-ctypes", 0) == -1
+    assert ctypes.get_errno() == errno.ENOENT
+
+    # missing symbols surface as AttributeError
+    with assert_raises(AttributeError):
+        libc.definitely_not_a_symbol
+
+# stdcall convention, GetLastError and HRESULT translation on Windows
+if sys.platform.startswith("win"):
+    kernel32 = ctypes.WinDLL("kernel32", use_last_error=True)
+
+    # GetCurrentProcessId is a no-argument stdcall function
+    kernel32.GetCurrentProcessId.restype = ctypes.c_ulong
+    import os
+
+    assert kernel32.GetCurrentProcessId() == os.getpid()
+
+    # a failing call records the thread's last error for get_last_error
+    ctypes.set_last_error(0)
+    kernel32.GetModuleHandleW.restype = ctypes.c_void_p
+    kernel32.GetModuleHandleW.argtypes = [ctypes.c_wchar_p]
+    assert kernel32.GetModuleHandleW("no-such-module.dll") is None
+    assert ctypes.get_last_error() != 0
+
+    # WinError builds an OSError carrying the winerror code
+    err = ctypes.WinError(6)  # ERROR_INVALID_HANDLE
+    assert isinstance(err, OSError)
+    assert err.winerror == 6
+    assert ctypes.FormatError(6)
+
+    # oledll raises on failing HRESULTs instead of returning them
+    ole32 = ctypes.oledll.ole32
+    ole32.CoInitialize(None)
+    try:
+        with assert_raises(OSError):
+            # E_INVALIDARG: null out-pointer
+            ole32.CoCreateInstance(None, None, 0, None, None)
+    finally:
+        ctypes.windll.ole32.CoUninitialize()
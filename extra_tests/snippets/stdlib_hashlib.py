@@ -48,3 +48,146 @@ print(h.hexdigest())
 assert (
     h.hexdigest() == "25738bfe4cc104131e1b45bece4dfd4e7e1d6f0dffda1211e996e9d5d3b66e81"
 )
+
+# blake2 with parameters: keyed MACs, salt/person, digest_size and tree mode
+h = hashlib.blake2b(b"abc")
+assert h.name == "blake2b"
+assert h.digest_size == 64
+assert h.block_size == 128
+assert (
+    h.hexdigest()
+    == "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d17d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"
+)
+
+h = hashlib.blake2s(b"abc")
+assert h.digest_size == 32
+assert h.block_size == 64
+assert (
+    h.hexdigest()
+    == "508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982"
+)
+
+# truncated digests are a distinct hash, not a prefix
+short = hashlib.blake2b(b"abc", digest_size=20)
+assert short.digest_size == 20
+assert len(short.digest()) == 20
+assert short.digest() != hashlib.blake2b(b"abc").digest()[:20]
+
+# keyed hashing replaces HMAC for blake2
+mac = hashlib.blake2s(b"message", key=b"secret-key")
+assert mac.digest() != hashlib.blake2s(b"message").digest()
+assert mac.hexdigest() == hashlib.blake2s(b"message", key=b"secret-key").hexdigest()
+
+# salt and personalization change the digest independently
+plain = hashlib.blake2b(b"data").digest()
+assert hashlib.blake2b(b"data", salt=b"NaCl").digest() != plain
+assert hashlib.blake2b(b"data", person=b"myapp").digest() != plain
+
+# tree hashing parameters are accepted and mixed into the state
+leaf = hashlib.blake2b(
+    b"leaf", fanout=2, depth=2, leaf_size=4096, node_offset=1, inner_size=32
+)
+root = hashlib.blake2b(
+    b"leaf", fanout=2, depth=2, leaf_size=4096, node_offset=0, node_depth=1,
+    inner_size=32, last_node=True,
+)
+assert leaf.digest() != root.digest()
+
+# incremental updates and copy match one-shot hashing
+h = hashlib.blake2b(key=b"k")
+h.update(b"split ")
+c = h.copy()
+h.update(b"message")
+c.update(b"message")
+assert h.digest() == c.digest() == hashlib.blake2b(b"split message", key=b"k").digest()
+
+assert hashlib.blake2b.MAX_DIGEST_SIZE == 64
+assert hashlib.blake2b.MAX_KEY_SIZE == 64
+assert hashlib.blake2b.SALT_SIZE == 16
+assert hashlib.blake2b.PERSON_SIZE == 16
+assert hashlib.blake2s.MAX_DIGEST_SIZE == 32
+assert hashlib.blake2s.MAX_KEY_SIZE == 32
+assert hashlib.blake2s.SALT_SIZE == 8
+assert hashlib.blake2s.PERSON_SIZE == 8
+
+for bad in (
+    lambda: hashlib.blake2b(digest_size=0),
+    lambda: hashlib.blake2b(digest_size=65),
+    lambda: hashlib.blake2s(digest_size=33),
+    lambda: hashlib.blake2b(key=b"k" * 65),
+    lambda: hashlib.blake2b(salt=b"s" * 17),
+    lambda: hashlib.blake2b(person=b"p" * 17),
+    lambda: hashlib.blake2b(depth=0),
+):
+    try:
+        bad()
+    except ValueError:
+        pass
+    else:
+        raise AssertionError("expected ValueError from blake2 parameter check")
+
+# SHA-3 fixed-output functions
+h = hashlib.sha3_224(b"abc")
+assert h.name == "sha3_224"
+assert h.digest_size == 28
+assert h.block_size == 144
+assert h.hexdigest() == "e642824c3f8cf24ad09234ee7d3c766fc9a3a5168d0c94ad73b46fdf"
+
+h = hashlib.sha3_256(b"abc")
+assert h.digest_size == 32
+assert h.block_size == 136
+assert (
+    h.hexdigest() == "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"
+)
+
+h = hashlib.sha3_384(b"abc")
+assert h.digest_size == 48
+assert h.block_size == 104
+assert (
+    h.hexdigest()
+    == "ec01498288516fc926459f58e2c6ad8df9b473cb0fc08c2596da7cf0e49be4b298d88cea927ac7f539f1edf228376d25"
+)
+
+h = hashlib.sha3_512(b"abc")
+assert h.digest_size == 64
+assert h.block_size == 72
+assert (
+    h.hexdigest()
+    == "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0"
+)
+
+# SHAKE extendable-output functions take the length at digest time
+x = hashlib.shake_128(b"abc")
+assert x.name == "shake_128"
+assert x.digest_size == 0
+assert x.block_size == 168
+assert x.hexdigest(16) == "5881092dd818bf5cf8a3ddb793fbcba7"
+# shorter outputs are prefixes of longer ones
+assert x.digest(64)[:16] == x.digest(16)
+assert len(x.digest(0)) == 0
+
+x = hashlib.shake_256(b"abc")
+assert x.block_size == 136
+assert (
+    x.hexdigest(32)
+    == "483366601360a8771c6863080cc4114d8db44530f8f1e1ee4f94ea37e78b5739"
+)
+
+# incremental updates and copies behave like the fixed-output hashes
+x = hashlib.shake_128()
+x.update(b"ab")
+c = x.copy()
+x.update(b"c")
+c.update(b"c")
+assert x.digest(16) == c.digest(16) == hashlib.shake_128(b"abc").digest(16)
+
+# every SHA-3 family name is reachable through hashlib.new and advertised
+for name in ("sha3_224", "sha3_256", "sha3_384", "sha3_512", "shake_128", "shake_256"):
+    assert name in hashlib.algorithms_guaranteed
+    assert name in hashlib.algorithms_available
+    made = hashlib.new(name, b"abc")
+    direct = getattr(hashlib, name)(b"abc")
+    if name.startswith("shake"):
+        assert made.digest(16) == direct.digest(16)
+    else:
+        assert made.digest() == direct.digest()
@@ -40,3 +40,163 @@ with FileIO("README.md") as fio:
     assert len(nres) == 1
     nres = fio.read(2)
     assert len(nres) == 2
+
+# BufferedReader/BufferedWriter/BufferedRandom buffering semantics
+import io
+import tempfile
+
+with tempfile.TemporaryDirectory() as tmpdir:
+    path = os.path.join(tmpdir, "buffered")
+
+    with io.BufferedWriter(FileIO(path, "w"), buffer_size=8) as bw:
+        assert bw.write(b"abc") == 3
+        # nothing is flushed yet: the data still fits in the buffer
+        with FileIO(path) as check:
+            assert check.read() == b""
+        # overflowing the buffer forces the buffered bytes out
+        assert bw.write(b"defghijkl") == 9
+        bw.flush()
+    with FileIO(path) as check:
+        assert check.read() == b"abcdefghijkl"
+
+    with io.BufferedReader(FileIO(path), buffer_size=4) as br:
+        assert br.peek(2)[:2] == b"ab"
+        # peek must not advance the stream position
+        assert br.read(3) == b"abc"
+        # read1 performs at most one raw read, bounded by the buffer size
+        chunk = br.read1(100)
+        assert 1 <= len(chunk) <= 100
+        rest = br.read()
+        assert b"abc" + chunk + rest == b"abcdefghijkl"
+        assert br.read() == b""
+
+        br.seek(0)
+        buf = bytearray(5)
+        assert br.readinto(buf) == 5
+        assert bytes(buf) == b"abcde"
+        n = br.readinto1(buf)
+        assert n >= 1
+        assert br.tell() == 5 + n
+
+    # interleaved reads, writes and seeks through one BufferedRandom
+    with io.BufferedRandom(FileIO(path, "r+"), buffer_size=4) as bf:
+        assert bf.read(3) == b"abc"
+        bf.seek(0)
+        assert bf.write(b"ABC") == 3
+        assert bf.read(3) == b"def"
+        bf.seek(-3, io.SEEK_CUR)
+        assert bf.write(b"DEF") == 3
+        bf.seek(0)
+        assert bf.read() == b"ABCDEFghijkl"
+        bf.seek(0, io.SEEK_END)
+        bf.write(b"!")
+        bf.seek(0)
+        assert bf.read() == b"ABCDEFghijkl!"
+
+# TextIOWrapper: incremental decoding, newline translation, reconfigure,
+# tell/seek cookies, and write_through
+with tempfile.TemporaryDirectory() as tmpdir:
+    path = os.path.join(tmpdir, "text")
+
+    # universal newline translation on read
+    with open(path, "wb") as f:
+        f.write(b"a\r\nb\rc\nd")
+    with open(path, "r") as f:
+        assert f.read() == "a\nb\nc\nd"
+        assert f.newlines == ("\r", "\n", "\r\n")
+    with open(path, "r", newline="") as f:
+        assert f.read() == "a\r\nb\rc\nd"
+    with open(path, "r", newline="\r\n") as f:
+        assert f.readlines() == ["a\r\n", "b\rc\nd"]
+
+    # os.linesep-independent translation on write
+    with open(path, "w", newline="\r\n") as f:
+        f.write("x\ny\n")
+    with open(path, "rb") as f:
+        assert f.read() == b"x\r\ny\r\n"
+
+    # multibyte encodings decode incrementally across buffer boundaries
+    text = "héllo wörld " * 500 + "end€"
+    for encoding in ("utf-8", "utf-16", "utf-16-le", "utf-32"):
+        with open(path, "w", encoding=encoding) as f:
+            f.write(text)
+        with open(path, "r", encoding=encoding) as f:
+            assert f.read() == text
+        with open(path, "r", encoding=encoding) as f:
+            assert "".join(iter(lambda: f.read(7), "")) == text
+
+    # tell() returns an opaque cookie that seek() accepts mid-stream
+    with open(path, "w", encoding="utf-8") as f:
+        f.write("aé€b\nc")
+    with open(path, "r", encoding="utf-8") as f:
+        assert f.read(2) == "aé"
+        cookie = f.tell()
+        rest = f.read()
+        f.seek(cookie)
+        assert f.read() == rest
+        f.seek(0)
+        assert f.read(1) == "a"
+
+    # write_through sends each write straight to the underlying buffer
+    bio = BytesIO()
+    tw = io.TextIOWrapper(bio, encoding="utf-8", write_through=False)
+    tw.write("x")
+    assert bio.getvalue() == b""
+    tw.reconfigure(write_through=True)
+    assert tw.write_through is True
+    # reconfigure flushes the pending text
+    assert bio.getvalue() == b"x"
+    tw.write("y")
+    assert bio.getvalue() == b"xy"
+
+    # reconfigure can change the encoding on the fly
+    with open(path, "w", encoding="utf-8") as f:
+        f.write("tail")
+    with open(path, "r", encoding="ascii") as f:
+        f.reconfigure(encoding="utf-8")
+        assert f.encoding == "utf-8"
+        assert f.read() == "tail"
+
+# raw FileIO: non-blocking EAGAIN -> None, EINTR retried per PEP 475
+import sys
+
+if not sys.platform.startswith("win"):
+    import signal
+
+    r, w = os.pipe()
+    os.set_blocking(r, False)
+    os.set_blocking(w, False)
+    rf = FileIO(r, closefd=True)
+    wf = FileIO(w, "w", closefd=True)
+    try:
+        # nothing to read yet: non-blocking read/readinto return None
+        assert rf.read(4) is None
+        assert rf.readinto(bytearray(4)) is None
+        assert wf.write(b"ping") == 4
+        assert rf.read(4) == b"ping"
+        # fill the pipe until the write side would block
+        while wf.write(b"x" * 65536) is not None:
+            pass
+    finally:
+        rf.close()
+        wf.close()
+
+    # a signal arriving during a blocked read runs the handler and retries
+    r, w = os.pipe()
+    handled = []
+
+    def handler(signum, frame):
+        handled.append(signum)
+        os.write(w, b"hello")
+
+    old_handler = signal.signal(signal.SIGALRM, handler)
+    try:
+        with FileIO(r, closefd=False) as rf:
+            signal.alarm(1)
+            assert rf.read(5) == b"hello"
+        assert handled == [signal.SIGALRM]
+    finally:
+        signal.alarm(0)
+        signal.signal(signal.SIGALRM, old_handler)
+        os.close(r)
+        os.close(w)
@@ -0,0 +1,60 @@
+import _lsprof
+
+
+def fib(n):
+    return n if n < 2 else fib(n - 1) + fib(n - 2)
+
+
+def driver():
+    fib(10)
+    return len([1, 2, 3])
+
+
+profiler = _lsprof.Profiler()
+profiler.enable(subcalls=True, builtins=True)
+driver()
+profiler.disable()
+
+stats = profiler.getstats()
+
+
+def co_name(entry):
+    return getattr(entry.code, "co_name", None)
+
+
+fib_entry = next(e for e in stats if co_name(e) == "fib")
+# fib(10) makes 177 calls, of which all but the outermost are recursive
+assert fib_entry.callcount == 177, fib_entry.callcount
+assert fib_entry.reccallcount == 176, fib_entry.reccallcount
+assert fib_entry.totaltime >= fib_entry.inlinetime >= 0.0
+
+driver_entry = next(e for e in stats if co_name(e) == "driver")
+assert driver_entry.callcount == 1
+assert driver_entry.reccallcount == 0
+# subcall breakdown: driver called fib and len
+assert driver_entry.calls is not None
+callees = {getattr(sub.code, "co_name", sub.code) for sub in driver_entry.calls}
+assert "fib" in callees
+
+# built-in callables show up as descriptive strings, not live objects
+assert any(isinstance(e.code, str) and "len" in e.code for e in stats)
+
+profiler.clear()
+assert profiler.getstats() == []
+
+# a custom timer is consulted instead of the built-in clock
+ticks = [0]
+
+
+def timer():
+    ticks[0] += 10
+    return ticks[0]
+
+
+p2 = _lsprof.Profiler(timer, 0.001)
+p2.enable()
+fib(3)
+p2.disable()
+entry = next(e for e in p2.getstats() if co_name(e) == "fib")
+assert entry.callcount == 5
+assert entry.totaltime > 0.0
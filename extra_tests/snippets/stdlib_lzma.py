@@ -0,0 +1,73 @@
+import io
+import lzma
+import tarfile
+
+from testutils import assert_raises
+
+data = b"the quick brown fox jumps over the lazy dog" * 64
+
+# one-shot xz round trip
+compressed = lzma.compress(data)
+assert compressed[:6] == b"\xfd7zXZ\x00"
+assert lzma.decompress(compressed) == data
+
+# legacy .lzma container
+alone = lzma.compress(data, format=lzma.FORMAT_ALONE)
+assert lzma.decompress(alone, format=lzma.FORMAT_ALONE) == data
+# FORMAT_AUTO detects both containers
+assert lzma.decompress(alone) == data
+
+# raw streams need an explicit filter chain on both ends
+filters = [{"id": lzma.FILTER_LZMA2, "preset": 6}]
+raw = lzma.compress(data, format=lzma.FORMAT_RAW, filters=filters)
+assert lzma.decompress(raw, format=lzma.FORMAT_RAW, filters=filters) == data
+
+# filter chains can combine a BCJ filter with LZMA2
+chained = lzma.compress(
+    data,
+    format=lzma.FORMAT_XZ,
+    filters=[{"id": lzma.FILTER_X86}, {"id": lzma.FILTER_LZMA2, "dict_size": 1 << 20}],
+)
+assert lzma.decompress(chained) == data
+
+with assert_raises(ValueError):
+    lzma.compress(data, format=lzma.FORMAT_RAW)
+with assert_raises(ValueError):
+    lzma.decompress(raw, format=lzma.FORMAT_RAW, filters=[{"id": 12345}])
+with assert_raises(ValueError):
+    lzma.decompress(compressed, filters=filters)
+
+# streaming compressor/decompressor objects
+comp = lzma.LZMACompressor()
+pieces = [comp.compress(data[i : i + 100]) for i in range(0, len(data), 100)]
+pieces.append(comp.flush())
+stream = b"".join(pieces)
+
+decomp = lzma.LZMADecompressor()
+out = []
+for i in range(0, len(stream), 64):
+    out.append(decomp.decompress(stream[i : i + 64]))
+assert b"".join(out) == data
+assert decomp.eof
+assert decomp.unused_data == b""
+
+# max_length caps output and leaves the rest for later calls
+decomp = lzma.LZMADecompressor()
+first = decomp.decompress(stream, max_length=10)
+assert len(first) == 10
+assert not decomp.needs_input
+rest = decomp.decompress(b"")
+assert first + rest == data
+
+# tarfile reads .tar.xz archives through this module
+buf = io.BytesIO()
+with tarfile.open(fileobj=buf, mode="w:xz") as tf:
+    payload = io.BytesIO(data)
+    info = tarfile.TarInfo("payload.txt")
+    info.size = len(data)
+    tf.addfile(info, payload)
+
+buf.seek(0)
+with tarfile.open(fileobj=buf, mode="r:xz") as tf:
+    member = tf.extractfile("payload.txt")
+    assert member.read() == data
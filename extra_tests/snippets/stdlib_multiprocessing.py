@@ -0,0 +1,52 @@
+import sys
+
+from testutils import assert_raises
+
+# the native primitives backing multiprocessing: SemLock and shared memory
+if not sys.platform.startswith("win"):
+    import time
+
+    import _multiprocessing
+
+    # kind=0 is RECURSIVE_MUTEX in multiprocessing.synchronize
+    sl = _multiprocessing.SemLock(0, 1, 1, "/rp-semlock-test-%d" % id(object()), True)
+    assert sl.maxvalue == 1
+    assert sl._count() == 0
+    assert sl.acquire()
+    assert sl._count() == 1
+    # recursive mutex: same thread may re-acquire
+    assert sl.acquire()
+    assert sl._count() == 2
+    sl.release()
+    sl.release()
+    assert sl._count() == 0
+
+    with sl:
+        assert sl._count() == 1
+    assert sl._count() == 0
+
+    # a non-recursive semaphore times out rather than deadlocking
+    sem = _multiprocessing.SemLock(1, 1, 1, "/rp-sem-test-%d" % id(object()), True)
+    assert sem.acquire(timeout=1)
+    start = time.monotonic()
+    assert sem.acquire(block=False) is False
+    assert sem.acquire(timeout=0.1) is False
+    assert time.monotonic() - start < 5
+    sem.release()
+    assert_raises(ValueError, sem.release)
+
+    from multiprocessing import shared_memory
+
+    shm = shared_memory.SharedMemory(create=True, size=64)
+    try:
+        shm.buf[:5] = b"hello"
+        other = shared_memory.SharedMemory(name=shm.name)
+        try:
+            assert bytes(other.buf[:5]) == b"hello"
+            other.buf[0] = ord(b"j")
+            assert bytes(shm.buf[:5]) == b"jello"
+        finally:
+            other.close()
+    finally:
+        shm.close()
+        shm.unlink()
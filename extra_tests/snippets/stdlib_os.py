@@ -33,6 +33,29 @@ if hasattr(os, "sendfile") and sys.platform.startswith("linux"):
     os.close(dest_fd)
     os.remove("destination.md")
 
+# splice requires one end to be a pipe
+if hasattr(os, "splice") and sys.platform.startswith("linux"):
+    src_fd = os.open("README.md", os.O_RDONLY)
+    src_len = os.stat("README.md").st_size
+    pipe_r, pipe_w = os.pipe()
+
+    spliced = os.splice(src_fd, pipe_w, min(src_len, 4096))
+    assert spliced > 0
+    os.lseek(src_fd, 0, 0)
+    assert os.read(pipe_r, spliced) == os.read(src_fd, spliced)
+
+    # an explicit source offset leaves the descriptor's position alone
+    os.lseek(src_fd, 0, 0)
+    spliced = os.splice(src_fd, pipe_w, 16, offset_src=4)
+    assert spliced == 16
+    assert os.lseek(src_fd, 0, os.SEEK_CUR) == 0
+    os.lseek(src_fd, 4, 0)
+    assert os.read(pipe_r, spliced) == os.read(src_fd, spliced)
+
+    os.close(pipe_r)
+    os.close(pipe_w)
+    os.close(src_fd)
+
 try:
     os.open("DOES_NOT_EXIST", 0)
 except OSError as err:
@@ -528,3 +551,242 @@ assert os.access("nonexistent_file_12345", os.F_OK) is False
 assert os.access("nonexistent_file_12345", os.W_OK) is False
 assert os.access("README.md", os.F_OK) is True
 assert os.access("README.md", os.R_OK) is True
+
+# dir_fd support for unlink/rmdir/rename/chmod and fd-based scandir/fwalk
+if sys.platform.startswith("linux"):
+    import tempfile
+
+    with tempfile.TemporaryDirectory() as base:
+        dfd = os.open(base, os.O_RDONLY)
+        try:
+            assert os.stat in os.supports_dir_fd
+            assert os.unlink in os.supports_dir_fd
+            assert os.rmdir in os.supports_dir_fd
+            assert os.rename in os.supports_dir_fd
+            assert os.chmod in os.supports_dir_fd
+            assert os.scandir in os.supports_fd
+
+            with open(os.path.join(base, "a"), "w") as f:
+                f.write("hello")
+
+            os.chmod("a", 0o641, dir_fd=dfd)
+            assert os.stat("a", dir_fd=dfd).st_mode & 0o777 == 0o641
+
+            os.rename("a", "b", src_dir_fd=dfd, dst_dir_fd=dfd)
+            assert os.listdir(base) == ["b"]
+
+            os.mkdir("d", dir_fd=dfd)
+            with os.scandir(dfd) as it:
+                entries = {e.name: e for e in it}
+            assert set(entries) == {"b", "d"}
+            assert entries["b"].is_file() and not entries["b"].is_dir()
+            assert entries["d"].is_dir() and not entries["d"].is_file()
+            assert not entries["b"].is_symlink()
+            assert entries["b"].stat(follow_symlinks=False).st_size == 5
+
+            walked = [
+                (os.path.relpath(top, base), sorted(dirs), sorted(files))
+                for top, dirs, files, rootfd in os.fwalk(base)
+            ]
+            assert walked == [(".", ["d"], ["b"]), ("d", [], [])]
+            for top, dirs, files, rootfd in os.fwalk(base):
+                assert sorted(os.listdir(rootfd)) == sorted(dirs + files)
+
+            os.unlink("b", dir_fd=dfd)
+            os.rmdir("d", dir_fd=dfd)
+            assert os.listdir(base) == []
+        finally:
+            os.close(dfd)
+
+# memfd_create / eventfd / pidfd_open / timerfd
+if sys.platform.startswith("linux"):
+    fd = os.memfd_create("rustpython-memfd-test")
+    try:
+        assert os.write(fd, b"hello") == 5
+        os.lseek(fd, 0, os.SEEK_SET)
+        assert os.read(fd, 5) == b"hello"
+    finally:
+        os.close(fd)
+
+    fd = os.eventfd(3, os.EFD_CLOEXEC | os.EFD_NONBLOCK)
+    try:
+        assert os.eventfd_read(fd) == 3
+        os.eventfd_write(fd, 5)
+        os.eventfd_write(fd, 2)
+        assert os.eventfd_read(fd) == 7
+        assert_raises(BlockingIOError, os.eventfd_read, fd)
+    finally:
+        os.close(fd)
+
+    if hasattr(os, "pidfd_open"):
+        fd = os.pidfd_open(os.getpid())
+        os.close(fd)
+
+    import time
+
+    fd = os.timerfd_create(time.CLOCK_MONOTONIC)
+    try:
+        old = os.timerfd_settime(fd, initial=60.0, interval=30.0)
+        assert old == (0.0, 0.0)
+        value, interval = os.timerfd_gettime(fd)
+        assert 0.0 < value <= 60.0
+        assert interval == 30.0
+        value_ns, interval_ns = os.timerfd_gettime_ns(fd)
+        assert 0 < value_ns <= 60 * 10**9
+        assert interval_ns == 30 * 10**9
+        old = os.timerfd_settime_ns(fd, initial=0, interval=0)
+        assert old[1] == 30 * 10**9
+        assert os.timerfd_gettime(fd) == (0.0, 0.0)
+    finally:
+        os.close(fd)
+
+# register_at_fork: before hooks run in reverse registration order,
+# after hooks run in registration order, child hooks run in the child only
+if hasattr(os, "fork") and hasattr(os, "register_at_fork"):
+    assert_raises(TypeError, os.register_at_fork)
+    assert_raises(TypeError, os.register_at_fork, before=1)
+
+    calls = []
+    os.register_at_fork(
+        before=lambda: calls.append("before1"),
+        after_in_parent=lambda: calls.append("parent1"),
+    )
+    os.register_at_fork(
+        before=lambda: calls.append("before2"),
+        after_in_parent=lambda: calls.append("parent2"),
+        after_in_child=lambda: calls.append("child"),
+    )
+
+    r, w = os.pipe()
+    pid = os.fork()
+    if pid == 0:
+        # child: report what ran here and exit without cleanup
+        os.close(r)
+        os.write(w, ",".join(calls).encode())
+        os.close(w)
+        os._exit(0)
+    os.close(w)
+    assert calls == ["before2", "before1", "parent1", "parent2"]
+    child_calls = b""
+    while True:
+        chunk = os.read(r, 4096)
+        if not chunk:
+            break
+        child_calls += chunk
+    os.close(r)
+    _, status = os.waitpid(pid, 0)
+    assert os.WIFEXITED(status) and os.WEXITSTATUS(status) == 0
+    assert child_calls == b"before2,before1,child"
+
+# statvfs / fstatvfs / confstr
+if not sys.platform.startswith("win"):
+    st = os.statvfs("/")
+    assert len(st) == 10
+    assert st.f_bsize == st[0]
+    assert st.f_blocks >= st.f_bfree >= 0
+    assert st.f_files >= 0
+    assert st.f_namemax > 0
+    assert hasattr(st, "f_fsid")
+
+    fd = os.open("/", os.O_RDONLY)
+    try:
+        assert os.fstatvfs(fd).f_bsize == st.f_bsize
+        assert os.statvfs(fd).f_bsize == st.f_bsize
+    finally:
+        os.close(fd)
+
+    import shutil
+
+    usage = shutil.disk_usage("/")
+    assert usage.total > 0
+    assert usage.total >= usage.used
+
+    assert len(os.confstr_names) > 0
+    assert "CS_PATH" in os.confstr_names
+    assert os.confstr("CS_PATH") == os.confstr(os.confstr_names["CS_PATH"])
+    assert_raises(ValueError, os.confstr, "CS_NOT_A_REAL_NAME")
+
+# extended attributes
+if sys.platform.startswith("linux"):
+    import tempfile
+
+    with tempfile.NamedTemporaryFile() as f:
+        try:
+            os.setxattr(f.name, "user.rp-test", b"hello")
+        except OSError:
+            # filesystem without user xattr support (e.g. tmpfs without the
+            # user_xattr mount option)
+            pass
+        else:
+            assert os.getxattr(f.name, "user.rp-test") == b"hello"
+            assert "user.rp-test" in os.listxattr(f.name)
+            assert os.getxattr(f.fileno(), "user.rp-test") == b"hello"
+            assert "user.rp-test" in os.listxattr(f.fileno())
+            assert_raises(
+                OSError,
+                os.setxattr,
+                f.name,
+                "user.rp-test",
+                b"x",
+                os.XATTR_CREATE,
+            )
+            os.setxattr(f.name, b"user.rp-test", b"world", os.XATTR_REPLACE)
+            assert os.getxattr(f.name, "user.rp-test") == b"world"
+            os.removexattr(f.name, "user.rp-test")
+            assert "user.rp-test" not in os.listxattr(f.name)
+            assert_raises(OSError, os.getxattr, f.name, "user.rp-test")
+
+# open(opener=), O_CLOEXEC/O_TMPFILE, and fd inheritability
+if not sys.platform.startswith("win"):
+    assert os.O_CLOEXEC > 0
+    if sys.platform.startswith("linux"):
+        assert os.O_TMPFILE > 0
+
+    import tempfile
+
+    with tempfile.TemporaryDirectory() as base:
+        opened = []
+
+        def opener(path, flags):
+            fd = os.open(path, flags, 0o600)
+            opened.append((path, fd))
+            return fd
+
+        name = os.path.join(base, "via-opener")
+        with open(name, "w", opener=opener) as f:
+            f.write("data")
+            assert opened == [(name, f.fileno())]
+        with open(name) as f:
+            assert f.read() == "data"
+
+        def bad_opener(path, flags):
+            return -1
+
+        assert_raises(ValueError, open, name, opener=bad_opener)
+
+        # descriptors opened by python are non-inheritable by default
+        fd = os.open(name, os.O_RDONLY)
+        try:
+            assert os.get_inheritable(fd) is False
+            os.set_inheritable(fd, True)
+            assert os.get_inheritable(fd) is True
+            os.set_inheritable(fd, False)
+            assert os.get_inheritable(fd) is False
+        finally:
+            os.close(fd)
+
+        r, w = os.pipe()
+        try:
+            assert os.get_inheritable(r) is False
+            assert os.get_inheritable(w) is False
+        finally:
+            os.close(r)
+            os.close(w)
+
+    import socket
+
+    with socket.socket() as s:
+        assert s.get_inheritable() is False
+        s.set_inheritable(True)
+        assert s.get_inheritable() is True
+        assert os.get_inheritable(s.fileno()) is True
@@ -0,0 +1,51 @@
+import sys
+
+from testutils import assert_raises
+
+# _overlapped backs asyncio's ProactorEventLoop and only exists on Windows
+if sys.platform.startswith("win"):
+    import _overlapped
+
+    # manual-reset event helpers
+    ev = _overlapped.CreateEvent(None, True, False, None)
+    try:
+        _overlapped.SetEvent(ev)
+        _overlapped.ResetEvent(ev)
+    finally:
+        import _winapi
+
+        _winapi.CloseHandle(ev)
+
+    # a fresh completion port delivers posted packets in order
+    port = _overlapped.CreateIoCompletionPort(
+        _overlapped.INVALID_HANDLE_VALUE, _overlapped.NULL, 0, 0
+    )
+    try:
+        _overlapped.PostQueuedCompletionStatus(port, 1, 2, 3)
+        status = _overlapped.GetQueuedCompletionStatus(port, 0)
+        assert status == (0, 1, 2, 3), status
+        # an empty port times out with None
+        assert _overlapped.GetQueuedCompletionStatus(port, 0) is None
+    finally:
+        import _winapi
+
+        _winapi.CloseHandle(port)
+
+    # a fresh Overlapped has no operation attached
+    ov = _overlapped.Overlapped(_overlapped.INVALID_HANDLE_VALUE)
+    assert not ov.pending
+    assert ov.error == 0
+    # no operation was started yet
+    assert_raises(ValueError, ov.getresult)
+
+    # the proactor event loop is built on this module end to end
+    import asyncio
+
+    async def answer():
+        return 42
+
+    loop = asyncio.ProactorEventLoop()
+    try:
+        assert loop.run_until_complete(answer()) == 42
+    finally:
+        loop.close()
@@ -171,3 +171,132 @@ for exc, expected_name in [
 
 # assert socket.timeout.__module__ == "builtins"
 # assert socket.timeout.__name__ == "TimeoutError"
+
+# sendmsg/recvmsg/recvmsg_into with SCM_RIGHTS fd passing over AF_UNIX
+if hasattr(socket, "AF_UNIX"):
+    import array
+
+    left, right = socket.socketpair(socket.AF_UNIX, socket.SOCK_STREAM)
+    with left, right:
+        rd, wr = os.pipe()
+        fds = array.array("i", [rd])
+        left.sendmsg([b"with-fd"], [(socket.SOL_SOCKET, socket.SCM_RIGHTS, fds)])
+        data, ancdata, msg_flags, _addr = right.recvmsg(
+            16, socket.CMSG_LEN(fds.itemsize)
+        )
+        assert data == b"with-fd"
+        assert len(ancdata) == 1
+        level, typ, cmsg_data = ancdata[0]
+        assert level == socket.SOL_SOCKET
+        assert typ == socket.SCM_RIGHTS
+        received = array.array("i")
+        received.frombytes(cmsg_data[: fds.itemsize])
+        passed_fd = received[0]
+        assert passed_fd != rd
+        # the passed descriptor refers to the same pipe
+        os.write(wr, b"through the pipe")
+        assert os.read(passed_fd, 16) == b"through the pipe"
+        os.close(passed_fd)
+        os.close(rd)
+        os.close(wr)
+
+        # recvmsg_into scatters into caller-provided buffers
+        left.sendmsg([b"abc", b"defgh"])
+        b1, b2 = bytearray(3), bytearray(8)
+        nbytes, ancdata, msg_flags, _addr = right.recvmsg_into([b1, b2])
+        assert nbytes == 8
+        assert ancdata == []
+        assert bytes(b1) == b"abc"
+        assert bytes(b2[: nbytes - len(b1)]) == b"defgh"
+
+# Linux abstract-namespace AF_UNIX addresses (leading NUL byte)
+import sys
+
+if sys.platform == "linux":
+    NAME = b"\0rustpython-abstract-socket-test"
+    with socket.socket(socket.AF_UNIX, socket.SOCK_STREAM) as srv:
+        srv.bind(NAME)
+        assert srv.getsockname() == NAME
+        srv.listen(1)
+        with socket.socket(socket.AF_UNIX, socket.SOCK_STREAM) as cli:
+            cli.connect(NAME)
+            conn, _ = srv.accept()
+            with conn:
+                cli.send(b"abstract")
+                assert conn.recv(8) == b"abstract"
+
+# struct-valued socket options round-trip through bytes setsockopt and
+# optlen-aware getsockopt
+import struct
+
+with socket.socket(socket.AF_INET, socket.SOCK_STREAM) as s:
+    s.setsockopt(socket.SOL_SOCKET, socket.SO_LINGER, struct.pack("ii", 1, 5))
+    onoff, linger = struct.unpack(
+        "ii", s.getsockopt(socket.SOL_SOCKET, socket.SO_LINGER, 8)
+    )
+    assert onoff != 0
+    assert linger == 5
+    if sys.platform == "linux":
+        s.setsockopt(socket.IPPROTO_TCP, socket.TCP_KEEPIDLE, 42)
+        assert s.getsockopt(socket.IPPROTO_TCP, socket.TCP_KEEPIDLE) == 42
+
+with socket.socket(socket.AF_INET, socket.SOCK_DGRAM) as s:
+    # multicast membership options take a packed ip_mreq
+    mreq = struct.pack(
+        "4s4s", socket.inet_aton("224.0.0.250"), socket.inet_aton("0.0.0.0")
+    )
+    try:
+        s.setsockopt(socket.IPPROTO_IP, socket.IP_ADD_MEMBERSHIP, mreq)
+        s.setsockopt(socket.IPPROTO_IP, socket.IP_DROP_MEMBERSHIP, mreq)
+    except OSError:
+        # no multicast-capable interface available
+        pass
+
+if sys.platform == "linux":
+    with socket.socket(socket.AF_INET, socket.SOCK_STREAM) as s:
+        try:
+            s.setsockopt(socket.SOL_SOCKET, socket.SO_BINDTODEVICE, b"lo\0")
+        except PermissionError:
+            # requires CAP_NET_RAW
+            pass
+        else:
+            name = s.getsockopt(socket.SOL_SOCKET, socket.SO_BINDTODEVICE, 16)
+            assert name.rstrip(b"\0") == b"lo"
+
+# getaddrinfo flag handling and IPv6 scope-ids, all resolvable offline
+res = socket.getaddrinfo(
+    "127.0.0.1",
+    "80",
+    socket.AF_INET,
+    socket.SOCK_STREAM,
+    0,
+    socket.AI_NUMERICHOST,
+)
+assert res[0][0] == socket.AF_INET
+assert res[0][4] == ("127.0.0.1", 80)
+
+# AI_NUMERICHOST means no resolution happens, so names must fail
+with assert_raises(socket.gaierror):
+    socket.getaddrinfo("localhost.invalid", 80, flags=socket.AI_NUMERICHOST)
+
+# AI_PASSIVE with no host yields the wildcard address
+res = socket.getaddrinfo(
+    None, 8080, socket.AF_INET, socket.SOCK_STREAM, 0, socket.AI_PASSIVE
+)
+assert res[0][4] == ("0.0.0.0", 8080)
+
+if socket.has_ipv6 and sys.platform == "linux":
+    # a link-local zone suffix parses and lands in the sockaddr scope-id
+    (*_, sockaddr) = socket.getaddrinfo(
+        "fe80::1%lo", 1234, socket.AF_INET6, socket.SOCK_STREAM, 0, socket.AI_NUMERICHOST
+    )[0]
+    host, port, flowinfo, scope_id = sockaddr
+    assert port == 1234
+    assert scope_id == socket.if_nametoindex("lo")
+
+# getnameinfo with numeric flags is a pure formatting operation
+name, service = socket.getnameinfo(
+    ("127.0.0.1", 80), socket.NI_NUMERICHOST | socket.NI_NUMERICSERV
+)
+assert name == "127.0.0.1"
+assert service == "80"
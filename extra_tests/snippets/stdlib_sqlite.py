@@ -53,3 +53,60 @@ class AggrText:
 cx.create_aggregate("aggtxt", 1, AggrText)
 cur.execute("select aggtxt(key) from foo")
 assert cur.fetchone()[0] == "341011"
+
+# named parameter binding
+cur.execute("SELECT key FROM foo WHERE key = :wanted", {"wanted": 10})
+assert cur.fetchall() == [(10,)]
+
+# scalar user-defined functions
+cx.create_function("twice", 1, lambda x: x * 2)
+cur.execute("SELECT twice(key) FROM foo WHERE key = 4")
+assert cur.fetchone() == (8,)
+
+# a UDF raising propagates as OperationalError
+def boom(_x):
+    raise ValueError("nope")
+
+cx.create_function("boom", 1, boom)
+try:
+    cur.execute("SELECT boom(key) FROM foo")
+    cur.fetchall()
+except sqlite.OperationalError:
+    pass
+else:
+    raise AssertionError("expected OperationalError from failing UDF")
+
+# row factories
+cx.row_factory = sqlite.Row
+row = cx.execute("SELECT key FROM foo WHERE key = 3").fetchone()
+assert row["key"] == 3
+assert row[0] == 3
+assert row.keys() == ["key"]
+cx.row_factory = None
+
+# explicit transactions: an uncommitted insert rolls back
+assert cx.isolation_level == ""
+cx.execute("INSERT INTO foo(key) VALUES (99)")
+cx.rollback()
+assert cx.execute("SELECT count(*) FROM foo WHERE key = 99").fetchone()[0] == 0
+cx.execute("INSERT INTO foo(key) VALUES (99)")
+cx.commit()
+assert cx.execute("SELECT count(*) FROM foo WHERE key = 99").fetchone()[0] == 1
+
+# autocommit mode runs without open transactions
+ac = sqlite.connect(":memory:", isolation_level=None)
+ac.execute("CREATE TABLE t(v)")
+ac.execute("INSERT INTO t(v) VALUES (1)")
+assert not ac.in_transaction
+ac.close()
+
+# incremental blob I/O
+cx.execute("CREATE TABLE blobs(b BLOB)")
+cx.execute("INSERT INTO blobs(b) VALUES (zeroblob(8))")
+rowid = cx.execute("SELECT rowid FROM blobs").fetchone()[0]
+with cx.blobopen("blobs", "b", rowid) as blob:
+    assert len(blob) == 8
+    blob.write(b"ratatosk")
+    blob.seek(0)
+    assert blob.read() == b"ratatosk"
+assert cx.execute("SELECT b FROM blobs").fetchone()[0] == b"ratatosk"
@@ -72,3 +72,76 @@ assert stdout.strip() == b"test"
 p = subprocess.Popen(sleep(5), stdout=subprocess.PIPE)
 with assert_raises(subprocess.TimeoutExpired):
     p.communicate(timeout=1)
+
+# fork_exec details: cwd/env handling, close_fds/pass_fds, start_new_session,
+# and restore_signals
+if is_unix:
+    import os
+
+    out = subprocess.check_output(["pwd"], cwd="/")
+    assert out.strip() == b"/"
+
+    out = subprocess.check_output(
+        [sys.executable, "-c", "import os; print(os.environ['RP_SUBPROC'])"],
+        env={"RP_SUBPROC": "yes", "PATH": os.environ.get("PATH", "")},
+    )
+    assert out.strip() == b"yes"
+
+    # close_fds leaves only the standard descriptors and pass_fds open
+    r, w = os.pipe()
+    try:
+        os.set_inheritable(w, True)
+        probe = "import os\n" + "print(os.path.exists('/proc/self/fd/%d'))" % w
+        out = subprocess.check_output([sys.executable, "-c", probe], close_fds=True)
+        assert out.strip() == b"False"
+        out = subprocess.check_output(
+            [sys.executable, "-c", probe], close_fds=True, pass_fds=(w,)
+        )
+        assert out.strip() == b"True"
+    finally:
+        os.close(r)
+        os.close(w)
+
+    # start_new_session puts the child in its own session
+    out = subprocess.check_output(
+        [sys.executable, "-c", "import os; print(os.getsid(0) == os.getpid())"],
+        start_new_session=True,
+    )
+    assert out.strip() == b"True"
+
+    # restore_signals resets an ignored SIGPIPE in the child: writing to a
+    # closed pipe then kills it, while restore_signals=False inherits SIG_IGN
+    old = signal.signal(signal.SIGPIPE, signal.SIG_IGN)
+    try:
+        p = subprocess.Popen(["yes"], stdout=subprocess.PIPE)
+        p.stdout.close()
+        assert p.wait() == -signal.SIGPIPE
+        p = subprocess.Popen(
+            ["yes"],
+            stdout=subprocess.PIPE,
+            stderr=subprocess.DEVNULL,
+            restore_signals=False,
+        )
+        p.stdout.close()
+        assert p.wait() == 1
+    finally:
+        signal.signal(signal.SIGPIPE, old)
+
+# Windows: process groups, CTRL_BREAK delivery, and handle inheritance lists
+if not is_unix:
+    # a child in its own process group receives CTRL_BREAK without
+    # interrupting the parent console
+    p = subprocess.Popen(
+        sleep(5), creationflags=subprocess.CREATE_NEW_PROCESS_GROUP
+    )
+    p.send_signal(signal.CTRL_BREAK_EVENT)
+    assert p.wait() != 0
+
+    # close_fds with explicit standard handles goes through the
+    # STARTUPINFOEX handle list
+    p = subprocess.Popen(
+        echo("handles"), stdout=subprocess.PIPE, close_fds=True
+    )
+    out, _ = p.communicate()
+    assert out.strip() == b"handles"
+    assert p.returncode == 0
@@ -62,3 +62,63 @@ for text in compressed_lorem_list:
 
 assert_raises(zlib.error, lambda: zlib.compress(b"123", -40))
 assert_raises(zlib.error, lambda: zlib.compress(b"123", 10))
+
+# wbits variants select the container: zlib, raw deflate, or gzip
+payload = lorem * 100
+zstream = zlib.compress(payload, wbits=15)
+assert zlib.decompress(zstream, wbits=15) == payload
+
+raw = zlib.compress(payload, wbits=-15)
+assert raw[:1] != b"\x78"
+assert zlib.decompress(raw, wbits=-15) == payload
+
+gz = zlib.compress(payload, wbits=31)
+assert gz[:2] == b"\x1f\x8b"
+assert zlib.decompress(gz, wbits=31) == payload
+# 25..31 all produce gzip containers with different window sizes
+assert zlib.decompress(zlib.compress(payload, wbits=25), wbits=31) == payload
+
+# preset dictionaries must match on both sides
+zdict = b"Lorem ipsum dolor sit amet"
+co = zlib.compressobj(zdict=zdict)
+with_dict = co.compress(payload) + co.flush()
+do = zlib.decompressobj(zdict=zdict)
+assert do.decompress(with_dict) + do.flush() == payload
+# without the dictionary the stream is rejected
+assert_raises(zlib.error, lambda: zlib.decompress(with_dict))
+
+# raw streams set the dictionary up front
+co = zlib.compressobj(wbits=-15, zdict=zdict)
+raw_dict = co.compress(payload) + co.flush()
+do = zlib.decompressobj(wbits=-15, zdict=zdict)
+assert do.decompress(raw_dict) + do.flush() == payload
+
+# sync/full flush emit complete blocks mid-stream
+co = zlib.compressobj()
+part1 = co.compress(payload[:1000]) + co.flush(zlib.Z_SYNC_FLUSH)
+part2 = co.compress(payload[1000:2000]) + co.flush(zlib.Z_FULL_FLUSH)
+part3 = co.compress(payload[2000:]) + co.flush(zlib.Z_FINISH)
+assert zlib.decompress(part1 + part2 + part3) == payload
+# a sync-flushed prefix decompresses on its own
+do = zlib.decompressobj()
+assert do.decompress(part1) == payload[:1000]
+
+# unconsumed_tail and eof track streaming state
+do = zlib.decompressobj()
+first = do.decompress(zstream, 10)
+assert len(first) == 10
+assert not do.eof
+rest = do.decompress(do.unconsumed_tail)
+assert do.unconsumed_tail == b""
+assert first + rest == payload
+assert do.eof
+
+# trailing bytes after the stream end up in unused_data
+do = zlib.decompressobj()
+assert do.decompress(zstream + b"garnish") == payload
+assert do.unused_data == b"garnish"
+assert do.eof
+
+# large buffers round-trip intact
+big = bytes(range(256)) * (1 << 14)  # 4 MiB
+assert zlib.decompress(zlib.compress(big, 1)) == big
@@ -0,0 +1,125 @@
+import hashlib
+import io
+
+from compression import zstd
+from testutils import assert_raises
+
+data = b"the quick brown fox jumps over the lazy dog" * 64
+
+# one-shot frame round trip; zstd frames start with the magic number
+compressed = zstd.compress(data)
+assert compressed[:4] == b"\x28\xb5\x2f\xfd"
+assert zstd.decompress(compressed) == data
+assert len(compressed) < len(data)
+
+# frame header introspection
+info = zstd.get_frame_info(compressed)
+assert info.decompressed_size == len(data)
+assert info.dictionary_id == 0
+assert zstd.get_frame_size(compressed) == len(compressed)
+
+assert zstd.COMPRESSION_LEVEL_DEFAULT == 3
+assert len(zstd.zstd_version_info) == 3
+assert zstd.zstd_version_info >= (1, 4, 5)
+
+# higher levels trade speed for density; both must round trip
+smaller = zstd.compress(data, level=19)
+assert zstd.decompress(smaller) == data
+assert len(smaller) <= len(compressed)
+
+# streaming compressor: FLUSH_BLOCK makes the data decodable mid-stream,
+# FLUSH_FRAME ends the frame
+comp = zstd.ZstdCompressor()
+assert comp.last_mode == zstd.ZstdCompressor.CONTINUE
+parts = [comp.compress(data[i : i + 100]) for i in range(0, len(data), 100)]
+parts.append(comp.flush(zstd.ZstdCompressor.FLUSH_BLOCK))
+assert comp.last_mode == zstd.ZstdCompressor.FLUSH_BLOCK
+parts.append(comp.flush())
+assert comp.last_mode == zstd.ZstdCompressor.FLUSH_FRAME
+stream = b"".join(parts)
+assert zstd.decompress(stream) == data
+
+# the same compressor object can emit further frames
+second = comp.compress(b"tail", zstd.ZstdCompressor.FLUSH_FRAME)
+assert zstd.decompress(second) == b"tail"
+# module-level decompress handles concatenated frames
+assert zstd.decompress(stream + second) == data + b"tail"
+
+with assert_raises(ValueError):
+    comp.compress(b"x", mode=17)
+
+# streaming decompressor with max_length and unused_data
+decomp = zstd.ZstdDecompressor()
+first = decomp.decompress(stream, max_length=10)
+assert len(first) == 10
+assert not decomp.needs_input
+assert not decomp.eof
+rest = decomp.decompress(b"")
+assert first + rest == data
+assert decomp.eof
+assert decomp.unused_data == b""
+
+# data past the end of the frame is preserved as unused_data
+decomp = zstd.ZstdDecompressor()
+out = decomp.decompress(stream + b"garnish")
+assert out == data
+assert decomp.eof
+assert decomp.unused_data == b"garnish"
+
+# truncated input leaves the decompressor waiting for more
+decomp = zstd.ZstdDecompressor()
+decomp.decompress(stream[:20])
+assert decomp.needs_input
+assert not decomp.eof
+
+# corrupt input raises ZstdError
+with assert_raises(zstd.ZstdError):
+    zstd.decompress(b"\x28\xb5\x2f\xfd" + b"\xff" * 16)
+
+# dictionary training and dictionary-aware round trips
+samples = [
+    hashlib.sha256(i.to_bytes(2)).digest() * 4 + b"common trailer for every sample"
+    for i in range(300)
+]
+trained = zstd.train_dict(samples, 1024)
+assert isinstance(trained, zstd.ZstdDict)
+assert trained.dict_id != 0
+assert len(trained.dict_content) <= 1024
+
+dict_compressed = zstd.compress(data, zstd_dict=trained)
+assert zstd.get_frame_info(dict_compressed).dictionary_id == trained.dict_id
+assert zstd.decompress(dict_compressed, zstd_dict=trained) == data
+# the dictionary is required once a frame references it
+with assert_raises(zstd.ZstdError):
+    zstd.decompress(dict_compressed)
+
+# raw content can serve as a dictionary or prefix
+raw = zstd.ZstdDict(data[:64], is_raw=True)
+assert raw.dict_id == 0
+prefixed = zstd.compress(data, zstd_dict=raw.as_prefix)
+assert len(prefixed) > 0
+
+# compression options dict and parameter bounds
+options = {
+    zstd.CompressionParameter.compression_level: 7,
+    zstd.CompressionParameter.checksum_flag: 1,
+}
+tuned = zstd.compress(data, options=options)
+assert zstd.decompress(tuned) == data
+
+lo, hi = zstd.CompressionParameter.compression_level.bounds()
+assert lo < 0 < hi
+lo, hi = zstd.DecompressionParameter.window_log_max.bounds()
+assert lo <= hi
+assert zstd.Strategy.fast < zstd.Strategy.btultra2
+
+with assert_raises(zstd.ZstdError):
+    zstd.compress(data, options={zstd.CompressionParameter.window_log: 10**6})
+
+# ZstdFile wraps the streaming classes for file-like access
+buf = io.BytesIO()
+with zstd.ZstdFile(buf, "w") as f:
+    f.write(data)
+buf.seek(0)
+with zstd.ZstdFile(buf, "r") as f:
+    assert f.read() == data
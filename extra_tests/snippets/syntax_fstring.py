@@ -172,3 +172,24 @@ self.assertEqual(f'X{x  =  }Y', 'Xx  =  '+repr(x)+'Y')
 # self.assertEqual(f'X{x  =}Y', 'Xx  ='+x+'Y')
 # self.assertEqual(f'X{x=  }Y', 'Xx=  '+x+'Y')
 # self.assertEqual(f'X{x  =  }Y', 'Xx  =  '+x+'Y')
+
+# PEP 701: the quote character of the f-string may be reused inside
+# replacement fields, nested arbitrarily deep.
+d = {'a': 1, 'b': 2}
+assert f"{d['a']}" == '1'
+assert f"{f"{f"{d['b']}"}"}" == '2'
+
+# PEP 701: backslashes are allowed inside replacement fields.
+names = ['alpha', 'beta']
+assert f"{'\n'.join(names)}" == 'alpha\nbeta'
+assert f"{'\N{PLUS SIGN}'}" == '+'
+
+# PEP 701: replacement fields may span multiple lines and contain comments.
+assert f"{1 +
+          1  # a comment inside the field
+         }" == '2'
+
+# The multi-line debug-text case from above now works too.
+assert f'''{
+3
+=}''' == '\n3\n=3'
@@ -0,0 +1,44 @@
+#!/usr/bin/env python3
+
+"""
+Generate the character decomposition table for the unicodedata module.
+
+Reads the decomposition mapping (UnicodeData.txt field 5, including the
+<compatibility> tags) out of the host CPython's unicodedata module and
+writes it as a sorted Rust array for binary search.
+
+Usage: python scripts/generate_unicodedata_decomp.py \
+    crates/stdlib/src/unicodedata/decomposition_data.rs
+"""
+
+import argparse
+import sys
+import unicodedata
+
+parser = argparse.ArgumentParser(description=__doc__)
+parser.add_argument("output", nargs="?", default=None)
+
+
+def main():
+    args = parser.parse_args()
+    out = open(args.output, "w", encoding="utf-8") if args.output else sys.stdout
+
+    print("// File generated by scripts/generate_unicodedata_decomp.py", file=out)
+    print(f"// Source: CPython unicodedata, UCD {unicodedata.unidata_version}", file=out)
+    print("// spell-checker: disable", file=out)
+    print(file=out)
+    print("/// Decomposition mappings (UnicodeData.txt field 5), sorted by", file=out)
+    print("/// code point for binary search.", file=out)
+    print("pub(crate) static DECOMPOSITIONS: &[(u32, &str)] = &[", file=out)
+    for cp in range(0x110000):
+        decomp = unicodedata.decomposition(chr(cp))
+        if decomp:
+            print(f'    (0x{cp:04X}, "{decomp}"),', file=out)
+    print("];", file=out)
+
+    if args.output:
+        out.close()
+
+
+if __name__ == "__main__":
+    main()
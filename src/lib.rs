@@ -153,6 +153,85 @@ fn install_pip(installer: InstallPipMode, scope: Scope, vm: &VirtualMachine) ->
     }
 }
 
+/// Compile every `.py` file under `dir` into a frozen module library, written to
+/// `output` (`frozen.bin` by default). The file can be embedded in a binary with
+/// `include_bytes!` and loaded via `FrozenLib::from_ref(..).decode()` into
+/// [`InterpreterBuilder::add_frozen_modules`], so applications can ship user
+/// packages without a Lib directory.
+fn freeze_dir(vm: &VirtualMachine, dir: &str, output: Option<&str>) -> PyResult<()> {
+    use vm::frozen::{FrozenCodeObject, FrozenLib, FrozenModule};
+
+    // Mirrors the module naming of the `py_freeze!` macro: `a/b/__init__.py`
+    // freezes as package `a.b`, `a/b/c.py` as module `a.b.c`.
+    fn compile_dir(
+        vm: &VirtualMachine,
+        path: &std::path::Path,
+        parent: &str,
+        modules: &mut Vec<(String, FrozenModule<Vec<u8>>)>,
+    ) -> PyResult<()> {
+        let entries = std::fs::read_dir(path).map_err(|err| vm.new_os_error(err.to_string()))?;
+        for entry in entries {
+            let entry = entry.map_err(|err| vm.new_os_error(err.to_string()))?;
+            let path = entry.path();
+            let Some(file_name) = path.file_name().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            if path.is_dir() {
+                let parent = if parent.is_empty() {
+                    file_name.to_owned()
+                } else {
+                    format!("{parent}.{file_name}")
+                };
+                compile_dir(vm, &path, &parent, modules)?;
+            } else if let Some(stem) = file_name.strip_suffix(".py") {
+                let is_init = stem == "__init__";
+                let module_name = if is_init {
+                    parent.to_owned()
+                } else if parent.is_empty() {
+                    stem.to_owned()
+                } else {
+                    format!("{parent}.{stem}")
+                };
+                let source = std::fs::read_to_string(&path)
+                    .map_err(|err| vm.new_os_error(err.to_string()))?;
+                let code = vm
+                    .compile(
+                        &source,
+                        vm::compiler::Mode::Exec,
+                        path.to_string_lossy().into_owned(),
+                    )
+                    .map_err(|err| vm.new_syntax_error(&err, Some(&source)))?;
+                modules.push((
+                    module_name,
+                    FrozenModule {
+                        code: FrozenCodeObject::encode(&code.code),
+                        package: is_init,
+                    },
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    let mut modules = Vec::new();
+    compile_dir(vm, std::path::Path::new(dir), "", &mut modules)?;
+    let lib = FrozenLib::encode(modules.iter().map(|(name, module)| {
+        (
+            name.as_str(),
+            FrozenModule {
+                code: FrozenCodeObject {
+                    bytes: &module.code.bytes[..],
+                },
+                package: module.package,
+            },
+        )
+    }));
+    let output = output.unwrap_or("frozen.bin");
+    std::fs::write(output, &lib.bytes).map_err(|err| vm.new_os_error(err.to_string()))?;
+    println!("froze {} modules from {dir} into {output}", modules.len());
+    Ok(())
+}
+
 // pymain_run_file_obj in Modules/main.c
 fn run_file(vm: &VirtualMachine, scope: Scope, path: &str) -> PyResult<()> {
     // Check if path is a package/directory with __main__.py
@@ -236,12 +315,16 @@ fn run_rustpython(vm: &VirtualMachine, run_mode: RunMode) -> PyResult<()> {
     // Import site first, before setting sys.path[0]
     // This matches CPython's behavior where site.removeduppaths() runs
     // before sys.path[0] is set, preventing '' from being converted to cwd
-    let site_result = vm.import("site", 0);
-    if site_result.is_err() {
-        warn!(
-            "Failed to import site, consider adding the Lib directory to your RUSTPYTHONPATH \
-             environment variable",
-        );
+    // Skipped entirely with -S; site.py itself honors -s for user site dirs
+    // and processes .pth files and sitecustomize/usercustomize on import.
+    if vm.state.config.settings.import_site {
+        let site_result = vm.import("site", 0);
+        if site_result.is_err() {
+            warn!(
+                "Failed to import site, consider adding the Lib directory to your RUSTPYTHONPATH \
+                 environment variable",
+            );
+        }
     }
 
     // _PyPathConfig_ComputeSysPath0 - set sys.path[0] after site import
@@ -252,6 +335,7 @@ fn run_rustpython(vm: &VirtualMachine, run_mode: RunMode) -> PyResult<()> {
                 .ok()
                 .and_then(|p| p.to_str().map(|s| s.to_owned())),
             RunMode::Script(_) | RunMode::InstallPip(_) => None, // handled by run_script
+            RunMode::FreezeDir { .. } => None,
             RunMode::Repl => Some(String::new()),
         };
 
@@ -294,6 +378,7 @@ fn run_rustpython(vm: &VirtualMachine, run_mode: RunMode) -> PyResult<()> {
             vm.run_module(&module)
         }
         RunMode::InstallPip(installer) => install_pip(installer, scope.clone(), vm),
+        RunMode::FreezeDir { dir, output } => freeze_dir(vm, &dir, output.as_deref()),
         RunMode::Script(script_path) => {
             // pymain_run_file_obj
             debug!("Running script {}", &script_path);
@@ -9,6 +9,7 @@ pub enum RunMode {
     Command(String),
     Module(String),
     InstallPip(InstallPipMode),
+    FreezeDir { dir: String, output: Option<String> },
     Repl,
 }
 
@@ -100,6 +101,10 @@ Options (and corresponding environment variables):
 --help-all: print complete help information and exit
 
 RustPython extensions:
+--freeze-dir dir [output]:
+         compile the .py files under dir into a frozen module library
+         (written to output, frozen.bin by default) and exit; embed it with
+         InterpreterBuilder::add_frozen_modules
 
 
 Arguments:
@@ -177,6 +182,15 @@ fn parse_args() -> Result<(CliArgs, RunMode, Vec<String>), lexopt::Error> {
                 };
                 return Ok((args, RunMode::InstallPip(mode), argv));
             }
+            Long("freeze-dir") => {
+                let dir = parser.value()?.string()?;
+                let output = parser
+                    .raw_args()?
+                    .next()
+                    .map(|arg| arg.string())
+                    .transpose()?;
+                return Ok((args, RunMode::FreezeDir { dir, output }, vec![]));
+            }
             Value(script_name) => {
                 let script_name = script_name.string()?;
                 let mode = if script_name == "-" {
@@ -209,6 +223,9 @@ pub fn parse_opts() -> Result<(Settings, RunMode), lexopt::Error> {
     let (args, mode, argv) = parse_args()?;
 
     let mut settings = Settings::default();
+    // Record the raw command line before any option processing; multiprocessing
+    // spawn and venv launchers rebuild the interpreter invocation from it.
+    settings.orig_argv = env::args().collect();
     settings.isolated = args.isolate;
     settings.ignore_environment = settings.isolated || args.ignore_environment;
     settings.bytes_warning = args.bytes_warning.into();
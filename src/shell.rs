@@ -105,22 +105,38 @@ fn shell_exec(
     }
 }
 
+fn save_history(repl: &mut Readline<helper::ShellHelper<'_>>, path: Option<&std::path::Path>) {
+    if let Some(path) = path
+        && let Err(err) = repl.save_history(path)
+    {
+        eprintln!("Failed to save history: {err}");
+    }
+}
+
 /// Enter a repl loop
 pub fn run_shell(vm: &VirtualMachine, scope: Scope) -> PyResult<()> {
     let mut repl = Readline::new(helper::ShellHelper::new(vm, scope.globals.clone()));
     let mut full_input = String::new();
 
-    // Retrieve a `history_path_str` dependent on the OS
-    let repl_history_path = match dirs::config_dir() {
-        Some(mut path) => {
-            path.push("rustpython");
-            path.push("repl_history.txt");
-            path
-        }
-        None => ".repl_history.txt".into(),
+    // PYTHON_HISTORY overrides where the history file lives (like CPython's
+    // new REPL); otherwise it goes in the OS config directory. Setting it to
+    // an empty string disables persistent history entirely.
+    let repl_history_path = match std::env::var_os("PYTHON_HISTORY") {
+        Some(path) if !path.is_empty() => Some(std::path::PathBuf::from(path)),
+        Some(_) => None,
+        None => Some(match dirs::config_dir() {
+            Some(mut path) => {
+                path.push("rustpython");
+                path.push("repl_history.txt");
+                path
+            }
+            None => ".repl_history.txt".into(),
+        }),
     };
 
-    if repl.load_history(&repl_history_path).is_err() {
+    if let Some(path) = &repl_history_path
+        && repl.load_history(path).is_err()
+    {
         println!("No previous history.");
     }
 
@@ -228,13 +244,13 @@ pub fn run_shell(vm: &VirtualMachine, scope: Scope) -> PyResult<()> {
 
         if let Err(exc) = result {
             if exc.fast_isinstance(vm.ctx.exceptions.system_exit) {
-                repl.save_history(&repl_history_path).unwrap();
+                save_history(&mut repl, repl_history_path.as_deref());
                 return Err(exc);
             }
             vm.print_exception(exc);
         }
     }
-    repl.save_history(&repl_history_path).unwrap();
+    save_history(&mut repl, repl_history_path.as_deref());
 
     Ok(())
 }